@@ -27,6 +27,8 @@ pub mod exports {
                     MagicAttack,
                     /// Shield bash (stuns enemies).
                     ShieldBash,
+                    /// Wide swing hitting every enemy in front of the player.
+                    SwingArc,
                 }
                 impl ::core::fmt::Debug for AttackType {
                     fn fmt(
@@ -49,6 +51,9 @@ pub mod exports {
                             AttackType::ShieldBash => {
                                 f.debug_tuple("AttackType::ShieldBash").finish()
                             }
+                            AttackType::SwingArc => {
+                                f.debug_tuple("AttackType::SwingArc").finish()
+                            }
                         }
                     }
                 }
@@ -64,10 +69,191 @@ pub mod exports {
                             2 => AttackType::BowShot,
                             3 => AttackType::MagicAttack,
                             4 => AttackType::ShieldBash,
+                            5 => AttackType::SwingArc,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// 2D position on the map.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Position {
+                    /// X coordinate (horizontal position).
+                    pub x: i32,
+                    /// Y coordinate (vertical position).
+                    pub y: i32,
+                }
+                impl ::core::fmt::Debug for Position {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Position")
+                            .field("x", &self.x)
+                            .field("y", &self.y)
+                            .finish()
+                    }
+                }
+                /// Direction the player faces.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum Direction {
+                    /// Facing upward (north).
+                    North,
+                    /// Facing downward (south).
+                    South,
+                    /// Facing leftward (west).
+                    West,
+                    /// Facing rightward (east).
+                    East,
+                }
+                impl ::core::fmt::Debug for Direction {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Direction::North => {
+                                f.debug_tuple("Direction::North").finish()
+                            }
+                            Direction::South => {
+                                f.debug_tuple("Direction::South").finish()
+                            }
+                            Direction::West => f.debug_tuple("Direction::West").finish(),
+                            Direction::East => f.debug_tuple("Direction::East").finish(),
+                        }
+                    }
+                }
+                impl Direction {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Direction {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => Direction::North,
+                            1 => Direction::South,
+                            2 => Direction::West,
+                            3 => Direction::East,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Lasting debuff applied to the player.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum StatusEffect {
+                    /// Deals damage each turn, growing with level.
+                    Poison,
+                    /// Deals a small fixed amount of damage each turn.
+                    Burn,
+                    /// Skips the player's action.
+                    Stun,
+                    /// Prevents movement.
+                    Freeze,
+                }
+                impl ::core::fmt::Debug for StatusEffect {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            StatusEffect::Poison => {
+                                f.debug_tuple("StatusEffect::Poison").finish()
+                            }
+                            StatusEffect::Burn => {
+                                f.debug_tuple("StatusEffect::Burn").finish()
+                            }
+                            StatusEffect::Stun => {
+                                f.debug_tuple("StatusEffect::Stun").finish()
+                            }
+                            StatusEffect::Freeze => {
+                                f.debug_tuple("StatusEffect::Freeze").finish()
+                            }
+                        }
+                    }
+                }
+                impl StatusEffect {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> StatusEffect {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => StatusEffect::Poison,
+                            1 => StatusEffect::Burn,
+                            2 => StatusEffect::Stun,
+                            3 => StatusEffect::Freeze,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Elemental affinity of an attack.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum Element {
+                    /// Plain physical damage.
+                    None,
+                    /// Fire damage.
+                    Fire,
+                    /// Ice damage.
+                    Ice,
+                    /// Lightning damage.
+                    Lightning,
+                }
+                impl ::core::fmt::Debug for Element {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Element::None => f.debug_tuple("Element::None").finish(),
+                            Element::Fire => f.debug_tuple("Element::Fire").finish(),
+                            Element::Ice => f.debug_tuple("Element::Ice").finish(),
+                            Element::Lightning => {
+                                f.debug_tuple("Element::Lightning").finish()
+                            }
+                        }
+                    }
+                }
+                impl Element {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Element {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => Element::None,
+                            1 => Element::Fire,
+                            2 => Element::Ice,
+                            3 => Element::Lightning,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
                 }
+                /// An enemy as seen by area attacks.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct EnemyState {
+                    /// Current position in the world.
+                    pub pos: Position,
+                    /// Current health points.
+                    pub health: u32,
+                    /// Whether the enemy is currently alive.
+                    pub is_alive: bool,
+                }
+                impl ::core::fmt::Debug for EnemyState {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("EnemyState")
+                            .field("pos", &self.pos)
+                            .field("health", &self.health)
+                            .field("is-alive", &self.is_alive)
+                            .finish()
+                    }
+                }
                 /// Result of a combat action.
                 #[derive(Clone)]
                 pub struct CombatResult {
@@ -81,6 +267,10 @@ pub mod exports {
                     pub exp_gained: u32,
                     /// Combat message/description.
                     pub message: _rt::String,
+                    /// Effect tags triggered by the attack, such as enchantments.
+                    pub tags: _rt::Vec<_rt::String>,
+                    /// Damage reflected back onto the attacker.
+                    pub reflected_damage: u32,
                 }
                 impl ::core::fmt::Debug for CombatResult {
                     fn fmt(
@@ -93,9 +283,44 @@ pub mod exports {
                             .field("target-defeated", &self.target_defeated)
                             .field("exp-gained", &self.exp_gained)
                             .field("message", &self.message)
+                            .field("tags", &self.tags)
+                            .field("reflected-damage", &self.reflected_damage)
                             .finish()
                     }
                 }
+                /// Weapon enchantment effects applied in combat.
+                #[derive(Clone, Copy)]
+                pub enum Enchantment {
+                    /// Adds fire damage to the attack.
+                    FireDamage(u32),
+                    /// Slows the target.
+                    FrostSlow,
+                    /// Heals the attacker by the given amount.
+                    LifeSteal(u32),
+                    /// Strikes from further away.
+                    ExtraReach,
+                }
+                impl ::core::fmt::Debug for Enchantment {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Enchantment::FireDamage(e) => {
+                                f.debug_tuple("Enchantment::FireDamage").field(e).finish()
+                            }
+                            Enchantment::FrostSlow => {
+                                f.debug_tuple("Enchantment::FrostSlow").finish()
+                            }
+                            Enchantment::LifeSteal(e) => {
+                                f.debug_tuple("Enchantment::LifeSteal").field(e).finish()
+                            }
+                            Enchantment::ExtraReach => {
+                                f.debug_tuple("Enchantment::ExtraReach").finish()
+                            }
+                        }
+                    }
+                }
                 /// Combat statistics for damage calculation.
                 #[repr(C)]
                 #[derive(Clone, Copy)]
@@ -110,6 +335,22 @@ pub mod exports {
                     pub max_health: u32,
                     /// Bonus from equipment.
                     pub equipment_bonus: u32,
+                    /// Current shield points absorbed before health.
+                    pub shield_hp: u32,
+                    /// Maximum shield points.
+                    pub max_shield_hp: u32,
+                    /// Damage reflected back to attackers (3 with a Magic Robe).
+                    pub reflect_damage: u32,
+                    /// Speed deciding initiative and flee success.
+                    pub speed: u32,
+                    /// Critical hit chance in percent (0-100).
+                    pub crit_chance: u32,
+                    /// Fire resistance in percent (100 = immune, negative = weakness).
+                    pub fire_resistance: i32,
+                    /// Ice resistance in percent (100 = immune, negative = weakness).
+                    pub ice_resistance: i32,
+                    /// Lightning resistance in percent (100 = immune, negative = weakness).
+                    pub lightning_resistance: i32,
                 }
                 impl ::core::fmt::Debug for CombatantStats {
                     fn fmt(
@@ -122,12 +363,19 @@ pub mod exports {
                             .field("health", &self.health)
                             .field("max-health", &self.max_health)
                             .field("equipment-bonus", &self.equipment_bonus)
+                            .field("shield-hp", &self.shield_hp)
+                            .field("max-shield-hp", &self.max_shield_hp)
+                            .field("reflect-damage", &self.reflect_damage)
+                            .field("speed", &self.speed)
+                            .field("crit-chance", &self.crit_chance)
+                            .field("fire-resistance", &self.fire_resistance)
+                            .field("ice-resistance", &self.ice_resistance)
+                            .field("lightning-resistance", &self.lightning_resistance)
                             .finish()
                     }
                 }
                 /// Battle state tracking.
-                #[repr(C)]
-                #[derive(Clone, Copy)]
+                #[derive(Clone)]
                 pub struct BattleState {
                     /// Whether a battle is currently active.
                     pub is_active: bool,
@@ -139,6 +387,14 @@ pub mod exports {
                     pub enemy_health: u32,
                     /// Whether it's the player's turn.
                     pub is_player_turn: bool,
+                    /// Whether a summoned ally is fighting alongside the player.
+                    pub summon_ally_active: bool,
+                    /// Summoned ally's remaining health.
+                    pub ally_health: u32,
+                    /// Status effects on the player with their remaining turns.
+                    pub active_effects: _rt::Vec<(StatusEffect, u32)>,
+                    /// Turn-by-turn battle history, keeping the latest 50 entries.
+                    pub log: _rt::Vec<_rt::String>,
                 }
                 impl ::core::fmt::Debug for BattleState {
                     fn fmt(
@@ -151,6 +407,10 @@ pub mod exports {
                             .field("player-health", &self.player_health)
                             .field("enemy-health", &self.enemy_health)
                             .field("is-player-turn", &self.is_player_turn)
+                            .field("summon-ally-active", &self.summon_ally_active)
+                            .field("ally-health", &self.ally_health)
+                            .field("active-effects", &self.active_effects)
+                            .field("log", &self.log)
                             .finish()
                     }
                 }
@@ -172,6 +432,7 @@ pub mod exports {
                 use super::super::super::super::_rt;
                 pub type AttackType = super::super::super::super::exports::docs::combat::types::AttackType;
                 pub type CombatantStats = super::super::super::super::exports::docs::combat::types::CombatantStats;
+                pub type Element = super::super::super::super::exports::docs::combat::types::Element;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_calculate_base_damage_cabi<T: Guest>(
@@ -181,6 +442,14 @@ pub mod exports {
                     arg3: i32,
                     arg4: i32,
                     arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::calculate_base_damage(
@@ -193,6 +462,14 @@ pub mod exports {
                             health: arg3 as u32,
                             max_health: arg4 as u32,
                             equipment_bonus: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            reflect_damage: arg8 as u32,
+                            speed: arg9 as u32,
+                            crit_chance: arg10 as u32,
+                            fire_resistance: arg11,
+                            ice_resistance: arg12,
+                            lightning_resistance: arg13,
                         },
                     );
                     _rt::as_i32(result0)
@@ -209,9 +486,34 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_roll_critical_cabi<T: Guest>(arg0: i32) -> i32 {
+                pub unsafe fn _export_roll_critical_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::roll_critical(
+                        arg0 as u32,
+                        arg1 as u32,
+                        arg2 as u32,
+                        arg3 as u32,
+                    );
+                    _rt::as_i32(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_roll_critical_with_chance_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::roll_critical(arg0 as u32);
+                    let result0 = T::roll_critical_with_chance(
+                        arg0 as u32,
+                        arg1 as u32,
+                        arg2 as u32,
+                    );
                     _rt::as_i32(result0)
                 }
                 #[doc(hidden)]
@@ -226,7 +528,18 @@ pub mod exports {
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_calculate_final_damage_cabi<T: Guest>(
+                pub unsafe fn _export_attack_element_cabi<T: Guest>(arg0: i32) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::attack_element(
+                        super::super::super::super::exports::docs::combat::types::AttackType::_lift(
+                            arg0 as u8,
+                        ),
+                    );
+                    result0.clone() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_element_multiplier_cabi<T: Guest>(
                     arg0: i32,
                     arg1: i32,
                     arg2: i32,
@@ -238,10 +551,13 @@ pub mod exports {
                     arg8: i32,
                     arg9: i32,
                     arg10: i32,
-                ) -> i32 {
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                ) -> f32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::calculate_final_damage(
-                        super::super::super::super::exports::docs::combat::types::AttackType::_lift(
+                    let result0 = T::element_multiplier(
+                        super::super::super::super::exports::docs::combat::types::Element::_lift(
                             arg0 as u8,
                         ),
                         super::super::super::super::exports::docs::combat::types::CombatantStats {
@@ -250,15 +566,96 @@ pub mod exports {
                             health: arg3 as u32,
                             max_health: arg4 as u32,
                             equipment_bonus: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            reflect_damage: arg8 as u32,
+                            speed: arg9 as u32,
+                            crit_chance: arg10 as u32,
+                            fire_resistance: arg11,
+                            ice_resistance: arg12,
+                            lightning_resistance: arg13,
+                        },
+                    );
+                    _rt::as_f32(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calculate_final_damage_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = *arg0.add(44).cast::<i32>();
+                    let l12 = *arg0.add(48).cast::<i32>();
+                    let l13 = *arg0.add(52).cast::<i32>();
+                    let l14 = *arg0.add(56).cast::<i32>();
+                    let l15 = *arg0.add(60).cast::<i32>();
+                    let l16 = *arg0.add(64).cast::<i32>();
+                    let l17 = *arg0.add(68).cast::<i32>();
+                    let l18 = *arg0.add(72).cast::<i32>();
+                    let l19 = *arg0.add(76).cast::<i32>();
+                    let l20 = *arg0.add(80).cast::<i32>();
+                    let l21 = *arg0.add(84).cast::<i32>();
+                    let l22 = *arg0.add(88).cast::<i32>();
+                    let l23 = *arg0.add(92).cast::<i32>();
+                    let l24 = *arg0.add(96).cast::<i32>();
+                    let l25 = *arg0.add(100).cast::<i32>();
+                    let l26 = *arg0.add(104).cast::<i32>();
+                    _rt::cabi_dealloc(arg0, 108, 4);
+                    let result27 = T::calculate_final_damage(
+                        super::super::super::super::exports::docs::combat::types::AttackType::_lift(
+                            l0 as u8,
+                        ),
+                        super::super::super::super::exports::docs::combat::types::CombatantStats {
+                            attack: l1 as u32,
+                            defense: l2 as u32,
+                            health: l3 as u32,
+                            max_health: l4 as u32,
+                            equipment_bonus: l5 as u32,
+                            shield_hp: l6 as u32,
+                            max_shield_hp: l7 as u32,
+                            reflect_damage: l8 as u32,
+                            speed: l9 as u32,
+                            crit_chance: l10 as u32,
+                            fire_resistance: l11,
+                            ice_resistance: l12,
+                            lightning_resistance: l13,
                         },
                         super::super::super::super::exports::docs::combat::types::CombatantStats {
-                            attack: arg6 as u32,
-                            defense: arg7 as u32,
-                            health: arg8 as u32,
-                            max_health: arg9 as u32,
-                            equipment_bonus: arg10 as u32,
+                            attack: l14 as u32,
+                            defense: l15 as u32,
+                            health: l16 as u32,
+                            max_health: l17 as u32,
+                            equipment_bonus: l18 as u32,
+                            shield_hp: l19 as u32,
+                            max_shield_hp: l20 as u32,
+                            reflect_damage: l21 as u32,
+                            speed: l22 as u32,
+                            crit_chance: l23 as u32,
+                            fire_resistance: l24,
+                            ice_resistance: l25,
+                            lightning_resistance: l26,
                         },
                     );
+                    _rt::as_i32(result27)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_armor_reflect_damage_cabi<T: Guest>(
+                    arg0: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::armor_reflect_damage(arg0 as u32);
                     _rt::as_i32(result0)
                 }
                 pub trait Guest {
@@ -269,16 +666,43 @@ pub mod exports {
                     ) -> u32;
                     /// Apply defense reduction to damage.
                     fn apply_defense(raw_damage: u32, defender_defense: u32) -> u32;
-                    /// Calculate critical hit chance (returns 1 for crit, 0 for normal).
-                    fn roll_critical(attacker_attack: u32) -> u32;
+                    /// Roll for a critical hit (returns 1 for crit, 0 for normal).
+                    ///
+                    /// Hashes attack, turn and seed so a crit lands about crit-chance percent of the time.
+                    fn roll_critical(
+                        attacker_attack: u32,
+                        crit_chance: u32,
+                        turn: u32,
+                        seed: u32,
+                    ) -> u32;
+                    /// Roll for a critical hit against a percent chance raised by luck.
+                    ///
+                    /// Crits when roll % 100 is below crit-chance + luck / 2 (returns 1 or 0).
+                    fn roll_critical_with_chance(
+                        roll: u32,
+                        crit_chance: u32,
+                        luck: u32,
+                    ) -> u32;
                     /// Apply critical hit multiplier if applicable.
                     fn apply_critical(damage: u32, is_critical: u32) -> u32;
+                    /// Get the element an attack type deals.
+                    fn attack_element(attack: AttackType) -> Element;
+                    /// Get the damage multiplier (0.25 to 2.0) a defender takes from an element.
+                    fn element_multiplier(
+                        element: Element,
+                        defender: CombatantStats,
+                    ) -> f32;
                     /// Calculate final damage with all modifiers.
+                    ///
+                    /// Elemental resistance is applied before defense; a defender with
+                    /// 100 resistance to the attack's element takes no damage.
                     fn calculate_final_damage(
                         attack: AttackType,
                         attacker: CombatantStats,
                         defender: CombatantStats,
                     ) -> u32;
+                    /// Get the damage reflected by an equipped armor (inventory item ID).
+                    fn armor_reflect_damage(armor_id: u32) -> u32;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_combat_damage_0_1_0_cabi {
@@ -286,28 +710,50 @@ pub mod exports {
                         const _ : () = { #[unsafe (export_name =
                         "docs:combat/damage@0.1.0#calculate-base-damage")] unsafe extern
                         "C" fn export_calculate_base_damage(arg0 : i32, arg1 : i32, arg2
-                        : i32, arg3 : i32, arg4 : i32, arg5 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_calculate_base_damage_cabi::<$ty >
-                        (arg0, arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
+                        : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 :
+                        i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 :
+                        i32, arg13 : i32,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_calculate_base_damage_cabi::<$ty > (arg0, arg1, arg2,
+                        arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12,
+                        arg13) } } #[unsafe (export_name =
                         "docs:combat/damage@0.1.0#apply-defense")] unsafe extern "C" fn
                         export_apply_defense(arg0 : i32, arg1 : i32,) -> i32 { unsafe {
                         $($path_to_types)*:: _export_apply_defense_cabi::<$ty > (arg0,
                         arg1) } } #[unsafe (export_name =
                         "docs:combat/damage@0.1.0#roll-critical")] unsafe extern "C" fn
-                        export_roll_critical(arg0 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_roll_critical_cabi::<$ty > (arg0) }
-                        } #[unsafe (export_name =
+                        export_roll_critical(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
+                        i32,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_roll_critical_cabi::<$ty > (arg0, arg1, arg2, arg3) } }
+                        #[unsafe (export_name =
+                        "docs:combat/damage@0.1.0#roll-critical-with-chance")] unsafe
+                        extern "C" fn export_roll_critical_with_chance(arg0 : i32, arg1 :
+                        i32, arg2 : i32,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_roll_critical_with_chance_cabi::<$ty > (arg0, arg1, arg2)
+                        } } #[unsafe (export_name =
                         "docs:combat/damage@0.1.0#apply-critical")] unsafe extern "C" fn
                         export_apply_critical(arg0 : i32, arg1 : i32,) -> i32 { unsafe {
                         $($path_to_types)*:: _export_apply_critical_cabi::<$ty > (arg0,
                         arg1) } } #[unsafe (export_name =
+                        "docs:combat/damage@0.1.0#attack-element")] unsafe extern "C" fn
+                        export_attack_element(arg0 : i32,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_attack_element_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:combat/damage@0.1.0#element-multiplier")] unsafe extern "C"
+                        fn export_element_multiplier(arg0 : i32, arg1 : i32, arg2 : i32,
+                        arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8
+                        : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 :
+                        i32,) -> f32 { unsafe { $($path_to_types)*::
+                        _export_element_multiplier_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13) }
+                        } #[unsafe (export_name =
                         "docs:combat/damage@0.1.0#calculate-final-damage")] unsafe extern
-                        "C" fn export_calculate_final_damage(arg0 : i32, arg1 : i32, arg2
-                        : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 :
-                        i32, arg8 : i32, arg9 : i32, arg10 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_calculate_final_damage_cabi::<$ty >
-                        (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9,
-                        arg10) } } };
+                        "C" fn export_calculate_final_damage(arg0 : * mut u8,) -> i32 {
+                        unsafe { $($path_to_types)*::
+                        _export_calculate_final_damage_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:combat/damage@0.1.0#armor-reflect-damage")]
+                        unsafe extern "C" fn export_armor_reflect_damage(arg0 : i32,) ->
+                        i32 { unsafe { $($path_to_types)*::
+                        _export_armor_reflect_damage_cabi::<$ty > (arg0) } } };
                     };
                 }
                 #[doc(hidden)]
@@ -323,72 +769,198 @@ pub mod exports {
                 pub type AttackType = super::super::super::super::exports::docs::combat::types::AttackType;
                 pub type CombatResult = super::super::super::super::exports::docs::combat::types::CombatResult;
                 pub type CombatantStats = super::super::super::super::exports::docs::combat::types::CombatantStats;
+                pub type BattleState = super::super::super::super::exports::docs::combat::types::BattleState;
+                pub type Enchantment = super::super::super::super::exports::docs::combat::types::Enchantment;
+                pub type Position = super::super::super::super::exports::docs::combat::types::Position;
+                pub type Direction = super::super::super::super::exports::docs::combat::types::Direction;
+                pub type EnemyState = super::super::super::super::exports::docs::combat::types::EnemyState;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_player_attack_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                    arg6: i32,
-                    arg7: i32,
-                    arg8: i32,
-                    arg9: i32,
-                    arg10: i32,
-                    arg11: i32,
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::player_attack(
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = *arg0.add(44).cast::<i32>();
+                    let l12 = *arg0.add(48).cast::<i32>();
+                    let l13 = *arg0.add(52).cast::<i32>();
+                    let l14 = *arg0.add(56).cast::<i32>();
+                    let l15 = *arg0.add(60).cast::<i32>();
+                    let l16 = *arg0.add(64).cast::<i32>();
+                    let l17 = *arg0.add(68).cast::<i32>();
+                    let l18 = *arg0.add(72).cast::<i32>();
+                    let l19 = *arg0.add(76).cast::<i32>();
+                    let l20 = *arg0.add(80).cast::<i32>();
+                    let l21 = *arg0.add(84).cast::<i32>();
+                    let l22 = *arg0.add(88).cast::<i32>();
+                    let l23 = *arg0.add(92).cast::<i32>();
+                    let l24 = *arg0.add(96).cast::<i32>();
+                    let l25 = *arg0.add(100).cast::<i32>();
+                    let l26 = *arg0.add(104).cast::<i32>();
+                    let l27 = *arg0.add(108).cast::<i32>();
+                    let l28 = *arg0.add(112).cast::<i32>();
+                    let l29 = *arg0.add(116).cast::<i32>();
+                    let l30 = *arg0.add(120).cast::<*mut u8>();
+                    let l31 = *arg0
+                        .add(120 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base35 = l30;
+                    let len35 = l31;
+                    let mut result35 = _rt::Vec::with_capacity(len35);
+                    for i in 0..len35 {
+                        let base = base35.add(i * 8);
+                        let e35 = {
+                            let l32 = i32::from(*base.add(0).cast::<u8>());
+                            match l32 {
+                                0 => {
+                                    let l33 = *base.add(4).cast::<i32>();
+                                    let e = l33 as u32;
+                                    super::super::super::super::exports::docs::combat::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::combat::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l34 = *base.add(4).cast::<i32>();
+                                    let e = l34 as u32;
+                                    super::super::super::super::exports::docs::combat::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::combat::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result35.push(e35);
+                    }
+                    _rt::cabi_dealloc(base35, len35 * 8, 4);
+                    _rt::cabi_dealloc(
+                        arg0,
+                        120 + 2 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result36 = T::player_attack(
                         super::super::super::super::exports::docs::combat::types::AttackType::_lift(
-                            arg0 as u8,
+                            l0 as u8,
                         ),
                         super::super::super::super::exports::docs::combat::types::CombatantStats {
-                            attack: arg1 as u32,
-                            defense: arg2 as u32,
-                            health: arg3 as u32,
-                            max_health: arg4 as u32,
-                            equipment_bonus: arg5 as u32,
+                            attack: l1 as u32,
+                            defense: l2 as u32,
+                            health: l3 as u32,
+                            max_health: l4 as u32,
+                            equipment_bonus: l5 as u32,
+                            shield_hp: l6 as u32,
+                            max_shield_hp: l7 as u32,
+                            reflect_damage: l8 as u32,
+                            speed: l9 as u32,
+                            crit_chance: l10 as u32,
+                            fire_resistance: l11,
+                            ice_resistance: l12,
+                            lightning_resistance: l13,
                         },
                         super::super::super::super::exports::docs::combat::types::CombatantStats {
-                            attack: arg6 as u32,
-                            defense: arg7 as u32,
-                            health: arg8 as u32,
-                            max_health: arg9 as u32,
-                            equipment_bonus: arg10 as u32,
+                            attack: l14 as u32,
+                            defense: l15 as u32,
+                            health: l16 as u32,
+                            max_health: l17 as u32,
+                            equipment_bonus: l18 as u32,
+                            shield_hp: l19 as u32,
+                            max_shield_hp: l20 as u32,
+                            reflect_damage: l21 as u32,
+                            speed: l22 as u32,
+                            crit_chance: l23 as u32,
+                            fire_resistance: l24,
+                            ice_resistance: l25,
+                            lightning_resistance: l26,
                         },
-                        arg11 as u32,
+                        l27 as u32,
+                        l28 as u32,
+                        l29 as u32,
+                        result35,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr37 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::combat::types::CombatResult {
-                        damage_dealt: damage_dealt2,
-                        is_critical: is_critical2,
-                        target_defeated: target_defeated2,
-                        exp_gained: exp_gained2,
-                        message: message2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(damage_dealt2);
-                    *ptr1.add(4).cast::<u8>() = (match is_critical2 {
+                        damage_dealt: damage_dealt38,
+                        is_critical: is_critical38,
+                        target_defeated: target_defeated38,
+                        exp_gained: exp_gained38,
+                        message: message38,
+                        tags: tags38,
+                        reflected_damage: reflected_damage38,
+                    } = result36;
+                    *ptr37.add(0).cast::<i32>() = _rt::as_i32(damage_dealt38);
+                    *ptr37.add(4).cast::<u8>() = (match is_critical38 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(5).cast::<u8>() = (match target_defeated2 {
+                    *ptr37.add(5).cast::<u8>() = (match target_defeated38 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(exp_gained2);
-                    let vec3 = (message2.into_bytes()).into_boxed_slice();
-                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                    let len3 = vec3.len();
-                    ::core::mem::forget(vec3);
-                    *ptr1
+                    *ptr37.add(8).cast::<i32>() = _rt::as_i32(exp_gained38);
+                    let vec39 = (message38.into_bytes()).into_boxed_slice();
+                    let ptr39 = vec39.as_ptr().cast::<u8>();
+                    let len39 = vec39.len();
+                    ::core::mem::forget(vec39);
+                    *ptr37
                         .add(8 + 2 * ::core::mem::size_of::<*const u8>())
-                        .cast::<usize>() = len3;
-                    *ptr1
+                        .cast::<usize>() = len39;
+                    *ptr37
                         .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                        .cast::<*mut u8>() = ptr3.cast_mut();
-                    ptr1
+                        .cast::<*mut u8>() = ptr39.cast_mut();
+                    let vec41 = tags38;
+                    let len41 = vec41.len();
+                    let layout41 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec41.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result41 = if layout41.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout41).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout41);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec41.into_iter().enumerate() {
+                        let base = result41
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec40 = (e.into_bytes()).into_boxed_slice();
+                            let ptr40 = vec40.as_ptr().cast::<u8>();
+                            let len40 = vec40.len();
+                            ::core::mem::forget(vec40);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len40;
+                            *base.add(0).cast::<*mut u8>() = ptr40.cast_mut();
+                        }
+                    }
+                    *ptr37
+                        .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len41;
+                    *ptr37
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result41;
+                    *ptr37
+                        .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(reflected_damage38);
+                    ptr37
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -400,69 +972,167 @@ pub mod exports {
                         .add(8 + 2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
                     _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base6 = l2;
+                    let len6 = l3;
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l4 = *base.add(0).cast::<*mut u8>();
+                            let l5 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l4, l5, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_enemy_attack_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                    arg6: i32,
-                    arg7: i32,
-                    arg8: i32,
-                    arg9: i32,
-                    arg10: i32,
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::enemy_attack(
-                        arg0 as u32,
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = *arg0.add(44).cast::<i32>();
+                    let l12 = *arg0.add(48).cast::<i32>();
+                    let l13 = *arg0.add(52).cast::<i32>();
+                    let l14 = *arg0.add(56).cast::<i32>();
+                    let l15 = *arg0.add(60).cast::<i32>();
+                    let l16 = *arg0.add(64).cast::<i32>();
+                    let l17 = *arg0.add(68).cast::<i32>();
+                    let l18 = *arg0.add(72).cast::<i32>();
+                    let l19 = *arg0.add(76).cast::<i32>();
+                    let l20 = *arg0.add(80).cast::<i32>();
+                    let l21 = *arg0.add(84).cast::<i32>();
+                    let l22 = *arg0.add(88).cast::<i32>();
+                    let l23 = *arg0.add(92).cast::<i32>();
+                    let l24 = *arg0.add(96).cast::<i32>();
+                    let l25 = *arg0.add(100).cast::<i32>();
+                    let l26 = *arg0.add(104).cast::<i32>();
+                    _rt::cabi_dealloc(arg0, 108, 4);
+                    let result27 = T::enemy_attack(
+                        l0 as u32,
                         super::super::super::super::exports::docs::combat::types::CombatantStats {
-                            attack: arg1 as u32,
-                            defense: arg2 as u32,
-                            health: arg3 as u32,
-                            max_health: arg4 as u32,
-                            equipment_bonus: arg5 as u32,
+                            attack: l1 as u32,
+                            defense: l2 as u32,
+                            health: l3 as u32,
+                            max_health: l4 as u32,
+                            equipment_bonus: l5 as u32,
+                            shield_hp: l6 as u32,
+                            max_shield_hp: l7 as u32,
+                            reflect_damage: l8 as u32,
+                            speed: l9 as u32,
+                            crit_chance: l10 as u32,
+                            fire_resistance: l11,
+                            ice_resistance: l12,
+                            lightning_resistance: l13,
                         },
                         super::super::super::super::exports::docs::combat::types::CombatantStats {
-                            attack: arg6 as u32,
-                            defense: arg7 as u32,
-                            health: arg8 as u32,
-                            max_health: arg9 as u32,
-                            equipment_bonus: arg10 as u32,
+                            attack: l14 as u32,
+                            defense: l15 as u32,
+                            health: l16 as u32,
+                            max_health: l17 as u32,
+                            equipment_bonus: l18 as u32,
+                            shield_hp: l19 as u32,
+                            max_shield_hp: l20 as u32,
+                            reflect_damage: l21 as u32,
+                            speed: l22 as u32,
+                            crit_chance: l23 as u32,
+                            fire_resistance: l24,
+                            ice_resistance: l25,
+                            lightning_resistance: l26,
                         },
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr28 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::combat::types::CombatResult {
-                        damage_dealt: damage_dealt2,
-                        is_critical: is_critical2,
-                        target_defeated: target_defeated2,
-                        exp_gained: exp_gained2,
-                        message: message2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(damage_dealt2);
-                    *ptr1.add(4).cast::<u8>() = (match is_critical2 {
+                        damage_dealt: damage_dealt29,
+                        is_critical: is_critical29,
+                        target_defeated: target_defeated29,
+                        exp_gained: exp_gained29,
+                        message: message29,
+                        tags: tags29,
+                        reflected_damage: reflected_damage29,
+                    } = result27;
+                    *ptr28.add(0).cast::<i32>() = _rt::as_i32(damage_dealt29);
+                    *ptr28.add(4).cast::<u8>() = (match is_critical29 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(5).cast::<u8>() = (match target_defeated2 {
+                    *ptr28.add(5).cast::<u8>() = (match target_defeated29 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(exp_gained2);
-                    let vec3 = (message2.into_bytes()).into_boxed_slice();
-                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                    let len3 = vec3.len();
-                    ::core::mem::forget(vec3);
-                    *ptr1
+                    *ptr28.add(8).cast::<i32>() = _rt::as_i32(exp_gained29);
+                    let vec30 = (message29.into_bytes()).into_boxed_slice();
+                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                    let len30 = vec30.len();
+                    ::core::mem::forget(vec30);
+                    *ptr28
                         .add(8 + 2 * ::core::mem::size_of::<*const u8>())
-                        .cast::<usize>() = len3;
-                    *ptr1
+                        .cast::<usize>() = len30;
+                    *ptr28
                         .add(8 + 1 * ::core::mem::size_of::<*const u8>())
-                        .cast::<*mut u8>() = ptr3.cast_mut();
-                    ptr1
+                        .cast::<*mut u8>() = ptr30.cast_mut();
+                    let vec32 = tags29;
+                    let len32 = vec32.len();
+                    let layout32 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec32.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result32 = if layout32.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout32).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout32);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec32.into_iter().enumerate() {
+                        let base = result32
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec31 = (e.into_bytes()).into_boxed_slice();
+                            let ptr31 = vec31.as_ptr().cast::<u8>();
+                            let len31 = vec31.len();
+                            ::core::mem::forget(vec31);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len31;
+                            *base.add(0).cast::<*mut u8>() = ptr31.cast_mut();
+                        }
+                    }
+                    *ptr28
+                        .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len32;
+                    *ptr28
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result32;
+                    *ptr28
+                        .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(reflected_damage29);
+                    ptr28
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -474,6 +1144,30 @@ pub mod exports {
                         .add(8 + 2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
                     _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base6 = l2;
+                    let len6 = l3;
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l4 = *base.add(0).cast::<*mut u8>();
+                            let l5 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l4, l5, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -484,6 +1178,14 @@ pub mod exports {
                     arg3: i32,
                     arg4: i32,
                     arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::can_special_attack(
@@ -496,6 +1198,14 @@ pub mod exports {
                             health: arg3 as u32,
                             max_health: arg4 as u32,
                             equipment_bonus: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            reflect_damage: arg8 as u32,
+                            speed: arg9 as u32,
+                            crit_chance: arg10 as u32,
+                            fire_resistance: arg11,
+                            ice_resistance: arg12,
+                            lightning_resistance: arg13,
                         },
                     );
                     match result0 {
@@ -516,13 +1226,262 @@ pub mod exports {
                         false => 0,
                     }
                 }
-                pub trait Guest {
-                    /// Execute a player attack against an enemy.
-                    fn player_attack(
-                        attack: AttackType,
-                        player_stats: CombatantStats,
-                        enemy_stats: CombatantStats,
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_swing_arc_attack_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base4 = arg3;
+                    let len4 = arg4;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4.add(i * 16);
+                        let e4 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            let l2 = *base.add(8).cast::<i32>();
+                            let l3 = i32::from(*base.add(12).cast::<u8>());
+                            super::super::super::super::exports::docs::combat::types::EnemyState {
+                                pos: super::super::super::super::exports::docs::combat::types::Position {
+                                    x: l0,
+                                    y: l1,
+                                },
+                                health: l2 as u32,
+                                is_alive: _rt::bool_lift(l3 as u8),
+                            }
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(base4, len4 * 16, 4);
+                    let result5 = T::swing_arc_attack(
+                        super::super::super::super::exports::docs::combat::types::Position {
+                            x: arg0,
+                            y: arg1,
+                        },
+                        super::super::super::super::exports::docs::combat::types::Direction::_lift(
+                            arg2 as u8,
+                        ),
+                        result4,
+                    );
+                    let ptr6 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec8 = result5;
+                    let len8 = vec8.len();
+                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec8.len() * 8,
+                        4,
+                    );
+                    let result8 = if layout8.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout8);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec8.into_iter().enumerate() {
+                        let base = result8.add(i * 8);
+                        {
+                            let (t7_0, t7_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t7_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t7_1);
+                        }
+                    }
+                    *ptr6.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
+                    *ptr6.add(0).cast::<*mut u8>() = result8;
+                    ptr6
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_swing_arc_attack<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_summon_ally_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::summon_ally(super::super::super::super::exports::docs::combat::types::CombatantStats {
+                        attack: arg0 as u32,
+                        defense: arg1 as u32,
+                        health: arg2 as u32,
+                        max_health: arg3 as u32,
+                        equipment_bonus: arg4 as u32,
+                        shield_hp: arg5 as u32,
+                        max_shield_hp: arg6 as u32,
+                        reflect_damage: arg7 as u32,
+                        speed: arg8 as u32,
+                        crit_chance: arg9 as u32,
+                        fire_resistance: arg10,
+                        ice_resistance: arg11,
+                        lightning_resistance: arg12,
+                    });
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: is_active2,
+                        turn_count: turn_count2,
+                        player_health: player_health2,
+                        enemy_health: enemy_health2,
+                        is_player_turn: is_player_turn2,
+                        summon_ally_active: summon_ally_active2,
+                        ally_health: ally_health2,
+                        active_effects: active_effects2,
+                        log: log2,
+                    } = result0;
+                    *ptr1.add(0).cast::<u8>() = (match is_active2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(turn_count2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(player_health2);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(enemy_health2);
+                    *ptr1.add(16).cast::<u8>() = (match is_player_turn2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(17).cast::<u8>() = (match summon_ally_active2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(ally_health2);
+                    let vec4 = active_effects2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            let (t3_0, t3_1) = e;
+                            *base.add(0).cast::<u8>() = (t3_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t3_1);
+                        }
+                    }
+                    *ptr1
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1.add(24).cast::<*mut u8>() = result4;
+                    let vec6 = log2;
+                    let len6 = vec6.len();
+                    let layout6 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec6.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result6 = if layout6.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout6);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec6.into_iter().enumerate() {
+                        let base = result6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                        }
+                    }
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr1
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result6;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_summon_ally<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                pub trait Guest {
+                    /// Execute a player attack against an enemy.
+                    ///
+                    /// The weapon ID is an inventory item ID (0 = none) and sets the
+                    /// attack element. The attack bonus is the inventory's
+                    /// get-total-attack-bonus for the main-hand and off-hand weapons.
+                    /// Weapon enchantments are reported in the result tags.
+                    fn player_attack(
+                        attack: AttackType,
+                        player_stats: CombatantStats,
+                        enemy_stats: CombatantStats,
                         enemy_exp: u32,
+                        weapon_id: u32,
+                        attack_bonus: u32,
+                        enchantments: _rt::Vec<Enchantment>,
                     ) -> CombatResult;
                     /// Execute an enemy attack against the player.
                     fn enemy_attack(
@@ -537,30 +1496,37 @@ pub mod exports {
                     ) -> bool;
                     /// Attempt to flee from combat.
                     ///
-                    /// Returns true if escape was successful.
+                    /// Returns true if the player is at least 80% as fast as the enemy.
                     fn attempt_flee(player_speed: u32, enemy_speed: u32) -> bool;
+                    /// Swing in a 180-degree arc, hitting the three tiles in front of the player.
+                    ///
+                    /// Returns the index of each living enemy hit paired with its damage.
+                    fn swing_arc_attack(
+                        player_pos: Position,
+                        facing: Direction,
+                        enemies: _rt::Vec<EnemyState>,
+                    ) -> _rt::Vec<(u32, u32)>;
+                    /// Summon an ally to fight alongside the player for one battle.
+                    ///
+                    /// The ally starts with half of the summoner's maximum health.
+                    /// Set enemy health afterwards with `update-health`.
+                    fn summon_ally(stats: CombatantStats) -> BattleState;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_combat_actions_0_1_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
                         "docs:combat/actions@0.1.0#player-attack")] unsafe extern "C" fn
-                        export_player_attack(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32, arg10 : i32, arg11 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_player_attack_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
-                        arg11) } } #[unsafe (export_name =
+                        export_player_attack(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_player_attack_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
                         "cabi_post_docs:combat/actions@0.1.0#player-attack")] unsafe
                         extern "C" fn _post_return_player_attack(arg0 : * mut u8,) {
                         unsafe { $($path_to_types)*:: __post_return_player_attack::<$ty >
                         (arg0) } } #[unsafe (export_name =
                         "docs:combat/actions@0.1.0#enemy-attack")] unsafe extern "C" fn
-                        export_enemy_attack(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32, arg10 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_enemy_attack_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10) } }
+                        export_enemy_attack(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_enemy_attack_cabi::<$ty > (arg0) } }
                         #[unsafe (export_name =
                         "cabi_post_docs:combat/actions@0.1.0#enemy-attack")] unsafe
                         extern "C" fn _post_return_enemy_attack(arg0 : * mut u8,) {
@@ -568,13 +1534,37 @@ pub mod exports {
                         (arg0) } } #[unsafe (export_name =
                         "docs:combat/actions@0.1.0#can-special-attack")] unsafe extern
                         "C" fn export_can_special_attack(arg0 : i32, arg1 : i32, arg2 :
-                        i32, arg3 : i32, arg4 : i32, arg5 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_can_special_attack_cabi::<$ty >
-                        (arg0, arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
+                        i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32,
+                        arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32,
+                        arg13 : i32,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_can_special_attack_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13) }
+                        } #[unsafe (export_name =
                         "docs:combat/actions@0.1.0#attempt-flee")] unsafe extern "C" fn
                         export_attempt_flee(arg0 : i32, arg1 : i32,) -> i32 { unsafe {
                         $($path_to_types)*:: _export_attempt_flee_cabi::<$ty > (arg0,
-                        arg1) } } };
+                        arg1) } } #[unsafe (export_name =
+                        "docs:combat/actions@0.1.0#swing-arc-attack")] unsafe extern "C"
+                        fn export_swing_arc_attack(arg0 : i32, arg1 : i32, arg2 : i32,
+                        arg3 : * mut u8, arg4 : usize,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_swing_arc_attack_cabi::<$ty > (arg0,
+                        arg1, arg2, arg3, arg4) } } #[unsafe (export_name =
+                        "cabi_post_docs:combat/actions@0.1.0#swing-arc-attack")] unsafe
+                        extern "C" fn _post_return_swing_arc_attack(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_swing_arc_attack::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:combat/actions@0.1.0#summon-ally")] unsafe
+                        extern "C" fn export_summon_ally(arg0 : i32, arg1 : i32, arg2 :
+                        i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32,
+                        arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32,)
+                        -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_summon_ally_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:combat/actions@0.1.0#summon-ally")] unsafe extern
+                        "C" fn _post_return_summon_ally(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_summon_ally::<$ty > (arg0) } }
+                        };
                     };
                 }
                 #[doc(hidden)]
@@ -584,11 +1574,11 @@ pub mod exports {
                 struct _RetArea(
                     [::core::mem::MaybeUninit<
                         u8,
-                    >; 8 + 3 * ::core::mem::size_of::<*const u8>()],
+                    >; 24 + 4 * ::core::mem::size_of::<*const u8>()],
                 );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 8
-                        + 3 * ::core::mem::size_of::<*const u8>()],
+                    [::core::mem::MaybeUninit::uninit(); 24
+                        + 4 * ::core::mem::size_of::<*const u8>()],
                 );
             }
             /// Battle management interface.
@@ -599,14 +1589,23 @@ pub mod exports {
                 static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
                 pub type BattleState = super::super::super::super::exports::docs::combat::types::BattleState;
+                pub type CombatantStats = super::super::super::super::exports::docs::combat::types::CombatantStats;
+                pub type StatusEffect = super::super::super::super::exports::docs::combat::types::StatusEffect;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_start_battle_cabi<T: Guest>(
                     arg0: i32,
                     arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::start_battle(arg0 as u32, arg1 as u32);
+                    let result0 = T::start_battle(
+                        arg0 as u32,
+                        arg1 as u32,
+                        arg2 as u32,
+                        arg3 as u32,
+                    );
                     let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::combat::types::BattleState {
                         is_active: is_active2,
@@ -614,99 +1613,1618 @@ pub mod exports {
                         player_health: player_health2,
                         enemy_health: enemy_health2,
                         is_player_turn: is_player_turn2,
+                        summon_ally_active: summon_ally_active2,
+                        ally_health: ally_health2,
+                        active_effects: active_effects2,
+                        log: log2,
                     } = result0;
                     *ptr1.add(0).cast::<u8>() = (match is_active2 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(turn_count2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(player_health2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(enemy_health2);
-                    *ptr1.add(16).cast::<u8>() = (match is_player_turn2 {
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(turn_count2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(player_health2);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(enemy_health2);
+                    *ptr1.add(16).cast::<u8>() = (match is_player_turn2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(17).cast::<u8>() = (match summon_ally_active2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(ally_health2);
+                    let vec4 = active_effects2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            let (t3_0, t3_1) = e;
+                            *base.add(0).cast::<u8>() = (t3_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t3_1);
+                        }
+                    }
+                    *ptr1
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1.add(24).cast::<*mut u8>() = result4;
+                    let vec6 = log2;
+                    let len6 = vec6.len();
+                    let layout6 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec6.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result6 = if layout6.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout6).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout6);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec6.into_iter().enumerate() {
+                        let base = result6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *base.add(0).cast::<*mut u8>() = ptr5.cast_mut();
+                        }
+                    }
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len6;
+                    *ptr1
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result6;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_start_battle<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_end_battle_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::end_battle(super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: _rt::bool_lift(arg0 as u8),
+                        turn_count: arg1 as u32,
+                        player_health: arg2 as u32,
+                        enemy_health: arg3 as u32,
+                        is_player_turn: _rt::bool_lift(arg4 as u8),
+                        summon_ally_active: _rt::bool_lift(arg5 as u8),
+                        ally_health: arg6 as u32,
+                        active_effects: result2,
+                        log: result6,
+                    });
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: is_active9,
+                        turn_count: turn_count9,
+                        player_health: player_health9,
+                        enemy_health: enemy_health9,
+                        is_player_turn: is_player_turn9,
+                        summon_ally_active: summon_ally_active9,
+                        ally_health: ally_health9,
+                        active_effects: active_effects9,
+                        log: log9,
+                    } = result7;
+                    *ptr8.add(0).cast::<u8>() = (match is_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(4).cast::<i32>() = _rt::as_i32(turn_count9);
+                    *ptr8.add(8).cast::<i32>() = _rt::as_i32(player_health9);
+                    *ptr8.add(12).cast::<i32>() = _rt::as_i32(enemy_health9);
+                    *ptr8.add(16).cast::<u8>() = (match is_player_turn9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(17).cast::<u8>() = (match summon_ally_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(20).cast::<i32>() = _rt::as_i32(ally_health9);
+                    let vec11 = active_effects9;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * 8,
+                        4,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11.add(i * 8);
+                        {
+                            let (t10_0, t10_1) = e;
+                            *base.add(0).cast::<u8>() = (t10_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t10_1);
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr8.add(24).cast::<*mut u8>() = result11;
+                    let vec13 = log9;
+                    let len13 = vec13.len();
+                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result13 = if layout13.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout13);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec13.into_iter().enumerate() {
+                        let base = result13
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len12;
+                            *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *ptr8
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result13;
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_end_battle<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_next_turn_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::next_turn(super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: _rt::bool_lift(arg0 as u8),
+                        turn_count: arg1 as u32,
+                        player_health: arg2 as u32,
+                        enemy_health: arg3 as u32,
+                        is_player_turn: _rt::bool_lift(arg4 as u8),
+                        summon_ally_active: _rt::bool_lift(arg5 as u8),
+                        ally_health: arg6 as u32,
+                        active_effects: result2,
+                        log: result6,
+                    });
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: is_active9,
+                        turn_count: turn_count9,
+                        player_health: player_health9,
+                        enemy_health: enemy_health9,
+                        is_player_turn: is_player_turn9,
+                        summon_ally_active: summon_ally_active9,
+                        ally_health: ally_health9,
+                        active_effects: active_effects9,
+                        log: log9,
+                    } = result7;
+                    *ptr8.add(0).cast::<u8>() = (match is_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(4).cast::<i32>() = _rt::as_i32(turn_count9);
+                    *ptr8.add(8).cast::<i32>() = _rt::as_i32(player_health9);
+                    *ptr8.add(12).cast::<i32>() = _rt::as_i32(enemy_health9);
+                    *ptr8.add(16).cast::<u8>() = (match is_player_turn9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(17).cast::<u8>() = (match summon_ally_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(20).cast::<i32>() = _rt::as_i32(ally_health9);
+                    let vec11 = active_effects9;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * 8,
+                        4,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11.add(i * 8);
+                        {
+                            let (t10_0, t10_1) = e;
+                            *base.add(0).cast::<u8>() = (t10_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t10_1);
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr8.add(24).cast::<*mut u8>() = result11;
+                    let vec13 = log9;
+                    let len13 = vec13.len();
+                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result13 = if layout13.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout13);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec13.into_iter().enumerate() {
+                        let base = result13
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len12;
+                            *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *ptr8
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result13;
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_next_turn<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_update_health_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: i32,
+                    arg12: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::update_health(
+                        super::super::super::super::exports::docs::combat::types::BattleState {
+                            is_active: _rt::bool_lift(arg0 as u8),
+                            turn_count: arg1 as u32,
+                            player_health: arg2 as u32,
+                            enemy_health: arg3 as u32,
+                            is_player_turn: _rt::bool_lift(arg4 as u8),
+                            summon_ally_active: _rt::bool_lift(arg5 as u8),
+                            ally_health: arg6 as u32,
+                            active_effects: result2,
+                            log: result6,
+                        },
+                        arg11 as u32,
+                        arg12 as u32,
+                    );
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: is_active9,
+                        turn_count: turn_count9,
+                        player_health: player_health9,
+                        enemy_health: enemy_health9,
+                        is_player_turn: is_player_turn9,
+                        summon_ally_active: summon_ally_active9,
+                        ally_health: ally_health9,
+                        active_effects: active_effects9,
+                        log: log9,
+                    } = result7;
+                    *ptr8.add(0).cast::<u8>() = (match is_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(4).cast::<i32>() = _rt::as_i32(turn_count9);
+                    *ptr8.add(8).cast::<i32>() = _rt::as_i32(player_health9);
+                    *ptr8.add(12).cast::<i32>() = _rt::as_i32(enemy_health9);
+                    *ptr8.add(16).cast::<u8>() = (match is_player_turn9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(17).cast::<u8>() = (match summon_ally_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(20).cast::<i32>() = _rt::as_i32(ally_health9);
+                    let vec11 = active_effects9;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * 8,
+                        4,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11.add(i * 8);
+                        {
+                            let (t10_0, t10_1) = e;
+                            *base.add(0).cast::<u8>() = (t10_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t10_1);
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr8.add(24).cast::<*mut u8>() = result11;
+                    let vec13 = log9;
+                    let len13 = vec13.len();
+                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result13 = if layout13.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout13);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec13.into_iter().enumerate() {
+                        let base = result13
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len12;
+                            *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *ptr8
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result13;
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_update_health<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_is_battle_over_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::is_battle_over(super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: _rt::bool_lift(arg0 as u8),
+                        turn_count: arg1 as u32,
+                        player_health: arg2 as u32,
+                        enemy_health: arg3 as u32,
+                        is_player_turn: _rt::bool_lift(arg4 as u8),
+                        summon_ally_active: _rt::bool_lift(arg5 as u8),
+                        ally_health: arg6 as u32,
+                        active_effects: result2,
+                        log: result6,
+                    });
+                    match result7 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_player_won_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::player_won(super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: _rt::bool_lift(arg0 as u8),
+                        turn_count: arg1 as u32,
+                        player_health: arg2 as u32,
+                        enemy_health: arg3 as u32,
+                        is_player_turn: _rt::bool_lift(arg4 as u8),
+                        summon_ally_active: _rt::bool_lift(arg5 as u8),
+                        ally_health: arg6 as u32,
+                        active_effects: result2,
+                        log: result6,
+                    });
+                    match result7 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_battle_summary_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::get_battle_summary(super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: _rt::bool_lift(arg0 as u8),
+                        turn_count: arg1 as u32,
+                        player_health: arg2 as u32,
+                        enemy_health: arg3 as u32,
+                        is_player_turn: _rt::bool_lift(arg4 as u8),
+                        summon_ally_active: _rt::bool_lift(arg5 as u8),
+                        ally_health: arg6 as u32,
+                        active_effects: result2,
+                        log: result6,
+                    });
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec9 = (result7.into_bytes()).into_boxed_slice();
+                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                    let len9 = vec9.len();
+                    ::core::mem::forget(vec9);
+                    *ptr8.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
+                    *ptr8.add(0).cast::<*mut u8>() = ptr9.cast_mut();
+                    ptr8
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_get_battle_summary<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_apply_enemy_hit_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::apply_enemy_hit(
+                        super::super::super::super::exports::docs::combat::types::BattleState {
+                            is_active: _rt::bool_lift(arg0 as u8),
+                            turn_count: arg1 as u32,
+                            player_health: arg2 as u32,
+                            enemy_health: arg3 as u32,
+                            is_player_turn: _rt::bool_lift(arg4 as u8),
+                            summon_ally_active: _rt::bool_lift(arg5 as u8),
+                            ally_health: arg6 as u32,
+                            active_effects: result2,
+                            log: result6,
+                        },
+                        arg11 as u32,
+                    );
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::combat::types::BattleState {
+                        is_active: is_active9,
+                        turn_count: turn_count9,
+                        player_health: player_health9,
+                        enemy_health: enemy_health9,
+                        is_player_turn: is_player_turn9,
+                        summon_ally_active: summon_ally_active9,
+                        ally_health: ally_health9,
+                        active_effects: active_effects9,
+                        log: log9,
+                    } = result7;
+                    *ptr8.add(0).cast::<u8>() = (match is_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(4).cast::<i32>() = _rt::as_i32(turn_count9);
+                    *ptr8.add(8).cast::<i32>() = _rt::as_i32(player_health9);
+                    *ptr8.add(12).cast::<i32>() = _rt::as_i32(enemy_health9);
+                    *ptr8.add(16).cast::<u8>() = (match is_player_turn9 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    ptr1
+                    *ptr8.add(17).cast::<u8>() = (match summon_ally_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(20).cast::<i32>() = _rt::as_i32(ally_health9);
+                    let vec11 = active_effects9;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * 8,
+                        4,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11.add(i * 8);
+                        {
+                            let (t10_0, t10_1) = e;
+                            *base.add(0).cast::<u8>() = (t10_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t10_1);
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr8.add(24).cast::<*mut u8>() = result11;
+                    let vec13 = log9;
+                    let len13 = vec13.len();
+                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result13 = if layout13.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout13);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec13.into_iter().enumerate() {
+                        let base = result13
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len12;
+                            *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *ptr8
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result13;
+                    ptr8
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_end_battle_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
+                pub unsafe fn __post_return_apply_enemy_hit<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_apply_player_hit_cabi<T: Guest>(
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::end_battle(super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: _rt::bool_lift(arg0 as u8),
-                        turn_count: arg1 as u32,
-                        player_health: arg2 as u32,
-                        enemy_health: arg3 as u32,
-                        is_player_turn: _rt::bool_lift(arg4 as u8),
-                    });
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = i32::from(*arg0.add(16).cast::<u8>());
+                    let l5 = i32::from(*arg0.add(17).cast::<u8>());
+                    let l6 = *arg0.add(20).cast::<i32>();
+                    let l7 = *arg0.add(24).cast::<*mut u8>();
+                    let l8 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base11 = l7;
+                    let len11 = l8;
+                    let mut result11 = _rt::Vec::with_capacity(len11);
+                    for i in 0..len11 {
+                        let base = base11.add(i * 8);
+                        let e11 = {
+                            let l9 = i32::from(*base.add(0).cast::<u8>());
+                            let l10 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l9 as u8,
+                                ),
+                                l10 as u32,
+                            )
+                        };
+                        result11.push(e11);
+                    }
+                    _rt::cabi_dealloc(base11, len11 * 8, 4);
+                    let l12 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l13 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base17 = l12;
+                    let len17 = l13;
+                    let mut result17 = _rt::Vec::with_capacity(len17);
+                    for i in 0..len17 {
+                        let base = base17
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e17 = {
+                            let l14 = *base.add(0).cast::<*mut u8>();
+                            let l15 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len16 = l15;
+                            let bytes16 = _rt::Vec::from_raw_parts(
+                                l14.cast(),
+                                len16,
+                                len16,
+                            );
+                            _rt::string_lift(bytes16)
+                        };
+                        result17.push(e17);
+                    }
+                    _rt::cabi_dealloc(
+                        base17,
+                        len17 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l18 = *arg0
+                        .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l19 = *arg0
+                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l20 = *arg0
+                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l21 = *arg0
+                        .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l22 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l25 = *arg0
+                        .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l26 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l27 = *arg0
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l28 = *arg0
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l29 = *arg0
+                        .add(68 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l30 = *arg0
+                        .add(72 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l31 = *arg0
+                        .add(76 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        80 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result32 = T::apply_player_hit(
+                        super::super::super::super::exports::docs::combat::types::BattleState {
+                            is_active: _rt::bool_lift(l0 as u8),
+                            turn_count: l1 as u32,
+                            player_health: l2 as u32,
+                            enemy_health: l3 as u32,
+                            is_player_turn: _rt::bool_lift(l4 as u8),
+                            summon_ally_active: _rt::bool_lift(l5 as u8),
+                            ally_health: l6 as u32,
+                            active_effects: result11,
+                            log: result17,
+                        },
+                        l18 as u32,
+                        super::super::super::super::exports::docs::combat::types::CombatantStats {
+                            attack: l19 as u32,
+                            defense: l20 as u32,
+                            health: l21 as u32,
+                            max_health: l22 as u32,
+                            equipment_bonus: l23 as u32,
+                            shield_hp: l24 as u32,
+                            max_shield_hp: l25 as u32,
+                            reflect_damage: l26 as u32,
+                            speed: l27 as u32,
+                            crit_chance: l28 as u32,
+                            fire_resistance: l29,
+                            ice_resistance: l30,
+                            lightning_resistance: l31,
+                        },
+                    );
+                    let ptr33 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: is_active2,
-                        turn_count: turn_count2,
-                        player_health: player_health2,
-                        enemy_health: enemy_health2,
-                        is_player_turn: is_player_turn2,
-                    } = result0;
-                    *ptr1.add(0).cast::<u8>() = (match is_active2 {
+                        is_active: is_active34,
+                        turn_count: turn_count34,
+                        player_health: player_health34,
+                        enemy_health: enemy_health34,
+                        is_player_turn: is_player_turn34,
+                        summon_ally_active: summon_ally_active34,
+                        ally_health: ally_health34,
+                        active_effects: active_effects34,
+                        log: log34,
+                    } = result32;
+                    *ptr33.add(0).cast::<u8>() = (match is_active34 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(turn_count2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(player_health2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(enemy_health2);
-                    *ptr1.add(16).cast::<u8>() = (match is_player_turn2 {
+                    *ptr33.add(4).cast::<i32>() = _rt::as_i32(turn_count34);
+                    *ptr33.add(8).cast::<i32>() = _rt::as_i32(player_health34);
+                    *ptr33.add(12).cast::<i32>() = _rt::as_i32(enemy_health34);
+                    *ptr33.add(16).cast::<u8>() = (match is_player_turn34 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    ptr1
+                    *ptr33.add(17).cast::<u8>() = (match summon_ally_active34 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr33.add(20).cast::<i32>() = _rt::as_i32(ally_health34);
+                    let vec36 = active_effects34;
+                    let len36 = vec36.len();
+                    let layout36 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec36.len() * 8,
+                        4,
+                    );
+                    let result36 = if layout36.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout36).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout36);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec36.into_iter().enumerate() {
+                        let base = result36.add(i * 8);
+                        {
+                            let (t35_0, t35_1) = e;
+                            *base.add(0).cast::<u8>() = (t35_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t35_1);
+                        }
+                    }
+                    *ptr33
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len36;
+                    *ptr33.add(24).cast::<*mut u8>() = result36;
+                    let vec38 = log34;
+                    let len38 = vec38.len();
+                    let layout38 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec38.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result38 = if layout38.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout38).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout38);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec38.into_iter().enumerate() {
+                        let base = result38
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec37 = (e.into_bytes()).into_boxed_slice();
+                            let ptr37 = vec37.as_ptr().cast::<u8>();
+                            let len37 = vec37.len();
+                            ::core::mem::forget(vec37);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len37;
+                            *base.add(0).cast::<*mut u8>() = ptr37.cast_mut();
+                        }
+                    }
+                    *ptr33
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len38;
+                    *ptr33
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result38;
+                    ptr33
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_next_turn_cabi<T: Guest>(
+                pub unsafe fn __post_return_apply_player_hit<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_apply_status_effect_cabi<T: Guest>(
                     arg0: i32,
                     arg1: i32,
                     arg2: i32,
                     arg3: i32,
                     arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: i32,
+                    arg12: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::next_turn(super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: _rt::bool_lift(arg0 as u8),
-                        turn_count: arg1 as u32,
-                        player_health: arg2 as u32,
-                        enemy_health: arg3 as u32,
-                        is_player_turn: _rt::bool_lift(arg4 as u8),
-                    });
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::apply_status_effect(
+                        super::super::super::super::exports::docs::combat::types::BattleState {
+                            is_active: _rt::bool_lift(arg0 as u8),
+                            turn_count: arg1 as u32,
+                            player_health: arg2 as u32,
+                            enemy_health: arg3 as u32,
+                            is_player_turn: _rt::bool_lift(arg4 as u8),
+                            summon_ally_active: _rt::bool_lift(arg5 as u8),
+                            ally_health: arg6 as u32,
+                            active_effects: result2,
+                            log: result6,
+                        },
+                        super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                            arg11 as u8,
+                        ),
+                        arg12 as u32,
+                    );
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: is_active2,
-                        turn_count: turn_count2,
-                        player_health: player_health2,
-                        enemy_health: enemy_health2,
-                        is_player_turn: is_player_turn2,
-                    } = result0;
-                    *ptr1.add(0).cast::<u8>() = (match is_active2 {
+                        is_active: is_active9,
+                        turn_count: turn_count9,
+                        player_health: player_health9,
+                        enemy_health: enemy_health9,
+                        is_player_turn: is_player_turn9,
+                        summon_ally_active: summon_ally_active9,
+                        ally_health: ally_health9,
+                        active_effects: active_effects9,
+                        log: log9,
+                    } = result7;
+                    *ptr8.add(0).cast::<u8>() = (match is_active9 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(turn_count2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(player_health2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(enemy_health2);
-                    *ptr1.add(16).cast::<u8>() = (match is_player_turn2 {
+                    *ptr8.add(4).cast::<i32>() = _rt::as_i32(turn_count9);
+                    *ptr8.add(8).cast::<i32>() = _rt::as_i32(player_health9);
+                    *ptr8.add(12).cast::<i32>() = _rt::as_i32(enemy_health9);
+                    *ptr8.add(16).cast::<u8>() = (match is_player_turn9 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    ptr1
+                    *ptr8.add(17).cast::<u8>() = (match summon_ally_active9 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr8.add(20).cast::<i32>() = _rt::as_i32(ally_health9);
+                    let vec11 = active_effects9;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * 8,
+                        4,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11.add(i * 8);
+                        {
+                            let (t10_0, t10_1) = e;
+                            *base.add(0).cast::<u8>() = (t10_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t10_1);
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len11;
+                    *ptr8.add(24).cast::<*mut u8>() = result11;
+                    let vec13 = log9;
+                    let len13 = vec13.len();
+                    let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result13 = if layout13.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout13);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec13.into_iter().enumerate() {
+                        let base = result13
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                            let len12 = vec12.len();
+                            ::core::mem::forget(vec12);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len12;
+                            *base.add(0).cast::<*mut u8>() = ptr12.cast_mut();
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len13;
+                    *ptr8
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result13;
+                    ptr8
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_update_health_cabi<T: Guest>(
+                pub unsafe fn __post_return_apply_status_effect<T: Guest>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_tick_status_effects_cabi<T: Guest>(
                     arg0: i32,
                     arg1: i32,
                     arg2: i32,
@@ -714,89 +3232,209 @@ pub mod exports {
                     arg4: i32,
                     arg5: i32,
                     arg6: i32,
+                    arg7: *mut u8,
+                    arg8: usize,
+                    arg9: *mut u8,
+                    arg10: usize,
+                    arg11: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::update_health(
+                    let base2 = arg7;
+                    let len2 = arg8;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = i32::from(*base.add(0).cast::<u8>());
+                            let l1 = *base.add(4).cast::<i32>();
+                            (
+                                super::super::super::super::exports::docs::combat::types::StatusEffect::_lift(
+                                    l0 as u8,
+                                ),
+                                l1 as u32,
+                            )
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let base6 = arg9;
+                    let len6 = arg10;
+                    let mut result6 = _rt::Vec::with_capacity(len6);
+                    for i in 0..len6 {
+                        let base = base6
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e6 = {
+                            let l3 = *base.add(0).cast::<*mut u8>();
+                            let l4 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len5 = l4;
+                            let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                            _rt::string_lift(bytes5)
+                        };
+                        result6.push(e6);
+                    }
+                    _rt::cabi_dealloc(
+                        base6,
+                        len6 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result7 = T::tick_status_effects(
                         super::super::super::super::exports::docs::combat::types::BattleState {
                             is_active: _rt::bool_lift(arg0 as u8),
                             turn_count: arg1 as u32,
                             player_health: arg2 as u32,
                             enemy_health: arg3 as u32,
                             is_player_turn: _rt::bool_lift(arg4 as u8),
+                            summon_ally_active: _rt::bool_lift(arg5 as u8),
+                            ally_health: arg6 as u32,
+                            active_effects: result2,
+                            log: result6,
                         },
-                        arg5 as u32,
-                        arg6 as u32,
+                        arg11 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr8 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t9_0, t9_1) = result7;
                     let super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: is_active2,
-                        turn_count: turn_count2,
-                        player_health: player_health2,
-                        enemy_health: enemy_health2,
-                        is_player_turn: is_player_turn2,
-                    } = result0;
-                    *ptr1.add(0).cast::<u8>() = (match is_active2 {
+                        is_active: is_active10,
+                        turn_count: turn_count10,
+                        player_health: player_health10,
+                        enemy_health: enemy_health10,
+                        is_player_turn: is_player_turn10,
+                        summon_ally_active: summon_ally_active10,
+                        ally_health: ally_health10,
+                        active_effects: active_effects10,
+                        log: log10,
+                    } = t9_0;
+                    *ptr8.add(0).cast::<u8>() = (match is_active10 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(turn_count2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(player_health2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(enemy_health2);
-                    *ptr1.add(16).cast::<u8>() = (match is_player_turn2 {
+                    *ptr8.add(4).cast::<i32>() = _rt::as_i32(turn_count10);
+                    *ptr8.add(8).cast::<i32>() = _rt::as_i32(player_health10);
+                    *ptr8.add(12).cast::<i32>() = _rt::as_i32(enemy_health10);
+                    *ptr8.add(16).cast::<u8>() = (match is_player_turn10 {
                         true => 1,
                         false => 0,
                     }) as u8;
-                    ptr1
-                }
-                #[doc(hidden)]
-                #[allow(non_snake_case)]
-                pub unsafe fn _export_is_battle_over_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                ) -> i32 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::is_battle_over(super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: _rt::bool_lift(arg0 as u8),
-                        turn_count: arg1 as u32,
-                        player_health: arg2 as u32,
-                        enemy_health: arg3 as u32,
-                        is_player_turn: _rt::bool_lift(arg4 as u8),
-                    });
-                    match result0 {
+                    *ptr8.add(17).cast::<u8>() = (match summon_ally_active10 {
                         true => 1,
                         false => 0,
+                    }) as u8;
+                    *ptr8.add(20).cast::<i32>() = _rt::as_i32(ally_health10);
+                    let vec12 = active_effects10;
+                    let len12 = vec12.len();
+                    let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec12.len() * 8,
+                        4,
+                    );
+                    let result12 = if layout12.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout12);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec12.into_iter().enumerate() {
+                        let base = result12.add(i * 8);
+                        {
+                            let (t11_0, t11_1) = e;
+                            *base.add(0).cast::<u8>() = (t11_0.clone() as i32) as u8;
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t11_1);
+                        }
+                    }
+                    *ptr8
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len12;
+                    *ptr8.add(24).cast::<*mut u8>() = result12;
+                    let vec14 = log10;
+                    let len14 = vec14.len();
+                    let layout14 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec14.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result14 = if layout14.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout14).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout14);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec14.into_iter().enumerate() {
+                        let base = result14
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec13 = (e.into_bytes()).into_boxed_slice();
+                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                            let len13 = vec13.len();
+                            ::core::mem::forget(vec13);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len13;
+                            *base.add(0).cast::<*mut u8>() = ptr13.cast_mut();
+                        }
                     }
+                    *ptr8
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len14;
+                    *ptr8
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result14;
+                    *ptr8
+                        .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(t9_1);
+                    ptr8
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_player_won_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                ) -> i32 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::player_won(super::super::super::super::exports::docs::combat::types::BattleState {
-                        is_active: _rt::bool_lift(arg0 as u8),
-                        turn_count: arg1 as u32,
-                        player_health: arg2 as u32,
-                        enemy_health: arg3 as u32,
-                        is_player_turn: _rt::bool_lift(arg4 as u8),
-                    });
-                    match result0 {
-                        true => 1,
-                        false => 0,
+                pub unsafe fn __post_return_tick_status_effects<T: Guest>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(24).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = *arg0
+                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l3;
+                    let len7 = l4;
+                    for i in 0..len7 {
+                        let base = base7
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l5 = *base.add(0).cast::<*mut u8>();
+                            let l6 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
                     }
+                    _rt::cabi_dealloc(
+                        base7,
+                        len7 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
                 }
                 pub trait Guest {
                     /// Start a new battle.
+                    ///
+                    /// The player moves first unless the enemy is faster.
                     fn start_battle(
                         player_health: u32,
                         enemy_health: u32,
+                        player_speed: u32,
+                        enemy_speed: u32,
                     ) -> BattleState;
                     /// End the current battle.
                     fn end_battle(state: BattleState) -> BattleState;
@@ -812,47 +3450,161 @@ pub mod exports {
                     fn is_battle_over(state: BattleState) -> bool;
                     /// Determine the battle outcome (true = player won).
                     fn player_won(state: BattleState) -> bool;
+                    /// Get the battle log as one entry per line.
+                    fn get_battle_summary(state: BattleState) -> _rt::String;
+                    /// Apply an enemy hit, letting an active ally absorb it on alternating turns.
+                    fn apply_enemy_hit(state: BattleState, damage: u32) -> BattleState;
+                    /// Apply a player hit, adding the ally's auto-attack when one is active.
+                    fn apply_player_hit(
+                        state: BattleState,
+                        damage: u32,
+                        player_stats: CombatantStats,
+                    ) -> BattleState;
+                    /// Apply a status effect to the player for `duration` turns.
+                    ///
+                    /// Reapplying an active effect keeps the longer of the two durations.
+                    fn apply_status_effect(
+                        state: BattleState,
+                        effect: StatusEffect,
+                        duration: u32,
+                    ) -> BattleState;
+                    /// Tick active status effects at the start of a turn.
+                    ///
+                    /// Poison deals 3 + level / 2 and burn deals 2 damage to the player.
+                    /// Returns the updated state and the damage dealt.
+                    fn tick_status_effects(
+                        state: BattleState,
+                        player_level: u32,
+                    ) -> (BattleState, u32);
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_combat_battle_0_1_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
                         "docs:combat/battle@0.1.0#start-battle")] unsafe extern "C" fn
-                        export_start_battle(arg0 : i32, arg1 : i32,) -> * mut u8 { unsafe
-                        { $($path_to_types)*:: _export_start_battle_cabi::<$ty > (arg0,
-                        arg1) } } #[unsafe (export_name =
-                        "docs:combat/battle@0.1.0#end-battle")] unsafe extern "C" fn
-                        export_end_battle(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32,
-                        arg4 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
-                        _export_end_battle_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4) }
-                        } #[unsafe (export_name = "docs:combat/battle@0.1.0#next-turn")]
+                        export_start_battle(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
+                        i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_start_battle_cabi::<$ty > (arg0, arg1, arg2, arg3) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#start-battle")] unsafe extern
+                        "C" fn _post_return_start_battle(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_start_battle::<$ty > (arg0) }
+                        } #[unsafe (export_name = "docs:combat/battle@0.1.0#end-battle")]
+                        unsafe extern "C" fn export_end_battle(arg0 : i32, arg1 : i32,
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : * mut u8, arg8 : usize, arg9 : * mut u8, arg10 : usize,) -> *
+                        mut u8 { unsafe { $($path_to_types)*::
+                        _export_end_battle_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10) } } #[unsafe (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#end-battle")] unsafe extern
+                        "C" fn _post_return_end_battle(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_end_battle::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:combat/battle@0.1.0#next-turn")]
                         unsafe extern "C" fn export_next_turn(arg0 : i32, arg1 : i32,
-                        arg2 : i32, arg3 : i32, arg4 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_next_turn_cabi::<$ty > (arg0, arg1,
-                        arg2, arg3, arg4) } } #[unsafe (export_name =
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : * mut u8, arg8 : usize, arg9 : * mut u8, arg10 : usize,) -> *
+                        mut u8 { unsafe { $($path_to_types)*::
+                        _export_next_turn_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10) } } #[unsafe (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#next-turn")] unsafe extern
+                        "C" fn _post_return_next_turn(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_next_turn::<$ty > (arg0) } }
+                        #[unsafe (export_name =
                         "docs:combat/battle@0.1.0#update-health")] unsafe extern "C" fn
                         export_update_health(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_update_health_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6) } } #[unsafe (export_name =
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : * mut u8, arg8 :
+                        usize, arg9 : * mut u8, arg10 : usize, arg11 : i32, arg12 : i32,)
+                        -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_update_health_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#update-health")] unsafe
+                        extern "C" fn _post_return_update_health(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_update_health::<$ty >
+                        (arg0) } } #[unsafe (export_name =
                         "docs:combat/battle@0.1.0#is-battle-over")] unsafe extern "C" fn
                         export_is_battle_over(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32,) -> i32 { unsafe { $($path_to_types)*::
-                        _export_is_battle_over_cabi::<$ty > (arg0, arg1, arg2, arg3,
-                        arg4) } } #[unsafe (export_name =
-                        "docs:combat/battle@0.1.0#player-won")] unsafe extern "C" fn
-                        export_player_won(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32,
-                        arg4 : i32,) -> i32 { unsafe { $($path_to_types)*::
-                        _export_player_won_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4) }
-                        } };
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : * mut u8, arg8 :
+                        usize, arg9 : * mut u8, arg10 : usize,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_is_battle_over_cabi::<$ty > (arg0,
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10) } }
+                        #[unsafe (export_name = "docs:combat/battle@0.1.0#player-won")]
+                        unsafe extern "C" fn export_player_won(arg0 : i32, arg1 : i32,
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : * mut u8, arg8 : usize, arg9 : * mut u8, arg10 : usize,) -> i32
+                        { unsafe { $($path_to_types)*:: _export_player_won_cabi::<$ty >
+                        (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9,
+                        arg10) } } #[unsafe (export_name =
+                        "docs:combat/battle@0.1.0#get-battle-summary")] unsafe extern "C"
+                        fn export_get_battle_summary(arg0 : i32, arg1 : i32, arg2 : i32,
+                        arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : * mut u8,
+                        arg8 : usize, arg9 : * mut u8, arg10 : usize,) -> * mut u8 {
+                        unsafe { $($path_to_types)*::
+                        _export_get_battle_summary_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#get-battle-summary")] unsafe
+                        extern "C" fn _post_return_get_battle_summary(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_get_battle_summary::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:combat/battle@0.1.0#apply-enemy-hit")]
+                        unsafe extern "C" fn export_apply_enemy_hit(arg0 : i32, arg1 :
+                        i32, arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32,
+                        arg7 : * mut u8, arg8 : usize, arg9 : * mut u8, arg10 : usize,
+                        arg11 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_apply_enemy_hit_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#apply-enemy-hit")] unsafe
+                        extern "C" fn _post_return_apply_enemy_hit(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_apply_enemy_hit::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "docs:combat/battle@0.1.0#apply-player-hit")] unsafe extern "C"
+                        fn export_apply_player_hit(arg0 : * mut u8,) -> * mut u8 { unsafe
+                        { $($path_to_types)*:: _export_apply_player_hit_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#apply-player-hit")] unsafe
+                        extern "C" fn _post_return_apply_player_hit(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_apply_player_hit::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:combat/battle@0.1.0#apply-status-effect")]
+                        unsafe extern "C" fn export_apply_status_effect(arg0 : i32, arg1
+                        : i32, arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 :
+                        i32, arg7 : * mut u8, arg8 : usize, arg9 : * mut u8, arg10 :
+                        usize, arg11 : i32, arg12 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_apply_status_effect_cabi::<$ty >
+                        (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9,
+                        arg10, arg11, arg12) } } #[unsafe (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#apply-status-effect")] unsafe
+                        extern "C" fn _post_return_apply_status_effect(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_apply_status_effect::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:combat/battle@0.1.0#tick-status-effects")]
+                        unsafe extern "C" fn export_tick_status_effects(arg0 : i32, arg1
+                        : i32, arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 :
+                        i32, arg7 : * mut u8, arg8 : usize, arg9 : * mut u8, arg10 :
+                        usize, arg11 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_tick_status_effects_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:combat/battle@0.1.0#tick-status-effects")] unsafe
+                        extern "C" fn _post_return_tick_status_effects(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_tick_status_effects::<$ty > (arg0) } } };
                     };
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_docs_combat_battle_0_1_0_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 20]);
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 24 + 5 * ::core::mem::size_of::<*const u8>()],
+                );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 20],
+                    [::core::mem::MaybeUninit::uninit(); 24
+                        + 5 * ::core::mem::size_of::<*const u8>()],
                 );
             }
         }
@@ -862,6 +3614,7 @@ pub mod exports {
 mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -925,6 +3678,23 @@ mod _rt {
             self as i32
         }
     }
+    pub fn as_f32<T: AsF32>(t: T) -> f32 {
+        t.as_f32()
+    }
+    pub trait AsF32 {
+        fn as_f32(self) -> f32;
+    }
+    impl<'a, T: Copy + AsF32> AsF32 for &'a T {
+        fn as_f32(self) -> f32 {
+            (*self).as_f32()
+        }
+    }
+    impl AsF32 for f32 {
+        #[inline]
+        fn as_f32(self) -> f32 {
+            self as f32
+        }
+    }
     pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
         if size == 0 {
             return;
@@ -932,6 +3702,13 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub unsafe fn bool_lift(val: u8) -> bool {
         if cfg!(debug_assertions) {
             match val {
@@ -943,6 +3720,13 @@ mod _rt {
             val != 0
         }
     }
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
     extern crate alloc as alloc_crate;
     pub use alloc_crate::alloc;
 }
@@ -991,39 +3775,67 @@ pub(crate) use __export_combat_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1538] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x85\x0b\x01A\x02\x01\
-A\x0c\x01B\x08\x01m\x05\x0bsword-slash\x0bspin-attack\x08bow-shot\x0cmagic-attac\
-k\x0bshield-bash\x04\0\x0battack-type\x03\0\0\x01r\x05\x0cdamage-dealty\x0bis-cr\
-itical\x7f\x0ftarget-defeated\x7f\x0aexp-gainedy\x07messages\x04\0\x0dcombat-res\
-ult\x03\0\x02\x01r\x05\x06attacky\x07defensey\x06healthy\x0amax-healthy\x0fequip\
-ment-bonusy\x04\0\x0fcombatant-stats\x03\0\x04\x01r\x05\x09is-active\x7f\x0aturn\
--county\x0dplayer-healthy\x0cenemy-healthy\x0eis-player-turn\x7f\x04\0\x0cbattle\
--state\x03\0\x06\x04\0\x17docs:combat/types@0.1.0\x05\0\x02\x03\0\0\x0battack-ty\
-pe\x02\x03\0\0\x0fcombatant-stats\x01B\x0e\x02\x03\x02\x01\x01\x04\0\x0battack-t\
-ype\x03\0\0\x02\x03\x02\x01\x02\x04\0\x0fcombatant-stats\x03\0\x02\x01@\x02\x06a\
-ttack\x01\x0eattacker-stats\x03\0y\x04\0\x15calculate-base-damage\x01\x04\x01@\x02\
-\x0araw-damagey\x10defender-defensey\0y\x04\0\x0dapply-defense\x01\x05\x01@\x01\x0f\
-attacker-attacky\0y\x04\0\x0droll-critical\x01\x06\x01@\x02\x06damagey\x0bis-cri\
-ticaly\0y\x04\0\x0eapply-critical\x01\x07\x01@\x03\x06attack\x01\x08attacker\x03\
-\x08defender\x03\0y\x04\0\x16calculate-final-damage\x01\x08\x04\0\x18docs:combat\
-/damage@0.1.0\x05\x03\x02\x03\0\0\x0dcombat-result\x02\x03\0\0\x0cbattle-state\x01\
-B\x10\x02\x03\x02\x01\x01\x04\0\x0battack-type\x03\0\0\x02\x03\x02\x01\x04\x04\0\
-\x0dcombat-result\x03\0\x02\x02\x03\x02\x01\x02\x04\0\x0fcombatant-stats\x03\0\x04\
-\x02\x03\x02\x01\x05\x04\0\x0cbattle-state\x03\0\x06\x01@\x04\x06attack\x01\x0cp\
-layer-stats\x05\x0benemy-stats\x05\x09enemy-expy\0\x03\x04\0\x0dplayer-attack\x01\
-\x08\x01@\x03\x0cenemy-attacky\x0benemy-stats\x05\x0cplayer-stats\x05\0\x03\x04\0\
-\x0cenemy-attack\x01\x09\x01@\x02\x06attack\x01\x0cplayer-stats\x05\0\x7f\x04\0\x12\
-can-special-attack\x01\x0a\x01@\x02\x0cplayer-speedy\x0benemy-speedy\0\x7f\x04\0\
-\x0cattempt-flee\x01\x0b\x04\0\x19docs:combat/actions@0.1.0\x05\x06\x01B\x0c\x02\
-\x03\x02\x01\x05\x04\0\x0cbattle-state\x03\0\0\x01@\x02\x0dplayer-healthy\x0cene\
-my-healthy\0\x01\x04\0\x0cstart-battle\x01\x02\x01@\x01\x05state\x01\0\x01\x04\0\
-\x0aend-battle\x01\x03\x04\0\x09next-turn\x01\x03\x01@\x03\x05state\x01\x0dplaye\
-r-healthy\x0cenemy-healthy\0\x01\x04\0\x0dupdate-health\x01\x04\x01@\x01\x05stat\
-e\x01\0\x7f\x04\0\x0eis-battle-over\x01\x05\x04\0\x0aplayer-won\x01\x05\x04\0\x18\
-docs:combat/battle@0.1.0\x05\x07\x04\0\x18docs:combat/combat@0.1.0\x04\0\x0b\x0c\
-\x01\0\x06combat\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-componen\
-t\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2868] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xb7\x15\x01A\x02\x01\
+A\x12\x01B\x17\x01m\x06\x0bsword-slash\x0bspin-attack\x08bow-shot\x0cmagic-attac\
+k\x0bshield-bash\x09swing-arc\x04\0\x0battack-type\x03\0\0\x01r\x02\x01xz\x01yz\x04\
+\0\x08position\x03\0\x02\x01m\x04\x05north\x05south\x04west\x04east\x04\0\x09dir\
+ection\x03\0\x04\x01m\x04\x06poison\x04burn\x04stun\x06freeze\x04\0\x0dstatus-ef\
+fect\x03\0\x06\x01m\x04\x04none\x04fire\x03ice\x09lightning\x04\0\x07element\x03\
+\0\x08\x01r\x03\x03pos\x03\x06healthy\x08is-alive\x7f\x04\0\x0benemy-state\x03\0\
+\x0a\x01ps\x01r\x07\x0cdamage-dealty\x0bis-critical\x7f\x0ftarget-defeated\x7f\x0a\
+exp-gainedy\x07messages\x04tags\x0c\x10reflected-damagey\x04\0\x0dcombat-result\x03\
+\0\x0d\x01q\x04\x0bfire-damage\x01y\0\x0afrost-slow\0\0\x0alife-steal\x01y\0\x0b\
+extra-reach\0\0\x04\0\x0benchantment\x03\0\x0f\x01r\x0d\x06attacky\x07defensey\x06\
+healthy\x0amax-healthy\x0fequipment-bonusy\x09shield-hpy\x0dmax-shield-hpy\x0ere\
+flect-damagey\x05speedy\x0bcrit-chancey\x0ffire-resistancez\x0eice-resistancez\x14\
+lightning-resistancez\x04\0\x0fcombatant-stats\x03\0\x11\x01o\x02\x07y\x01p\x13\x01\
+r\x09\x09is-active\x7f\x0aturn-county\x0dplayer-healthy\x0cenemy-healthy\x0eis-p\
+layer-turn\x7f\x12summon-ally-active\x7f\x0bally-healthy\x0eactive-effects\x14\x03\
+log\x0c\x04\0\x0cbattle-state\x03\0\x15\x04\0\x17docs:combat/types@0.1.0\x05\0\x02\
+\x03\0\0\x0battack-type\x02\x03\0\0\x0fcombatant-stats\x02\x03\0\0\x07element\x01\
+B\x18\x02\x03\x02\x01\x01\x04\0\x0battack-type\x03\0\0\x02\x03\x02\x01\x02\x04\0\
+\x0fcombatant-stats\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x07element\x03\0\x04\x01\
+@\x02\x06attack\x01\x0eattacker-stats\x03\0y\x04\0\x15calculate-base-damage\x01\x06\
+\x01@\x02\x0araw-damagey\x10defender-defensey\0y\x04\0\x0dapply-defense\x01\x07\x01\
+@\x04\x0fattacker-attacky\x0bcrit-chancey\x04turny\x04seedy\0y\x04\0\x0droll-cri\
+tical\x01\x08\x01@\x03\x04rolly\x0bcrit-chancey\x04lucky\0y\x04\0\x19roll-critic\
+al-with-chance\x01\x09\x01@\x02\x06damagey\x0bis-criticaly\0y\x04\0\x0eapply-cri\
+tical\x01\x0a\x01@\x01\x06attack\x01\0\x05\x04\0\x0eattack-element\x01\x0b\x01@\x02\
+\x07element\x05\x08defender\x03\0v\x04\0\x12element-multiplier\x01\x0c\x01@\x03\x06\
+attack\x01\x08attacker\x03\x08defender\x03\0y\x04\0\x16calculate-final-damage\x01\
+\x0d\x01@\x01\x08armor-idy\0y\x04\0\x14armor-reflect-damage\x01\x0e\x04\0\x18doc\
+s:combat/damage@0.1.0\x05\x04\x02\x03\0\0\x0dcombat-result\x02\x03\0\0\x0cbattle\
+-state\x02\x03\0\0\x0benchantment\x02\x03\0\0\x08position\x02\x03\0\0\x09directi\
+on\x02\x03\0\0\x0benemy-state\x01B\x20\x02\x03\x02\x01\x01\x04\0\x0battack-type\x03\
+\0\0\x02\x03\x02\x01\x05\x04\0\x0dcombat-result\x03\0\x02\x02\x03\x02\x01\x02\x04\
+\0\x0fcombatant-stats\x03\0\x04\x02\x03\x02\x01\x06\x04\0\x0cbattle-state\x03\0\x06\
+\x02\x03\x02\x01\x07\x04\0\x0benchantment\x03\0\x08\x02\x03\x02\x01\x08\x04\0\x08\
+position\x03\0\x0a\x02\x03\x02\x01\x09\x04\0\x09direction\x03\0\x0c\x02\x03\x02\x01\
+\x0a\x04\0\x0benemy-state\x03\0\x0e\x01p\x09\x01@\x07\x06attack\x01\x0cplayer-st\
+ats\x05\x0benemy-stats\x05\x09enemy-expy\x09weapon-idy\x0cattack-bonusy\x0cencha\
+ntments\x10\0\x03\x04\0\x0dplayer-attack\x01\x11\x01@\x03\x0cenemy-attacky\x0ben\
+emy-stats\x05\x0cplayer-stats\x05\0\x03\x04\0\x0cenemy-attack\x01\x12\x01@\x02\x06\
+attack\x01\x0cplayer-stats\x05\0\x7f\x04\0\x12can-special-attack\x01\x13\x01@\x02\
+\x0cplayer-speedy\x0benemy-speedy\0\x7f\x04\0\x0cattempt-flee\x01\x14\x01p\x0f\x01\
+o\x02yy\x01p\x16\x01@\x03\x0aplayer-pos\x0b\x06facing\x0d\x07enemies\x15\0\x17\x04\
+\0\x10swing-arc-attack\x01\x18\x01@\x01\x05stats\x05\0\x07\x04\0\x0bsummon-ally\x01\
+\x19\x04\0\x19docs:combat/actions@0.1.0\x05\x0b\x02\x03\0\0\x0dstatus-effect\x01\
+B\x1b\x02\x03\x02\x01\x06\x04\0\x0cbattle-state\x03\0\0\x02\x03\x02\x01\x02\x04\0\
+\x0fcombatant-stats\x03\0\x02\x02\x03\x02\x01\x0c\x04\0\x0dstatus-effect\x03\0\x04\
+\x01@\x04\x0dplayer-healthy\x0cenemy-healthy\x0cplayer-speedy\x0benemy-speedy\0\x01\
+\x04\0\x0cstart-battle\x01\x06\x01@\x01\x05state\x01\0\x01\x04\0\x0aend-battle\x01\
+\x07\x04\0\x09next-turn\x01\x07\x01@\x03\x05state\x01\x0dplayer-healthy\x0cenemy\
+-healthy\0\x01\x04\0\x0dupdate-health\x01\x08\x01@\x01\x05state\x01\0\x7f\x04\0\x0e\
+is-battle-over\x01\x09\x04\0\x0aplayer-won\x01\x09\x01@\x01\x05state\x01\0s\x04\0\
+\x12get-battle-summary\x01\x0a\x01@\x02\x05state\x01\x06damagey\0\x01\x04\0\x0fa\
+pply-enemy-hit\x01\x0b\x01@\x03\x05state\x01\x06damagey\x0cplayer-stats\x03\0\x01\
+\x04\0\x10apply-player-hit\x01\x0c\x01@\x03\x05state\x01\x06effect\x05\x08durati\
+ony\0\x01\x04\0\x13apply-status-effect\x01\x0d\x01o\x02\x01y\x01@\x02\x05state\x01\
+\x0cplayer-levely\0\x0e\x04\0\x13tick-status-effects\x01\x0f\x04\0\x18docs:comba\
+t/battle@0.1.0\x05\x0d\x04\0\x18docs:combat/combat@0.1.0\x04\0\x0b\x0c\x01\0\x06\
+combat\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227\
+.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -33,11 +33,15 @@ use bindings::exports::docs::combat::actions::{
     CombatResult as ActionsCombatResult, CombatantStats as ActionsCombatantStats,
     Guest as ActionsGuest,
 };
-use bindings::exports::docs::combat::battle::{BattleState, Guest as BattleGuest};
+use bindings::exports::docs::combat::battle::{
+    BattleState, CombatantStats as BattleCombatantStats, Guest as BattleGuest,
+};
 use bindings::exports::docs::combat::damage::{
     AttackType, CombatantStats as DamageCombatantStats, Guest as DamageGuest,
 };
-use bindings::exports::docs::combat::types::CombatResult;
+use bindings::exports::docs::combat::types::{
+    CombatResult, Direction, Element, Enchantment, EnemyState, Position, StatusEffect,
+};
 
 /// Critical hit multiplier.
 const CRITICAL_MULTIPLIER: u32 = 2;
@@ -48,6 +52,39 @@ const MINIMUM_DAMAGE: u32 = 1;
 /// Attack boost per 10 points of attack.
 const ATTACK_DIVISOR: u32 = 10;
 
+/// Inventory item ID of the Magic Robe armor.
+const MAGIC_ROBE_ID: u32 = 105;
+
+/// Damage reflected to attackers while wearing the Magic Robe.
+const MAGIC_ROBE_REFLECT: u32 = 3;
+
+/// Divisor applied to the summoner's attack for ally auto-attacks.
+const ALLY_ATTACK_DIVISOR: u32 = 3;
+
+/// Damage dealt to each enemy caught in a swing arc.
+const SWING_ARC_BASE_DAMAGE: u32 = 8;
+
+/// Base poison damage per turn, before the level bonus.
+const POISON_BASE_DAMAGE: u32 = 3;
+
+/// Burn damage per turn.
+const BURN_DAMAGE: u32 = 2;
+
+/// Most entries kept in the battle log.
+const MAX_LOG_ENTRIES: usize = 50;
+
+/// Inventory item ID of the Fire Rod, whose attacks deal fire damage.
+const FIRE_ROD_ID: u32 = 5;
+
+/// Resistance at which a defender ignores an element entirely.
+const IMMUNE_RESISTANCE: i32 = 100;
+
+/// Smallest elemental damage multiplier.
+const MIN_ELEMENT_MULTIPLIER: f32 = 0.25;
+
+/// Largest elemental damage multiplier.
+const MAX_ELEMENT_MULTIPLIER: f32 = 2.0;
+
 /// Component structure for combat functionality.
 struct Component;
 
@@ -69,6 +106,7 @@ fn attack_base_damage(attack: &AttackType) -> u32 {
         AttackType::BowShot => 8,
         AttackType::MagicAttack => 15,
         AttackType::ShieldBash => 5,
+        AttackType::SwingArc => SWING_ARC_BASE_DAMAGE,
     }
 }
 
@@ -127,17 +165,53 @@ fn apply_defense_reduction(raw: u32, reduction: u32) -> u32 {
     raw.saturating_sub(reduction).max(MINIMUM_DAMAGE)
 }
 
-/// Check if critical hit based on attack stat.
+/// Check if critical hit using a deterministic hash roll.
+///
+/// The hash is the roll and the chance is capped like any other crit
+/// through `is_lucky_critical`, with no luck bonus.
 ///
 /// # Arguments
 ///
 /// * `attack` - Attacker's attack value
+/// * `crit_chance` - Crit chance in percent
+/// * `turn` - Current battle turn
+/// * `seed` - Extra seed mixed into the roll
+///
+/// # Returns
+///
+/// * `bool` - True if critical
+fn is_critical_hit(attack: u32, crit_chance: u32, turn: u32, seed: u32) -> bool {
+    let hash = (attack.wrapping_mul(turn) ^ seed).wrapping_mul(2_654_435_761) >> 16;
+    is_lucky_critical(hash, crit_chance, 0)
+}
+
+/// Calculate crit chance after the luck bonus.
+///
+/// # Arguments
+///
+/// * `crit_chance` - Base crit chance in percent
+/// * `luck` - Attacker's luck
+///
+/// # Returns
+///
+/// * `u32` - Effective crit chance in percent, at most 100
+fn effective_crit_chance(crit_chance: u32, luck: u32) -> u32 {
+    crit_chance.saturating_add(luck / 2).min(100)
+}
+
+/// Check if a percent roll lands a critical hit.
+///
+/// # Arguments
+///
+/// * `roll` - Random roll, reduced to 0-99
+/// * `crit_chance` - Base crit chance in percent
+/// * `luck` - Attacker's luck
 ///
 /// # Returns
 ///
 /// * `bool` - True if critical
-fn is_critical_hit(attack: u32) -> bool {
-    attack % 10 == 7
+fn is_lucky_critical(roll: u32, crit_chance: u32, luck: u32) -> bool {
+    roll % 100 < effective_crit_chance(crit_chance, luck)
 }
 
 /// Apply critical multiplier to damage.
@@ -158,6 +232,110 @@ fn apply_critical_multiplier(damage: u32, is_crit: bool) -> u32 {
     }
 }
 
+/// Get the element an attack type deals.
+///
+/// # Arguments
+///
+/// * `attack` - Attack type
+///
+/// # Returns
+///
+/// * `Element` - Element of the attack
+fn attack_element(attack: &AttackType) -> Element {
+    match attack {
+        AttackType::MagicAttack => Element::Lightning,
+        _ => Element::None,
+    }
+}
+
+/// Get the element a weapon adds to its attacks.
+///
+/// # Arguments
+///
+/// * `weapon_id` - Inventory weapon ID (0 = none)
+///
+/// # Returns
+///
+/// * `Element` - Element of the weapon
+fn weapon_element(weapon_id: u32) -> Element {
+    if weapon_id == FIRE_ROD_ID {
+        Element::Fire
+    } else {
+        Element::None
+    }
+}
+
+/// Get a defender's resistance to an element.
+///
+/// # Arguments
+///
+/// * `element` - Incoming element
+/// * `defender` - Defender stats
+///
+/// # Returns
+///
+/// * `i32` - Resistance in percent, negative for a weakness
+fn element_resistance(element: Element, defender: &DamageCombatantStats) -> i32 {
+    match element {
+        Element::None => 0,
+        Element::Fire => defender.fire_resistance,
+        Element::Ice => defender.ice_resistance,
+        Element::Lightning => defender.lightning_resistance,
+    }
+}
+
+/// Get the damage multiplier a defender takes from an element.
+///
+/// Each point of resistance takes a percent off the damage and each point
+/// of weakness adds one, so -100 resistance doubles it.
+///
+/// # Arguments
+///
+/// * `element` - Incoming element
+/// * `defender` - Defender stats
+///
+/// # Returns
+///
+/// * `f32` - Damage multiplier between 0.25 and 2.0
+fn get_element_multiplier(element: Element, defender: &DamageCombatantStats) -> f32 {
+    let resistance = element_resistance(element, defender).min(IMMUNE_RESISTANCE);
+    let multiplier = (IMMUNE_RESISTANCE - resistance) as f32 / IMMUNE_RESISTANCE as f32;
+    multiplier.clamp(MIN_ELEMENT_MULTIPLIER, MAX_ELEMENT_MULTIPLIER)
+}
+
+/// Calculate final damage for an attack of a given element.
+///
+/// # Arguments
+///
+/// * `attack` - Attack type
+/// * `element` - Element of the attack
+/// * `attacker` - Attacker stats
+/// * `defender` - Defender stats
+///
+/// # Returns
+///
+/// * `u32` - Final damage (0 if the defender is immune)
+fn elemental_damage(
+    attack: AttackType,
+    element: Element,
+    attacker: DamageCombatantStats,
+    defender: DamageCombatantStats,
+) -> u32 {
+    if element != Element::None && element_resistance(element, &defender) >= IMMUNE_RESISTANCE {
+        return 0;
+    }
+    let base = <Component as DamageGuest>::calculate_base_damage(attack, attacker);
+    let resisted = (base as f32 * get_element_multiplier(element, &defender)) as u32;
+    let after_def = <Component as DamageGuest>::apply_defense(resisted, defender.defense);
+    let crit = <Component as DamageGuest>::roll_critical(
+        attacker.attack,
+        attacker.crit_chance,
+        1,
+        defender.health,
+    );
+    <Component as DamageGuest>::apply_critical(after_def, crit)
+}
+
 /// Check if target is defeated.
 ///
 /// # Arguments
@@ -209,9 +387,32 @@ fn create_combat_result(damage: u32, is_crit: bool, defeated: bool, exp: u32) ->
         target_defeated: defeated,
         exp_gained: if defeated { exp } else { 0 },
         message: generate_combat_message(damage, is_crit),
+        tags: Vec::new(),
+        reflected_damage: 0,
     }
 }
 
+/// Describe weapon enchantments as combat result tags.
+///
+/// # Arguments
+///
+/// * `enchantments` - Weapon enchantments
+///
+/// # Returns
+///
+/// * `Vec<String>` - One tag per enchantment
+fn enchantment_tags(enchantments: &[Enchantment]) -> Vec<String> {
+    enchantments
+        .iter()
+        .map(|enchantment| match enchantment {
+            Enchantment::FireDamage(n) => format!("fire-damage:{}", n),
+            Enchantment::FrostSlow => "frost-slow".to_string(),
+            Enchantment::LifeSteal(n) => format!("life-steal:{}", n),
+            Enchantment::ExtraReach => "extra-reach".to_string(),
+        })
+        .collect()
+}
+
 /// Calculate flee chance.
 ///
 /// # Arguments
@@ -223,7 +424,26 @@ fn create_combat_result(damage: u32, is_crit: bool, defeated: bool, exp: u32) ->
 ///
 /// * `bool` - True if flee successful
 fn calculate_flee_success(player_speed: u32, enemy_speed: u32) -> bool {
-    player_speed > enemy_speed
+    player_speed as u64 * 5 >= enemy_speed as u64 * 4
+}
+
+/// Calculate health as a percentage of the maximum.
+///
+/// Mirrors the player component's `health-percentage`.
+///
+/// # Arguments
+///
+/// * `current` - Current health
+/// * `max` - Maximum health
+///
+/// # Returns
+///
+/// * `u32` - Health percentage from 0 to 100
+fn health_percentage(current: u32, max: u32) -> u32 {
+    if max == 0 {
+        return 0;
+    }
+    (u64::from(current) * 100 / u64::from(max)).min(100) as u32
 }
 
 /// Check if special attack available.
@@ -239,8 +459,10 @@ fn calculate_flee_success(player_speed: u32, enemy_speed: u32) -> bool {
 /// * `bool` - True if can use special
 fn can_use_special(attack: &AttackType, health: u32, max_health: u32) -> bool {
     match attack {
-        AttackType::SpinAttack => health >= max_health / 2,
-        AttackType::MagicAttack => health >= max_health / 4,
+        AttackType::SpinAttack | AttackType::SwingArc => {
+            health_percentage(health, max_health) >= 50
+        }
+        AttackType::MagicAttack => health_percentage(health, max_health) >= 25,
         _ => true,
     }
 }
@@ -262,9 +484,80 @@ fn create_battle_state(player_hp: u32, enemy_hp: u32) -> BattleState {
         player_health: player_hp,
         enemy_health: enemy_hp,
         is_player_turn: true,
+        summon_ally_active: false,
+        ally_health: 0,
+        active_effects: Vec::new(),
+        log: Vec::new(),
+    }
+}
+
+/// Append an entry to the battle log, dropping the oldest past the limit.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+/// * `entry` - Entry to record
+///
+/// # Returns
+///
+/// * `BattleState` - State with the entry logged
+fn push_log(mut state: BattleState, entry: String) -> BattleState {
+    state.log.push(entry);
+    if state.log.len() > MAX_LOG_ENTRIES {
+        let excess = state.log.len() - MAX_LOG_ENTRIES;
+        state.log.drain(..excess);
+    }
+    state
+}
+
+/// Describe a change in battle health for the log.
+///
+/// # Arguments
+///
+/// * `state` - Battle state before the change
+/// * `player_hp` - New player health
+/// * `enemy_hp` - New enemy health
+///
+/// # Returns
+///
+/// * `String` - Log entry
+fn describe_health_change(state: &BattleState, player_hp: u32, enemy_hp: u32) -> String {
+    let turn = state.turn_count;
+    let enemy_loss = state.enemy_health.saturating_sub(enemy_hp);
+    let player_loss = state.player_health.saturating_sub(player_hp);
+    match (enemy_loss, player_loss) {
+        (0, 0) => format!(
+            "Turn {}: Player HP: {}. Enemy HP: {}",
+            turn, player_hp, enemy_hp
+        ),
+        (dealt, 0) => format!(
+            "Turn {}: Player deals {} damage. Enemy HP: {}",
+            turn, dealt, enemy_hp
+        ),
+        (0, taken) => format!(
+            "Turn {}: Enemy deals {} damage. Player HP: {}",
+            turn, taken, player_hp
+        ),
+        (dealt, taken) => format!(
+            "Turn {}: Player deals {} damage and takes {}. Player HP: {}. Enemy HP: {}",
+            turn, dealt, taken, player_hp, enemy_hp
+        ),
     }
 }
 
+/// Join the battle log into a summary.
+///
+/// # Arguments
+///
+/// * `state` - Battle state
+///
+/// # Returns
+///
+/// * `String` - Log entries separated by newlines
+fn get_battle_summary(state: BattleState) -> String {
+    state.log.join("\n")
+}
+
 /// End battle state.
 ///
 /// # Arguments
@@ -275,10 +568,21 @@ fn create_battle_state(player_hp: u32, enemy_hp: u32) -> BattleState {
 ///
 /// * `BattleState` - Ended battle state
 fn end_battle_state(state: BattleState) -> BattleState {
-    BattleState {
-        is_active: false,
-        ..state
-    }
+    let outcome = if determine_player_won(&state) {
+        "Player wins"
+    } else if state.player_health == 0 {
+        "Enemy wins"
+    } else {
+        "No winner"
+    };
+    let entry = format!("Turn {}: Battle over. {}.", state.turn_count, outcome);
+    push_log(
+        BattleState {
+            is_active: false,
+            ..state
+        },
+        entry,
+    )
 }
 
 /// Advance turn in battle.
@@ -291,11 +595,18 @@ fn end_battle_state(state: BattleState) -> BattleState {
 ///
 /// * `BattleState` - Next turn state
 fn advance_turn(state: BattleState) -> BattleState {
-    BattleState {
+    let next = BattleState {
         turn_count: state.turn_count + 1,
         is_player_turn: !state.is_player_turn,
         ..state
-    }
+    };
+    let actor = if next.is_player_turn {
+        "Player"
+    } else {
+        "Enemy"
+    };
+    let entry = format!("Turn {}: {} to act.", next.turn_count, actor);
+    push_log(next, entry)
 }
 
 /// Update battle health values.
@@ -310,11 +621,15 @@ fn advance_turn(state: BattleState) -> BattleState {
 ///
 /// * `BattleState` - Updated battle state
 fn update_battle_health(state: BattleState, player_hp: u32, enemy_hp: u32) -> BattleState {
-    BattleState {
-        player_health: player_hp,
-        enemy_health: enemy_hp,
-        ..state
-    }
+    let entry = describe_health_change(&state, player_hp, enemy_hp);
+    push_log(
+        BattleState {
+            player_health: player_hp,
+            enemy_health: enemy_hp,
+            ..state
+        },
+        entry,
+    )
 }
 
 /// Check if battle is over.
@@ -343,6 +658,219 @@ fn determine_player_won(state: &BattleState) -> bool {
     state.enemy_health == 0 && state.player_health > 0
 }
 
+/// Add a status effect, refreshing it if already active.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+/// * `effect` - Effect to apply
+/// * `duration` - Turns the effect lasts
+///
+/// # Returns
+///
+/// * `BattleState` - State with the effect active
+fn add_status_effect(state: BattleState, effect: StatusEffect, duration: u32) -> BattleState {
+    let mut active_effects = state.active_effects;
+    match active_effects.iter_mut().find(|(e, _)| *e == effect) {
+        Some((_, turns)) => *turns = (*turns).max(duration),
+        None => active_effects.push((effect, duration)),
+    }
+    BattleState {
+        active_effects,
+        ..state
+    }
+}
+
+/// Get the damage a status effect deals per turn.
+///
+/// # Arguments
+///
+/// * `effect` - Active effect
+/// * `player_level` - Player's level
+///
+/// # Returns
+///
+/// * `u32` - Damage dealt this turn
+fn status_effect_damage(effect: StatusEffect, player_level: u32) -> u32 {
+    match effect {
+        StatusEffect::Poison => POISON_BASE_DAMAGE + player_level / 2,
+        StatusEffect::Burn => BURN_DAMAGE,
+        StatusEffect::Stun | StatusEffect::Freeze => 0,
+    }
+}
+
+/// Deal status effect damage and count down their durations.
+///
+/// Effects with no turns left deal no damage and are dropped.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+/// * `player_level` - Player's level
+///
+/// # Returns
+///
+/// * `(BattleState, u32)` - Updated state and damage dealt
+fn advance_status_effects(state: BattleState, player_level: u32) -> (BattleState, u32) {
+    let damage = state
+        .active_effects
+        .iter()
+        .filter(|&&(_, turns)| turns > 0)
+        .map(|&(effect, _)| status_effect_damage(effect, player_level))
+        .sum();
+    let active_effects = state
+        .active_effects
+        .iter()
+        .filter(|&&(_, turns)| turns > 1)
+        .map(|&(effect, turns)| (effect, turns - 1))
+        .collect();
+    let state = BattleState {
+        player_health: state.player_health.saturating_sub(damage),
+        active_effects,
+        ..state
+    };
+    (state, damage)
+}
+
+/// Get the tiles covered by a swing arc.
+///
+/// # Arguments
+///
+/// * `player_pos` - Player position
+/// * `facing` - Direction the player faces
+///
+/// # Returns
+///
+/// * `[(i32, i32); 3]` - Left-front, front and right-front tiles
+fn swing_arc_tiles(player_pos: &Position, facing: Direction) -> [(i32, i32); 3] {
+    let (fx, fy): (i32, i32) = match facing {
+        Direction::North => (0, -1),
+        Direction::South => (0, 1),
+        Direction::West => (-1, 0),
+        Direction::East => (1, 0),
+    };
+    let (sx, sy) = (fy.abs(), fx.abs());
+    [-1, 0, 1].map(|side| (player_pos.x + fx + side * sx, player_pos.y + fy + side * sy))
+}
+
+/// Hit every living enemy in the arc in front of the player.
+///
+/// # Arguments
+///
+/// * `player_pos` - Player position
+/// * `facing` - Direction the player faces
+/// * `enemies` - Enemies on the map
+///
+/// # Returns
+///
+/// * `Vec<(usize, u32)>` - Index of each enemy hit and its damage
+fn swing_arc_attack(
+    player_pos: Position,
+    facing: Direction,
+    enemies: &[EnemyState],
+) -> Vec<(usize, u32)> {
+    let tiles = swing_arc_tiles(&player_pos, facing);
+    enemies
+        .iter()
+        .enumerate()
+        .filter(|(_, enemy)| enemy.is_alive && tiles.contains(&(enemy.pos.x, enemy.pos.y)))
+        .map(|(index, _)| (index, SWING_ARC_BASE_DAMAGE))
+        .collect()
+}
+
+/// Create battle state with a summoned ally.
+///
+/// # Arguments
+///
+/// * `stats` - Summoner's stats
+///
+/// # Returns
+///
+/// * `BattleState` - Battle state with an active ally
+fn create_ally_state(stats: &ActionsCombatantStats) -> BattleState {
+    BattleState {
+        summon_ally_active: true,
+        ally_health: stats.max_health / 2,
+        ..create_battle_state(stats.health, 0)
+    }
+}
+
+/// Check if the ally absorbs this enemy hit.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+///
+/// # Returns
+///
+/// * `bool` - True if the ally takes the hit
+fn ally_absorbs_hit(state: &BattleState) -> bool {
+    state.summon_ally_active && state.turn_count % 2 == 1
+}
+
+/// Apply enemy damage to the ally or the player.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+/// * `damage` - Damage dealt by the enemy
+///
+/// # Returns
+///
+/// * `BattleState` - Updated battle state
+fn resolve_enemy_hit(state: BattleState, damage: u32) -> BattleState {
+    if ally_absorbs_hit(&state) {
+        let ally_health = state.ally_health.saturating_sub(damage);
+        BattleState {
+            ally_health,
+            summon_ally_active: ally_health > 0,
+            ..state
+        }
+    } else {
+        BattleState {
+            player_health: state.player_health.saturating_sub(damage),
+            ..state
+        }
+    }
+}
+
+/// Calculate bonus damage from the ally's auto-attack.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+/// * `attack` - Summoner's attack stat
+///
+/// # Returns
+///
+/// * `u32` - Ally bonus damage
+fn ally_bonus_damage(state: &BattleState, attack: u32) -> u32 {
+    if state.summon_ally_active {
+        attack / ALLY_ATTACK_DIVISOR
+    } else {
+        0
+    }
+}
+
+/// Apply player damage plus any ally bonus to the enemy.
+///
+/// # Arguments
+///
+/// * `state` - Current battle state
+/// * `damage` - Damage dealt by the player
+/// * `attack` - Summoner's attack stat
+///
+/// # Returns
+///
+/// * `BattleState` - Updated battle state
+fn resolve_player_hit(state: BattleState, damage: u32, attack: u32) -> BattleState {
+    let total = damage + ally_bonus_damage(&state, attack);
+    BattleState {
+        enemy_health: state.enemy_health.saturating_sub(total),
+        ..state
+    }
+}
+
 /// Convert actions combat result.
 ///
 /// # Arguments
@@ -359,6 +887,8 @@ fn to_actions_result(result: CombatResult) -> ActionsCombatResult {
         target_defeated: result.target_defeated,
         exp_gained: result.exp_gained,
         message: result.message,
+        tags: result.tags,
+        reflected_damage: result.reflected_damage,
     }
 }
 
@@ -380,6 +910,7 @@ fn from_actions_attack(attack: bindings::exports::docs::combat::actions::AttackT
             AttackType::MagicAttack
         }
         bindings::exports::docs::combat::actions::AttackType::ShieldBash => AttackType::ShieldBash,
+        bindings::exports::docs::combat::actions::AttackType::SwingArc => AttackType::SwingArc,
     }
 }
 
@@ -420,12 +951,34 @@ impl DamageGuest for Component {
     /// # Arguments
     ///
     /// * `attacker_attack` - Attacker's attack stat
+    /// * `crit_chance` - Crit chance in percent
+    /// * `turn` - Current battle turn
+    /// * `seed` - Extra seed mixed into the roll
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - 1 if critical, 0 otherwise
+    fn roll_critical(attacker_attack: u32, crit_chance: u32, turn: u32, seed: u32) -> u32 {
+        if is_critical_hit(attacker_attack, crit_chance, turn, seed) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Roll for critical hit with a luck-adjusted chance.
+    ///
+    /// # Arguments
+    ///
+    /// * `roll` - Random roll
+    /// * `crit_chance` - Base crit chance in percent
+    /// * `luck` - Attacker's luck
     ///
     /// # Returns
     ///
     /// * `u32` - 1 if critical, 0 otherwise
-    fn roll_critical(attacker_attack: u32) -> u32 {
-        if is_critical_hit(attacker_attack) {
+    fn roll_critical_with_chance(roll: u32, crit_chance: u32, luck: u32) -> u32 {
+        if is_lucky_critical(roll, crit_chance, luck) {
             1
         } else {
             0
@@ -446,6 +999,33 @@ impl DamageGuest for Component {
         apply_critical_multiplier(damage, is_critical == 1)
     }
 
+    /// Get the element an attack type deals.
+    ///
+    /// # Arguments
+    ///
+    /// * `attack` - Attack type
+    ///
+    /// # Returns
+    ///
+    /// * `Element` - Element of the attack
+    fn attack_element(attack: AttackType) -> Element {
+        attack_element(&attack)
+    }
+
+    /// Get the damage multiplier a defender takes from an element.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - Incoming element
+    /// * `defender` - Defender stats
+    ///
+    /// # Returns
+    ///
+    /// * `f32` - Damage multiplier between 0.25 and 2.0
+    fn element_multiplier(element: Element, defender: DamageCombatantStats) -> f32 {
+        get_element_multiplier(element, &defender)
+    }
+
     /// Calculate final damage with all modifiers.
     ///
     /// # Arguments
@@ -462,10 +1042,25 @@ impl DamageGuest for Component {
         attacker: DamageCombatantStats,
         defender: DamageCombatantStats,
     ) -> u32 {
-        let base = Self::calculate_base_damage(attack, attacker);
-        let after_def = Self::apply_defense(base, defender.defense);
-        let crit = Self::roll_critical(attacker.attack);
-        Self::apply_critical(after_def, crit)
+        let element = attack_element(&attack);
+        elemental_damage(attack, element, attacker, defender)
+    }
+
+    /// Get the damage reflected by an equipped armor.
+    ///
+    /// # Arguments
+    ///
+    /// * `armor_id` - Equipped armor item ID
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Reflected damage per enemy attack
+    fn armor_reflect_damage(armor_id: u32) -> u32 {
+        if armor_id == MAGIC_ROBE_ID {
+            MAGIC_ROBE_REFLECT
+        } else {
+            0
+        }
     }
 }
 
@@ -478,6 +1073,9 @@ impl ActionsGuest for Component {
     /// * `player_stats` - Player stats
     /// * `enemy_stats` - Enemy stats
     /// * `enemy_exp` - Enemy experience reward
+    /// * `weapon_id` - Main-hand weapon ID
+    /// * `attack_bonus` - Total weapon attack bonus from the inventory
+    /// * `enchantments` - Main-hand weapon enchantments
     ///
     /// # Returns
     ///
@@ -487,14 +1085,28 @@ impl ActionsGuest for Component {
         player_stats: ActionsCombatantStats,
         enemy_stats: ActionsCombatantStats,
         enemy_exp: u32,
+        weapon_id: u32,
+        attack_bonus: u32,
+        enchantments: Vec<Enchantment>,
     ) -> ActionsCombatResult {
         let att = from_actions_attack(attack);
-        let p_stats = to_damage_stats(&player_stats);
+        let mut p_stats = to_damage_stats(&player_stats);
+        p_stats.equipment_bonus += attack_bonus;
         let e_stats = to_damage_stats(&enemy_stats);
-        let damage = <Component as DamageGuest>::calculate_final_damage(att, p_stats, e_stats);
-        let is_crit = is_critical_hit(player_stats.attack);
+        let element = match weapon_element(weapon_id) {
+            Element::None => attack_element(&att),
+            element => element,
+        };
+        let damage = elemental_damage(att, element, p_stats, e_stats);
+        let is_crit = is_critical_hit(
+            player_stats.attack,
+            player_stats.crit_chance,
+            1,
+            enemy_stats.health,
+        );
         let defeated = check_defeat(enemy_stats.health, damage);
-        let result = create_combat_result(damage, is_crit, defeated, enemy_exp);
+        let mut result = create_combat_result(damage, is_crit, defeated, enemy_exp);
+        result.tags = enchantment_tags(&enchantments);
         to_actions_result(result)
     }
 
@@ -521,9 +1133,15 @@ impl ActionsGuest for Component {
             e_stats,
             p_stats,
         );
-        let is_crit = is_critical_hit(enemy_stats.attack);
-        let defeated = check_defeat(player_stats.health, damage);
-        let result = create_combat_result(damage, is_crit, defeated, 0);
+        let is_crit = is_critical_hit(
+            enemy_stats.attack,
+            enemy_stats.crit_chance,
+            1,
+            player_stats.health,
+        );
+        let defeated = check_defeat(player_stats.health + player_stats.shield_hp, damage);
+        let mut result = create_combat_result(damage, is_crit, defeated, 0);
+        result.reflected_damage = player_stats.reflect_damage;
         to_actions_result(result)
     }
 
@@ -558,6 +1176,41 @@ impl ActionsGuest for Component {
     fn attempt_flee(player_speed: u32, enemy_speed: u32) -> bool {
         calculate_flee_success(player_speed, enemy_speed)
     }
+
+    /// Summon an ally to fight alongside the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Summoner's stats
+    ///
+    /// # Returns
+    ///
+    /// * `BattleState` - Battle state with an active ally
+    fn summon_ally(stats: ActionsCombatantStats) -> BattleState {
+        create_ally_state(&stats)
+    }
+
+    /// Swing at every enemy in the arc in front of the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_pos` - Player position
+    /// * `facing` - Direction the player faces
+    /// * `enemies` - Enemies on the map
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(u32, u32)>` - Index of each enemy hit and its damage
+    fn swing_arc_attack(
+        player_pos: Position,
+        facing: Direction,
+        enemies: Vec<EnemyState>,
+    ) -> Vec<(u32, u32)> {
+        swing_arc_attack(player_pos, facing, &enemies)
+            .into_iter()
+            .map(|(index, damage)| (index as u32, damage))
+            .collect()
+    }
 }
 
 /// Convert actions stats to damage stats.
@@ -576,6 +1229,14 @@ fn to_damage_stats(stats: &ActionsCombatantStats) -> DamageCombatantStats {
         health: stats.health,
         max_health: stats.max_health,
         equipment_bonus: stats.equipment_bonus,
+        shield_hp: stats.shield_hp,
+        max_shield_hp: stats.max_shield_hp,
+        reflect_damage: stats.reflect_damage,
+        speed: stats.speed,
+        crit_chance: stats.crit_chance,
+        fire_resistance: stats.fire_resistance,
+        ice_resistance: stats.ice_resistance,
+        lightning_resistance: stats.lightning_resistance,
     }
 }
 
@@ -586,12 +1247,22 @@ impl BattleGuest for Component {
     ///
     /// * `player_health` - Player starting health
     /// * `enemy_health` - Enemy starting health
+    /// * `player_speed` - Player speed stat
+    /// * `enemy_speed` - Enemy speed stat
     ///
     /// # Returns
     ///
     /// * `BattleState` - Initial battle state
-    fn start_battle(player_health: u32, enemy_health: u32) -> BattleState {
-        create_battle_state(player_health, enemy_health)
+    fn start_battle(
+        player_health: u32,
+        enemy_health: u32,
+        player_speed: u32,
+        enemy_speed: u32,
+    ) -> BattleState {
+        BattleState {
+            is_player_turn: player_speed >= enemy_speed,
+            ..create_battle_state(player_health, enemy_health)
+        }
     }
 
     /// End the current battle.
@@ -660,6 +1331,81 @@ impl BattleGuest for Component {
     fn player_won(state: BattleState) -> bool {
         determine_player_won(&state)
     }
+
+    /// Get the battle log as a summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Battle state
+    ///
+    /// # Returns
+    ///
+    /// * `String` - Log entries separated by newlines
+    fn get_battle_summary(state: BattleState) -> String {
+        get_battle_summary(state)
+    }
+
+    /// Apply an enemy hit to the ally or the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Current battle state
+    /// * `damage` - Damage dealt by the enemy
+    ///
+    /// # Returns
+    ///
+    /// * `BattleState` - Updated battle state
+    fn apply_enemy_hit(state: BattleState, damage: u32) -> BattleState {
+        resolve_enemy_hit(state, damage)
+    }
+
+    /// Apply a player hit including the ally's auto-attack.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Current battle state
+    /// * `damage` - Damage dealt by the player
+    /// * `player_stats` - Player stats
+    ///
+    /// # Returns
+    ///
+    /// * `BattleState` - Updated battle state
+    fn apply_player_hit(
+        state: BattleState,
+        damage: u32,
+        player_stats: BattleCombatantStats,
+    ) -> BattleState {
+        resolve_player_hit(state, damage, player_stats.attack)
+    }
+
+    /// Apply a status effect to the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Current battle state
+    /// * `effect` - Effect to apply
+    /// * `duration` - Turns the effect lasts
+    ///
+    /// # Returns
+    ///
+    /// * `BattleState` - Updated battle state
+    fn apply_status_effect(state: BattleState, effect: StatusEffect, duration: u32) -> BattleState {
+        add_status_effect(state, effect, duration)
+    }
+
+    /// Tick active status effects at the start of a turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - Current battle state
+    /// * `player_level` - Player's level
+    ///
+    /// # Returns
+    ///
+    /// * `(BattleState, u32)` - Updated state and damage dealt
+    fn tick_status_effects(state: BattleState, player_level: u32) -> (BattleState, u32) {
+        advance_status_effects(state, player_level)
+    }
 }
 
 #[cfg(test)]
@@ -723,15 +1469,31 @@ mod tests {
     }
 
     #[test]
-    /// Test critical hit detection true.
+    /// Test a 100% crit chance always crits.
     fn test_is_critical_hit_true() {
-        assert!(is_critical_hit(17));
+        assert!((1..50).all(|turn| is_critical_hit(25, 100, turn, 7)));
     }
 
     #[test]
-    /// Test critical hit detection false.
+    /// Test a crit chance above 100% is capped without overflowing.
+    fn test_is_critical_hit_capped_chance() {
+        assert!((1..50).all(|turn| is_critical_hit(25, u32::MAX, turn, 7)));
+        assert_eq!(effective_crit_chance(u32::MAX, u32::MAX), 100);
+    }
+
+    #[test]
+    /// Test a 0% crit chance never crits.
     fn test_is_critical_hit_false() {
-        assert!(!is_critical_hit(15));
+        assert!((1..50).all(|turn| !is_critical_hit(25, 0, turn, 7)));
+    }
+
+    #[test]
+    /// Test a partial crit chance crits on some turns only.
+    fn test_is_critical_hit_partial_chance() {
+        let crits = (1..200)
+            .filter(|&turn| is_critical_hit(25, 50, turn, 3))
+            .count();
+        assert!(crits > 0 && crits < 199);
     }
 
     #[test]
@@ -786,6 +1548,13 @@ mod tests {
         assert!(!calculate_flee_success(5, 10));
     }
 
+    #[test]
+    /// Test flee checks do not overflow on huge speeds.
+    fn test_calculate_flee_success_huge_speeds() {
+        assert!(calculate_flee_success(u32::MAX, u32::MAX));
+        assert!(!calculate_flee_success(u32::MAX / 2, u32::MAX));
+    }
+
     #[test]
     /// Test can use spin attack.
     fn test_can_use_special_spin_true() {
@@ -867,8 +1636,9 @@ mod tests {
     #[test]
     /// Test start battle function.
     fn test_start_battle() {
-        let state = <Component as BattleGuest>::start_battle(100, 50);
+        let state = <Component as BattleGuest>::start_battle(100, 50, 10, 10);
         assert!(state.is_active);
+        assert!(state.is_player_turn);
     }
 
     #[test]
@@ -914,4 +1684,371 @@ mod tests {
     fn test_attempt_flee_failure() {
         assert!(!<Component as ActionsGuest>::attempt_flee(5, 10));
     }
+
+    /// Helper to build summoner stats.
+    fn ally_summoner() -> ActionsCombatantStats {
+        ActionsCombatantStats {
+            attack: 30,
+            defense: 5,
+            health: 100,
+            max_health: 100,
+            equipment_bonus: 0,
+            shield_hp: 0,
+            max_shield_hp: 0,
+            reflect_damage: 0,
+            speed: 10,
+            crit_chance: 0,
+            fire_resistance: 0,
+            ice_resistance: 0,
+            lightning_resistance: 0,
+        }
+    }
+
+    #[test]
+    /// Test summon ally sets ally health to half max health.
+    fn test_summon_ally() {
+        let state = <Component as ActionsGuest>::summon_ally(ally_summoner());
+        assert!(state.summon_ally_active);
+        assert_eq!(state.ally_health, 50);
+    }
+
+    #[test]
+    /// Test ally absorbs a hit before disappearing.
+    fn test_ally_absorbs_hit_then_disappears() {
+        let mut state = <Component as ActionsGuest>::summon_ally(ally_summoner());
+        state.turn_count = 1;
+        let state = <Component as BattleGuest>::apply_enemy_hit(state, 30);
+        assert_eq!(state.player_health, 100);
+        assert_eq!(state.ally_health, 20);
+        assert!(state.summon_ally_active);
+        let state = <Component as BattleGuest>::apply_enemy_hit(state, 30);
+        assert_eq!(state.ally_health, 0);
+        assert!(!state.summon_ally_active);
+        let state = <Component as BattleGuest>::apply_enemy_hit(state, 30);
+        assert_eq!(state.player_health, 70);
+    }
+
+    #[test]
+    /// Test player takes the hit on even turns.
+    fn test_ally_skips_even_turn() {
+        let state = <Component as ActionsGuest>::summon_ally(ally_summoner());
+        let state = resolve_enemy_hit(state, 10);
+        assert_eq!(state.player_health, 90);
+        assert_eq!(state.ally_health, 50);
+    }
+
+    #[test]
+    /// Test ally adds bonus damage to player hits.
+    fn test_ally_bonus_damage() {
+        let mut state = <Component as ActionsGuest>::summon_ally(ally_summoner());
+        state.enemy_health = 50;
+        let state = <Component as BattleGuest>::apply_player_hit(state, 10, ally_summoner());
+        assert_eq!(state.enemy_health, 30);
+    }
+
+    #[test]
+    /// Test no bonus damage without an ally.
+    fn test_no_ally_bonus_damage() {
+        let state = create_battle_state(100, 50);
+        let state = resolve_player_hit(state, 10, 30);
+        assert_eq!(state.enemy_health, 40);
+    }
+
+    #[test]
+    /// Test the inventory's dual-wield attack bonus increases player damage.
+    fn test_player_attack_dual_wield() {
+        let mut player = ally_summoner();
+        player.attack = 34;
+        let mut enemy = ally_summoner();
+        enemy.health = 1000;
+        let single = <Component as ActionsGuest>::player_attack(
+            bindings::exports::docs::combat::actions::AttackType::SwordSlash,
+            player,
+            enemy,
+            0,
+            2,
+            10,
+            Vec::new(),
+        );
+        let dual = <Component as ActionsGuest>::player_attack(
+            bindings::exports::docs::combat::actions::AttackType::SwordSlash,
+            player,
+            enemy,
+            0,
+            2,
+            16,
+            Vec::new(),
+        );
+        assert!(dual.damage_dealt > single.damage_dealt);
+    }
+
+    #[test]
+    /// Test weapon enchantments are reported as result tags.
+    fn test_player_attack_enchantment_tags() {
+        let result = <Component as ActionsGuest>::player_attack(
+            bindings::exports::docs::combat::actions::AttackType::SwordSlash,
+            ally_summoner(),
+            ally_summoner(),
+            0,
+            2,
+            0,
+            vec![Enchantment::FireDamage(5), Enchantment::FrostSlow],
+        );
+        assert_eq!(result.tags, vec!["fire-damage:5", "frost-slow"]);
+    }
+
+    /// Create stats for a player wearing the given armor.
+    fn armored_player(armor_id: u32) -> ActionsCombatantStats {
+        ActionsCombatantStats {
+            attack: 15,
+            defense: 8,
+            health: 100,
+            max_health: 100,
+            equipment_bonus: 0,
+            shield_hp: 0,
+            max_shield_hp: 0,
+            reflect_damage: <Component as DamageGuest>::armor_reflect_damage(armor_id),
+            speed: 10,
+            crit_chance: 0,
+            fire_resistance: 0,
+            ice_resistance: 0,
+            lightning_resistance: 0,
+        }
+    }
+
+    /// Create stats for a weak enemy with the given health.
+    fn weak_enemy(health: u32) -> ActionsCombatantStats {
+        ActionsCombatantStats {
+            attack: 12,
+            defense: 0,
+            health,
+            max_health: 10,
+            equipment_bonus: 0,
+            shield_hp: 0,
+            max_shield_hp: 0,
+            reflect_damage: 0,
+            speed: 5,
+            crit_chance: 0,
+            fire_resistance: 0,
+            ice_resistance: 0,
+            lightning_resistance: 0,
+        }
+    }
+
+    #[test]
+    /// Test the Magic Robe reflects 3 damage on every enemy attack.
+    fn test_enemy_attack_reflects_damage() {
+        let robed = armored_player(105);
+        let mut enemy_health = 7;
+        while enemy_health > 0 {
+            let result =
+                <Component as ActionsGuest>::enemy_attack(12, weak_enemy(enemy_health), robed);
+            assert_eq!(result.reflected_damage, 3);
+            enemy_health = enemy_health.saturating_sub(result.reflected_damage);
+        }
+        let result =
+            <Component as ActionsGuest>::enemy_attack(12, weak_enemy(7), armored_player(103));
+        assert_eq!(result.reflected_damage, 0);
+    }
+
+    #[test]
+    /// Test luck of 20 raises crit chance by 10 percent.
+    fn test_roll_critical_with_chance_luck() {
+        assert_eq!(effective_crit_chance(10, 20), 20);
+        assert_eq!(
+            <Component as DamageGuest>::roll_critical_with_chance(15, 10, 0),
+            0
+        );
+        assert_eq!(
+            <Component as DamageGuest>::roll_critical_with_chance(15, 10, 20),
+            1
+        );
+        let lucky = (0..100).filter(|&r| is_lucky_critical(r, 10, 20)).count();
+        assert_eq!(lucky, 20);
+    }
+
+    #[test]
+    /// Test a faster Bat takes the first turn against a default-speed player.
+    fn test_start_battle_bat_initiative() {
+        let state = <Component as BattleGuest>::start_battle(100, 20, 10, 15);
+        assert!(!state.is_player_turn);
+    }
+
+    #[test]
+    /// Test fleeing succeeds at 80% of the enemy's speed.
+    fn test_calculate_flee_success_threshold() {
+        assert!(calculate_flee_success(8, 10));
+        assert!(!calculate_flee_success(7, 10));
+        assert!(!calculate_flee_success(10, 15));
+    }
+
+    #[test]
+    /// Test health percentage matches the player component.
+    fn test_health_percentage() {
+        assert_eq!(health_percentage(0, 100), 0);
+        assert_eq!(health_percentage(100, 100), 100);
+        assert_eq!(health_percentage(25, 100), 25);
+        assert_eq!(health_percentage(25, 0), 0);
+    }
+
+    #[test]
+    /// Test swing arc hits the three tiles in front but not behind.
+    fn test_swing_arc_attack() {
+        let enemy = |x, y| EnemyState {
+            pos: Position { x, y },
+            health: 20,
+            is_alive: true,
+        };
+        let enemies = vec![enemy(6, 4), enemy(6, 5), enemy(6, 6), enemy(4, 5)];
+        let hits = swing_arc_attack(Position { x: 5, y: 5 }, Direction::East, &enemies);
+        assert_eq!(
+            hits,
+            vec![
+                (0, SWING_ARC_BASE_DAMAGE),
+                (1, SWING_ARC_BASE_DAMAGE),
+                (2, SWING_ARC_BASE_DAMAGE)
+            ]
+        );
+    }
+
+    #[test]
+    /// Test swing arc needs half health like the spin attack.
+    fn test_can_use_special_swing_arc() {
+        assert!(can_use_special(&AttackType::SwingArc, 50, 100));
+        assert!(!can_use_special(&AttackType::SwingArc, 49, 100));
+    }
+
+    #[test]
+    /// Test poison deals 3 + level / 2 each tick and expires.
+    fn test_tick_poison() {
+        let state = <Component as BattleGuest>::start_battle(50, 20, 10, 10);
+        let state = <Component as BattleGuest>::apply_status_effect(state, StatusEffect::Poison, 2);
+        let (state, damage) = <Component as BattleGuest>::tick_status_effects(state, 5);
+        assert_eq!(damage, 5);
+        assert_eq!(state.player_health, 45);
+        let (state, _) = <Component as BattleGuest>::tick_status_effects(state, 5);
+        assert!(state.active_effects.is_empty());
+        let (state, damage) = <Component as BattleGuest>::tick_status_effects(state, 5);
+        assert_eq!((damage, state.player_health), (0, 40));
+    }
+
+    #[test]
+    /// Test an effect with no turns left deals no damage.
+    fn test_zero_turn_effect_deals_no_damage() {
+        let mut state = create_battle_state(50, 20);
+        state.active_effects = vec![(StatusEffect::Poison, 0)];
+        let (state, damage) = <Component as BattleGuest>::tick_status_effects(state, 5);
+        assert_eq!((damage, state.player_health), (0, 50));
+        assert!(state.active_effects.is_empty());
+    }
+
+    #[test]
+    /// Test reapplying an effect keeps the longer duration.
+    fn test_apply_status_effect_refreshes() {
+        let state = create_battle_state(50, 20);
+        let state = add_status_effect(state, StatusEffect::Stun, 3);
+        let state = add_status_effect(state, StatusEffect::Stun, 1);
+        assert_eq!(state.active_effects, vec![(StatusEffect::Stun, 3)]);
+    }
+
+    #[test]
+    /// Test element multipliers follow the defender's resistance or weakness.
+    fn test_get_element_multiplier() {
+        let mut defender = to_damage_stats(&ally_summoner());
+        defender.fire_resistance = 50;
+        defender.lightning_resistance = 90;
+        assert_eq!(get_element_multiplier(Element::Fire, &defender), 0.5);
+        assert_eq!(get_element_multiplier(Element::Lightning, &defender), 0.25);
+        assert_eq!(get_element_multiplier(Element::Ice, &defender), 1.0);
+        assert_eq!(get_element_multiplier(Element::None, &defender), 1.0);
+        defender.ice_resistance = -50;
+        assert_eq!(get_element_multiplier(Element::Ice, &defender), 1.5);
+        defender.ice_resistance = -300;
+        assert_eq!(get_element_multiplier(Element::Ice, &defender), 2.0);
+    }
+
+    #[test]
+    /// Test a fire-immune enemy takes no damage from the Fire Rod.
+    fn test_fire_immune_enemy_takes_no_fire_damage() {
+        let mut enemy = ally_summoner();
+        enemy.fire_resistance = 100;
+        let fire = <Component as ActionsGuest>::player_attack(
+            bindings::exports::docs::combat::actions::AttackType::SwordSlash,
+            ally_summoner(),
+            enemy,
+            0,
+            FIRE_ROD_ID,
+            0,
+            Vec::new(),
+        );
+        assert_eq!(fire.damage_dealt, 0);
+        let steel = <Component as ActionsGuest>::player_attack(
+            bindings::exports::docs::combat::actions::AttackType::SwordSlash,
+            ally_summoner(),
+            enemy,
+            0,
+            2,
+            0,
+            Vec::new(),
+        );
+        assert!(steel.damage_dealt > 0);
+    }
+
+    #[test]
+    /// Test resistance is applied before defense.
+    fn test_calculate_final_damage_resistance() {
+        let attacker = to_damage_stats(&ally_summoner());
+        let mut defender = attacker;
+        defender.defense = 10;
+        let plain = <Component as DamageGuest>::calculate_final_damage(
+            AttackType::MagicAttack,
+            attacker,
+            defender,
+        );
+        assert_eq!(plain, 55);
+        defender.lightning_resistance = 50;
+        let resisted = <Component as DamageGuest>::calculate_final_damage(
+            AttackType::MagicAttack,
+            attacker,
+            defender,
+        );
+        assert_eq!(resisted, 25);
+    }
+
+    #[test]
+    /// Test a full battle is recorded in the log.
+    fn test_battle_log_full_combat() {
+        let mut state = <Component as BattleGuest>::start_battle(100, 30, 10, 5);
+        let mut enemy_hp = 30;
+        while enemy_hp > 0 {
+            enemy_hp -= 15;
+            state = <Component as BattleGuest>::update_health(state, 100, enemy_hp);
+            state = <Component as BattleGuest>::next_turn(state);
+            state = <Component as BattleGuest>::update_health(state, 92, enemy_hp);
+            state = <Component as BattleGuest>::next_turn(state);
+        }
+        assert_eq!(state.log[0], "Turn 0: Player deals 15 damage. Enemy HP: 15");
+        assert_eq!(state.log[1], "Turn 1: Enemy to act.");
+        assert_eq!(state.log[2], "Turn 1: Enemy deals 8 damage. Player HP: 92");
+        let state = <Component as BattleGuest>::end_battle(state);
+        assert!(<Component as BattleGuest>::player_won(state.clone()));
+        assert_eq!(
+            state.log.last().map(String::as_str),
+            Some("Turn 4: Battle over. Player wins.")
+        );
+        let summary = <Component as BattleGuest>::get_battle_summary(state.clone());
+        assert_eq!(summary.lines().count(), state.log.len());
+    }
+
+    #[test]
+    /// Test the battle log keeps only the latest entries.
+    fn test_battle_log_bounded() {
+        let mut state = create_battle_state(100, 50);
+        for _ in 0..60 {
+            state = advance_turn(state);
+        }
+        assert_eq!(state.log.len(), MAX_LOG_ENTRIES);
+        assert_eq!(state.log[0], "Turn 11: Enemy to act.");
+        assert_eq!(state.log[MAX_LOG_ENTRIES - 1], "Turn 60: Player to act.");
+    }
 }
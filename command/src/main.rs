@@ -21,12 +21,207 @@
 //!
 //! MIT License
 
+use std::collections::{HashSet, VecDeque};
+use std::fs;
 use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize, Serializer};
 
 /// Map dimensions for the game world.
 const MAP_WIDTH: i32 = 20;
+/// Palette color of the player (bright cyan).
+const PLAYER_COLOR: u8 = 14;
+/// Palette color of enemies (red).
+const ENEMY_COLOR: u8 = 1;
+/// Palette color of potions (green).
+const POTION_COLOR: u8 = 2;
+/// Palette color of gold (yellow).
+const GOLD_COLOR: u8 = 3;
+/// Palette color of walls (gray).
+const WALL_COLOR: u8 = 8;
+/// Palette color of water (blue).
+const WATER_COLOR: u8 = 4;
+/// Palette color of trees (dark green).
+const TREE_COLOR: u8 = 22;
+/// RGB values of the 16 basic palette colors, as in xterm.
+const BASIC_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
 /// Map height for the game world.
 const MAP_HEIGHT: i32 = 15;
+/// Maximum number of saved waypoints.
+const MAX_WAYPOINTS: usize = 10;
+/// Gold charged per player level for fast travel.
+const TRAVEL_COST_PER_LEVEL: i32 = 10;
+/// Fast travel arrival point of each area as (name, x, y).
+const AREA_ENTRY_POINTS: &[(&str, i32, i32)] = &[
+    ("Hyrule Field NW", 2, 2),
+    ("Hyrule Castle", 10, 2),
+    ("Kakariko Village", 17, 2),
+    ("Lost Woods", 3, 7),
+    ("Lake Hylia", 8, 7),
+    ("Death Mountain", 17, 7),
+    ("Zora's Domain", 3, 12),
+    ("Gerudo Valley", 10, 12),
+    ("Temple of Time", 17, 12),
+];
+/// Guided prompts for each tutorial step.
+const TUTORIAL_PROMPTS: [&str; 10] = [
+    "Move in any direction with n/s/e/w.",
+    "Check your stats with 'stat'.",
+    "Open your inventory with 'i'.",
+    "Wait a turn with '.'.",
+    "Swing your weapon with 'a'.",
+    "Drink a potion with 'u'.",
+    "Mark this spot as a waypoint with 'mark <name>'.",
+    "List your waypoints with 'waypoints'.",
+    "Look closely at a tile with 'inspect <x> <y>'.",
+    "See every command with 'h'.",
+];
+/// Maximum number of field notes kept at once.
+const MAX_FIELD_NOTES: usize = 50;
+/// Cardinal neighbor offsets in north, south, east, west order.
+///
+/// This matches the engine's adjacent tile order and is the tie-break
+/// priority for targeting and pathfinding, so east is checked before west.
+const CARDINAL_OFFSETS: [(i32, i32); 4] = [(0, -1), (0, 1), (1, 0), (-1, 0)];
+/// Width of HUD progress bars in characters.
+const BAR_WIDTH: i32 = 8;
+/// Starting attack power of the player.
+const BASE_ATTACK: i32 = 15;
+/// Gold found in a pile of coins.
+const GOLD_PILE: i32 = 25;
+/// Merchant stock as (item ID, name, price), mirroring the inventory component.
+///
+/// The merchant buys its stock back at half these prices.
+const MERCHANT_CATALOG: [(u32, &str, i32); 5] = [
+    (201, "Health Potion", 20),
+    (MAGIC_MAP_ID, "Magic Map", 50),
+    (2, "Steel Sword", 100),
+    (103, "Chain Mail", 150),
+    (102, "Leather Armor", 80),
+];
+/// Taxicab distance the player can see around them.
+const VISION_RADIUS: i32 = 4;
+/// Most items the player can carry.
+const INVENTORY_CAPACITY: usize = 20;
+/// Gold a thief steals on contact.
+const THIEF_STEAL_AMOUNT: i32 = 15;
+/// Attack gained from a sword upgrade.
+const SWORD_BONUS: i32 = 10;
+/// Attack gained from an attack boost found in a chest.
+const ATTACK_BOOST: i32 = 5;
+/// Chest rewards as (item ID, quantity, rarity weight, minimum level).
+const CHEST_REWARDS: [(u32, u32, u64, u32); 7] = [
+    (201, 2, 34, 1),
+    (203, 1, 25, 1),
+    (MAGIC_MAP_ID, 1, 15, 1),
+    (2, 1, 13, 1),
+    (103, 1, 8, 1),
+    (MAGIC_ROBE_ID, 1, 6, 3),
+    (3, 1, 5, 5),
+];
+/// Inventory item ID of the Full Health Potion.
+const FULL_HEALTH_POTION_ID: u32 = 202;
+/// Inventory item ID of the Magic Map.
+const MAGIC_MAP_ID: u32 = 211;
+/// Inventory item ID of the Magic Robe.
+const MAGIC_ROBE_ID: u32 = 105;
+/// Damage the Magic Robe reflects, matching the combat component.
+const MAGIC_ROBE_REFLECT: i32 = 3;
+/// Inventory item ID of the Shield armor.
+const SHIELD_ARMOR_ID: u32 = 104;
+/// Shield points the Shield grants, matching the player component.
+const SHIELD_HP: i32 = 30;
+/// Shield points regenerated each turn, matching the player component.
+const SHIELD_REGEN_PER_TURN: i32 = 5;
+/// Luck a new player starts with.
+const STARTING_LUCK: u32 = 5;
+/// Speed a new player starts with.
+const STARTING_SPEED: u32 = 10;
+/// Experience needed per level to reach the next one.
+const EXP_PER_LEVEL: i32 = 25;
+/// Max health gained on level up.
+const LEVEL_UP_HEALTH: i32 = 10;
+/// Attack gained on level up.
+const LEVEL_UP_ATTACK: i32 = 3;
+/// Defense gained on level up.
+const LEVEL_UP_DEFENSE: i32 = 2;
+/// ID of the escorted NPC companion.
+const ESCORT_NPC_ID: u32 = 1;
+/// Starting health of the escorted NPC.
+const ESCORT_HP: u32 = 30;
+/// Where the escorted NPC must be brought.
+const ESCORT_DESTINATION: (i32, i32) = (17, 12);
+/// Score awarded for completing an escort.
+const ESCORT_REWARD: i32 = 300;
+/// Hint symbol marking where an enemy was defeated.
+const BATTLE_SITE_SYMBOL: char = 'x';
+/// Turns a map hint stays visible.
+const HINT_TURNS: u32 = 5;
+/// Survival wave that ends the game in victory once cleared.
+const SURVIVAL_FINAL_WAVE: u32 = 10;
+/// Enemies in a survival wave before adding the wave number.
+const SURVIVAL_BASE_ENEMIES: u32 = 3;
+/// Tile index step between survival spawn points.
+const SURVIVAL_SPAWN_STRIDE: usize = 11;
+/// Positions of the enemies spawned at the start of a game, besides the boss.
+const ENEMY_SPAWN_POINTS: [(i32, i32); 6] = [(5, 3), (14, 5), (8, 9), (16, 10), (3, 12), (17, 13)];
+/// Enemy kinds allowed to spawn in each area.
+///
+/// Dragons spawn in Death Mountain, their home area in the enemy component.
+const AREA_ENEMY_TABLES: &[(&str, &[EnemyKind])] = &[
+    ("Hyrule Field NW", &[EnemyKind::Slime, EnemyKind::Bat]),
+    (
+        "Hyrule Castle",
+        &[EnemyKind::Skeleton, EnemyKind::DarkKnight],
+    ),
+    (
+        "Kakariko Village",
+        &[EnemyKind::Slime, EnemyKind::Goblin, EnemyKind::Thief],
+    ),
+    ("Lost Woods", &[EnemyKind::Bat, EnemyKind::Slime]),
+    ("Lake Hylia", &[EnemyKind::Slime, EnemyKind::Skeleton]),
+    (
+        "Death Mountain",
+        &[
+            EnemyKind::Dragon,
+            EnemyKind::Skeleton,
+            EnemyKind::Bat,
+            EnemyKind::Goblin,
+        ],
+    ),
+    ("Zora's Domain", &[EnemyKind::Slime, EnemyKind::Bat]),
+    ("Gerudo Valley", &[EnemyKind::Goblin, EnemyKind::DarkKnight]),
+    (
+        "Temple of Time",
+        &[EnemyKind::Skeleton, EnemyKind::DarkKnight],
+    ),
+];
+/// Enemy kinds cycled through when spawning a survival wave.
+const SURVIVAL_KINDS: [EnemyKind; 5] = [
+    EnemyKind::Slime,
+    EnemyKind::Bat,
+    EnemyKind::Skeleton,
+    EnemyKind::Goblin,
+    EnemyKind::DarkKnight,
+];
 
 /// Represents a user input command.
 #[derive(Debug, Clone, PartialEq)]
@@ -49,6 +244,68 @@ pub enum Command {
     Quit,
     /// Wait/skip a turn.
     Wait,
+    /// Preview a battle against an enemy kind without fighting it.
+    Duel(EnemyKind),
+    /// Mark the current position as a named waypoint.
+    Mark(String),
+    /// Travel toward a named waypoint.
+    Goto(String),
+    /// List all saved waypoints.
+    Waypoints,
+    /// Annotate the current tile with a note.
+    Note(String),
+    /// List all field notes.
+    Notes,
+    /// Start a new game without restarting the program.
+    NewGame,
+    /// Drop an item at the player's position.
+    Drop(ItemKind),
+    /// Start an escort quest.
+    Escort,
+    /// Pay gold to teleport to a named area.
+    Travel(String),
+    /// Show the distance to the boss.
+    WhereBoss,
+    /// Show a compass pointing to the quest objective and the boss.
+    Compass,
+    /// Preview the bonuses of the next level up.
+    LevelUpPreview,
+    /// Apply a pending level up.
+    ConfirmLevelUp,
+    /// Equip the strongest weapon and armor the player owns.
+    Optimize,
+    /// Open the merchant's shop menu.
+    Shop,
+    /// Buy an item by its number on the shop menu.
+    Buy(usize),
+    /// Sell an item back by its number on the shop menu.
+    Sell(usize),
+    /// Toggle colored enemy threat levels on the map.
+    Color,
+    /// Start the guided tutorial.
+    Tutorial,
+    /// Read a Magic Map to reveal the current area.
+    ReadMap,
+    /// Pause the game.
+    Pause,
+    /// Resume a paused game.
+    Resume,
+    /// Describe everything at a map position.
+    Inspect(i32, i32),
+    /// Preview stat changes from equipping an item by ID.
+    PreviewEquip(u32),
+    /// Choose how attacks pick among adjacent enemies.
+    Target(TargetingMode),
+    /// Undo item and gold changes from the last turn.
+    UndoItem,
+    /// Start a new game in the given mode.
+    Mode(GameMode),
+    /// Set the world seed before the first turn.
+    Seed(u64),
+    /// Save the game to a file.
+    Save(String),
+    /// Load a saved game from a file.
+    Load(String),
     /// Unknown or invalid command.
     Unknown,
 }
@@ -64,10 +321,18 @@ pub enum Direction {
     East,
     /// Move leftward (west).
     West,
+    /// Move up and right (north-east).
+    NorthEast,
+    /// Move down and right (south-east).
+    SouthEast,
+    /// Move down and left (south-west).
+    SouthWest,
+    /// Move up and left (north-west).
+    NorthWest,
 }
 
 /// Enemy type in the game.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EnemyKind {
     /// Weak enemy, easy to defeat.
     Slime,
@@ -81,10 +346,25 @@ pub enum EnemyKind {
     DarkKnight,
     /// Boss enemy.
     Boss,
+    /// Gold-stealing enemy that flees after contact.
+    Thief,
+    /// Fire-breathing dragon.
+    Dragon,
+    /// Spellcasting wizard.
+    Wizard,
+}
+
+/// How an enemy moves each turn.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Behavior {
+    /// Step toward the player.
+    Chase,
+    /// Step away from the player.
+    Flee,
 }
 
 /// An enemy in the game world.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enemy {
     /// Enemy type.
     pub kind: EnemyKind,
@@ -98,10 +378,71 @@ pub struct Enemy {
     pub attack: i32,
     /// Experience reward.
     pub exp: i32,
+    /// Current movement behavior.
+    pub behavior: Behavior,
+}
+
+/// Item and gold counts saved before a turn so item use can be undone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InventorySnapshot {
+    /// Number of potions.
+    pub potions: i32,
+    /// Number of Magic Maps.
+    pub magic_maps: i32,
+    /// Gold amount.
+    pub gold: i32,
+    /// Health points.
+    pub health: i32,
+}
+
+/// How dangerous an enemy is to the player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThreatLevel {
+    /// Poses little risk.
+    Safe,
+    /// Can be fought with some care.
+    Manageable,
+    /// Best avoided when low on health.
+    Dangerous,
+    /// Likely to defeat the player.
+    Deadly,
+}
+
+/// How an attack picks among adjacent enemies.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TargetingMode {
+    /// First enemy found around the player.
+    FirstFound,
+    /// Enemy with the least health.
+    Weakest,
+    /// Enemy with the most health.
+    Strongest,
+    /// Enemy closest to the player.
+    Nearest,
+}
+
+/// How the terminal displays colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorMode {
+    /// Plain characters without escape codes.
+    None,
+    /// The 256-color palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+/// Rules the current game is played under.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GameMode {
+    /// Defeat every enemy on the map.
+    Adventure,
+    /// Survive escalating waves of enemies.
+    Survival,
 }
 
 /// A collectible item in the game world.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ItemKind {
     /// Health potion.
     Potion,
@@ -114,7 +455,7 @@ pub enum ItemKind {
 }
 
 /// An item on the map.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
     /// Item type.
     pub kind: ItemKind,
@@ -124,8 +465,61 @@ pub struct Item {
     pub y: i32,
 }
 
+/// Goal of a quest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuestObjective {
+    /// Escort an NPC companion safely to a destination.
+    EscortNpc {
+        /// ID of the escorted NPC.
+        npc_id: u32,
+        /// Position the NPC must reach.
+        destination: (i32, i32),
+        /// Whether the NPC is still alive.
+        npc_alive: bool,
+        /// Remaining health of the NPC.
+        escort_hp: u32,
+    },
+}
+
+/// Progress of a quest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuestStatus {
+    /// Quest is in progress.
+    Active,
+    /// Quest objective was met.
+    Completed,
+    /// Quest can no longer be completed.
+    Failed,
+}
+
+/// A quest the player has taken on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quest {
+    /// Quest goal.
+    pub objective: QuestObjective,
+    /// Quest progress.
+    pub status: QuestStatus,
+    /// X position of the escorted NPC.
+    pub npc_x: i32,
+    /// Y position of the escorted NPC.
+    pub npc_y: i32,
+}
+
+/// Summary of a won battle, shown on the next map.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BattleReward {
+    /// Experience earned.
+    pub exp_gained: i32,
+    /// Names of items dropped by the enemy.
+    pub items_dropped: Vec<String>,
+    /// Gold earned.
+    pub gold_gained: i32,
+    /// Turns the battle lasted.
+    pub turns_taken: u32,
+}
+
 /// Tile type for terrain.
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 pub enum Tile {
     /// Open grass tile.
     Grass,
@@ -146,6 +540,10 @@ fn parse_move(input: &str) -> Option<Command> {
         "s" | "south" => Some(Command::Move(Direction::South)),
         "e" | "east" => Some(Command::Move(Direction::East)),
         "w" | "west" => Some(Command::Move(Direction::West)),
+        "ne" | "northeast" => Some(Command::Move(Direction::NorthEast)),
+        "se" | "southeast" => Some(Command::Move(Direction::SouthEast)),
+        "sw" | "southwest" => Some(Command::Move(Direction::SouthWest)),
+        "nw" | "northwest" => Some(Command::Move(Direction::NorthWest)),
         _ => None,
     }
 }
@@ -168,16 +566,178 @@ fn parse_system(input: &str) -> Option<Command> {
         "stat" | "status" => Some(Command::Status),
         "h" | "help" | "?" => Some(Command::Help),
         "q" | "quit" | "exit" => Some(Command::Quit),
+        "new" => Some(Command::NewGame),
+        "escort" => Some(Command::Escort),
+        "where boss" => Some(Command::WhereBoss),
+        "compass" => Some(Command::Compass),
+        "levelup?" => Some(Command::LevelUpPreview),
+        "level up" => Some(Command::ConfirmLevelUp),
+        "optimize" => Some(Command::Optimize),
+        "shop" => Some(Command::Shop),
+        "color" => Some(Command::Color),
+        "tutorial" => Some(Command::Tutorial),
+        "read map" => Some(Command::ReadMap),
+        "pause" => Some(Command::Pause),
+        "resume" => Some(Command::Resume),
+        "undo item" => Some(Command::UndoItem),
+        _ => None,
+    }
+}
+
+/// Parse an enemy kind from its name.
+fn parse_enemy_kind(input: &str) -> Option<EnemyKind> {
+    match input {
+        "slime" => Some(EnemyKind::Slime),
+        "skeleton" => Some(EnemyKind::Skeleton),
+        "bat" => Some(EnemyKind::Bat),
+        "goblin" => Some(EnemyKind::Goblin),
+        "dark knight" | "darkknight" | "dark_knight" => Some(EnemyKind::DarkKnight),
+        "boss" => Some(EnemyKind::Boss),
+        "thief" => Some(EnemyKind::Thief),
+        _ => None,
+    }
+}
+
+/// Parse input for a duel preview command.
+fn parse_duel(input: &str) -> Option<Command> {
+    let name = input.strip_prefix("duel ")?;
+    parse_enemy_kind(name.trim()).map(Command::Duel)
+}
+
+/// Parse input for a waypoint command.
+fn parse_waypoint(input: &str) -> Option<Command> {
+    if input == "waypoints" {
+        return Some(Command::Waypoints);
+    }
+    if let Some(name) = input.strip_prefix("mark ") {
+        return Some(Command::Mark(name.trim().to_string()));
+    }
+    let name = input.strip_prefix("goto ")?;
+    Some(Command::Goto(name.trim().to_string()))
+}
+
+/// Parse input for a field note command.
+fn parse_note(input: &str) -> Option<Command> {
+    if input.eq_ignore_ascii_case("notes") {
+        return Some(Command::Notes);
+    }
+    let (keyword, text) = input.split_once(' ')?;
+    keyword
+        .eq_ignore_ascii_case("note")
+        .then(|| Command::Note(text.trim().to_string()))
+}
+
+/// Parse input for a shop purchase command.
+fn parse_buy(input: &str) -> Option<Command> {
+    let number = input.strip_prefix("buy ")?.trim().parse().ok()?;
+    Some(Command::Buy(number))
+}
+
+/// Parse input for a shop sale command.
+fn parse_sell(input: &str) -> Option<Command> {
+    let number = input.strip_prefix("sell ")?.trim().parse().ok()?;
+    Some(Command::Sell(number))
+}
+
+/// Parse input for a world seed command.
+fn parse_seed(input: &str) -> Option<Command> {
+    let seed = input.strip_prefix("seed ")?.trim().parse().ok()?;
+    Some(Command::Seed(seed))
+}
+
+/// Parse input for a save or load command.
+fn parse_save_load(input: &str) -> Option<Command> {
+    let (keyword, path) = input.split_once(' ')?;
+    let path = path.trim().to_string();
+    if keyword.eq_ignore_ascii_case("save") {
+        Some(Command::Save(path))
+    } else if keyword.eq_ignore_ascii_case("load") {
+        Some(Command::Load(path))
+    } else {
+        None
+    }
+}
+
+/// Parse input for a fast travel command.
+fn parse_travel(input: &str) -> Option<Command> {
+    let area = input.strip_prefix("travel ")?;
+    Some(Command::Travel(area.trim().to_string()))
+}
+
+/// Parse input for a targeting mode command.
+fn parse_target(input: &str) -> Option<Command> {
+    let mode = match input.strip_prefix("target ")?.trim() {
+        "first" => TargetingMode::FirstFound,
+        "weakest" => TargetingMode::Weakest,
+        "strongest" => TargetingMode::Strongest,
+        "nearest" => TargetingMode::Nearest,
+        _ => return None,
+    };
+    Some(Command::Target(mode))
+}
+
+/// Parse input for a game mode command.
+fn parse_mode(input: &str) -> Option<Command> {
+    let mode = match input.strip_prefix("mode ")?.trim() {
+        "adventure" => GameMode::Adventure,
+        "survival" => GameMode::Survival,
+        _ => return None,
+    };
+    Some(Command::Mode(mode))
+}
+
+/// Parse a droppable item kind from its name.
+fn parse_item_kind(input: &str) -> Option<ItemKind> {
+    match input {
+        "potion" => Some(ItemKind::Potion),
+        "gold" => Some(ItemKind::Gold),
+        "sword" => Some(ItemKind::Sword),
+        _ => None,
+    }
+}
+
+/// Parse input for a drop command.
+fn parse_drop(input: &str) -> Option<Command> {
+    let name = input.strip_prefix("drop ")?;
+    parse_item_kind(name.trim()).map(Command::Drop)
+}
+
+/// Parse input for an inspect command.
+fn parse_inspect(input: &str) -> Option<Command> {
+    let args = input.strip_prefix("inspect ")?;
+    let mut coords = args.split_whitespace().map(|n| n.parse::<i32>().ok());
+    match (coords.next(), coords.next(), coords.next()) {
+        (Some(Some(x)), Some(Some(y)), None) => Some(Command::Inspect(x, y)),
         _ => None,
     }
 }
 
+/// Parse input for an equip preview command.
+fn parse_preview_equip(input: &str) -> Option<Command> {
+    let id = input.strip_prefix("equip? ")?;
+    id.trim().parse().ok().map(Command::PreviewEquip)
+}
+
 /// Parse user input into a command.
 pub fn parse_input(input: &str) -> Command {
-    let input = input.trim().to_lowercase();
+    let raw = input.trim();
+    let input = raw.to_lowercase();
     parse_move(&input)
         .or_else(|| parse_action(&input))
         .or_else(|| parse_system(&input))
+        .or_else(|| parse_duel(&input))
+        .or_else(|| parse_waypoint(&input))
+        .or_else(|| parse_note(raw))
+        .or_else(|| parse_travel(&input))
+        .or_else(|| parse_drop(&input))
+        .or_else(|| parse_inspect(&input))
+        .or_else(|| parse_preview_equip(&input))
+        .or_else(|| parse_target(&input))
+        .or_else(|| parse_mode(&input))
+        .or_else(|| parse_seed(&input))
+        .or_else(|| parse_save_load(raw))
+        .or_else(|| parse_buy(&input))
+        .or_else(|| parse_sell(&input))
         .unwrap_or(Command::Unknown)
 }
 
@@ -190,6 +750,9 @@ fn enemy_symbol(kind: &EnemyKind) -> char {
         EnemyKind::Goblin => 'g',
         EnemyKind::DarkKnight => 'D',
         EnemyKind::Boss => 'B',
+        EnemyKind::Thief => 't',
+        EnemyKind::Dragon => 'd',
+        EnemyKind::Wizard => 'w',
     }
 }
 
@@ -214,6 +777,15 @@ fn tile_symbol(tile: &Tile) -> char {
     }
 }
 
+/// Get symbol for a grass tile, varied by position like `get_tile_variant`.
+fn grass_variant_symbol(x: i32, y: i32) -> char {
+    match (x * 7 + y * 13).rem_euclid(3) {
+        0 => '.',
+        1 => ',',
+        _ => '"',
+    }
+}
+
 /// Create a new slime enemy.
 fn create_slime(x: i32, y: i32) -> Enemy {
     Enemy {
@@ -223,6 +795,7 @@ fn create_slime(x: i32, y: i32) -> Enemy {
         health: 10,
         attack: 3,
         exp: 5,
+        behavior: Behavior::Chase,
     }
 }
 
@@ -235,6 +808,7 @@ fn create_skeleton(x: i32, y: i32) -> Enemy {
         health: 20,
         attack: 5,
         exp: 10,
+        behavior: Behavior::Chase,
     }
 }
 
@@ -247,6 +821,7 @@ fn create_bat(x: i32, y: i32) -> Enemy {
         health: 8,
         attack: 4,
         exp: 7,
+        behavior: Behavior::Chase,
     }
 }
 
@@ -259,6 +834,7 @@ fn create_goblin(x: i32, y: i32) -> Enemy {
         health: 25,
         attack: 8,
         exp: 15,
+        behavior: Behavior::Chase,
     }
 }
 
@@ -271,6 +847,7 @@ fn create_dark_knight(x: i32, y: i32) -> Enemy {
         health: 40,
         attack: 12,
         exp: 25,
+        behavior: Behavior::Chase,
     }
 }
 
@@ -283,10 +860,66 @@ fn create_boss(x: i32, y: i32) -> Enemy {
         health: 100,
         attack: 20,
         exp: 100,
+        behavior: Behavior::Chase,
+    }
+}
+
+/// Create a new thief enemy.
+fn create_thief(x: i32, y: i32) -> Enemy {
+    Enemy {
+        kind: EnemyKind::Thief,
+        x,
+        y,
+        health: 25,
+        attack: 2,
+        exp: 30,
+        behavior: Behavior::Chase,
+    }
+}
+
+/// Create a new dragon enemy.
+fn create_dragon(x: i32, y: i32) -> Enemy {
+    Enemy {
+        kind: EnemyKind::Dragon,
+        x,
+        y,
+        health: 150,
+        attack: 25,
+        exp: 75,
+        behavior: Behavior::Chase,
+    }
+}
+
+/// Create a new wizard enemy.
+fn create_wizard(x: i32, y: i32) -> Enemy {
+    Enemy {
+        kind: EnemyKind::Wizard,
+        x,
+        y,
+        health: 35,
+        attack: 18,
+        exp: 40,
+        behavior: Behavior::Chase,
+    }
+}
+
+/// Create a new enemy of the given kind.
+fn create_enemy(kind: &EnemyKind, x: i32, y: i32) -> Enemy {
+    match kind {
+        EnemyKind::Slime => create_slime(x, y),
+        EnemyKind::Skeleton => create_skeleton(x, y),
+        EnemyKind::Bat => create_bat(x, y),
+        EnemyKind::Goblin => create_goblin(x, y),
+        EnemyKind::DarkKnight => create_dark_knight(x, y),
+        EnemyKind::Boss => create_boss(x, y),
+        EnemyKind::Thief => create_thief(x, y),
+        EnemyKind::Dragon => create_dragon(x, y),
+        EnemyKind::Wizard => create_wizard(x, y),
     }
 }
 
 /// Simple game state for the game.
+#[derive(Serialize, Deserialize)]
 pub struct SimpleGameState {
     /// Player X position.
     pub player_x: i32,
@@ -306,12 +939,39 @@ pub struct SimpleGameState {
     pub exp: i32,
     /// Player level.
     pub level: i32,
+    /// Whether a level up is waiting to be confirmed.
+    pub pending_level_up: bool,
     /// Gold coins.
     pub gold: i32,
     /// Number of potions.
     pub potions: i32,
+    /// Number of Magic Maps.
+    pub magic_maps: i32,
+    /// Luck stat.
+    pub luck: u32,
+    /// Speed stat.
+    pub speed: u32,
+    /// Positions the player has seen.
+    #[serde(serialize_with = "serialize_sorted_tiles")]
+    pub visited_tiles: HashSet<(i32, i32)>,
     /// Whether the game is running.
     pub is_running: bool,
+    /// Whether the game is paused.
+    pub paused: bool,
+    /// Whether the merchant's shop menu is open.
+    pub shopping: bool,
+    /// Whether the map uses ANSI colors.
+    pub color: bool,
+    /// How attacks pick among adjacent enemies.
+    pub targeting_mode: TargetingMode,
+    /// Item counts before the last turn, for undoing item use.
+    pub last_inventory_snapshot: Option<InventorySnapshot>,
+    /// Rules the game is played under.
+    pub mode: GameMode,
+    /// Current survival wave (0 outside survival mode).
+    pub survival_wave: u32,
+    /// Seed for procedural content such as chest rewards.
+    pub world_seed: u64,
     /// Enemies on the map.
     pub enemies: Vec<Enemy>,
     /// Items on the map.
@@ -321,7 +981,35 @@ pub struct SimpleGameState {
     /// Turn counter.
     pub turn: i32,
     /// Message to display.
+    #[serde(skip)]
     pub message: String,
+    /// Named navigation targets as (name, x, y).
+    pub waypoints: Vec<(String, i32, i32)>,
+    /// Player annotations on map tiles as (x, y, text).
+    pub field_notes: Vec<(i32, i32, String)>,
+    /// Temporary map hints as (x, y, symbol, turns remaining).
+    pub map_annotations: Vec<(i32, i32, char, u32)>,
+    /// Current quest, if any.
+    pub quest: Option<Quest>,
+    /// Damage reflected back to attacking enemies, set by the equipped armor.
+    pub reflect_damage: i32,
+    /// Shield points absorbing damage before health, granted by the Shield.
+    pub shield_hp: i32,
+    /// Inventory item ID of the equipped weapon (0 for none).
+    pub equipped_weapon: u32,
+    /// Inventory item ID of the equipped armor (0 for none).
+    pub equipped_armor: u32,
+    /// Inventory item IDs of every weapon and armor found.
+    pub owned_gear: Vec<u32>,
+    /// Current tutorial step, or `None` when the tutorial is off.
+    pub tutorial_step: Option<u32>,
+    /// Most recent command, used to check tutorial goals.
+    #[serde(skip)]
+    pub last_command: Option<Command>,
+    /// Hits landed in the current battle.
+    pub battle_turns: u32,
+    /// Reward from the last battle, until the map is shown again.
+    pub pending_reward: Option<BattleReward>,
 }
 
 /// Initialize terrain grid with grass.
@@ -416,17 +1104,53 @@ fn generate_terrain() -> Vec<Vec<Tile>> {
     terrain
 }
 
-/// Spawn initial enemies.
+/// Pick the kind of enemy to spawn at a position from its area's table.
+pub fn area_enemy_kind(x: i32, y: i32) -> EnemyKind {
+    let area = area_from_position(x, y);
+    let table = AREA_ENEMY_TABLES
+        .iter()
+        .find(|(name, _)| *name == area)
+        .map_or(&[EnemyKind::Slime][..], |(_, kinds)| kinds);
+    table[(x * 31 + y * 17) as usize % table.len()].clone()
+}
+
+/// Spawn initial enemies, choosing each kind from its area's table.
 fn spawn_enemies() -> Vec<Enemy> {
-    vec![
-        create_slime(5, 3),
-        create_slime(14, 5),
-        create_bat(8, 9),
-        create_skeleton(16, 10),
-        create_goblin(3, 12),
-        create_dark_knight(17, 13),
-        create_boss(10, 2),
-    ]
+    ENEMY_SPAWN_POINTS
+        .iter()
+        .map(|&(x, y)| create_enemy(&area_enemy_kind(x, y), x, y))
+        .chain([create_boss(10, 2)])
+        .collect()
+}
+
+/// Scale an enemy stat for a survival wave (1 + wave * 0.1).
+fn scale_for_wave(value: i32, wave: u32) -> i32 {
+    value * (10 + wave as i32) / 10
+}
+
+/// Spawn the enemies of a survival wave with stats scaled by wave number.
+///
+/// Enemies never spawn on the player, an existing enemy or the escorted NPC.
+pub fn spawn_survival_wave(state: &SimpleGameState, wave_number: u32) -> Vec<Enemy> {
+    let terrain = generate_terrain();
+    let count = (SURVIVAL_BASE_ENEMIES + wave_number) as usize;
+    let escort = state.quest.as_ref().map(|q| (q.npc_x, q.npc_y));
+    (0..MAP_WIDTH * MAP_HEIGHT)
+        .step_by(SURVIVAL_SPAWN_STRIDE)
+        .map(|i| (i % MAP_WIDTH, i / MAP_WIDTH))
+        .filter(|&(x, y)| is_walkable(&terrain, x, y))
+        .filter(|&(x, y)| !is_position_occupied(state, x, y, usize::MAX))
+        .filter(|&pos| escort != Some(pos))
+        .take(count)
+        .enumerate()
+        .map(|(i, (x, y))| {
+            let mut enemy = create_enemy(&SURVIVAL_KINDS[i % SURVIVAL_KINDS.len()], x, y);
+            enemy.health = scale_for_wave(enemy.health, wave_number);
+            enemy.attack = scale_for_wave(enemy.attack, wave_number);
+            enemy.exp = scale_for_wave(enemy.exp, wave_number);
+            enemy
+        })
+        .collect()
 }
 
 /// Spawn initial items.
@@ -474,18 +1198,44 @@ impl SimpleGameState {
             health: 100,
             max_health: 100,
             score: 0,
-            attack: 15,
+            attack: BASE_ATTACK,
             defense: 5,
             exp: 0,
             level: 1,
+            pending_level_up: false,
             gold: 0,
             potions: 1,
+            magic_maps: 0,
+            luck: STARTING_LUCK,
+            speed: STARTING_SPEED,
+            visited_tiles: HashSet::from([(10, 10)]),
             is_running: true,
+            paused: false,
+            shopping: false,
+            color: false,
+            targeting_mode: TargetingMode::FirstFound,
+            last_inventory_snapshot: None,
+            mode: GameMode::Adventure,
+            survival_wave: 0,
+            world_seed: 0,
             enemies: spawn_enemies(),
             items: spawn_items(),
             terrain: generate_terrain(),
             turn: 0,
             message: String::new(),
+            waypoints: Vec::new(),
+            field_notes: Vec::new(),
+            map_annotations: Vec::new(),
+            quest: None,
+            reflect_damage: 0,
+            shield_hp: 0,
+            equipped_weapon: 0,
+            equipped_armor: 0,
+            owned_gear: Vec::new(),
+            tutorial_step: None,
+            last_command: None,
+            battle_turns: 0,
+            pending_reward: None,
         }
     }
 
@@ -544,6 +1294,129 @@ fn is_walkable(terrain: &[Vec<Tile>], x: i32, y: i32) -> bool {
     matches!(tile, Tile::Grass)
 }
 
+/// Pay gold to teleport to the entry point of a named area.
+pub fn fast_travel(state: &mut SimpleGameState, target_area: &str) -> Result<(), &'static str> {
+    let &(name, x, y) = AREA_ENTRY_POINTS
+        .iter()
+        .find(|(name, _, _)| name.eq_ignore_ascii_case(target_area))
+        .ok_or("Unknown area")?;
+    let cost = state.level * TRAVEL_COST_PER_LEVEL;
+    if state.gold < cost {
+        return Err("Not enough gold");
+    }
+    state.gold -= cost;
+    state.player_x = x;
+    state.player_y = y;
+    state.set_message(&format!("You travel to {} for {} gold.", name, cost));
+    Ok(())
+}
+
+/// Get the Manhattan distance from the player to the boss, if it is alive.
+pub fn distance_to_boss(state: &SimpleGameState) -> Option<i32> {
+    state
+        .enemies
+        .iter()
+        .find(|e| e.kind == EnemyKind::Boss)
+        .map(|boss| (boss.x - state.player_x).abs() + (boss.y - state.player_y).abs())
+}
+
+/// Display the distance to the boss.
+fn display_boss_distance(state: &SimpleGameState) {
+    match distance_to_boss(state) {
+        Some(distance) => println!("\nBoss: {} tiles away", distance),
+        None => println!("\nThe boss has been defeated."),
+    }
+}
+
+/// Discretize an offset into one of 8 compass steps, or (0, 0) when there is none.
+fn compass_offset(dx: i32, dy: i32) -> (i32, i32) {
+    // tan(22.5 degrees) is about 2/5, so smaller components round to zero.
+    let step_x = if 5 * dx.abs() <= 2 * dy.abs() {
+        0
+    } else {
+        dx.signum()
+    };
+    let step_y = if 5 * dy.abs() <= 2 * dx.abs() {
+        0
+    } else {
+        dy.signum()
+    };
+    (step_x, step_y)
+}
+
+/// Get the compass direction from the player to an objective.
+pub fn get_objective_direction(
+    player_x: i32,
+    player_y: i32,
+    objective_x: i32,
+    objective_y: i32,
+) -> &'static str {
+    match compass_offset(objective_x - player_x, objective_y - player_y) {
+        (0, -1) => "N",
+        (1, -1) => "NE",
+        (1, 0) => "E",
+        (1, 1) => "SE",
+        (0, 1) => "S",
+        (-1, 1) => "SW",
+        (-1, 0) => "W",
+        (-1, -1) => "NW",
+        _ => "Here",
+    }
+}
+
+/// Get the destination of the active quest, if any.
+fn quest_target(state: &SimpleGameState) -> Option<(i32, i32)> {
+    let quest = state.quest.as_ref()?;
+    if quest.status != QuestStatus::Active {
+        return None;
+    }
+    match quest.objective {
+        QuestObjective::EscortNpc { destination, .. } => Some(destination),
+    }
+}
+
+/// Render a 3x3 compass rose with each target's symbol in its direction.
+pub fn compass_rose(state: &SimpleGameState, targets: &[(char, (i32, i32))]) -> Vec<String> {
+    let mut grid = [['.', 'N', '.'], ['W', '+', 'E'], ['.', 'S', '.']];
+    for &(symbol, (x, y)) in targets {
+        let (step_x, step_y) = compass_offset(x - state.player_x, y - state.player_y);
+        let cell = &mut grid[(step_y + 1) as usize][(step_x + 1) as usize];
+        *cell = if cell.is_ascii_lowercase() {
+            '*'
+        } else {
+            symbol
+        };
+    }
+    grid.iter().map(|row| row.iter().collect()).collect()
+}
+
+/// Display a compass pointing to the active quest objective and the boss.
+fn display_compass(state: &SimpleGameState) {
+    let quest = quest_target(state);
+    let boss = state
+        .enemies
+        .iter()
+        .find(|e| e.kind == EnemyKind::Boss)
+        .map(|boss| (boss.x, boss.y));
+    let targets: Vec<(char, (i32, i32))> = [('q', quest), ('b', boss)]
+        .into_iter()
+        .filter_map(|(symbol, pos)| pos.map(|pos| (symbol, pos)))
+        .collect();
+    println!("\n=== COMPASS ===");
+    for line in compass_rose(state, &targets) {
+        println!("  {}", line);
+    }
+    let direction = |(x, y)| get_objective_direction(state.player_x, state.player_y, x, y);
+    match quest {
+        Some(pos) => println!("q Quest: {}", direction(pos)),
+        None => println!("q Quest: none active"),
+    }
+    match boss {
+        Some(pos) => println!("b Boss: {}", direction(pos)),
+        None => println!("b Boss: defeated"),
+    }
+}
+
 /// Apply north movement to game state.
 fn apply_north(state: &mut SimpleGameState) {
     let new_y = state.player_y - 1;
@@ -591,6 +1464,146 @@ fn apply_move(state: &mut SimpleGameState, dir: &Direction) {
         Direction::South => apply_south(state),
         Direction::East => apply_east(state),
         Direction::West => apply_west(state),
+        Direction::NorthEast => apply_diagonal(state, 1, -1),
+        Direction::SouthEast => apply_diagonal(state, 1, 1),
+        Direction::SouthWest => apply_diagonal(state, -1, 1),
+        Direction::NorthWest => apply_diagonal(state, -1, -1),
+    }
+}
+
+/// Apply diagonal movement to game state.
+fn apply_diagonal(state: &mut SimpleGameState, dx: i32, dy: i32) {
+    let (new_x, new_y) = (state.player_x + dx, state.player_y + dy);
+    if is_walkable(&state.terrain, new_x, new_y) {
+        state.player_x = new_x;
+        state.player_y = new_y;
+    } else {
+        state.set_message("You can't go that way!");
+    }
+}
+
+/// Save the player's position as a named waypoint.
+fn mark_waypoint(state: &mut SimpleGameState, name: &str) {
+    let (x, y) = (state.player_x, state.player_y);
+    state.waypoints.retain(|(n, _, _)| n != name);
+    if state.waypoints.len() >= MAX_WAYPOINTS {
+        state.waypoints.remove(0);
+    }
+    state.waypoints.push((name.to_string(), x, y));
+    state.set_message(&format!("Waypoint '{}' marked at ({}, {}).", name, x, y));
+}
+
+/// Annotate the player's tile, replacing any earlier note there.
+fn add_field_note(state: &mut SimpleGameState, text: &str) {
+    let (x, y) = (state.player_x, state.player_y);
+    state.field_notes.retain(|(nx, ny, _)| (*nx, *ny) != (x, y));
+    if state.field_notes.len() >= MAX_FIELD_NOTES {
+        state.field_notes.remove(0);
+    }
+    state.field_notes.push((x, y, text.to_string()));
+    state.set_message(&format!("Noted at ({}, {}).", x, y));
+}
+
+/// Check if a tile has a field note.
+fn has_field_note(state: &SimpleGameState, x: i32, y: i32) -> bool {
+    state
+        .field_notes
+        .iter()
+        .any(|(nx, ny, _)| (*nx, *ny) == (x, y))
+}
+
+/// Display all field notes.
+fn display_field_notes(state: &SimpleGameState) {
+    println!("\n=== FIELD NOTES ===");
+    if state.field_notes.is_empty() {
+        println!("No notes yet. Use 'note <text>' to add one.");
+    }
+    for (x, y, text) in &state.field_notes {
+        println!("({}, {}): {}", x, y, text);
+    }
+}
+
+/// Find a waypoint position by name.
+fn find_waypoint(state: &SimpleGameState, name: &str) -> Option<(i32, i32)> {
+    state
+        .waypoints
+        .iter()
+        .find(|(n, _, _)| n == name)
+        .map(|(_, x, y)| (*x, *y))
+}
+
+/// Find the shortest walkable path between two positions using BFS.
+///
+/// Neighbors are explored in `CARDINAL_OFFSETS` order, which picks
+/// between equally short paths.
+fn find_path(terrain: &[Vec<Tile>], from: (i32, i32), to: (i32, i32)) -> Option<Vec<(i32, i32)>> {
+    let index = |(x, y): (i32, i32)| (y * MAP_WIDTH + x) as usize;
+    let mut prev: Vec<Option<(i32, i32)>> = vec![None; (MAP_WIDTH * MAP_HEIGHT) as usize];
+    let mut queue = VecDeque::from([from]);
+    prev[index(from)] = Some(from);
+    while let Some(pos) = queue.pop_front() {
+        if pos == to {
+            return Some(trace_path(&prev, from, to, index));
+        }
+        for (dx, dy) in CARDINAL_OFFSETS {
+            let next = (pos.0 + dx, pos.1 + dy);
+            if is_walkable(terrain, next.0, next.1) && prev[index(next)].is_none() {
+                prev[index(next)] = Some(pos);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Rebuild a BFS path from the predecessor table, excluding the start.
+fn trace_path(
+    prev: &[Option<(i32, i32)>],
+    from: (i32, i32),
+    to: (i32, i32),
+    index: impl Fn((i32, i32)) -> usize,
+) -> Vec<(i32, i32)> {
+    let mut path = Vec::new();
+    let mut pos = to;
+    while pos != from {
+        path.push(pos);
+        pos = prev[index(pos)].unwrap_or(from);
+    }
+    path.reverse();
+    path
+}
+
+/// Travel toward a named waypoint, one turn per step.
+fn goto_waypoint(state: &mut SimpleGameState, name: &str) {
+    let Some(target) = find_waypoint(state, name) else {
+        state.set_message(&format!("No waypoint named '{}'.", name));
+        return;
+    };
+    let start = (state.player_x, state.player_y);
+    let Some(path) = find_path(&state.terrain, start, target) else {
+        state.set_message(&format!("No path to waypoint '{}'.", name));
+        return;
+    };
+    for (x, y) in path {
+        state.player_x = x;
+        state.player_y = y;
+        end_turn(state);
+        if !state.is_running || find_target_enemy(state, TargetingMode::FirstFound).is_some() {
+            state.set_message(&format!("Travel to '{}' interrupted!", name));
+            return;
+        }
+    }
+    state.set_message(&format!("You arrive at waypoint '{}'.", name));
+}
+
+/// Display all saved waypoints.
+fn display_waypoints(state: &SimpleGameState) {
+    println!("\n=== WAYPOINTS ===");
+    if state.waypoints.is_empty() {
+        println!("No waypoints marked. Use 'mark <name>' to add one.");
+    }
+    for (name, x, y) in &state.waypoints {
+        println!("{}: ({}, {})", name, x, y);
     }
 }
 
@@ -599,17 +1612,22 @@ fn find_enemy_at(enemies: &[Enemy], x: i32, y: i32) -> Option<usize> {
     enemies.iter().position(|e| e.x == x && e.y == y)
 }
 
-/// Find adjacent enemy.
-fn find_adjacent_enemy(state: &SimpleGameState) -> Option<usize> {
-    let dirs = [(0, -1), (0, 1), (-1, 0), (1, 0)];
-    for (dx, dy) in dirs {
-        let x = state.player_x + dx;
-        let y = state.player_y + dy;
-        if let Some(idx) = find_enemy_at(&state.enemies, x, y) {
-            return Some(idx);
-        }
+/// Find the adjacent enemy to attack using a targeting mode.
+///
+/// Ties go to the enemy found first in `CARDINAL_OFFSETS` order.
+fn find_target_enemy(state: &SimpleGameState, mode: TargetingMode) -> Option<usize> {
+    let mut adjacent = CARDINAL_OFFSETS.iter().filter_map(|(dx, dy)| {
+        find_enemy_at(&state.enemies, state.player_x + dx, state.player_y + dy)
+    });
+    let enemies = &state.enemies;
+    match mode {
+        TargetingMode::FirstFound => adjacent.next(),
+        TargetingMode::Weakest => adjacent.min_by_key(|&idx| enemies[idx].health),
+        TargetingMode::Strongest => adjacent.max_by_key(|&idx| enemies[idx].health),
+        TargetingMode::Nearest => adjacent.min_by_key(|&idx| {
+            (enemies[idx].x - state.player_x).abs() + (enemies[idx].y - state.player_y).abs()
+        }),
     }
-    None
 }
 
 /// Calculate damage dealt to enemy.
@@ -617,12 +1635,64 @@ fn calc_damage(attack: i32) -> i32 {
     attack + (attack / 4)
 }
 
+/// Get the drops of an enemy kind as (item ID, chance in permille),
+/// mirroring the enemy component's loot tables.
+fn loot_table(kind: &EnemyKind) -> &'static [(u32, u32)] {
+    match kind {
+        EnemyKind::Slime => &[(201, 300)],
+        EnemyKind::Skeleton => &[(2, 200)],
+        EnemyKind::Bat => &[(201, 100)],
+        EnemyKind::Goblin => &[(201, 200), (203, 100)],
+        EnemyKind::DarkKnight => &[(103, 250)],
+        EnemyKind::Boss => &[(3, 1000)],
+        EnemyKind::Thief => &[(MAGIC_MAP_ID, 150)],
+        EnemyKind::Dragon => &[(FULL_HEALTH_POTION_ID, 1000)],
+        EnemyKind::Wizard => &[(FULL_HEALTH_POTION_ID, 250)],
+    }
+}
+
+/// Roll the items a defeated enemy drops, mirroring the enemy
+/// component's `generate-loot`.
+pub fn generate_loot(kind: &EnemyKind, seed: u32) -> Vec<u32> {
+    let mut roll = seed;
+    loot_table(kind)
+        .iter()
+        .filter_map(|&(id, permille)| {
+            roll = roll.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            ((roll >> 16) % 1000 < permille).then_some(id)
+        })
+        .collect()
+}
+
+/// Seed the loot roll of an enemy defeated this turn.
+///
+/// The enemy's position is mixed in so enemies defeated on the same
+/// turn roll their drops independently.
+fn loot_seed(world_seed: u64, turn: u64, enemy: &Enemy) -> u32 {
+    (world_seed ^ turn) as u32
+        ^ (enemy.x as u32).wrapping_mul(31)
+        ^ (enemy.y as u32).wrapping_mul(17)
+}
+
 /// Process enemy defeat.
 fn defeat_enemy(state: &mut SimpleGameState, idx: usize) {
     let enemy = state.enemies.remove(idx);
+    add_map_annotation(state, enemy.x, enemy.y, BATTLE_SITE_SYMBOL, HINT_TURNS);
     let name = enemy_kind_name(&enemy.kind);
     state.exp += enemy.exp;
     state.score += enemy.exp * 10;
+    let seed = loot_seed(state.world_seed, state.turn as u64, &enemy);
+    let items_dropped = generate_loot(&enemy.kind, seed)
+        .into_iter()
+        .map(|id| grant_chest_reward(state, id, 1))
+        .collect();
+    state.pending_reward = Some(BattleReward {
+        exp_gained: enemy.exp,
+        items_dropped,
+        gold_gained: 0,
+        turns_taken: state.battle_turns,
+    });
+    state.battle_turns = 0;
     state.set_message(&format!(
         "You defeated the {}! +{} EXP, +{} score",
         name,
@@ -632,6 +1702,19 @@ fn defeat_enemy(state: &mut SimpleGameState, idx: usize) {
     check_level_up(state);
 }
 
+/// Add a temporary hint symbol to the map.
+pub fn add_map_annotation(state: &mut SimpleGameState, x: i32, y: i32, symbol: char, turns: u32) {
+    state.map_annotations.push((x, y, symbol, turns));
+}
+
+/// Count down map hints by one turn and remove the expired ones.
+fn tick_map_annotations(state: &mut SimpleGameState) {
+    for annotation in state.map_annotations.iter_mut() {
+        annotation.3 = annotation.3.saturating_sub(1);
+    }
+    state.map_annotations.retain(|&(_, _, _, turns)| turns > 0);
+}
+
 /// Get enemy kind name.
 fn enemy_kind_name(kind: &EnemyKind) -> &'static str {
     match kind {
@@ -641,28 +1724,91 @@ fn enemy_kind_name(kind: &EnemyKind) -> &'static str {
         EnemyKind::Goblin => "Goblin",
         EnemyKind::DarkKnight => "Dark Knight",
         EnemyKind::Boss => "Boss",
+        EnemyKind::Thief => "Thief",
+        EnemyKind::Dragon => "Dragon",
+        EnemyKind::Wizard => "Wizard",
     }
 }
 
-/// Check for level up.
+/// Flag a level up once the player has enough experience.
 fn check_level_up(state: &mut SimpleGameState) {
-    let exp_needed = state.level * 25;
-    if state.exp >= exp_needed {
-        state.level += 1;
-        state.exp -= exp_needed;
-        state.max_health += 10;
-        state.health = state.max_health;
-        state.attack += 3;
-        state.defense += 2;
-        println!("*** LEVEL UP! You are now level {}! ***", state.level);
+    if state.exp >= state.level * EXP_PER_LEVEL {
+        state.pending_level_up = true;
+    }
+}
+
+/// Apply a pending level up, flagging the next one if EXP is left over.
+fn apply_level_up(state: &mut SimpleGameState) {
+    state.exp -= state.level * EXP_PER_LEVEL;
+    state.level += 1;
+    state.max_health += LEVEL_UP_HEALTH;
+    state.health = (state.health + LEVEL_UP_HEALTH).min(state.max_health);
+    state.attack += LEVEL_UP_ATTACK;
+    state.defense += LEVEL_UP_DEFENSE;
+    state.pending_level_up = false;
+    println!("*** LEVEL UP! You are now level {}! ***", state.level);
+    check_level_up(state);
+}
+
+/// Confirm a pending level up.
+fn confirm_level_up(state: &mut SimpleGameState) {
+    if !state.pending_level_up {
+        state.set_message("No level up is ready.");
+        return;
+    }
+    apply_level_up(state);
+    state.set_message(&format!("You are now level {}!", state.level));
+}
+
+/// Recommend the strongest weapon and armor, mirroring the inventory's
+/// `calculate-optimal-gear`; ties prefer the lower ID.
+pub fn calculate_optimal_gear(gear: &[u32]) -> (Option<u32>, Option<u32>) {
+    let best = |range: std::ops::RangeInclusive<u32>, bonus: fn(u32) -> i32| {
+        gear.iter()
+            .copied()
+            .filter(|id| range.contains(id))
+            .min_by_key(|&id| (std::cmp::Reverse(bonus(id)), id))
+    };
+    (best(1..=5, weapon_bonus), best(101..=105, armor_bonus))
+}
+
+/// Equip the recommended weapon and armor.
+fn optimize_gear(state: &mut SimpleGameState) {
+    let (weapon, armor) = calculate_optimal_gear(&state.owned_gear);
+    if weapon.is_none() && armor.is_none() {
+        state.set_message("You have no gear to equip.");
+        return;
     }
+    state.equipped_weapon = weapon.unwrap_or(state.equipped_weapon);
+    equip_armor(state, armor.unwrap_or(state.equipped_armor));
+    state.set_message(&format!(
+        "Equipped weapon #{} and armor #{}.",
+        state.equipped_weapon, state.equipped_armor
+    ));
+}
+
+/// Describe the bonuses the next level up will provide.
+pub fn level_up_summary(level: i32) -> String {
+    format!(
+        "Level {} → {}: +{} HP, +{} ATK, +{} DEF (requires {} EXP)",
+        level,
+        level + 1,
+        LEVEL_UP_HEALTH,
+        LEVEL_UP_ATTACK,
+        LEVEL_UP_DEFENSE,
+        level * EXP_PER_LEVEL
+    )
 }
 
 /// Apply an attack command to the game state.
 fn apply_attack(state: &mut SimpleGameState) {
-    if let Some(idx) = find_adjacent_enemy(state) {
-        let damage = calc_damage(state.attack);
+    if let Some(idx) = find_target_enemy(state, state.targeting_mode) {
+        if state.pending_level_up && state.battle_turns == 0 {
+            apply_level_up(state);
+        }
+        let damage = calc_damage(total_attack(state));
         state.enemies[idx].health -= damage;
+        state.battle_turns += 1;
         let name = enemy_kind_name(&state.enemies[idx].kind);
         if state.enemies[idx].health <= 0 {
             defeat_enemy(state, idx);
@@ -678,6 +1824,69 @@ fn apply_attack(state: &mut SimpleGameState) {
     }
 }
 
+/// Projected result of a simulated duel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuelOutcome {
+    /// Whether the player would win.
+    pub player_wins: bool,
+    /// Number of turns the battle would last.
+    pub turns: i32,
+    /// Damage the player would take.
+    pub damage_taken: i32,
+    /// Damage the player would deal.
+    pub damage_dealt: i32,
+}
+
+/// Simulate a full battle using copies of the player's stats.
+pub fn simulate_battle(health: i32, attack: i32, defense: i32, kind: &EnemyKind) -> DuelOutcome {
+    let enemy = create_enemy(kind, 0, 0);
+    let mut player_hp = health;
+    let mut enemy_hp = enemy.health;
+    let player_damage = calc_damage(attack).max(1);
+    let enemy_damage = (enemy.attack - defense).max(1);
+    let mut turns = 0;
+    while player_hp > 0 && enemy_hp > 0 {
+        turns += 1;
+        enemy_hp -= player_damage;
+        if enemy_hp > 0 {
+            player_hp -= enemy_damage;
+        }
+    }
+    DuelOutcome {
+        player_wins: enemy_hp <= 0,
+        turns,
+        damage_taken: health - player_hp,
+        damage_dealt: enemy.health - enemy_hp.max(0),
+    }
+}
+
+/// Build the duel prediction message for an enemy kind.
+fn duel_prediction(state: &SimpleGameState, kind: &EnemyKind) -> String {
+    let outcome = simulate_battle(
+        state.health,
+        total_attack(state),
+        total_defense(state),
+        kind,
+    );
+    if outcome.player_wins {
+        format!(
+            "You would win in ~{} turns, taking ~{} damage.",
+            outcome.turns, outcome.damage_taken
+        )
+    } else {
+        format!(
+            "You would lose in ~{} turns, dealing ~{} damage.",
+            outcome.turns, outcome.damage_dealt
+        )
+    }
+}
+
+/// Display the duel prediction against an enemy kind.
+fn display_duel(state: &SimpleGameState, kind: &EnemyKind) {
+    println!("\n=== DUEL: {} ===", enemy_kind_name(kind));
+    println!("{}", duel_prediction(state, kind));
+}
+
 /// Use a health potion.
 fn use_potion(state: &mut SimpleGameState) {
     if state.potions > 0 {
@@ -693,6 +1902,32 @@ fn use_potion(state: &mut SimpleGameState) {
     }
 }
 
+/// Save the item and gold counts before a turn.
+fn take_inventory_snapshot(state: &mut SimpleGameState) {
+    state.last_inventory_snapshot = Some(InventorySnapshot {
+        potions: state.potions,
+        magic_maps: state.magic_maps,
+        gold: state.gold,
+        health: state.health,
+    });
+}
+
+/// Revert item and gold changes made since the last snapshot.
+///
+/// Health only goes back down, so undoing a potion removes its healing
+/// without restoring damage taken.
+pub fn undo_item(state: &mut SimpleGameState) {
+    let Some(snapshot) = state.last_inventory_snapshot.take() else {
+        state.set_message("Nothing to undo.");
+        return;
+    };
+    state.potions = snapshot.potions;
+    state.magic_maps = snapshot.magic_maps;
+    state.gold = snapshot.gold;
+    state.health = state.health.min(snapshot.health);
+    state.set_message("Item changes from last turn undone.");
+}
+
 /// Collect item at player position.
 fn collect_item(state: &mut SimpleGameState) {
     let x = state.player_x;
@@ -711,67 +1946,654 @@ fn apply_item_effect(state: &mut SimpleGameState, item: &Item) {
             state.set_message("You found a health potion!");
         }
         ItemKind::Gold => {
-            state.gold += 25;
+            state.gold += GOLD_PILE;
             state.score += 50;
             state.set_message("You found 25 gold coins! +50 score");
         }
         ItemKind::Chest => {
-            state.gold += 100;
             state.score += 200;
-            state.set_message("You opened a treasure chest! +100 gold, +200 score");
+            let roll_turn = state.world_seed ^ state.turn as u64;
+            let rewards = roll_chest_reward(roll_turn, state.level as u32);
+            let names: Vec<String> = rewards
+                .iter()
+                .map(|&(id, quantity)| grant_chest_reward(state, id, quantity))
+                .collect();
+            state.set_message(&format!(
+                "You opened a treasure chest! Found {}. +200 score",
+                names.join(", ")
+            ));
         }
         ItemKind::Sword => {
-            state.attack += 10;
+            state.attack += SWORD_BONUS;
             state.set_message("You found a better sword! +10 attack");
         }
     }
 }
 
-/// Move enemies toward player.
-fn move_enemies(state: &mut SimpleGameState) {
-    for i in 0..state.enemies.len() {
-        move_single_enemy(state, i);
+/// Roll the rewards of a treasure chest as (item ID, quantity) pairs.
+///
+/// A single LCG step on the global turn picks one entry from the
+/// rarity-weighted reward table; rare rewards need a higher level.
+pub fn roll_chest_reward(global_turn: u64, player_level: u32) -> Vec<(u32, u32)> {
+    let roll = global_turn
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407)
+        >> 33;
+    let eligible: Vec<_> = CHEST_REWARDS
+        .iter()
+        .filter(|&&(_, _, _, min_level)| player_level >= min_level)
+        .collect();
+    let total: u64 = eligible.iter().map(|&&(_, _, weight, _)| weight).sum();
+    let mut pick = roll % total;
+    for &&(id, quantity, weight, _) in &eligible {
+        if pick < weight {
+            return vec![(id, quantity)];
+        }
+        pick -= weight;
     }
+    Vec::new()
 }
 
-/// Move a single enemy.
-fn move_single_enemy(state: &mut SimpleGameState, idx: usize) {
-    let enemy = &state.enemies[idx];
-    let dx = (state.player_x - enemy.x).signum();
-    let dy = (state.player_y - enemy.y).signum();
-    let new_x = enemy.x + dx;
-    let new_y = enemy.y + dy;
-    let walkable = is_walkable(&state.terrain, new_x, new_y);
-    let occupied = is_position_occupied(state, new_x, new_y, idx);
-    if walkable && !occupied {
-        state.enemies[idx].x = new_x;
-        state.enemies[idx].y = new_y;
+/// Give the player a chest reward, returning its display name.
+fn grant_chest_reward(state: &mut SimpleGameState, id: u32, quantity: u32) -> String {
+    match id {
+        201 => {
+            state.potions += quantity as i32;
+            format!("{} health potions", quantity)
+        }
+        FULL_HEALTH_POTION_ID => {
+            state.health = state.max_health;
+            "a Full Health Potion, drunk on the spot".to_string()
+        }
+        203 => {
+            state.attack += ATTACK_BOOST * quantity as i32;
+            "an attack boost".to_string()
+        }
+        MAGIC_MAP_ID => {
+            state.magic_maps += quantity as i32;
+            "a Magic Map".to_string()
+        }
+        1..=5 => {
+            state.owned_gear.push(id);
+            if weapon_bonus(id) > weapon_bonus(state.equipped_weapon) {
+                state.equipped_weapon = id;
+            }
+            format!("weapon #{}", id)
+        }
+        _ => {
+            state.owned_gear.push(id);
+            if armor_bonus(id) > armor_bonus(state.equipped_armor) {
+                equip_armor(state, id);
+            }
+            format!("armor #{}", id)
+        }
     }
 }
 
-/// Check if position is occupied by another enemy.
-fn is_position_occupied(state: &SimpleGameState, x: i32, y: i32, skip: usize) -> bool {
-    for (i, e) in state.enemies.iter().enumerate() {
-        if i != skip && e.x == x && e.y == y {
-            return true;
-        }
-    }
-    x == state.player_x && y == state.player_y
+/// Count the items the player is carrying.
+fn carried_item_count(state: &SimpleGameState) -> usize {
+    (state.potions + state.magic_maps) as usize + state.owned_gear.len()
 }
 
-/// Process enemy attacks.
-fn enemy_attacks(state: &mut SimpleGameState) {
-    for enemy in &state.enemies {
-        if is_adjacent(enemy.x, enemy.y, state.player_x, state.player_y) {
-            let damage = (enemy.attack - state.defense).max(1);
-            state.health -= damage;
-            let name = enemy_kind_name(&enemy.kind);
-            println!("The {} hits you for {} damage!", name, damage);
-        }
+/// Print the merchant's numbered stock.
+fn print_shop_stock() {
+    for (number, (_, name, price)) in MERCHANT_CATALOG.iter().enumerate() {
+        println!("{}. {} - {} gold", number + 1, name, price);
     }
 }
 
-/// Check if two positions are adjacent.
+/// Open the merchant's shop and list its stock.
+fn open_shop(state: &mut SimpleGameState) {
+    state.shopping = true;
+    println!("\n=== MERCHANT ===");
+    print_shop_stock();
+    println!("Gold: {}", state.gold);
+    println!("Use 'buy <number>' to purchase or 'sell <number>' to sell one back.");
+}
+
+/// Buy the item at a shop menu number.
+fn buy_from_merchant(state: &mut SimpleGameState, number: usize) -> Result<String, &'static str> {
+    if !state.shopping {
+        return Err("You need to open the shop first.");
+    }
+    let &(id, name, price) = number
+        .checked_sub(1)
+        .and_then(|i| MERCHANT_CATALOG.get(i))
+        .ok_or("The merchant doesn't sell that.")?;
+    if state.gold < price {
+        return Err("Not enough gold.");
+    }
+    if carried_item_count(state) >= INVENTORY_CAPACITY {
+        return Err("Your inventory is full.");
+    }
+    state.gold -= price;
+    grant_chest_reward(state, id, 1);
+    Ok(format!("You bought a {} for {} gold.", name, price))
+}
+
+/// Take one carried item away, unequipping gear that is no longer owned.
+fn take_item(state: &mut SimpleGameState, id: u32) -> bool {
+    match id {
+        201 if state.potions > 0 => state.potions -= 1,
+        MAGIC_MAP_ID if state.magic_maps > 0 => state.magic_maps -= 1,
+        201 | MAGIC_MAP_ID => return false,
+        _ => {
+            let Some(index) = state.owned_gear.iter().position(|&gear| gear == id) else {
+                return false;
+            };
+            state.owned_gear.remove(index);
+            if !state.owned_gear.contains(&id) {
+                if state.equipped_weapon == id {
+                    state.equipped_weapon = 0;
+                }
+                if state.equipped_armor == id {
+                    equip_armor(state, 0);
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Sell one of the item at a shop menu number back for half its price.
+fn sell_to_merchant(state: &mut SimpleGameState, number: usize) -> Result<String, &'static str> {
+    if !state.shopping {
+        return Err("You need to open the shop first.");
+    }
+    let &(id, name, price) = number
+        .checked_sub(1)
+        .and_then(|i| MERCHANT_CATALOG.get(i))
+        .ok_or("The merchant doesn't buy that.")?;
+    if !take_item(state, id) {
+        return Err("You don't have one to sell.");
+    }
+    state.gold += price / 2;
+    Ok(format!("You sold a {} for {} gold.", name, price / 2))
+}
+
+/// Read a Magic Map, marking every tile of the current area as visited.
+pub fn use_magic_map(state: &mut SimpleGameState) {
+    if state.magic_maps <= 0 {
+        state.set_message("You don't have a Magic Map.");
+        return;
+    }
+    state.magic_maps -= 1;
+    let area = state.area_name();
+    for y in 0..MAP_HEIGHT {
+        for x in 0..MAP_WIDTH {
+            if area_from_position(x, y) == area {
+                state.visited_tiles.insert((x, y));
+            }
+        }
+    }
+    state.set_message(&format!("The map reveals all of {}!", area));
+}
+
+/// Get the guided prompt for a tutorial step.
+pub fn get_tutorial_prompt(step: u32) -> Option<&'static str> {
+    TUTORIAL_PROMPTS.get(step as usize).copied()
+}
+
+/// Check whether a command completes a tutorial step.
+fn tutorial_goal_met(step: u32, cmd: &Command) -> bool {
+    matches!(
+        (step, cmd),
+        (0, Command::Move(_))
+            | (1, Command::Status)
+            | (2, Command::Inventory)
+            | (3, Command::Wait)
+            | (4, Command::Attack)
+            | (5, Command::UseItem)
+            | (6, Command::Mark(_))
+            | (7, Command::Waypoints)
+            | (8, Command::Inspect(_, _))
+            | (9, Command::Help)
+    )
+}
+
+/// Start the tutorial from its first step.
+fn start_tutorial(state: &mut SimpleGameState) {
+    state.tutorial_step = Some(0);
+    state.set_message("Tutorial started! Follow the prompts above the map.");
+}
+
+/// Advance the tutorial if the last command met the current step's goal.
+pub fn advance_tutorial(state: &mut SimpleGameState) {
+    let (Some(step), Some(cmd)) = (state.tutorial_step, state.last_command.take()) else {
+        return;
+    };
+    if !tutorial_goal_met(step, &cmd) {
+        return;
+    }
+    state.tutorial_step = Some(step + 1).filter(|&next| get_tutorial_prompt(next).is_some());
+    if state.tutorial_step.is_none() {
+        state.set_message("Tutorial complete! Good luck, hero.");
+    }
+}
+
+/// Get the player's attack including the equipped weapon.
+fn total_attack(state: &SimpleGameState) -> i32 {
+    state.attack + weapon_bonus(state.equipped_weapon)
+}
+
+/// Get the player's defense including the equipped armor.
+fn total_defense(state: &SimpleGameState) -> i32 {
+    state.defense + armor_bonus(state.equipped_armor)
+}
+
+/// Remove one item of a kind from the player, returning whether it was held.
+fn take_from_player(state: &mut SimpleGameState, kind: &ItemKind) -> bool {
+    match kind {
+        ItemKind::Potion if state.potions > 0 => state.potions -= 1,
+        ItemKind::Gold if state.gold >= GOLD_PILE => state.gold -= GOLD_PILE,
+        ItemKind::Sword if state.attack - SWORD_BONUS >= BASE_ATTACK => state.attack -= SWORD_BONUS,
+        _ => return false,
+    }
+    true
+}
+
+/// Drop an item at the player's position, returning whether it was dropped.
+pub fn drop_item(state: &mut SimpleGameState, kind: &ItemKind) -> bool {
+    let (x, y) = (state.player_x, state.player_y);
+    if state.items.iter().any(|i| i.x == x && i.y == y) {
+        state.set_message("There's already an item here.");
+        return false;
+    }
+    if !take_from_player(state, kind) {
+        state.set_message("You don't have that to drop!");
+        return false;
+    }
+    state.items.push(Item {
+        kind: kind.clone(),
+        x,
+        y,
+    });
+    state.set_message(&format!("You drop the {}.", item_kind_name(kind)));
+    true
+}
+
+/// Get display name for an item kind.
+fn item_kind_name(kind: &ItemKind) -> &'static str {
+    match kind {
+        ItemKind::Potion => "potion",
+        ItemKind::Gold => "gold",
+        ItemKind::Chest => "chest",
+        ItemKind::Sword => "sword",
+    }
+}
+
+/// Get display name for a tile.
+fn tile_name(tile: &Tile) -> &'static str {
+    match tile {
+        Tile::Grass => "grass",
+        Tile::Tree => "tree",
+        Tile::Water => "water",
+        Tile::Mountain => "mountain",
+        Tile::Wall => "wall",
+    }
+}
+
+/// Describe what an enemy is currently doing.
+fn enemy_behavior(state: &SimpleGameState, enemy: &Enemy) -> &'static str {
+    if is_adjacent(enemy.x, enemy.y, state.player_x, state.player_y) {
+        "attacking the player"
+    } else {
+        "chasing the player"
+    }
+}
+
+/// Describe everything at a map position, one fact per line.
+pub fn inspect_position(state: &SimpleGameState, x: i32, y: i32) -> String {
+    let mut lines = vec![format!("=== INSPECT ({}, {}) ===", x, y)];
+    if !(0..MAP_WIDTH).contains(&x) || !(0..MAP_HEIGHT).contains(&y) {
+        lines.push("Out of bounds".to_string());
+        return lines.join("\n");
+    }
+    lines.push(format!(
+        "Tile: {}",
+        tile_name(&state.terrain[y as usize][x as usize])
+    ));
+    let player_here = state.player_x == x && state.player_y == y;
+    lines.push(format!(
+        "Player: {}",
+        if player_here { "yes" } else { "no" }
+    ));
+    let enemy = match find_enemy_at(&state.enemies, x, y).map(|i| &state.enemies[i]) {
+        Some(e) => format!(
+            "{} (HP {}, {})",
+            enemy_kind_name(&e.kind),
+            e.health,
+            enemy_behavior(state, e)
+        ),
+        None => "none".to_string(),
+    };
+    lines.push(format!("Enemy: {}", enemy));
+    let item = state.items.iter().find(|i| i.x == x && i.y == y);
+    lines.push(format!(
+        "Item: {}",
+        item.map_or("none", |i| item_kind_name(&i.kind))
+    ));
+    let mut events = Vec::new();
+    if let Some(quest) = state
+        .quest
+        .as_ref()
+        .filter(|q| q.status == QuestStatus::Active)
+    {
+        if (quest.npc_x, quest.npc_y) == (x, y) {
+            events.push("escorted traveler");
+        }
+        if ESCORT_DESTINATION == (x, y) {
+            events.push("escort destination");
+        }
+    }
+    lines.push(format!(
+        "Events: {}",
+        if events.is_empty() {
+            "none".to_string()
+        } else {
+            events.join(", ")
+        }
+    ));
+    lines.join("\n")
+}
+
+/// Get attack bonus of a weapon, matching the inventory item table.
+fn weapon_bonus(item_id: u32) -> i32 {
+    match item_id {
+        1 => 5,
+        2 => 10,
+        3 => 25,
+        4 => 8,
+        5 => 15,
+        _ => 0,
+    }
+}
+
+/// Get defense bonus of an armor, matching the inventory item table.
+fn armor_bonus(item_id: u32) -> i32 {
+    match item_id {
+        101 => 2,
+        102 => 5,
+        103 => 10,
+        104 => 8,
+        105 => 4,
+        _ => 0,
+    }
+}
+
+/// Get the damage an armor reflects, matching the combat component.
+fn armor_reflect_damage(item_id: u32) -> i32 {
+    match item_id {
+        MAGIC_ROBE_ID => MAGIC_ROBE_REFLECT,
+        _ => 0,
+    }
+}
+
+/// Get the most shield points an armor grants, matching the player component.
+fn armor_shield_hp(item_id: u32) -> i32 {
+    match item_id {
+        SHIELD_ARMOR_ID => SHIELD_HP,
+        _ => 0,
+    }
+}
+
+/// Wear an armor, taking on the damage it reflects (0 = none) and its
+/// shield points. Re-equipping the worn armor keeps the current shield.
+fn equip_armor(state: &mut SimpleGameState, item_id: u32) {
+    if item_id != state.equipped_armor {
+        state.shield_hp = armor_shield_hp(item_id);
+    }
+    state.equipped_armor = item_id;
+    state.reflect_damage = armor_reflect_damage(item_id);
+}
+
+/// Regenerate shield points, matching the player component's `regen_shield`.
+fn regen_shield(state: &mut SimpleGameState) {
+    state.shield_hp =
+        (state.shield_hp + SHIELD_REGEN_PER_TURN).min(armor_shield_hp(state.equipped_armor));
+}
+
+/// Score the player's overall power, matching the player component formula.
+pub fn combat_rating(state: &SimpleGameState) -> i32 {
+    state.level * 50
+        + state.attack * 3
+        + state.defense * 2
+        + state.max_health / 5
+        + state.gold / 10
+        + weapon_bonus(state.equipped_weapon) * 4
+}
+
+/// Format a stat change as `before → after (delta)`.
+fn format_stat_change(label: &str, before: i32, after: i32) -> String {
+    let delta = match after - before {
+        0 => "±0".to_string(),
+        d => format!("{:+}", d),
+    };
+    format!("{}: {} → {} ({})", label, before, after, delta)
+}
+
+/// Preview attack and defense totals after equipping an item.
+///
+/// The item replaces whatever is equipped in its slot, so the preview
+/// swaps the equipped item's bonus for the new one.
+pub fn preview_equip(state: &SimpleGameState, item_id: u32) -> String {
+    let (weapon, armor) = match item_id {
+        1..=5 => (item_id, state.equipped_armor),
+        101..=105 => (state.equipped_weapon, item_id),
+        _ => return format!("Item #{} cannot be equipped.", item_id),
+    };
+    let attack = total_attack(state);
+    let defense = total_defense(state);
+    let new_attack = attack - weapon_bonus(state.equipped_weapon) + weapon_bonus(weapon);
+    let new_defense = defense - armor_bonus(state.equipped_armor) + armor_bonus(armor);
+    format!(
+        "{} | {}",
+        format_stat_change("Attack", attack, new_attack),
+        format_stat_change("Defense", defense, new_defense)
+    )
+}
+
+/// Move enemies toward player, despawning fleeing enemies that escape.
+fn move_enemies(state: &mut SimpleGameState) {
+    for i in 0..state.enemies.len() {
+        move_single_enemy(state, i);
+    }
+    state
+        .enemies
+        .retain(|e| e.behavior != Behavior::Flee || !is_map_edge(e.x, e.y));
+}
+
+/// Check if a position lies on the edge of the map.
+fn is_map_edge(x: i32, y: i32) -> bool {
+    x == 0 || y == 0 || x == MAP_WIDTH - 1 || y == MAP_HEIGHT - 1
+}
+
+/// Get the step a fleeing enemy takes, directly away from the player.
+fn flee_movement(enemy: &Enemy, player_x: i32, player_y: i32) -> (i32, i32) {
+    (
+        -(player_x - enemy.x).signum(),
+        -(player_y - enemy.y).signum(),
+    )
+}
+
+/// Move a single enemy.
+fn move_single_enemy(state: &mut SimpleGameState, idx: usize) {
+    let enemy = &state.enemies[idx];
+    let (dx, dy) = match enemy.behavior {
+        Behavior::Chase => (
+            (state.player_x - enemy.x).signum(),
+            (state.player_y - enemy.y).signum(),
+        ),
+        Behavior::Flee => flee_movement(enemy, state.player_x, state.player_y),
+    };
+    let new_x = enemy.x + dx;
+    let new_y = enemy.y + dy;
+    let walkable = is_walkable(&state.terrain, new_x, new_y);
+    let occupied = is_position_occupied(state, new_x, new_y, idx);
+    if walkable && !occupied {
+        state.enemies[idx].x = new_x;
+        state.enemies[idx].y = new_y;
+    }
+}
+
+/// Check if position is occupied by another enemy.
+fn is_position_occupied(state: &SimpleGameState, x: i32, y: i32, skip: usize) -> bool {
+    for (i, e) in state.enemies.iter().enumerate() {
+        if i != skip && e.x == x && e.y == y {
+            return true;
+        }
+    }
+    x == state.player_x && y == state.player_y
+}
+
+/// Start an escort quest with the NPC one tile behind the player.
+pub fn start_escort_quest(state: &mut SimpleGameState) {
+    if matches!(&state.quest, Some(q) if q.status == QuestStatus::Active) {
+        state.set_message("You are already on a quest!");
+        return;
+    }
+    state.quest = Some(Quest {
+        objective: QuestObjective::EscortNpc {
+            npc_id: ESCORT_NPC_ID,
+            destination: ESCORT_DESTINATION,
+            npc_alive: true,
+            escort_hp: ESCORT_HP,
+        },
+        status: QuestStatus::Active,
+        npc_x: state.player_x - 1,
+        npc_y: state.player_y,
+    });
+    let (x, y) = ESCORT_DESTINATION;
+    state.set_message(&format!("Escort the traveler safely to ({}, {}).", x, y));
+}
+
+/// Get the active escort quest, if any.
+fn active_escort(state: &mut SimpleGameState) -> Option<&mut Quest> {
+    state
+        .quest
+        .as_mut()
+        .filter(|q| q.status == QuestStatus::Active)
+}
+
+/// Move the escorted NPC one step toward the player, staying a tile behind.
+fn move_escort(state: &mut SimpleGameState) {
+    let (px, py) = (state.player_x, state.player_y);
+    let Some(quest) = state
+        .quest
+        .as_ref()
+        .filter(|q| q.status == QuestStatus::Active)
+    else {
+        return;
+    };
+    let new_x = quest.npc_x + (px - quest.npc_x).signum();
+    let new_y = quest.npc_y + (py - quest.npc_y).signum();
+    let walkable = is_walkable(&state.terrain, new_x, new_y);
+    let occupied = is_position_occupied(state, new_x, new_y, usize::MAX);
+    if walkable && !occupied {
+        if let Some(quest) = active_escort(state) {
+            quest.npc_x = new_x;
+            quest.npc_y = new_y;
+        }
+    }
+}
+
+/// Damage the escorted NPC, failing the quest if it dies.
+fn damage_escort(state: &mut SimpleGameState, damage: u32) {
+    let Some(quest) = active_escort(state) else {
+        return;
+    };
+    let QuestObjective::EscortNpc {
+        npc_alive,
+        escort_hp,
+        ..
+    } = &mut quest.objective;
+    *escort_hp = escort_hp.saturating_sub(damage);
+    if *escort_hp == 0 {
+        *npc_alive = false;
+        quest.status = QuestStatus::Failed;
+        state.set_message("The traveler has fallen. Quest failed!");
+    }
+}
+
+/// Complete the escort quest once the NPC reaches its destination.
+fn check_escort_arrival(state: &mut SimpleGameState) {
+    let Some(quest) = active_escort(state) else {
+        return;
+    };
+    let QuestObjective::EscortNpc { destination, .. } = quest.objective;
+    if (quest.npc_x, quest.npc_y) == destination {
+        quest.status = QuestStatus::Completed;
+        state.score += ESCORT_REWARD;
+        state.set_message("The traveler arrived safely! Quest complete!");
+    }
+}
+
+/// Check whether enemies target the escorted NPC this turn.
+fn escort_is_targeted(state: &SimpleGameState) -> bool {
+    let escorting = matches!(&state.quest, Some(q) if q.status == QuestStatus::Active);
+    escorting && state.turn % 3 == 0
+}
+
+/// Deal reflected damage back to the enemy at an index.
+fn reflect_onto_enemy(state: &mut SimpleGameState, idx: usize) {
+    state.enemies[idx].health -= state.reflect_damage;
+    let name = enemy_kind_name(&state.enemies[idx].kind);
+    println!(
+        "The {} takes {} reflected damage!",
+        name, state.reflect_damage
+    );
+}
+
+/// Steal gold from the player and send the thief fleeing.
+fn steal_gold(state: &mut SimpleGameState, thief_idx: usize) {
+    let stolen = state.gold.min(THIEF_STEAL_AMOUNT);
+    state.gold -= stolen;
+    state.enemies[thief_idx].behavior = Behavior::Flee;
+    println!("A Thief stole {} gold!", stolen);
+}
+
+/// Process enemy attacks.
+fn enemy_attacks(state: &mut SimpleGameState) {
+    let attackers: Vec<usize> = (0..state.enemies.len())
+        .filter(|&i| {
+            let e = &state.enemies[i];
+            e.behavior != Behavior::Flee && is_adjacent(e.x, e.y, state.player_x, state.player_y)
+        })
+        .collect();
+    for &idx in &attackers {
+        if state.enemies[idx].kind == EnemyKind::Thief {
+            steal_gold(state, idx);
+            continue;
+        }
+        let name = enemy_kind_name(&state.enemies[idx].kind);
+        let attack = state.enemies[idx].attack;
+        if escort_is_targeted(state) {
+            let damage = attack.max(1);
+            damage_escort(state, damage as u32);
+            println!("The {} hits the traveler for {} damage!", name, damage);
+            continue;
+        }
+        let absorbed = state.shield_hp.min(attack.max(0));
+        state.shield_hp -= absorbed;
+        if absorbed > 0 && absorbed == attack {
+            println!("Your shield absorbs the {}'s attack!", name);
+        } else {
+            let damage = (attack - absorbed - total_defense(state)).max(1);
+            state.health -= damage;
+            println!("The {} hits you for {} damage!", name, damage);
+        }
+        if state.reflect_damage > 0 {
+            reflect_onto_enemy(state, idx);
+        }
+    }
+    for &idx in attackers.iter().rev() {
+        if state.enemies[idx].health <= 0 {
+            defeat_enemy(state, idx);
+        }
+    }
+}
+
+/// Check if two positions are adjacent.
 fn is_adjacent(x1: i32, y1: i32, x2: i32, y2: i32) -> bool {
     let dx = (x1 - x2).abs();
     let dy = (y1 - y2).abs();
@@ -781,9 +2603,17 @@ fn is_adjacent(x1: i32, y1: i32, x2: i32, y2: i32) -> bool {
 /// Process end of turn.
 fn end_turn(state: &mut SimpleGameState) {
     state.turn += 1;
+    state.visited_tiles.insert((state.player_x, state.player_y));
+    tick_map_annotations(state);
     collect_item(state);
     move_enemies(state);
+    move_escort(state);
     enemy_attacks(state);
+    regen_shield(state);
+    check_escort_arrival(state);
+    if state.enemies.is_empty() && state.mode == GameMode::Survival {
+        next_survival_wave(state);
+    }
     if state.enemies.is_empty() {
         state.set_message("Victory! All enemies defeated!");
         state.score += 500;
@@ -793,11 +2623,41 @@ fn end_turn(state: &mut SimpleGameState) {
         state.set_message("You have been defeated...");
         state.is_running = false;
     }
+    advance_tutorial(state);
+}
+
+/// Start the next survival wave, unless the final wave was cleared.
+fn next_survival_wave(state: &mut SimpleGameState) {
+    if state.survival_wave >= SURVIVAL_FINAL_WAVE {
+        return;
+    }
+    state.survival_wave += 1;
+    state.enemies = spawn_survival_wave(state, state.survival_wave);
+    state.set_message(&format!(
+        "Wave {} begins! {} enemies approach.",
+        state.survival_wave,
+        state.enemies.len()
+    ));
+}
+
+/// Start a new game in the given mode.
+fn start_mode(state: &mut SimpleGameState, mode: GameMode) {
+    reset_game(state);
+    state.mode = mode;
+    match mode {
+        GameMode::Adventure => state.set_message("Adventure mode: defeat every enemy!"),
+        GameMode::Survival => {
+            state.enemies.clear();
+            next_survival_wave(state);
+        }
+    }
 }
 
 /// Process a command on the game state.
 pub fn process_command(state: &mut SimpleGameState, cmd: &Command) {
     state.clear_message();
+    state.last_command = Some(cmd.clone());
+    take_inventory_snapshot(state);
     match cmd {
         Command::Move(dir) => {
             apply_move(state, dir);
@@ -827,38 +2687,241 @@ fn print_map_header() {
 
 /// Print map legend.
 fn print_legend() {
-    println!("@ You | s/k/b/g/D/B Enemies | * Potion | $ Gold | C Chest | + Sword");
+    println!(
+        "@ You | N Traveler | s/k/b/g/D/B Enemies | * Potion | $ Gold | C Chest | + Sword | ! Note"
+    );
+    println!(". , \" Grass | T Tree | ~ Water | ^ Mountain | # Wall | x Battle site | (blank) Unexplored");
+}
+
+/// Check whether the player has stood on a tile.
+pub fn has_visited(state: &SimpleGameState, x: i32, y: i32) -> bool {
+    state.visited_tiles.contains(&(x, y))
+}
+
+/// Check whether a tile is within the given taxicab distance of the player.
+fn within_sight(state: &SimpleGameState, x: i32, y: i32, radius: i32) -> bool {
+    (x - state.player_x).abs() + (y - state.player_y).abs() <= radius
+}
+
+/// Get every on-map tile within the given taxicab distance of the player.
+pub fn compute_visible_tiles(state: &SimpleGameState, radius: i32) -> Vec<(i32, i32)> {
+    (0..MAP_HEIGHT)
+        .flat_map(|y| (0..MAP_WIDTH).map(move |x| (x, y)))
+        .filter(|&(x, y)| within_sight(state, x, y, radius))
+        .collect()
+}
+
+/// Get the terrain symbol at a map position.
+fn terrain_char(state: &SimpleGameState, x: i32, y: i32) -> char {
+    match state.terrain[y as usize][x as usize] {
+        Tile::Grass => grass_variant_symbol(x, y),
+        tile => tile_symbol(&tile),
+    }
 }
 
-/// Get character at map position.
+/// Get character at map position, hidden by fog of war.
+///
+/// Unexplored tiles out of sight are blank, and visited tiles out of
+/// sight show only their remembered terrain.
 fn get_map_char(state: &SimpleGameState, x: i32, y: i32) -> char {
-    if state.player_x == x && state.player_y == y {
-        return '@';
+    let in_sight = within_sight(state, x, y, VISION_RADIUS);
+    if in_sight {
+        if state.player_x == x && state.player_y == y {
+            return '@';
+        }
+        if let Some(enemy) = state.enemies.iter().find(|e| e.x == x && e.y == y) {
+            return enemy_symbol(&enemy.kind);
+        }
+        if matches!(&state.quest, Some(q) if q.status == QuestStatus::Active && (q.npc_x, q.npc_y) == (x, y))
+        {
+            return 'N';
+        }
+    }
+    if has_field_note(state, x, y) {
+        return '!';
+    }
+    if in_sight {
+        if let Some(item) = state.items.iter().find(|i| i.x == x && i.y == y) {
+            return item_symbol(&item.kind);
+        }
+    }
+    if let Some(&(_, _, symbol, _)) = state
+        .map_annotations
+        .iter()
+        .rev()
+        .find(|&&(ax, ay, _, _)| (ax, ay) == (x, y))
+    {
+        return symbol;
+    }
+    if in_sight || has_visited(state, x, y) {
+        terrain_char(state, x, y)
+    } else {
+        ' '
+    }
+}
+
+/// Rate how dangerous an enemy is to the player.
+pub fn get_enemy_threat_level(state: &SimpleGameState, enemy: &Enemy) -> ThreatLevel {
+    let hits_to_win = (enemy.health + state.attack - 1) / state.attack.max(1);
+    let hits_to_lose = (state.health + enemy.attack - 1) / enemy.attack.max(1);
+    if enemy.attack * 3 > state.health || hits_to_win > hits_to_lose {
+        ThreatLevel::Deadly
+    } else if enemy.attack * 5 > state.health {
+        ThreatLevel::Dangerous
+    } else if enemy.attack * 10 > state.health {
+        ThreatLevel::Manageable
+    } else {
+        ThreatLevel::Safe
+    }
+}
+
+/// Get the ANSI color code for a threat level.
+fn threat_color(threat: ThreatLevel) -> &'static str {
+    match threat {
+        ThreatLevel::Safe => "\x1b[32m",
+        ThreatLevel::Manageable => "\x1b[33m",
+        ThreatLevel::Dangerous => "\x1b[38;5;208m",
+        ThreatLevel::Deadly => "\x1b[31m",
+    }
+}
+
+/// Get the map cell at a position, coloring enemies by threat when enabled.
+fn get_map_cell(state: &SimpleGameState, x: i32, y: i32) -> String {
+    let symbol = get_map_char(state, x, y);
+    let enemy = state.enemies.iter().find(|e| e.x == x && e.y == y);
+    match enemy {
+        Some(enemy) if state.color && symbol != '@' => {
+            let color = threat_color(get_enemy_threat_level(state, enemy));
+            format!("{}{}\x1b[0m", color, symbol)
+        }
+        _ => symbol.to_string(),
+    }
+}
+
+/// Pick the color mode from the `TERM` and `COLORTERM` values.
+pub fn detect_color_mode(term: Option<&str>, colorterm: Option<&str>) -> ColorMode {
+    if matches!(colorterm, Some("truecolor" | "24bit")) {
+        return ColorMode::TrueColor;
+    }
+    match term {
+        None | Some("" | "dumb") => ColorMode::None,
+        Some(_) => ColorMode::Ansi256,
+    }
+}
+
+/// Detect the terminal's color mode from the environment.
+fn color_mode_from_env() -> ColorMode {
+    let term = std::env::var("TERM").ok();
+    let colorterm = std::env::var("COLORTERM").ok();
+    detect_color_mode(term.as_deref(), colorterm.as_deref())
+}
+
+/// Get the RGB value of a 256-color palette index.
+fn palette_rgb(index: u8) -> (u8, u8, u8) {
+    let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+    match index {
+        0..=15 => BASIC_PALETTE[index as usize],
+        16..=231 => {
+            let cube = index - 16;
+            (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Wrap a character in ANSI color codes for a palette foreground and
+/// optional background, or return it unchanged without color.
+pub fn colorize(ch: char, fg: u8, bg: Option<u8>, mode: ColorMode) -> String {
+    let code = |layer: u8, index: u8| match mode {
+        ColorMode::TrueColor => {
+            let (r, g, b) = palette_rgb(index);
+            format!("\x1b[{};2;{};{};{}m", layer, r, g, b)
+        }
+        _ => format!("\x1b[{};5;{}m", layer, index),
+    };
+    if mode == ColorMode::None {
+        return ch.to_string();
     }
-    if let Some(enemy) = state.enemies.iter().find(|e| e.x == x && e.y == y) {
-        return enemy_symbol(&enemy.kind);
+    let background = bg.map_or(String::new(), |bg| code(48, bg));
+    format!("{}{}{}\x1b[0m", code(38, fg), background, ch)
+}
+
+/// Get the palette color of a map symbol, if it has one.
+fn symbol_color(state: &SimpleGameState, x: i32, y: i32, symbol: char) -> Option<u8> {
+    let enemy_here = state
+        .enemies
+        .iter()
+        .any(|e| (e.x, e.y) == (x, y) && enemy_symbol(&e.kind) == symbol);
+    match symbol {
+        '@' => Some(PLAYER_COLOR),
+        _ if enemy_here => Some(ENEMY_COLOR),
+        '*' => Some(POTION_COLOR),
+        '$' => Some(GOLD_COLOR),
+        '#' => Some(WALL_COLOR),
+        '~' => Some(WATER_COLOR),
+        'T' => Some(TREE_COLOR),
+        _ => None,
     }
-    if let Some(item) = state.items.iter().find(|i| i.x == x && i.y == y) {
-        return item_symbol(&item.kind);
+}
+
+/// Get the map cell at a position, colored by tile and entity type.
+///
+/// Threat colors take precedence for enemies when they are on.
+fn get_map_cell_colored(state: &SimpleGameState, x: i32, y: i32, mode: ColorMode) -> String {
+    let symbol = get_map_char(state, x, y);
+    let threat_colored =
+        state.color && symbol != '@' && state.enemies.iter().any(|e| (e.x, e.y) == (x, y));
+    match symbol_color(state, x, y, symbol) {
+        Some(fg) if mode != ColorMode::None && !threat_colored => colorize(symbol, fg, None, mode),
+        _ => get_map_cell(state, x, y),
     }
-    tile_symbol(&state.terrain[y as usize][x as usize])
 }
 
 /// Print a single map row.
-fn print_map_row(state: &SimpleGameState, y: i32) {
+fn print_map_row(state: &SimpleGameState, y: i32, mode: ColorMode) {
     for x in 0..MAP_WIDTH {
-        print!("{} ", get_map_char(state, x, y));
+        print!("{} ", get_map_cell_colored(state, x, y, mode));
     }
     println!();
 }
 
 /// Display the game map.
 pub fn display_map(state: &SimpleGameState) {
+    display_map_colored(state, ColorMode::None);
+}
+
+/// Display the game map with tiles and entities in color.
+pub fn display_map_colored(state: &SimpleGameState, mode: ColorMode) {
+    if let Some(prompt) = state.tutorial_step.and_then(get_tutorial_prompt) {
+        println!("\n[Tutorial] {}", prompt);
+    }
     print_map_header();
     for y in 0..MAP_HEIGHT {
-        print_map_row(state, y);
+        print_map_row(state, y, mode);
     }
     print_legend();
+    if let Some(reward) = &state.pending_reward {
+        println!("\n{}", format_battle_reward(reward));
+    }
+}
+
+/// Format the summary of a won battle.
+pub fn format_battle_reward(reward: &BattleReward) -> String {
+    let mut lines = vec![
+        "=== BATTLE REWARD ===".to_string(),
+        format!("Turns taken: {}", reward.turns_taken),
+        format!("+{} EXP", reward.exp_gained),
+    ];
+    if reward.gold_gained > 0 {
+        lines.push(format!("+{} gold", reward.gold_gained));
+    }
+    if !reward.items_dropped.is_empty() {
+        lines.push(format!("Dropped: {}", reward.items_dropped.join(", ")));
+    }
+    lines.join("\n")
 }
 
 /// Print the title border line.
@@ -931,6 +2994,20 @@ pub fn display_help() {
     println!("i - Inventory");
     println!("stat - Status");
     println!(". - Wait a turn");
+    println!("duel <enemy> - Preview a battle");
+    println!("mark <name> / goto <name> / waypoints - Navigation");
+    println!("note <text> / notes - Field notes");
+    println!("drop <potion|gold|sword> - Drop an item here");
+    println!("escort - Start an escort quest");
+    println!("travel <area> - Fast travel for 10 gold per level");
+    println!("where boss - Show the distance to the boss");
+    println!("inspect <x> <y> - Describe a map position");
+    println!("equip? <item id> - Preview equipping an item");
+    println!("read map - Reveal this area with a Magic Map");
+    println!("shop / buy <number> / sell <number> - Trade with the merchant");
+    println!("save <file> / load <file> - Save or load progress");
+    println!("tutorial - Start the guided tutorial");
+    println!("new - Start a new game");
     println!("h - Help");
     println!("q - Quit");
 }
@@ -943,33 +3020,158 @@ pub fn display_status(state: &SimpleGameState) {
         "Level: {} (EXP: {}/{})",
         state.level,
         state.exp,
-        state.level * 25
+        state.level * EXP_PER_LEVEL
+    );
+    println!(
+        "Attack: {}  Defense: {}  Luck: {}",
+        total_attack(state),
+        total_defense(state),
+        state.luck
     );
-    println!("Attack: {}  Defense: {}", state.attack, state.defense);
+    println!("Speed: {}", state.speed);
+    println!("Combat Rating: {}", combat_rating(state));
     println!("Gold: {}  Potions: {}", state.gold, state.potions);
+    println!(
+        "Explored: {}/{} tiles",
+        state.visited_tiles.len(),
+        MAP_WIDTH * MAP_HEIGHT
+    );
     println!("Score: {}", state.score);
     println!("Area: {}", state.area_name());
     println!("Turn: {}", state.turn);
     println!("Enemies remaining: {}", state.enemies.len());
+    if state.shopping {
+        println!("For sale:");
+        print_shop_stock();
+    }
 }
 
 /// Display inventory.
 fn display_inventory(state: &SimpleGameState) {
     println!("\n=== INVENTORY ===");
     println!("Potions: {}", state.potions);
+    println!("Magic Maps: {}", state.magic_maps);
     println!("Gold: {}", state.gold);
     if state.potions > 0 {
         println!("\nUse 'u' to drink a potion.");
     }
+    if state.magic_maps > 0 {
+        println!("Use 'read map' to reveal this area.");
+    }
 }
 
-/// Read user input from stdin.
-pub fn read_input() -> String {
-    print!("> ");
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-    input
+/// Check whether a confirmation answer is yes.
+pub fn parse_confirmation(input: &str) -> bool {
+    matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Ask a yes/no question and read the answer.
+fn prompt_yes_no(question: &str) -> bool {
+    println!("{}", question);
+    parse_confirmation(&read_input())
+}
+
+/// Reset the game state to a fresh game.
+pub fn reset_game(state: &mut SimpleGameState) {
+    let world_seed = state.world_seed;
+    *state = SimpleGameState::new();
+    state.world_seed = world_seed;
+}
+
+/// Derive a world seed from the current time.
+fn timestamp_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+/// Save file format version written by `save_state`.
+const SAVE_VERSION: u32 = 1;
+
+/// A save file: the format version and the saved game.
+#[derive(Serialize, Deserialize)]
+struct SaveFile<S> {
+    /// Save format version.
+    version: u32,
+    /// Saved game state.
+    state: S,
+}
+
+/// Serialize visited tiles in sorted order so saves are deterministic.
+fn serialize_sorted_tiles<S: Serializer>(
+    tiles: &HashSet<(i32, i32)>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut sorted: Vec<_> = tiles.iter().collect();
+    sorted.sort_unstable();
+    sorted.serialize(serializer)
+}
+
+/// Encode a game state as a version 1 JSON save.
+pub fn encode_save(state: &SimpleGameState) -> String {
+    let save = SaveFile {
+        version: SAVE_VERSION,
+        state,
+    };
+    serde_json::to_string_pretty(&save).unwrap_or_default()
+}
+
+/// Decode a version 1 JSON save.
+pub fn decode_save(text: &str) -> Result<SimpleGameState, &'static str> {
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|_| "not a valid save file")?;
+    if value.get("version").and_then(serde_json::Value::as_u64) != Some(SAVE_VERSION.into()) {
+        return Err("unsupported save version");
+    }
+    let save: SaveFile<SimpleGameState> =
+        serde_json::from_value(value).map_err(|_| "save file is missing or has invalid fields")?;
+    Ok(save.state)
+}
+
+/// Save a game state to a JSON file.
+pub fn save_state(state: &SimpleGameState, path: &str) -> io::Result<()> {
+    fs::write(path, encode_save(state))
+}
+
+/// Load a game state from a JSON file written by `save_state`.
+pub fn load_state(path: &str) -> io::Result<SimpleGameState> {
+    let text = fs::read_to_string(path)?;
+    decode_save(&text).map_err(|msg| io::Error::new(io::ErrorKind::InvalidData, msg))
+}
+
+/// Set the world seed, which is only allowed before the first turn.
+fn set_world_seed(state: &mut SimpleGameState, seed: u64) {
+    if state.turn > 0 {
+        state.set_message("The seed can only be set before the first turn.");
+        return;
+    }
+    state.world_seed = seed;
+    state.set_message(&format!("World seed set to {}.", seed));
+}
+
+/// Start a new game in place, confirming first if score would be lost.
+fn new_game(state: &mut SimpleGameState) {
+    if state.score > 0 {
+        let question = format!(
+            "Start over? Your score of {} will be lost. (y/n)",
+            state.score
+        );
+        if !prompt_yes_no(&question) {
+            state.set_message("Continuing the current game.");
+            return;
+        }
+    }
+    reset_game(state);
+    start_game();
+}
+
+/// Read user input from stdin.
+pub fn read_input() -> String {
+    print!("> ");
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    input
 }
 
 /// Handle unknown command.
@@ -984,27 +3186,160 @@ fn display_message(state: &SimpleGameState) {
     }
 }
 
+/// Render an ASCII progress bar such as `[####    ]`.
+pub fn progress_bar(current: i32, max: i32) -> String {
+    let filled = if max <= 0 {
+        0
+    } else {
+        (current.clamp(0, max) * BAR_WIDTH / max) as usize
+    };
+    let empty = BAR_WIDTH as usize - filled;
+    format!("[{}{}]", "#".repeat(filled), " ".repeat(empty))
+}
+
+/// Build the HUD lines with health and XP bars.
+pub fn hud_lines(state: &SimpleGameState) -> Vec<String> {
+    let exp_needed = state.level * EXP_PER_LEVEL;
+    let mut lines = vec![
+        format!(
+            "HP: {}/{} {}",
+            state.health,
+            state.max_health,
+            progress_bar(state.health, state.max_health)
+        ),
+        format!(
+            "XP: {}/{} {}",
+            state.exp,
+            exp_needed,
+            progress_bar(state.exp, exp_needed)
+        ),
+        format!(
+            "Lvl: {}  Score: {}  Turn: {}",
+            state.level, state.score, state.turn
+        ),
+    ];
+    if state.pending_level_up {
+        lines.push("*** LEVEL UP READY! ***".to_string());
+    }
+    if state.paused {
+        lines.push("[PAUSED]".to_string());
+    }
+    lines
+}
+
 /// Display HUD (heads up display).
 fn display_hud(state: &SimpleGameState) {
-    println!(
-        "HP: {}/{}  Lvl: {}  Score: {}  Turn: {}",
-        state.health, state.max_health, state.level, state.score, state.turn
-    );
+    for line in hud_lines(state) {
+        println!("{}", line);
+    }
 }
 
 /// Execute a parsed command.
 fn execute_command(state: &mut SimpleGameState, cmd: &Command) {
+    if state.paused && !matches!(cmd, Command::Resume | Command::Quit) {
+        state.set_message("Game is paused.");
+        return;
+    }
+    state.last_command = Some(cmd.clone());
+    state.pending_reward = None;
+    if !matches!(cmd, Command::Shop | Command::Buy(_) | Command::Sell(_)) {
+        state.shopping = false;
+    }
     match cmd {
+        Command::Pause => {
+            state.paused = true;
+            state.set_message("Game paused.");
+        }
+        Command::Resume => {
+            state.paused = false;
+            state.set_message("Game resumed.");
+        }
         Command::Help => display_help(),
         Command::Inventory => display_inventory(state),
         Command::Status => display_status(state),
         Command::Unknown => handle_unknown(),
+        Command::Duel(kind) => display_duel(state, kind),
+        Command::Waypoints => display_waypoints(state),
+        Command::WhereBoss => display_boss_distance(state),
+        Command::Compass => display_compass(state),
+        Command::LevelUpPreview => println!("\n{}", level_up_summary(state.level)),
+        Command::Tutorial => start_tutorial(state),
+        Command::ReadMap => {
+            state.clear_message();
+            use_magic_map(state);
+        }
+        Command::Notes => display_field_notes(state),
+        Command::Note(text) => {
+            state.clear_message();
+            add_field_note(state, text);
+        }
+        Command::NewGame => new_game(state),
+        Command::Mark(name) => {
+            state.clear_message();
+            mark_waypoint(state, name);
+        }
+        Command::Goto(name) => {
+            state.clear_message();
+            goto_waypoint(state, name);
+        }
+        Command::Drop(kind) => {
+            state.clear_message();
+            drop_item(state, kind);
+        }
+        Command::Escort => {
+            state.clear_message();
+            start_escort_quest(state);
+        }
+        Command::Travel(area) => match fast_travel(state, area) {
+            Ok(()) => end_turn(state),
+            Err(msg) => state.set_message(msg),
+        },
+        Command::Inspect(x, y) => println!("\n{}", inspect_position(state, *x, *y)),
+        Command::PreviewEquip(id) => println!("\n{}", preview_equip(state, *id)),
+        Command::UndoItem => {
+            state.clear_message();
+            undo_item(state);
+        }
+        Command::Mode(mode) => start_mode(state, *mode),
+        Command::Seed(seed) => set_world_seed(state, *seed),
+        Command::Save(path) => match save_state(state, path) {
+            Ok(()) => state.set_message(&format!("Game saved to {}.", path)),
+            Err(err) => state.set_message(&format!("Could not save the game: {}", err)),
+        },
+        Command::Load(path) => match load_state(path) {
+            Ok(loaded) => {
+                *state = loaded;
+                state.set_message(&format!("Game loaded from {}.", path));
+            }
+            Err(err) => state.set_message(&format!("Could not load the game: {}", err)),
+        },
+        Command::ConfirmLevelUp => confirm_level_up(state),
+        Command::Optimize => optimize_gear(state),
+        Command::Shop => open_shop(state),
+        Command::Buy(number) => match buy_from_merchant(state, *number) {
+            Ok(msg) => state.set_message(&msg),
+            Err(msg) => state.set_message(msg),
+        },
+        Command::Sell(number) => match sell_to_merchant(state, *number) {
+            Ok(msg) => state.set_message(&msg),
+            Err(msg) => state.set_message(msg),
+        },
+        Command::Color => {
+            state.color = !state.color;
+            let setting = if state.color { "on" } else { "off" };
+            state.set_message(&format!("Threat colors {}.", setting));
+        }
+        Command::Target(mode) => {
+            state.targeting_mode = *mode;
+            state.set_message(&format!("Targeting: {:?}", mode));
+        }
         Command::Interact => {
             state.set_message("Nothing to interact with here.");
             end_turn(state);
         }
         _ => process_command(state, cmd),
     }
+    advance_tutorial(state);
 }
 
 /// Run a single game loop iteration.
@@ -1014,14 +3349,22 @@ fn game_loop_iteration(state: &mut SimpleGameState) {
     execute_command(state, &cmd);
 }
 
-/// Run the main game loop.
-pub fn run_game_loop(state: &mut SimpleGameState) {
+/// Check whether the player quit before winning or losing.
+fn quit_early(state: &SimpleGameState) -> bool {
+    state.health > 0 && !state.enemies.is_empty()
+}
+
+/// Run the main game loop, returning whether to play again.
+pub fn run_game_loop(state: &mut SimpleGameState) -> bool {
+    let mode = color_mode_from_env();
     while state.is_running && state.health > 0 {
-        display_map(state);
+        display_map_colored(state, mode);
         display_hud(state);
         display_message(state);
         game_loop_iteration(state);
     }
+    end_game(state);
+    !quit_early(state) && prompt_yes_no("Play again? (y/n)")
 }
 
 /// Start the game and display intro.
@@ -1044,10 +3387,14 @@ fn end_game(state: &SimpleGameState) {
 
 /// Main entry point for the command-line game.
 fn main() {
-    start_game();
-    let mut state = SimpleGameState::new();
-    run_game_loop(&mut state);
-    end_game(&state);
+    loop {
+        start_game();
+        let mut state = SimpleGameState::new();
+        state.world_seed = timestamp_seed();
+        if !run_game_loop(&mut state) {
+            break;
+        }
+    }
 }
 
 // ============================================================================
@@ -1353,7 +3700,7 @@ mod tests {
         assert!(items.len() >= 4);
     }
 
-    /// Test find_adjacent_enemy.
+    /// Test find_target_enemy.
     #[test]
     fn test_find_adjacent_enemy() {
         let mut state = SimpleGameState::new();
@@ -1361,10 +3708,10 @@ mod tests {
         state.player_y = 5;
         state.enemies.clear();
         state.enemies.push(create_slime(5, 4));
-        assert!(find_adjacent_enemy(&state).is_some());
+        assert!(find_target_enemy(&state, TargetingMode::FirstFound).is_some());
     }
 
-    /// Test find_adjacent_enemy none.
+    /// Test find_target_enemy none.
     #[test]
     fn test_find_adjacent_enemy_none() {
         let mut state = SimpleGameState::new();
@@ -1372,7 +3719,7 @@ mod tests {
         state.player_y = 5;
         state.enemies.clear();
         state.enemies.push(create_slime(10, 10));
-        assert!(find_adjacent_enemy(&state).is_none());
+        assert!(find_target_enemy(&state, TargetingMode::FirstFound).is_none());
     }
 
     /// Test state area_name method.
@@ -1383,4 +3730,1236 @@ mod tests {
         state.player_y = 7;
         assert!(!state.area_name().is_empty());
     }
+
+    /// Test parsing duel command.
+    #[test]
+    fn test_parse_duel() {
+        assert_eq!(parse_input("duel slime"), Command::Duel(EnemyKind::Slime));
+        assert_eq!(
+            parse_input("duel dark knight"),
+            Command::Duel(EnemyKind::DarkKnight)
+        );
+        assert_eq!(parse_input("duel dragon"), Command::Unknown);
+    }
+
+    /// Test duel against slime at full health predicts a win.
+    #[test]
+    fn test_duel_slime_full_health_wins() {
+        let state = SimpleGameState::new();
+        let outcome = simulate_battle(state.health, state.attack, state.defense, &EnemyKind::Slime);
+        assert!(outcome.player_wins);
+        assert!(duel_prediction(&state, &EnemyKind::Slime).starts_with("You would win"));
+    }
+
+    /// Test duel against boss at low health predicts a loss.
+    #[test]
+    fn test_duel_boss_low_health_loses() {
+        let mut state = SimpleGameState::new();
+        state.health = 10;
+        let outcome = simulate_battle(state.health, state.attack, state.defense, &EnemyKind::Boss);
+        assert!(!outcome.player_wins);
+        assert!(duel_prediction(&state, &EnemyKind::Boss).starts_with("You would lose"));
+    }
+
+    /// Test duel leaves the game state unchanged.
+    #[test]
+    fn test_duel_does_not_modify_state() {
+        let mut state = SimpleGameState::new();
+        execute_command(&mut state, &Command::Duel(EnemyKind::Goblin));
+        assert_eq!(state.health, 100);
+        assert_eq!(state.exp, 0);
+        assert_eq!(state.gold, 0);
+        assert_eq!(state.turn, 0);
+    }
+
+    /// Test grass variant symbols are deterministic.
+    #[test]
+    fn test_grass_variant_symbol() {
+        assert_eq!(grass_variant_symbol(0, 0), '.');
+        assert_eq!(grass_variant_symbol(1, 0), ',');
+        assert_eq!(grass_variant_symbol(5, 5), grass_variant_symbol(5, 5));
+    }
+
+    /// Test parsing waypoint commands.
+    #[test]
+    fn test_parse_waypoint() {
+        assert_eq!(parse_input("mark camp"), Command::Mark("camp".to_string()));
+        assert_eq!(parse_input("goto camp"), Command::Goto("camp".to_string()));
+        assert_eq!(parse_input("waypoints"), Command::Waypoints);
+    }
+
+    /// Test marking a waypoint at the player position.
+    #[test]
+    fn test_mark_waypoint() {
+        let mut state = SimpleGameState::new();
+        mark_waypoint(&mut state, "camp");
+        assert_eq!(find_waypoint(&state, "camp"), Some((10, 10)));
+    }
+
+    /// Test the oldest waypoint is replaced past the limit.
+    #[test]
+    fn test_mark_waypoint_limit() {
+        let mut state = SimpleGameState::new();
+        for i in 0..=MAX_WAYPOINTS {
+            mark_waypoint(&mut state, &format!("wp{}", i));
+        }
+        assert_eq!(state.waypoints.len(), MAX_WAYPOINTS);
+        assert_eq!(find_waypoint(&state, "wp0"), None);
+        assert!(find_waypoint(&state, "wp10").is_some());
+    }
+
+    /// Test goto moves the player toward the waypoint.
+    #[test]
+    fn test_goto_waypoint() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        // The slime at (6, 11) is walled in and cannot interrupt travel.
+        state.enemies.push(create_slime(6, 11));
+        mark_waypoint(&mut state, "camp");
+        state.player_x = 5;
+        state.player_y = 5;
+        goto_waypoint(&mut state, "camp");
+        assert_eq!((state.player_x, state.player_y), (10, 10));
+    }
+
+    /// Test goto with an unknown waypoint keeps the player in place.
+    #[test]
+    fn test_goto_unknown_waypoint() {
+        let mut state = SimpleGameState::new();
+        goto_waypoint(&mut state, "nowhere");
+        assert_eq!((state.player_x, state.player_y), (10, 10));
+        assert_eq!(state.turn, 0);
+    }
+
+    /// Test HUD health bar at half health.
+    #[test]
+    fn test_hud_health_bar_half() {
+        let mut state = SimpleGameState::new();
+        state.health = 50;
+        state.max_health = 100;
+        assert_eq!(hud_lines(&state)[0], "HP: 50/100 [####    ]");
+    }
+
+    /// Test HUD XP bar.
+    #[test]
+    fn test_hud_xp_bar() {
+        let mut state = SimpleGameState::new();
+        state.exp = 20;
+        assert_eq!(hud_lines(&state)[1], "XP: 20/25 [######  ]");
+    }
+
+    /// Test parsing the new game command.
+    #[test]
+    fn test_parse_new_game() {
+        assert_eq!(parse_input("new"), Command::NewGame);
+    }
+
+    /// Test confirmation parsing.
+    #[test]
+    fn test_parse_confirmation() {
+        assert!(parse_confirmation("y\n"));
+        assert!(parse_confirmation("YES"));
+        assert!(!parse_confirmation("n"));
+        assert!(!parse_confirmation(""));
+    }
+
+    /// Test resetting the game state.
+    #[test]
+    fn test_reset_game() {
+        let mut state = SimpleGameState::new();
+        state.turn = 42;
+        state.player_x = 3;
+        state.player_y = 4;
+        state.enemies.clear();
+        reset_game(&mut state);
+        assert_eq!(state.turn, 0);
+        assert_eq!(state.enemies.len(), spawn_enemies().len());
+        assert_eq!((state.player_x, state.player_y), (10, 10));
+    }
+
+    /// Test parsing the drop command.
+    #[test]
+    fn test_parse_drop() {
+        assert_eq!(parse_input("drop potion"), Command::Drop(ItemKind::Potion));
+        assert_eq!(parse_input("drop chest"), Command::Unknown);
+    }
+
+    /// Test dropping a potion spawns it at the player's position.
+    #[test]
+    fn test_drop_item_spawns_at_player() {
+        let mut state = SimpleGameState::new();
+        state.items.clear();
+        assert!(drop_item(&mut state, &ItemKind::Potion));
+        assert_eq!(state.potions, 0);
+        assert_eq!(state.items.len(), 1);
+        assert_eq!((state.items[0].x, state.items[0].y), (10, 10));
+        assert!(!drop_item(&mut state, &ItemKind::Gold));
+    }
+
+    /// Test the escorted NPC follows the player.
+    #[test]
+    fn test_escort_follows_player() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        state.player_x = 14;
+        state.player_y = 3;
+        start_escort_quest(&mut state);
+        state.player_x = 15;
+        state.player_y = 4;
+        move_escort(&mut state);
+        let quest = state.quest.as_ref().unwrap();
+        assert_eq!((quest.npc_x, quest.npc_y), (14, 4));
+        move_escort(&mut state);
+        let quest = state.quest.as_ref().unwrap();
+        assert_eq!((quest.npc_x, quest.npc_y), (14, 4));
+    }
+
+    /// Test the escort quest fails when the NPC dies.
+    #[test]
+    fn test_escort_fails_when_npc_dies() {
+        let mut state = SimpleGameState::new();
+        start_escort_quest(&mut state);
+        state.enemies = vec![create_slime(state.player_x + 1, state.player_y)];
+        state.enemies[0].attack = ESCORT_HP as i32;
+        state.turn = 3;
+        let health = state.health;
+        enemy_attacks(&mut state);
+        let quest = state.quest.as_ref().unwrap();
+        assert_eq!(quest.status, QuestStatus::Failed);
+        assert!(matches!(
+            quest.objective,
+            QuestObjective::EscortNpc {
+                npc_alive: false,
+                escort_hp: 0,
+                ..
+            }
+        ));
+        assert_eq!(state.health, health);
+    }
+
+    /// Test reflected damage can defeat an attacking enemy.
+    #[test]
+    fn test_enemy_attacks_reflect_damage() {
+        let mut state = SimpleGameState::new();
+        grant_chest_reward(&mut state, MAGIC_ROBE_ID, 1);
+        assert_eq!(state.equipped_armor, MAGIC_ROBE_ID);
+        assert_eq!(state.reflect_damage, MAGIC_ROBE_REFLECT);
+        state.enemies = vec![create_slime(state.player_x + 1, state.player_y)];
+        state.enemies[0].health = 5;
+        enemy_attacks(&mut state);
+        assert_eq!(state.enemies[0].health, 2);
+        enemy_attacks(&mut state);
+        assert!(state.enemies.is_empty());
+    }
+
+    /// Test the Shield absorbs enemy attacks and regenerates each turn.
+    #[test]
+    fn test_shield_absorbs_and_regenerates() {
+        let mut state = SimpleGameState::new();
+        grant_chest_reward(&mut state, SHIELD_ARMOR_ID, 1);
+        assert_eq!(state.shield_hp, SHIELD_HP);
+        state.enemies = vec![create_slime(state.player_x + 1, state.player_y)];
+        let attack = state.enemies[0].attack;
+        let health = state.health;
+        enemy_attacks(&mut state);
+        assert_eq!(state.shield_hp, SHIELD_HP - attack);
+        assert_eq!(state.health, health);
+        equip_armor(&mut state, SHIELD_ARMOR_ID);
+        assert_eq!(state.shield_hp, SHIELD_HP - attack);
+        state.enemies.clear();
+        regen_shield(&mut state);
+        assert_eq!(
+            state.shield_hp,
+            (SHIELD_HP - attack + SHIELD_REGEN_PER_TURN).min(SHIELD_HP)
+        );
+        equip_armor(&mut state, 103);
+        assert_eq!(state.shield_hp, 0);
+        regen_shield(&mut state);
+        assert_eq!(state.shield_hp, 0);
+    }
+
+    /// Test swapping the Magic Robe for other armor stops the reflection.
+    #[test]
+    fn test_reflect_damage_follows_armor() {
+        let mut state = SimpleGameState::new();
+        grant_chest_reward(&mut state, MAGIC_ROBE_ID, 1);
+        grant_chest_reward(&mut state, 103, 1);
+        assert_eq!(state.equipped_armor, 103);
+        assert_eq!(state.reflect_damage, 0);
+        state.enemies = vec![create_slime(state.player_x + 1, state.player_y)];
+        let health = state.enemies[0].health;
+        enemy_attacks(&mut state);
+        assert_eq!(state.enemies[0].health, health);
+        assert!((0..1000).any(|turn| roll_chest_reward(turn, 3) == vec![(MAGIC_ROBE_ID, 1)]));
+    }
+
+    /// Test parsing the inspect command.
+    #[test]
+    fn test_parse_inspect() {
+        assert_eq!(parse_input("inspect 3 4"), Command::Inspect(3, 4));
+        assert_eq!(parse_input("inspect 3"), Command::Unknown);
+        assert_eq!(parse_input("inspect a b"), Command::Unknown);
+    }
+
+    /// Test inspecting the player's position.
+    #[test]
+    fn test_inspect_player_position() {
+        let state = SimpleGameState::new();
+        let report = inspect_position(&state, state.player_x, state.player_y);
+        assert!(report.contains("Player: yes"));
+        assert!(report.contains("Tile: grass"));
+    }
+
+    /// Test inspecting an enemy's position names the enemy.
+    #[test]
+    fn test_inspect_enemy_position() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_goblin(12, 10)];
+        let report = inspect_position(&state, 12, 10);
+        assert!(report.contains("Enemy: Goblin"));
+        assert!(report.contains("Player: no"));
+        assert!(report.contains("chasing the player"));
+    }
+
+    /// Test parsing the equip preview command.
+    #[test]
+    fn test_parse_preview_equip() {
+        assert_eq!(parse_input("equip? 3"), Command::PreviewEquip(3));
+        assert_eq!(parse_input("equip? sword"), Command::Unknown);
+    }
+
+    /// Test previewing a sword upgrade shows the attack delta.
+    #[test]
+    fn test_preview_equip_master_sword() {
+        let mut state = SimpleGameState::new();
+        state.equipped_weapon = 2;
+        let preview = preview_equip(&state, 3);
+        assert_eq!(preview, "Attack: 25 → 40 (+15) | Defense: 5 → 5 (±0)");
+        assert_eq!(state.equipped_weapon, 2);
+    }
+
+    /// Test previewing armor and non-equipment items.
+    #[test]
+    fn test_preview_equip_armor_and_invalid() {
+        let mut state = SimpleGameState::new();
+        state.equipped_armor = 103;
+        assert_eq!(
+            preview_equip(&state, 101),
+            "Attack: 15 → 15 (±0) | Defense: 15 → 7 (-8)"
+        );
+        assert_eq!(preview_equip(&state, 201), "Item #201 cannot be equipped.");
+    }
+
+    /// Test parsing field note commands.
+    #[test]
+    fn test_parse_note() {
+        assert_eq!(
+            parse_input("note danger here"),
+            Command::Note("danger here".to_string())
+        );
+        assert_eq!(parse_input("notes"), Command::Notes);
+        assert_eq!(
+            parse_input("Note Hidden door near Kakariko"),
+            Command::Note("Hidden door near Kakariko".to_string())
+        );
+        assert_eq!(parse_input("NOTES"), Command::Notes);
+    }
+
+    /// Test a field note is listed and shown on the map.
+    #[test]
+    fn test_field_note_on_map() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        state.items.clear();
+        state.player_x = 5;
+        state.player_y = 6;
+        execute_command(&mut state, &parse_input("note danger here"));
+        assert_eq!(state.field_notes, vec![(5, 6, "danger here".to_string())]);
+        state.player_x = 10;
+        assert_eq!(get_map_char(&state, 5, 6), '!');
+    }
+
+    /// Test field notes overwrite by position and drop the oldest on overflow.
+    #[test]
+    fn test_field_note_overwrite_and_limit() {
+        let mut state = SimpleGameState::new();
+        add_field_note(&mut state, "first");
+        add_field_note(&mut state, "second");
+        assert_eq!(state.field_notes, vec![(10, 10, "second".to_string())]);
+        for i in 0..MAX_FIELD_NOTES as i32 {
+            state.player_x = i % MAP_WIDTH;
+            state.player_y = i / MAP_WIDTH;
+            add_field_note(&mut state, "spot");
+        }
+        assert_eq!(state.field_notes.len(), MAX_FIELD_NOTES);
+        assert!(!has_field_note(&state, 10, 10));
+    }
+
+    /// Test parsing the travel command.
+    #[test]
+    fn test_parse_travel() {
+        assert_eq!(
+            parse_input("travel Death Mountain"),
+            Command::Travel("death mountain".to_string())
+        );
+    }
+
+    /// Test fast travel entry points are walkable and in their area.
+    #[test]
+    fn test_area_entry_points() {
+        let state = SimpleGameState::new();
+        for &(name, x, y) in AREA_ENTRY_POINTS {
+            assert!(is_walkable(&state.terrain, x, y));
+            assert_eq!(area_from_position(x, y), name);
+        }
+    }
+
+    /// Test fast travel charges gold and rejects poor players.
+    #[test]
+    fn test_fast_travel() {
+        let mut state = SimpleGameState::new();
+        assert_eq!(
+            fast_travel(&mut state, "death mountain"),
+            Err("Not enough gold")
+        );
+        state.gold = 15;
+        assert_eq!(fast_travel(&mut state, "death mountain"), Ok(()));
+        assert_eq!((state.player_x, state.player_y, state.gold), (17, 7, 5));
+        assert_eq!(fast_travel(&mut state, "atlantis"), Err("Unknown area"));
+    }
+
+    /// Test the distance to the boss.
+    #[test]
+    fn test_distance_to_boss() {
+        let mut state = SimpleGameState::new();
+        assert_eq!(parse_input("where boss"), Command::WhereBoss);
+        state.enemies = vec![create_boss(15, 12)];
+        assert_eq!(distance_to_boss(&state), Some(7));
+        state.enemies.clear();
+        assert_eq!(distance_to_boss(&state), None);
+    }
+
+    /// Test chest rewards vary with the global turn.
+    #[test]
+    fn test_roll_chest_reward_varies() {
+        let mut seen = Vec::new();
+        for turn in 0..100 {
+            let reward = roll_chest_reward(turn, 1);
+            assert_eq!(reward.len(), 1);
+            if !seen.contains(&reward[0].0) {
+                seen.push(reward[0].0);
+            }
+        }
+        assert!(seen.len() >= 2);
+        assert!(!seen.contains(&3));
+    }
+
+    /// Test the Master Sword needs level 5.
+    #[test]
+    fn test_roll_chest_reward_rare_level() {
+        let rare = (0..1000).any(|turn| roll_chest_reward(turn, 5) == vec![(3, 1)]);
+        assert!(rare);
+    }
+
+    /// Test opening a chest grants the rolled reward.
+    #[test]
+    fn test_open_chest_grants_reward() {
+        let mut state = SimpleGameState::new();
+        let chest = Item {
+            kind: ItemKind::Chest,
+            x: 10,
+            y: 10,
+        };
+        let potions = state.potions;
+        apply_item_effect(&mut state, &chest);
+        assert_eq!(state.score, 200);
+        assert_eq!(state.gold, 0);
+        let (id, quantity) = roll_chest_reward(0, 1)[0];
+        match id {
+            201 => assert_eq!(state.potions, potions + quantity as i32),
+            203 => assert_eq!(state.attack, BASE_ATTACK + ATTACK_BOOST),
+            MAGIC_MAP_ID => assert_eq!(state.magic_maps, 1),
+            1..=5 => assert_eq!(state.equipped_weapon, id),
+            _ => assert_eq!(state.equipped_armor, id),
+        }
+    }
+
+    /// Test tutorial prompts cover steps 0 to 9.
+    #[test]
+    fn test_get_tutorial_prompt() {
+        assert_eq!(parse_input("tutorial"), Command::Tutorial);
+        assert!(get_tutorial_prompt(0).unwrap().contains("Move"));
+        assert!(get_tutorial_prompt(9).is_some());
+        assert_eq!(get_tutorial_prompt(10), None);
+    }
+
+    /// Test the first tutorial step advances after moving.
+    #[test]
+    fn test_tutorial_advances_after_move() {
+        let mut state = SimpleGameState::new();
+        execute_command(&mut state, &Command::Tutorial);
+        assert_eq!(state.tutorial_step, Some(0));
+        execute_command(&mut state, &Command::Wait);
+        assert_eq!(state.tutorial_step, Some(0));
+        process_command(&mut state, &Command::Move(Direction::West));
+        assert_eq!(state.tutorial_step, Some(1));
+    }
+
+    /// Test the tutorial ends after the last step.
+    #[test]
+    fn test_tutorial_ends_after_last_step() {
+        let mut state = SimpleGameState::new();
+        state.tutorial_step = Some(9);
+        state.last_command = Some(Command::Help);
+        advance_tutorial(&mut state);
+        assert_eq!(state.tutorial_step, None);
+    }
+
+    /// Test reading a Magic Map reveals every tile of the area.
+    #[test]
+    fn test_use_magic_map_reveals_area() {
+        let mut state = SimpleGameState::new();
+        assert_eq!(parse_input("read map"), Command::ReadMap);
+        state.player_x = 10;
+        state.player_y = 2;
+        assert_eq!(state.area_name(), "Hyrule Castle");
+        state.magic_maps = 1;
+        use_magic_map(&mut state);
+        assert_eq!(state.magic_maps, 0);
+        let area_tiles: Vec<_> = state
+            .visited_tiles
+            .iter()
+            .filter(|&&(x, y)| area_from_position(x, y) == "Hyrule Castle")
+            .collect();
+        assert_eq!(area_tiles.len(), 7 * 5);
+        assert!(state.visited_tiles.contains(&(13, 4)));
+    }
+
+    /// Test chests can hold a Magic Map.
+    #[test]
+    fn test_roll_chest_reward_magic_map() {
+        let found = (0..1000).any(|turn| roll_chest_reward(turn, 1) == vec![(MAGIC_MAP_ID, 1)]);
+        assert!(found);
+    }
+
+    /// Test pausing blocks commands until resumed.
+    #[test]
+    fn test_pause_and_resume() {
+        let mut state = SimpleGameState::new();
+        assert_eq!(parse_input("pause"), Command::Pause);
+        assert_eq!(parse_input("resume"), Command::Resume);
+        execute_command(&mut state, &Command::Pause);
+        assert_eq!(hud_lines(&state).last().unwrap(), "[PAUSED]");
+        execute_command(&mut state, &Command::Move(Direction::North));
+        assert_eq!((state.player_x, state.player_y), (10, 10));
+        assert_eq!(state.message, "Game is paused.");
+        execute_command(&mut state, &Command::Resume);
+        execute_command(&mut state, &Command::Move(Direction::North));
+        assert_eq!((state.player_x, state.player_y), (10, 9));
+        assert_eq!(hud_lines(&state).len(), 3);
+    }
+
+    /// Test defeating an enemy leaves a reward summary.
+    #[test]
+    fn test_battle_reward_summary() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_enemy(&EnemyKind::Slime, 11, 10)];
+        state.attack = 100;
+        let turn = state.turn as u64;
+        let slime = state.enemies[0].clone();
+        state.world_seed = (0..)
+            .find(|&seed| {
+                (turn..=turn + 1).all(|t| {
+                    generate_loot(&EnemyKind::Slime, loot_seed(seed, t, &slime)).is_empty()
+                })
+            })
+            .unwrap();
+        execute_command(&mut state, &Command::Attack);
+        let reward = state.pending_reward.clone().unwrap();
+        assert_eq!(reward.turns_taken, 1);
+        assert_eq!(
+            format_battle_reward(&reward),
+            format!(
+                "=== BATTLE REWARD ===\nTurns taken: 1\n+{} EXP",
+                reward.exp_gained
+            )
+        );
+        execute_command(&mut state, &Command::Wait);
+        assert!(state.pending_reward.is_none());
+    }
+
+    /// Test weakest targeting picks the adjacent enemy with the least health.
+    #[test]
+    fn test_find_target_enemy_weakest() {
+        let mut state = SimpleGameState::new();
+        state.player_x = 5;
+        state.player_y = 5;
+        state.enemies = vec![create_slime(5, 4), create_slime(5, 6)];
+        state.enemies[0].health = 20;
+        state.enemies[1].health = 5;
+        assert_eq!(
+            find_target_enemy(&state, TargetingMode::FirstFound),
+            Some(0)
+        );
+        assert_eq!(find_target_enemy(&state, TargetingMode::Weakest), Some(1));
+        assert_eq!(find_target_enemy(&state, TargetingMode::Strongest), Some(0));
+        assert_eq!(
+            parse_input("target weakest"),
+            Command::Target(TargetingMode::Weakest)
+        );
+        assert_eq!(parse_input("target random"), Command::Unknown);
+    }
+
+    /// Test undoing a potion restores it and removes its healing.
+    #[test]
+    fn test_undo_item_potion() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        state.health = 50;
+        execute_command(&mut state, &Command::UseItem);
+        assert_eq!((state.potions, state.health), (0, 80));
+        execute_command(&mut state, &parse_input("undo item"));
+        assert_eq!((state.potions, state.health), (1, 50));
+        execute_command(&mut state, &Command::UndoItem);
+        assert_eq!(state.message, "Nothing to undo.");
+    }
+
+    /// Test combat rating uses level, stats, gold and weapon.
+    #[test]
+    fn test_combat_rating() {
+        let mut state = SimpleGameState::new();
+        let base = state.level * 50
+            + state.attack * 3
+            + state.defense * 2
+            + state.max_health / 5
+            + state.gold / 10;
+        assert_eq!(combat_rating(&state), base);
+        state.equipped_weapon = 1;
+        assert_eq!(combat_rating(&state), base + 20);
+    }
+
+    /// Test survival wave size and stat scaling.
+    #[test]
+    fn test_spawn_survival_wave() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        let wave = spawn_survival_wave(&state, 3);
+        assert_eq!(wave.len(), 6);
+        let slime = create_slime(0, 0);
+        assert_eq!(wave[0].kind, EnemyKind::Slime);
+        assert_eq!(wave[0].health, slime.health * 13 / 10);
+        assert_eq!(wave[0].attack, slime.attack * 13 / 10);
+        let terrain = generate_terrain();
+        assert!(wave.iter().all(|e| is_walkable(&terrain, e.x, e.y)));
+        assert_eq!(spawn_survival_wave(&state, SURVIVAL_FINAL_WAVE).len(), 13);
+    }
+
+    /// Test survival mode advances waves and wins after the final wave.
+    #[test]
+    fn test_survival_mode_waves() {
+        let mut state = SimpleGameState::new();
+        execute_command(&mut state, &parse_input("mode survival"));
+        assert_eq!(state.mode, GameMode::Survival);
+        assert_eq!((state.survival_wave, state.enemies.len()), (1, 4));
+        state.enemies.clear();
+        end_turn(&mut state);
+        assert_eq!((state.survival_wave, state.enemies.len()), (2, 5));
+        assert!(state.is_running);
+        state.survival_wave = SURVIVAL_FINAL_WAVE;
+        state.enemies.clear();
+        end_turn(&mut state);
+        assert!(!state.is_running);
+        assert_eq!(state.message, "Victory! All enemies defeated!");
+    }
+
+    /// Test compass directions to objectives.
+    #[test]
+    fn test_get_objective_direction() {
+        assert_eq!(get_objective_direction(0, 0, 10, 0), "E");
+        assert_eq!(get_objective_direction(0, 0, 10, -10), "NE");
+        assert_eq!(get_objective_direction(5, 5, 5, 0), "N");
+        assert_eq!(get_objective_direction(5, 5, 4, 15), "S");
+        assert_eq!(get_objective_direction(5, 5, -5, 15), "SW");
+        assert_eq!(get_objective_direction(5, 5, 5, 5), "Here");
+    }
+
+    /// Test the compass rose marks quest and boss directions.
+    #[test]
+    fn test_compass_rose() {
+        let state = SimpleGameState::new();
+        assert_eq!(parse_input("compass"), Command::Compass);
+        let rose = compass_rose(&state, &[('q', (17, 12)), ('b', (10, 2))]);
+        assert_eq!(rose, vec![".b.", "W+q", ".S."]);
+        let rose = compass_rose(&state, &[('q', (10, 0)), ('b', (10, 2))]);
+        assert_eq!(rose[0], ".*.");
+    }
+
+    /// Test the level up preview matches the level up rules.
+    #[test]
+    fn test_level_up_summary() {
+        assert_eq!(parse_input("levelup?"), Command::LevelUpPreview);
+        assert_eq!(
+            level_up_summary(3),
+            "Level 3 → 4: +10 HP, +3 ATK, +2 DEF (requires 75 EXP)"
+        );
+        let mut state = SimpleGameState::new();
+        state.exp = EXP_PER_LEVEL;
+        check_level_up(&mut state);
+        confirm_level_up(&mut state);
+        assert_eq!(state.level, 2);
+        assert_eq!(state.max_health, 100 + LEVEL_UP_HEALTH);
+    }
+
+    /// Test enemy threat levels against a new player.
+    #[test]
+    fn test_get_enemy_threat_level() {
+        let state = SimpleGameState::new();
+        assert_eq!(
+            get_enemy_threat_level(&state, &create_boss(0, 0)),
+            ThreatLevel::Deadly
+        );
+        assert_eq!(
+            get_enemy_threat_level(&state, &create_slime(0, 0)),
+            ThreatLevel::Safe
+        );
+        let mut knight = create_dark_knight(0, 0);
+        assert_eq!(
+            get_enemy_threat_level(&state, &knight),
+            ThreatLevel::Manageable
+        );
+        knight.attack = 25;
+        assert_eq!(
+            get_enemy_threat_level(&state, &knight),
+            ThreatLevel::Dangerous
+        );
+    }
+
+    /// Test enemy symbols are colored only when color is on.
+    #[test]
+    fn test_get_map_cell_color() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_slime(10, 8)];
+        assert_eq!(get_map_cell(&state, 10, 8), "s");
+        execute_command(&mut state, &parse_input("color"));
+        assert_eq!(get_map_cell(&state, 10, 8), "\x1b[32ms\x1b[0m");
+        assert_eq!(get_map_cell(&state, 10, 10), "@");
+    }
+
+    /// Test colorize leaves characters plain without color.
+    #[test]
+    fn test_colorize_none() {
+        assert_eq!(colorize('@', PLAYER_COLOR, None, ColorMode::None), "@");
+        assert_eq!(colorize('~', WATER_COLOR, Some(0), ColorMode::None), "~");
+    }
+
+    /// Test colorize wraps characters in ANSI escape codes.
+    #[test]
+    fn test_colorize_ansi() {
+        assert_eq!(
+            colorize('@', PLAYER_COLOR, None, ColorMode::Ansi256),
+            "\x1b[38;5;14m@\x1b[0m"
+        );
+        assert_eq!(
+            colorize('~', 15, Some(WATER_COLOR), ColorMode::Ansi256),
+            "\x1b[38;5;15m\x1b[48;5;4m~\x1b[0m"
+        );
+        assert_eq!(
+            colorize('T', TREE_COLOR, None, ColorMode::TrueColor),
+            "\x1b[38;2;0;95;0mT\x1b[0m"
+        );
+    }
+
+    /// Test the color mode is detected from the terminal variables.
+    #[test]
+    fn test_detect_color_mode() {
+        assert_eq!(detect_color_mode(None, None), ColorMode::None);
+        assert_eq!(detect_color_mode(Some("dumb"), None), ColorMode::None);
+        assert_eq!(
+            detect_color_mode(Some("xterm-256color"), None),
+            ColorMode::Ansi256
+        );
+        assert_eq!(
+            detect_color_mode(Some("xterm"), Some("truecolor")),
+            ColorMode::TrueColor
+        );
+    }
+
+    /// Test colored map cells match the plain map without color.
+    #[test]
+    fn test_get_map_cell_colored() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_slime(10, 8)];
+        for y in 0..MAP_HEIGHT {
+            for x in 0..MAP_WIDTH {
+                assert_eq!(
+                    get_map_cell_colored(&state, x, y, ColorMode::None),
+                    get_map_cell(&state, x, y)
+                );
+            }
+        }
+        let player = get_map_cell_colored(&state, 10, 10, ColorMode::Ansi256);
+        assert_eq!(player, "\x1b[38;5;14m@\x1b[0m");
+        let slime = get_map_cell_colored(&state, 10, 8, ColorMode::Ansi256);
+        assert_eq!(slime, "\x1b[38;5;1ms\x1b[0m");
+        execute_command(&mut state, &parse_input("color"));
+        let slime = get_map_cell_colored(&state, 10, 8, ColorMode::Ansi256);
+        assert_eq!(slime, get_map_cell(&state, 10, 8));
+    }
+
+    /// Test the world seed can only be set before the first turn.
+    #[test]
+    fn test_set_world_seed() {
+        let mut state = SimpleGameState::new();
+        assert_eq!(parse_input("seed 42"), Command::Seed(42));
+        assert_eq!(parse_input("seed abc"), Command::Unknown);
+        execute_command(&mut state, &Command::Seed(42));
+        assert_eq!(state.world_seed, 42);
+        reset_game(&mut state);
+        assert_eq!(state.world_seed, 42);
+        state.turn = 1;
+        execute_command(&mut state, &Command::Seed(7));
+        assert_eq!(state.world_seed, 42);
+    }
+
+    /// Test level ups wait for the level up command.
+    #[test]
+    fn test_confirm_level_up() {
+        let mut state = SimpleGameState::new();
+        state.exp = EXP_PER_LEVEL;
+        check_level_up(&mut state);
+        assert!(state.pending_level_up);
+        assert_eq!(state.level, 1);
+        assert!(hud_lines(&state).contains(&"*** LEVEL UP READY! ***".to_string()));
+        execute_command(&mut state, &parse_input("level up"));
+        assert_eq!(state.level, 2);
+        assert!(!state.pending_level_up);
+        execute_command(&mut state, &Command::ConfirmLevelUp);
+        assert_eq!(state.message, "No level up is ready.");
+    }
+
+    /// Test a level up raises health by the level up amount only.
+    #[test]
+    fn test_level_up_does_not_fully_heal() {
+        let mut state = SimpleGameState::new();
+        state.health = 10;
+        state.exp = EXP_PER_LEVEL;
+        check_level_up(&mut state);
+        apply_level_up(&mut state);
+        assert_eq!(state.health, 10 + LEVEL_UP_HEALTH);
+        assert_eq!(state.max_health, 100 + LEVEL_UP_HEALTH);
+    }
+
+    /// Test the first attack of a battle confirms a pending level up.
+    #[test]
+    fn test_attack_confirms_level_up() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_boss(10, 9)];
+        state.exp = EXP_PER_LEVEL;
+        check_level_up(&mut state);
+        apply_attack(&mut state);
+        assert_eq!(state.level, 2);
+    }
+
+    /// Test map hints show over terrain until they expire.
+    #[test]
+    fn test_map_annotation_expires() {
+        let mut state = SimpleGameState::new();
+        add_map_annotation(&mut state, 0, 0, '!', HINT_TURNS);
+        for _ in 0..HINT_TURNS {
+            assert_eq!(get_map_char(&state, 0, 0), '!');
+            end_turn(&mut state);
+        }
+        assert_ne!(get_map_char(&state, 0, 0), '!');
+        assert!(state.map_annotations.is_empty());
+    }
+
+    /// Test defeating an enemy marks the battle site.
+    #[test]
+    fn test_defeat_enemy_marks_battle_site() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_slime(10, 9), create_boss(2, 2)];
+        state.enemies[0].health = 1;
+        apply_attack(&mut state);
+        assert_eq!(get_map_char(&state, 10, 9), BATTLE_SITE_SYMBOL);
+    }
+
+    /// Test parsing save and load commands.
+    #[test]
+    fn test_parse_save_load() {
+        assert_eq!(
+            parse_input("save slot1.json"),
+            Command::Save("slot1.json".to_string())
+        );
+        assert_eq!(
+            parse_input("load slot1.json"),
+            Command::Load("slot1.json".to_string())
+        );
+    }
+
+    /// Test save and load paths keep their case.
+    #[test]
+    fn test_parse_save_load_keeps_path_case() {
+        assert_eq!(
+            parse_input("save Saves/Run1.json"),
+            Command::Save("Saves/Run1.json".to_string())
+        );
+        assert_eq!(
+            parse_input("LOAD Saves/Run1.json"),
+            Command::Load("Saves/Run1.json".to_string())
+        );
+    }
+
+    /// Test saving and loading restores the player's progress.
+    #[test]
+    fn test_save_load_round_trip() {
+        let mut state = SimpleGameState::new();
+        state.items = vec![Item {
+            kind: ItemKind::Potion,
+            x: 11,
+            y: 10,
+        }];
+        state.enemies = vec![create_enemy(&EnemyKind::Thief, 3, 4)];
+        state.enemies[0].behavior = Behavior::Flee;
+        state.owned_gear = vec![2, 103];
+        state.mode = GameMode::Survival;
+        state.survival_wave = 3;
+        state.reflect_damage = 3;
+        state.tutorial_step = Some(2);
+        add_map_annotation(&mut state, 4, 5, BATTLE_SITE_SYMBOL, HINT_TURNS);
+        start_escort_quest(&mut state);
+        let potions = state.potions;
+        execute_command(&mut state, &Command::Move(Direction::East));
+        assert_eq!(state.potions, potions + 1);
+        let path = std::env::temp_dir().join(format!("wasm-game-save-{}.json", std::process::id()));
+        let path = path.to_str().unwrap().to_string();
+        execute_command(&mut state, &Command::Save(path.clone()));
+        assert_eq!(state.message, format!("Game saved to {}.", path));
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.contains("\"version\": 1"));
+        let mut loaded = SimpleGameState::new();
+        execute_command(&mut loaded, &Command::Load(path.clone()));
+        fs::remove_file(&path).unwrap();
+        assert_eq!((loaded.player_x, loaded.player_y), (11, 10));
+        assert_eq!(loaded.potions, potions + 1);
+        assert_eq!(loaded.turn, state.turn);
+        assert_eq!(loaded.owned_gear, vec![2, 103]);
+        assert_eq!(loaded.visited_tiles, state.visited_tiles);
+        assert!(loaded.items.is_empty());
+        assert_eq!(loaded.enemies.len(), 1);
+        assert_eq!(loaded.enemies[0].kind, EnemyKind::Thief);
+        assert_eq!(loaded.enemies[0].behavior, Behavior::Flee);
+        assert_eq!(loaded.mode, GameMode::Survival);
+        assert_eq!(loaded.survival_wave, 3);
+        assert_eq!(loaded.reflect_damage, 3);
+        assert_eq!(loaded.tutorial_step, state.tutorial_step);
+        assert_eq!(loaded.map_annotations, state.map_annotations);
+        let quest = loaded.quest.as_ref().unwrap();
+        assert_eq!(quest.objective, state.quest.as_ref().unwrap().objective);
+        assert_eq!(encode_save(&loaded), encode_save(&state));
+    }
+
+    /// Test loading reports missing and malformed save files.
+    #[test]
+    fn test_load_errors() {
+        let mut state = SimpleGameState::new();
+        execute_command(&mut state, &Command::Load("no-such-save.json".to_string()));
+        assert!(state.message.starts_with("Could not load the game"));
+        assert_eq!(decode_save("not json").err(), Some("not a valid save file"));
+        assert_eq!(
+            decode_save("{\"version\": 2}").err(),
+            Some("unsupported save version")
+        );
+        assert!(decode_save("{\"version\": 1}").is_err());
+        let nested = "[".repeat(100_000);
+        assert_eq!(decode_save(&nested).err(), Some("not a valid save file"));
+        let mut text = encode_save(&SimpleGameState::new());
+        text = text.replacen("\"player_x\": 10", "\"player_x\": 4294967306", 1);
+        assert!(decode_save(&text).is_err());
+    }
+
+    /// Test defeated enemies drop loot into the inventory.
+    #[test]
+    fn test_defeat_enemy_drops_loot() {
+        for seed in 0..100 {
+            assert_eq!(generate_loot(&EnemyKind::Boss, seed), vec![3]);
+        }
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_boss(10, 9)];
+        state.enemies[0].health = 1;
+        apply_attack(&mut state);
+        assert!(state.owned_gear.contains(&3));
+        let reward = state.pending_reward.as_ref().unwrap();
+        assert_eq!(reward.items_dropped.len(), 1);
+    }
+
+    /// Test area spawn tables limit the enemy kinds of each area, with dragons at home on Death Mountain.
+    #[test]
+    fn test_area_enemy_kind() {
+        for y in 0..MAP_HEIGHT {
+            for x in 0..MAP_WIDTH {
+                let kind = area_enemy_kind(x, y);
+                match area_from_position(x, y) {
+                    "Gerudo Valley" => {
+                        assert!(matches!(kind, EnemyKind::Goblin | EnemyKind::DarkKnight))
+                    }
+                    "Lost Woods" => {
+                        assert!(matches!(kind, EnemyKind::Bat | EnemyKind::Slime))
+                    }
+                    "Death Mountain" => {}
+                    _ => assert!(!matches!(kind, EnemyKind::Boss | EnemyKind::Dragon)),
+                }
+            }
+        }
+        assert!((0..MAP_HEIGHT)
+            .flat_map(|y| (0..MAP_WIDTH).map(move |x| (x, y)))
+            .any(|(x, y)| area_enemy_kind(x, y) == EnemyKind::Dragon));
+        let spawned = spawn_enemies();
+        assert!(spawned
+            .iter()
+            .all(|e| e.kind == EnemyKind::Boss || e.kind == area_enemy_kind(e.x, e.y)));
+    }
+
+    /// Test optimize equips the strongest owned gear.
+    #[test]
+    fn test_optimize_gear() {
+        assert_eq!(calculate_optimal_gear(&[1, 2, 3]), (Some(3), None));
+        let mut state = SimpleGameState::new();
+        state.owned_gear = vec![2, 3, 1, 102, 103];
+        state.equipped_weapon = 1;
+        assert_eq!(parse_input("optimize"), Command::Optimize);
+        execute_command(&mut state, &Command::Optimize);
+        assert_eq!((state.equipped_weapon, state.equipped_armor), (3, 103));
+    }
+
+    /// Test a thief steals at most its cap and then flees.
+    #[test]
+    fn test_thief_steals_gold_and_flees() {
+        let mut state = SimpleGameState::new();
+        state.enemies = vec![create_thief(5, 5)];
+        state.gold = 10;
+        steal_gold(&mut state, 0);
+        assert_eq!(state.gold, 0);
+        assert_eq!(state.enemies[0].behavior, Behavior::Flee);
+        state.enemies[0].behavior = Behavior::Chase;
+        state.gold = 40;
+        steal_gold(&mut state, 0);
+        assert_eq!(state.gold, 40 - THIEF_STEAL_AMOUNT);
+        assert_eq!(state.enemies[0].behavior, Behavior::Flee);
+    }
+
+    /// Test a fleeing enemy moves away and despawns at the map edge.
+    #[test]
+    fn test_fleeing_thief_despawns_at_edge() {
+        let thief = create_thief(5, 5);
+        assert_eq!(flee_movement(&thief, 6, 5), (-1, 0));
+        assert!(is_map_edge(0, 7));
+        assert!(!is_map_edge(5, 5));
+    }
+
+    /// Test diagonal aliases parse and move along both axes.
+    #[test]
+    fn test_diagonal_movement() {
+        assert_eq!(parse_input("ne"), Command::Move(Direction::NorthEast));
+        assert_eq!(parse_input("se"), Command::Move(Direction::SouthEast));
+        assert_eq!(parse_input("sw"), Command::Move(Direction::SouthWest));
+        assert_eq!(parse_input("nw"), Command::Move(Direction::NorthWest));
+        let mut state = SimpleGameState::new();
+        state.player_x = 5;
+        state.player_y = 5;
+        apply_move(&mut state, &Direction::NorthEast);
+        assert_eq!((state.player_x, state.player_y), (6, 4));
+    }
+
+    /// Test buying from the merchant spends gold and grants the item.
+    #[test]
+    fn test_shop_buy() {
+        assert_eq!(parse_input("shop"), Command::Shop);
+        assert_eq!(parse_input("buy 1"), Command::Buy(1));
+        let mut state = SimpleGameState::new();
+        state.gold = 25;
+        execute_command(&mut state, &Command::Shop);
+        assert!(state.shopping);
+        execute_command(&mut state, &Command::Buy(1));
+        assert_eq!((state.gold, state.potions), (5, 2));
+    }
+
+    /// Test every way a shop purchase can fail.
+    #[test]
+    fn test_shop_buy_failures() {
+        let mut state = SimpleGameState::new();
+        state.gold = 1000;
+        assert_eq!(
+            buy_from_merchant(&mut state, 1),
+            Err("You need to open the shop first.")
+        );
+        state.shopping = true;
+        assert_eq!(
+            buy_from_merchant(&mut state, 0),
+            Err("The merchant doesn't sell that.")
+        );
+        assert_eq!(
+            buy_from_merchant(&mut state, 9),
+            Err("The merchant doesn't sell that.")
+        );
+        state.potions = INVENTORY_CAPACITY as i32;
+        assert_eq!(
+            buy_from_merchant(&mut state, 1),
+            Err("Your inventory is full.")
+        );
+        state.potions = 0;
+        state.gold = 10;
+        assert_eq!(buy_from_merchant(&mut state, 1), Err("Not enough gold."));
+        assert_eq!(state.gold, 10);
+    }
+
+    /// Test buying gear and selling it back returns half its price.
+    #[test]
+    fn test_shop_buy_sell_round_trip() {
+        assert_eq!(parse_input("sell 3"), Command::Sell(3));
+        let mut state = SimpleGameState::new();
+        state.gold = 100;
+        execute_command(&mut state, &Command::Shop);
+        execute_command(&mut state, &Command::Buy(3));
+        assert_eq!(state.equipped_weapon, 2);
+        assert_eq!(state.gold, 0);
+        execute_command(&mut state, &Command::Sell(3));
+        assert_eq!(state.gold, 50);
+        assert!(state.owned_gear.is_empty());
+        assert_eq!(state.equipped_weapon, 0);
+        assert_eq!(
+            sell_to_merchant(&mut state, 3),
+            Err("You don't have one to sell.")
+        );
+        assert_eq!(
+            sell_to_merchant(&mut state, 9),
+            Err("The merchant doesn't buy that.")
+        );
+        assert_eq!(state.gold, 50);
+    }
+
+    /// Test other commands close the shop.
+    #[test]
+    fn test_shop_closes_on_other_command() {
+        let mut state = SimpleGameState::new();
+        execute_command(&mut state, &Command::Shop);
+        execute_command(&mut state, &Command::Wait);
+        assert!(!state.shopping);
+    }
+
+    /// Test moving marks the new tile visited and sight is limited.
+    #[test]
+    fn test_fog_of_war() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        state.items.clear();
+        assert!(!has_visited(&state, 10, 9));
+        execute_command(&mut state, &Command::Move(Direction::North));
+        assert_eq!((state.player_x, state.player_y), (10, 9));
+        assert!(has_visited(&state, 10, 9));
+        let visible = compute_visible_tiles(&state, VISION_RADIUS);
+        assert!(visible.contains(&(10, 5)));
+        assert!(!visible.contains(&(10, 4)));
+        assert!(!visible.contains(&(15, 9)));
+        assert_eq!(get_map_char(&state, 15, 9), ' ');
+        assert_ne!(get_map_char(&state, 14, 9), ' ');
+    }
+
+    /// Test enemies turn on the player once the escorted NPC has fallen.
+    #[test]
+    fn test_enemies_attack_player_after_escort_dies() {
+        let mut state = SimpleGameState::new();
+        start_escort_quest(&mut state);
+        state.enemies = vec![
+            create_slime(state.player_x + 1, state.player_y),
+            create_slime(state.player_x - 1, state.player_y),
+        ];
+        state.enemies[0].attack = ESCORT_HP as i32;
+        state.turn = 3;
+        let health = state.health;
+        enemy_attacks(&mut state);
+        assert_eq!(state.quest.as_ref().unwrap().status, QuestStatus::Failed);
+        assert!(state.health < health);
+    }
+
+    /// Test survival waves avoid the player and occupied tiles.
+    #[test]
+    fn test_survival_wave_avoids_occupied_tiles() {
+        let mut state = SimpleGameState::new();
+        state.enemies.clear();
+        let first = spawn_survival_wave(&state, 1);
+        state.player_x = first[0].x;
+        state.player_y = first[0].y;
+        state.enemies = vec![create_slime(first[1].x, first[1].y)];
+        let wave = spawn_survival_wave(&state, 1);
+        assert_eq!(wave.len(), first.len());
+        assert!(wave
+            .iter()
+            .all(|e| !is_position_occupied(&state, e.x, e.y, usize::MAX)));
+    }
+
+    /// Test adjacent targeting checks north, south, east, then west.
+    #[test]
+    fn test_find_target_enemy_cardinal_priority() {
+        let mut state = SimpleGameState::new();
+        state.player_x = 5;
+        state.player_y = 5;
+        state.enemies = vec![create_slime(4, 5), create_slime(6, 5)];
+        assert_eq!(
+            find_target_enemy(&state, TargetingMode::FirstFound),
+            Some(1)
+        );
+        assert_eq!(find_target_enemy(&state, TargetingMode::Weakest), Some(1));
+        state.enemies.push(create_slime(5, 6));
+        assert_eq!(
+            find_target_enemy(&state, TargetingMode::FirstFound),
+            Some(2)
+        );
+    }
+
+    /// Test previewing compares against gear equipped from a chest.
+    #[test]
+    fn test_preview_equip_against_equipped_gear() {
+        let mut state = SimpleGameState::new();
+        grant_chest_reward(&mut state, 3, 1);
+        assert_eq!(state.equipped_weapon, 3);
+        assert_eq!(
+            preview_equip(&state, 2),
+            "Attack: 40 → 25 (-15) | Defense: 5 → 5 (±0)"
+        );
+        assert_eq!(
+            preview_equip(&state, 3),
+            "Attack: 40 → 40 (±0) | Defense: 5 → 5 (±0)"
+        );
+    }
+
+    /// Test enemies defeated on the same turn roll their loot independently.
+    #[test]
+    fn test_loot_seed_differs_per_enemy() {
+        let (a, b) = (create_slime(3, 4), create_slime(4, 3));
+        assert_ne!(loot_seed(7, 2, &a), loot_seed(7, 2, &b));
+        assert!((0..100).any(|seed| {
+            generate_loot(&a.kind, loot_seed(seed, 2, &a))
+                != generate_loot(&b.kind, loot_seed(seed, 2, &b))
+        }));
+    }
+
+    /// Test the dragon and wizard loot tables mirror the enemy component.
+    #[test]
+    fn test_dragon_and_wizard_loot() {
+        for seed in 0..100 {
+            assert_eq!(
+                generate_loot(&EnemyKind::Dragon, seed),
+                vec![FULL_HEALTH_POTION_ID]
+            );
+        }
+        assert_eq!(
+            loot_table(&EnemyKind::Wizard),
+            &[(FULL_HEALTH_POTION_ID, 250)]
+        );
+        let mut state = SimpleGameState::new();
+        state.health = 1;
+        grant_chest_reward(&mut state, FULL_HEALTH_POTION_ID, 1);
+        assert_eq!(state.health, state.max_health);
+        assert!(!state.owned_gear.contains(&FULL_HEALTH_POTION_ID));
+    }
 }
@@ -28,6 +28,12 @@ pub mod exports {
                     DarkKnight,
                     /// Boss - powerful, unique attack patterns.
                     Boss,
+                    /// Dragon - channels a devastating fire breath.
+                    Dragon,
+                    /// Thief - steals gold on contact, then flees.
+                    Thief,
+                    /// Wizard - casts spells from a distance.
+                    Wizard,
                 }
                 impl ::core::fmt::Debug for EnemyKind {
                     fn fmt(
@@ -49,6 +55,15 @@ pub mod exports {
                                 f.debug_tuple("EnemyKind::DarkKnight").finish()
                             }
                             EnemyKind::Boss => f.debug_tuple("EnemyKind::Boss").finish(),
+                            EnemyKind::Dragon => {
+                                f.debug_tuple("EnemyKind::Dragon").finish()
+                            }
+                            EnemyKind::Thief => {
+                                f.debug_tuple("EnemyKind::Thief").finish()
+                            }
+                            EnemyKind::Wizard => {
+                                f.debug_tuple("EnemyKind::Wizard").finish()
+                            }
                         }
                     }
                 }
@@ -65,10 +80,89 @@ pub mod exports {
                             3 => EnemyKind::Goblin,
                             4 => EnemyKind::DarkKnight,
                             5 => EnemyKind::Boss,
+                            6 => EnemyKind::Dragon,
+                            7 => EnemyKind::Thief,
+                            8 => EnemyKind::Wizard,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
                 }
+                /// Damage-over-time from being set alight.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Burning {
+                    /// Turns the burn keeps hurting.
+                    pub turns_remaining: u32,
+                    /// Damage dealt each turn.
+                    pub damage_per_turn: u32,
+                }
+                impl ::core::fmt::Debug for Burning {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Burning")
+                            .field("turns-remaining", &self.turns_remaining)
+                            .field("damage-per-turn", &self.damage_per_turn)
+                            .finish()
+                    }
+                }
+                /// Lasting effects an enemy attack can inflict on the player.
+                #[derive(Clone, Copy)]
+                pub enum StatusEffect {
+                    /// The player burns for a few turns.
+                    Burning(Burning),
+                }
+                impl ::core::fmt::Debug for StatusEffect {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            StatusEffect::Burning(e) => {
+                                f.debug_tuple("StatusEffect::Burning").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// Special abilities unleashed by enemies.
+                #[derive(Clone, Copy)]
+                pub enum EnemyAbility {
+                    /// Breathes fire for the given damage.
+                    FireBreath(u32),
+                }
+                impl ::core::fmt::Debug for EnemyAbility {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            EnemyAbility::FireBreath(e) => {
+                                f.debug_tuple("EnemyAbility::FireBreath").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// A special ability fired this turn.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct AbilityUse {
+                    /// The ability that fired.
+                    pub ability: EnemyAbility,
+                    /// Status effect the hit inflicts, if any.
+                    pub inflicts_status: Option<StatusEffect>,
+                }
+                impl ::core::fmt::Debug for AbilityUse {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("AbilityUse")
+                            .field("ability", &self.ability)
+                            .field("inflicts-status", &self.inflicts_status)
+                            .finish()
+                    }
+                }
                 /// AI behavior patterns for enemies.
                 #[repr(u8)]
                 #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
@@ -83,6 +177,10 @@ pub mod exports {
                     Flee,
                     /// Complex boss-specific attack patterns.
                     BossPattern,
+                    /// Enraged boss charging two tiles per turn.
+                    BossRampage,
+                    /// Keeps 3-5 tiles from the player, backing off when approached.
+                    Ranged,
                 }
                 impl ::core::fmt::Debug for Behavior {
                     fn fmt(
@@ -99,6 +197,12 @@ pub mod exports {
                             Behavior::BossPattern => {
                                 f.debug_tuple("Behavior::BossPattern").finish()
                             }
+                            Behavior::BossRampage => {
+                                f.debug_tuple("Behavior::BossRampage").finish()
+                            }
+                            Behavior::Ranged => {
+                                f.debug_tuple("Behavior::Ranged").finish()
+                            }
                         }
                     }
                 }
@@ -114,6 +218,45 @@ pub mod exports {
                             2 => Behavior::Guard,
                             3 => Behavior::Flee,
                             4 => Behavior::BossPattern,
+                            5 => Behavior::BossRampage,
+                            6 => Behavior::Ranged,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Stage of a boss fight.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum BossPhase {
+                    /// Opening phase with base stats.
+                    PhaseOne,
+                    /// Enraged phase entered at half health.
+                    PhaseTwo,
+                }
+                impl ::core::fmt::Debug for BossPhase {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            BossPhase::PhaseOne => {
+                                f.debug_tuple("BossPhase::PhaseOne").finish()
+                            }
+                            BossPhase::PhaseTwo => {
+                                f.debug_tuple("BossPhase::PhaseTwo").finish()
+                            }
+                        }
+                    }
+                }
+                impl BossPhase {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> BossPhase {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => BossPhase::PhaseOne,
+                            1 => BossPhase::PhaseTwo,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
@@ -138,6 +281,141 @@ pub mod exports {
                             .finish()
                     }
                 }
+                /// Quality of a dropped item.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum LootRarity {
+                    /// Ordinary drop.
+                    Common,
+                    /// Better than usual drop.
+                    Uncommon,
+                    /// Rare and valuable drop.
+                    Rare,
+                }
+                impl ::core::fmt::Debug for LootRarity {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            LootRarity::Common => {
+                                f.debug_tuple("LootRarity::Common").finish()
+                            }
+                            LootRarity::Uncommon => {
+                                f.debug_tuple("LootRarity::Uncommon").finish()
+                            }
+                            LootRarity::Rare => {
+                                f.debug_tuple("LootRarity::Rare").finish()
+                            }
+                        }
+                    }
+                }
+                impl LootRarity {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> LootRarity {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => LootRarity::Common,
+                            1 => LootRarity::Uncommon,
+                            2 => LootRarity::Rare,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// How dangerous an enemy is to the player.
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum ThreatLevel {
+                    /// Poses little risk.
+                    Safe,
+                    /// Can be fought with some care.
+                    Manageable,
+                    /// Best avoided when low on health.
+                    Dangerous,
+                    /// Likely to defeat the player.
+                    Deadly,
+                }
+                impl ::core::fmt::Debug for ThreatLevel {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            ThreatLevel::Safe => {
+                                f.debug_tuple("ThreatLevel::Safe").finish()
+                            }
+                            ThreatLevel::Manageable => {
+                                f.debug_tuple("ThreatLevel::Manageable").finish()
+                            }
+                            ThreatLevel::Dangerous => {
+                                f.debug_tuple("ThreatLevel::Dangerous").finish()
+                            }
+                            ThreatLevel::Deadly => {
+                                f.debug_tuple("ThreatLevel::Deadly").finish()
+                            }
+                        }
+                    }
+                }
+                impl ThreatLevel {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> ThreatLevel {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => ThreatLevel::Safe,
+                            1 => ThreatLevel::Manageable,
+                            2 => ThreatLevel::Dangerous,
+                            3 => ThreatLevel::Deadly,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                /// Player stats used to judge enemy threat.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct PlayerStats {
+                    /// Current health points.
+                    pub health: u32,
+                    /// Attack power.
+                    pub attack: u32,
+                }
+                impl ::core::fmt::Debug for PlayerStats {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("PlayerStats")
+                            .field("health", &self.health)
+                            .field("attack", &self.attack)
+                            .finish()
+                    }
+                }
+                /// Elemental resistances in percent (100 = immune).
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct Resistances {
+                    /// Resistance to fire damage.
+                    pub fire: u32,
+                    /// Resistance to ice damage.
+                    pub ice: u32,
+                    /// Resistance to lightning damage.
+                    pub lightning: u32,
+                }
+                impl ::core::fmt::Debug for Resistances {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("Resistances")
+                            .field("fire", &self.fire)
+                            .field("ice", &self.ice)
+                            .field("lightning", &self.lightning)
+                            .finish()
+                    }
+                }
                 /// Complete enemy state.
                 #[repr(C)]
                 #[derive(Clone, Copy)]
@@ -154,12 +432,20 @@ pub mod exports {
                     pub defense: u32,
                     /// Experience reward when defeated.
                     pub exp_reward: u32,
+                    /// Speed deciding battle initiative.
+                    pub speed: u32,
                     /// Current position in the world.
                     pub pos: Position,
+                    /// Position before the enemy's last move.
+                    pub last_pos: Position,
                     /// Current AI behavior.
                     pub current_behavior: Behavior,
                     /// Whether the enemy is currently alive.
                     pub is_alive: bool,
+                    /// Turns left channeling before a special ability fires.
+                    pub channel_turns: u32,
+                    /// Current stage of a boss fight (always phase-one for other enemies).
+                    pub boss_phase: BossPhase,
                 }
                 impl ::core::fmt::Debug for EnemyState {
                     fn fmt(
@@ -173,9 +459,13 @@ pub mod exports {
                             .field("attack", &self.attack)
                             .field("defense", &self.defense)
                             .field("exp-reward", &self.exp_reward)
+                            .field("speed", &self.speed)
                             .field("pos", &self.pos)
+                            .field("last-pos", &self.last_pos)
                             .field("current-behavior", &self.current_behavior)
                             .field("is-alive", &self.is_alive)
+                            .field("channel-turns", &self.channel_turns)
+                            .field("boss-phase", &self.boss_phase)
                             .finish()
                     }
                 }
@@ -198,6 +488,7 @@ pub mod exports {
                 pub type EnemyKind = super::super::super::super::exports::docs::enemy::types::EnemyKind;
                 pub type EnemyState = super::super::super::super::exports::docs::enemy::types::EnemyState;
                 pub type Position = super::super::super::super::exports::docs::enemy::types::Position;
+                pub type Resistances = super::super::super::super::exports::docs::enemy::types::Resistances;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_spawn_enemy_cabi<T: Guest>(
@@ -223,9 +514,13 @@ pub mod exports {
                         attack: attack2,
                         defense: defense2,
                         exp_reward: exp_reward2,
+                        speed: speed2,
                         pos: pos2,
+                        last_pos: last_pos2,
                         current_behavior: current_behavior2,
                         is_alive: is_alive2,
+                        channel_turns: channel_turns2,
+                        boss_phase: boss_phase2,
                     } = result0;
                     *ptr1.add(0).cast::<u8>() = (kind2.clone() as i32) as u8;
                     *ptr1.add(4).cast::<i32>() = _rt::as_i32(health2);
@@ -233,18 +528,27 @@ pub mod exports {
                     *ptr1.add(12).cast::<i32>() = _rt::as_i32(attack2);
                     *ptr1.add(16).cast::<i32>() = _rt::as_i32(defense2);
                     *ptr1.add(20).cast::<i32>() = _rt::as_i32(exp_reward2);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(speed2);
                     let super::super::super::super::exports::docs::enemy::types::Position {
                         x: x3,
                         y: y3,
                     } = pos2;
-                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(x3);
-                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(y3);
-                    *ptr1.add(32).cast::<u8>() = (current_behavior2.clone() as i32)
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(x3);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(y3);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x4,
+                        y: y4,
+                    } = last_pos2;
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(x4);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(y4);
+                    *ptr1.add(44).cast::<u8>() = (current_behavior2.clone() as i32)
                         as u8;
-                    *ptr1.add(33).cast::<u8>() = (match is_alive2 {
+                    *ptr1.add(45).cast::<u8>() = (match is_alive2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1.add(48).cast::<i32>() = _rt::as_i32(channel_turns2);
+                    *ptr1.add(52).cast::<u8>() = (boss_phase2.clone() as i32) as u8;
                     ptr1
                 }
                 #[doc(hidden)]
@@ -266,9 +570,13 @@ pub mod exports {
                         attack: attack2,
                         defense: defense2,
                         exp_reward: exp_reward2,
+                        speed: speed2,
                         pos: pos2,
+                        last_pos: last_pos2,
                         current_behavior: current_behavior2,
                         is_alive: is_alive2,
+                        channel_turns: channel_turns2,
+                        boss_phase: boss_phase2,
                     } = result0;
                     *ptr1.add(0).cast::<u8>() = (kind2.clone() as i32) as u8;
                     *ptr1.add(4).cast::<i32>() = _rt::as_i32(health2);
@@ -276,18 +584,27 @@ pub mod exports {
                     *ptr1.add(12).cast::<i32>() = _rt::as_i32(attack2);
                     *ptr1.add(16).cast::<i32>() = _rt::as_i32(defense2);
                     *ptr1.add(20).cast::<i32>() = _rt::as_i32(exp_reward2);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(speed2);
                     let super::super::super::super::exports::docs::enemy::types::Position {
                         x: x3,
                         y: y3,
                     } = pos2;
-                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(x3);
-                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(y3);
-                    *ptr1.add(32).cast::<u8>() = (current_behavior2.clone() as i32)
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(x3);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(y3);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x4,
+                        y: y4,
+                    } = last_pos2;
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(x4);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(y4);
+                    *ptr1.add(44).cast::<u8>() = (current_behavior2.clone() as i32)
                         as u8;
-                    *ptr1.add(33).cast::<u8>() = (match is_alive2 {
+                    *ptr1.add(45).cast::<u8>() = (match is_alive2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1.add(48).cast::<i32>() = _rt::as_i32(channel_turns2);
+                    *ptr1.add(52).cast::<u8>() = (boss_phase2.clone() as i32) as u8;
                     ptr1
                 }
                 #[doc(hidden)]
@@ -309,9 +626,13 @@ pub mod exports {
                         attack: attack2,
                         defense: defense2,
                         exp_reward: exp_reward2,
+                        speed: speed2,
                         pos: pos2,
+                        last_pos: last_pos2,
                         current_behavior: current_behavior2,
                         is_alive: is_alive2,
+                        channel_turns: channel_turns2,
+                        boss_phase: boss_phase2,
                     } = result0;
                     *ptr1.add(0).cast::<u8>() = (kind2.clone() as i32) as u8;
                     *ptr1.add(4).cast::<i32>() = _rt::as_i32(health2);
@@ -319,20 +640,100 @@ pub mod exports {
                     *ptr1.add(12).cast::<i32>() = _rt::as_i32(attack2);
                     *ptr1.add(16).cast::<i32>() = _rt::as_i32(defense2);
                     *ptr1.add(20).cast::<i32>() = _rt::as_i32(exp_reward2);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(speed2);
                     let super::super::super::super::exports::docs::enemy::types::Position {
                         x: x3,
                         y: y3,
                     } = pos2;
-                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(x3);
-                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(y3);
-                    *ptr1.add(32).cast::<u8>() = (current_behavior2.clone() as i32)
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(x3);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(y3);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x4,
+                        y: y4,
+                    } = last_pos2;
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(x4);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(y4);
+                    *ptr1.add(44).cast::<u8>() = (current_behavior2.clone() as i32)
                         as u8;
-                    *ptr1.add(33).cast::<u8>() = (match is_alive2 {
+                    *ptr1.add(45).cast::<u8>() = (match is_alive2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1.add(48).cast::<i32>() = _rt::as_i32(channel_turns2);
+                    *ptr1.add(52).cast::<u8>() = (boss_phase2.clone() as i32) as u8;
                     ptr1
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_resistances_cabi<T: Guest>(
+                    arg0: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_resistances(
+                        super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                            arg0 as u8,
+                        ),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::enemy::types::Resistances {
+                        fire: fire2,
+                        ice: ice2,
+                        lightning: lightning2,
+                    } = result0;
+                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(fire2);
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(ice2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(lightning2);
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_home_area_cabi<T: Guest>(
+                    arg0: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_home_area(
+                        super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                            arg0 as u8,
+                        ),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *ptr1
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len2;
+                            *ptr1
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_get_home_area<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
                 pub trait Guest {
                     /// Spawn a new enemy of the specified kind at the given position.
                     fn spawn_enemy(kind: EnemyKind, pos: Position) -> EnemyState;
@@ -340,6 +741,12 @@ pub mod exports {
                     fn spawn_boss(pos: Position) -> EnemyState;
                     /// Get the base stats for an enemy kind.
                     fn get_base_stats(kind: EnemyKind) -> EnemyState;
+                    /// Get the elemental resistances for an enemy kind.
+                    fn get_resistances(kind: EnemyKind) -> Resistances;
+                    /// Get the area an enemy kind calls home, where it should spawn.
+                    ///
+                    /// Returns none for kinds that roam everywhere.
+                    fn get_home_area(kind: EnemyKind) -> Option<_rt::String>;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_enemy_spawn_0_1_0_cabi {
@@ -356,15 +763,28 @@ pub mod exports {
                         "docs:enemy/spawn@0.1.0#get-base-stats")] unsafe extern "C" fn
                         export_get_base_stats(arg0 : i32,) -> * mut u8 { unsafe {
                         $($path_to_types)*:: _export_get_base_stats_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:enemy/spawn@0.1.0#get-resistances")] unsafe extern "C" fn
+                        export_get_resistances(arg0 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_get_resistances_cabi::<$ty > (arg0)
+                        } } #[unsafe (export_name =
+                        "docs:enemy/spawn@0.1.0#get-home-area")] unsafe extern "C" fn
+                        export_get_home_area(arg0 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_get_home_area_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "cabi_post_docs:enemy/spawn@0.1.0#get-home-area")] unsafe extern
+                        "C" fn _post_return_get_home_area(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_get_home_area::<$ty > (arg0) }
                         } };
                     };
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_docs_enemy_spawn_0_1_0_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 36]);
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 56]);
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 36],
+                    [::core::mem::MaybeUninit::uninit(); 56],
                 );
             }
             /// Enemy AI and behavior interface.
@@ -377,98 +797,321 @@ pub mod exports {
                 pub type EnemyState = super::super::super::super::exports::docs::enemy::types::EnemyState;
                 pub type Position = super::super::super::super::exports::docs::enemy::types::Position;
                 pub type Behavior = super::super::super::super::exports::docs::enemy::types::Behavior;
+                pub type PlayerStats = super::super::super::super::exports::docs::enemy::types::PlayerStats;
+                pub type ThreatLevel = super::super::super::super::exports::docs::enemy::types::ThreatLevel;
+                pub type AbilityUse = super::super::super::super::exports::docs::enemy::types::AbilityUse;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_calculate_move_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                    arg6: i32,
-                    arg7: i32,
-                    arg8: i32,
-                    arg9: i32,
-                    arg10: i32,
-                    arg11: i32,
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::calculate_move(
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = i32::from(*arg0.add(44).cast::<u8>());
+                    let l12 = i32::from(*arg0.add(45).cast::<u8>());
+                    let l13 = *arg0.add(48).cast::<i32>();
+                    let l14 = i32::from(*arg0.add(52).cast::<u8>());
+                    let l15 = *arg0.add(56).cast::<i32>();
+                    let l16 = *arg0.add(60).cast::<i32>();
+                    let l17 = *arg0.add(64).cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(64 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base23 = l17;
+                    let len23 = l18;
+                    let mut result23 = _rt::Vec::with_capacity(len23);
+                    for i in 0..len23 {
+                        let base = base23
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e23 = {
+                            let l19 = *base.add(0).cast::<*mut u8>();
+                            let l20 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l19;
+                            let len22 = l20;
+                            let mut result22 = _rt::Vec::with_capacity(len22);
+                            for i in 0..len22 {
+                                let base = base22.add(i * 1);
+                                let e22 = {
+                                    let l21 = i32::from(*base.add(0).cast::<u8>());
+                                    _rt::bool_lift(l21 as u8)
+                                };
+                                result22.push(e22);
+                            }
+                            _rt::cabi_dealloc(base22, len22 * 1, 1);
+                            result22
+                        };
+                        result23.push(e23);
+                    }
+                    _rt::cabi_dealloc(
+                        base23,
+                        len23 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(64 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        64 + 3 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result25 = T::calculate_move(
                         super::super::super::super::exports::docs::enemy::types::EnemyState {
                             kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
-                                arg0 as u8,
+                                l0 as u8,
                             ),
-                            health: arg1 as u32,
-                            max_health: arg2 as u32,
-                            attack: arg3 as u32,
-                            defense: arg4 as u32,
-                            exp_reward: arg5 as u32,
+                            health: l1 as u32,
+                            max_health: l2 as u32,
+                            attack: l3 as u32,
+                            defense: l4 as u32,
+                            exp_reward: l5 as u32,
+                            speed: l6 as u32,
                             pos: super::super::super::super::exports::docs::enemy::types::Position {
-                                x: arg6,
-                                y: arg7,
+                                x: l7,
+                                y: l8,
+                            },
+                            last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: l9,
+                                y: l10,
                             },
                             current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                                arg8 as u8,
+                                l11 as u8,
+                            ),
+                            is_alive: _rt::bool_lift(l12 as u8),
+                            channel_turns: l13 as u32,
+                            boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                                l14 as u8,
                             ),
-                            is_alive: _rt::bool_lift(arg9 as u8),
                         },
                         super::super::super::super::exports::docs::enemy::types::Position {
-                            x: arg10,
-                            y: arg11,
+                            x: l15,
+                            y: l16,
                         },
+                        result23,
+                        l24 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr26 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::enemy::types::Position {
-                        x: x2,
-                        y: y2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(x2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(y2);
-                    ptr1
+                        x: x27,
+                        y: y27,
+                    } = result25;
+                    *ptr26.add(0).cast::<i32>() = _rt::as_i32(x27);
+                    *ptr26.add(4).cast::<i32>() = _rt::as_i32(y27);
+                    ptr26
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_move_enemy_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = i32::from(*arg0.add(44).cast::<u8>());
+                    let l12 = i32::from(*arg0.add(45).cast::<u8>());
+                    let l13 = *arg0.add(48).cast::<i32>();
+                    let l14 = i32::from(*arg0.add(52).cast::<u8>());
+                    let l15 = *arg0.add(56).cast::<i32>();
+                    let l16 = *arg0.add(60).cast::<i32>();
+                    _rt::cabi_dealloc(arg0, 64, 4);
+                    let result17 = T::move_enemy(
+                        super::super::super::super::exports::docs::enemy::types::EnemyState {
+                            kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                                l0 as u8,
+                            ),
+                            health: l1 as u32,
+                            max_health: l2 as u32,
+                            attack: l3 as u32,
+                            defense: l4 as u32,
+                            exp_reward: l5 as u32,
+                            speed: l6 as u32,
+                            pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: l7,
+                                y: l8,
+                            },
+                            last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: l9,
+                                y: l10,
+                            },
+                            current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
+                                l11 as u8,
+                            ),
+                            is_alive: _rt::bool_lift(l12 as u8),
+                            channel_turns: l13 as u32,
+                            boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                                l14 as u8,
+                            ),
+                        },
+                        super::super::super::super::exports::docs::enemy::types::Position {
+                            x: l15,
+                            y: l16,
+                        },
+                    );
+                    let ptr18 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::enemy::types::EnemyState {
+                        kind: kind19,
+                        health: health19,
+                        max_health: max_health19,
+                        attack: attack19,
+                        defense: defense19,
+                        exp_reward: exp_reward19,
+                        speed: speed19,
+                        pos: pos19,
+                        last_pos: last_pos19,
+                        current_behavior: current_behavior19,
+                        is_alive: is_alive19,
+                        channel_turns: channel_turns19,
+                        boss_phase: boss_phase19,
+                    } = result17;
+                    *ptr18.add(0).cast::<u8>() = (kind19.clone() as i32) as u8;
+                    *ptr18.add(4).cast::<i32>() = _rt::as_i32(health19);
+                    *ptr18.add(8).cast::<i32>() = _rt::as_i32(max_health19);
+                    *ptr18.add(12).cast::<i32>() = _rt::as_i32(attack19);
+                    *ptr18.add(16).cast::<i32>() = _rt::as_i32(defense19);
+                    *ptr18.add(20).cast::<i32>() = _rt::as_i32(exp_reward19);
+                    *ptr18.add(24).cast::<i32>() = _rt::as_i32(speed19);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x20,
+                        y: y20,
+                    } = pos19;
+                    *ptr18.add(28).cast::<i32>() = _rt::as_i32(x20);
+                    *ptr18.add(32).cast::<i32>() = _rt::as_i32(y20);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x21,
+                        y: y21,
+                    } = last_pos19;
+                    *ptr18.add(36).cast::<i32>() = _rt::as_i32(x21);
+                    *ptr18.add(40).cast::<i32>() = _rt::as_i32(y21);
+                    *ptr18.add(44).cast::<u8>() = (current_behavior19.clone() as i32)
+                        as u8;
+                    *ptr18.add(45).cast::<u8>() = (match is_alive19 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr18.add(48).cast::<i32>() = _rt::as_i32(channel_turns19);
+                    *ptr18.add(52).cast::<u8>() = (boss_phase19.clone() as i32) as u8;
+                    ptr18
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_should_attack_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                    arg6: i32,
-                    arg7: i32,
-                    arg8: i32,
-                    arg9: i32,
-                    arg10: i32,
-                    arg11: i32,
+                    arg0: *mut u8,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::should_attack(
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = i32::from(*arg0.add(44).cast::<u8>());
+                    let l12 = i32::from(*arg0.add(45).cast::<u8>());
+                    let l13 = *arg0.add(48).cast::<i32>();
+                    let l14 = i32::from(*arg0.add(52).cast::<u8>());
+                    let l15 = *arg0.add(56).cast::<i32>();
+                    let l16 = *arg0.add(60).cast::<i32>();
+                    let l17 = *arg0.add(64).cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(64 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base23 = l17;
+                    let len23 = l18;
+                    let mut result23 = _rt::Vec::with_capacity(len23);
+                    for i in 0..len23 {
+                        let base = base23
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e23 = {
+                            let l19 = *base.add(0).cast::<*mut u8>();
+                            let l20 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l19;
+                            let len22 = l20;
+                            let mut result22 = _rt::Vec::with_capacity(len22);
+                            for i in 0..len22 {
+                                let base = base22.add(i * 1);
+                                let e22 = {
+                                    let l21 = i32::from(*base.add(0).cast::<u8>());
+                                    _rt::bool_lift(l21 as u8)
+                                };
+                                result22.push(e22);
+                            }
+                            _rt::cabi_dealloc(base22, len22 * 1, 1);
+                            result22
+                        };
+                        result23.push(e23);
+                    }
+                    _rt::cabi_dealloc(
+                        base23,
+                        len23 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    _rt::cabi_dealloc(
+                        arg0,
+                        64 + 2 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::should_attack(
                         super::super::super::super::exports::docs::enemy::types::EnemyState {
                             kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
-                                arg0 as u8,
+                                l0 as u8,
                             ),
-                            health: arg1 as u32,
-                            max_health: arg2 as u32,
-                            attack: arg3 as u32,
-                            defense: arg4 as u32,
-                            exp_reward: arg5 as u32,
+                            health: l1 as u32,
+                            max_health: l2 as u32,
+                            attack: l3 as u32,
+                            defense: l4 as u32,
+                            exp_reward: l5 as u32,
+                            speed: l6 as u32,
                             pos: super::super::super::super::exports::docs::enemy::types::Position {
-                                x: arg6,
-                                y: arg7,
+                                x: l7,
+                                y: l8,
+                            },
+                            last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: l9,
+                                y: l10,
                             },
                             current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                                arg8 as u8,
+                                l11 as u8,
+                            ),
+                            is_alive: _rt::bool_lift(l12 as u8),
+                            channel_turns: l13 as u32,
+                            boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                                l14 as u8,
                             ),
-                            is_alive: _rt::bool_lift(arg9 as u8),
                         },
                         super::super::super::super::exports::docs::enemy::types::Position {
-                            x: arg10,
-                            y: arg11,
+                            x: l15,
+                            y: l16,
                         },
+                        result23,
                     );
-                    match result0 {
+                    match result24 {
                         true => 1,
                         false => 0,
                     }
@@ -486,6 +1129,11 @@ pub mod exports {
                     arg7: i32,
                     arg8: i32,
                     arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::update_behavior(super::super::super::super::exports::docs::enemy::types::EnemyState {
@@ -497,19 +1145,120 @@ pub mod exports {
                         attack: arg3 as u32,
                         defense: arg4 as u32,
                         exp_reward: arg5 as u32,
+                        speed: arg6 as u32,
                         pos: super::super::super::super::exports::docs::enemy::types::Position {
-                            x: arg6,
-                            y: arg7,
+                            x: arg7,
+                            y: arg8,
+                        },
+                        last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg9,
+                            y: arg10,
                         },
                         current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                            arg8 as u8,
+                            arg11 as u8,
+                        ),
+                        is_alive: _rt::bool_lift(arg12 as u8),
+                        channel_turns: arg13 as u32,
+                        boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                            arg14 as u8,
                         ),
-                        is_alive: _rt::bool_lift(arg9 as u8),
                     });
                     result0.clone() as i32
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn _export_maybe_enter_phase_two_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::maybe_enter_phase_two(super::super::super::super::exports::docs::enemy::types::EnemyState {
+                        kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                            arg0 as u8,
+                        ),
+                        health: arg1 as u32,
+                        max_health: arg2 as u32,
+                        attack: arg3 as u32,
+                        defense: arg4 as u32,
+                        exp_reward: arg5 as u32,
+                        speed: arg6 as u32,
+                        pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg7,
+                            y: arg8,
+                        },
+                        last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg9,
+                            y: arg10,
+                        },
+                        current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
+                            arg11 as u8,
+                        ),
+                        is_alive: _rt::bool_lift(arg12 as u8),
+                        channel_turns: arg13 as u32,
+                        boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                            arg14 as u8,
+                        ),
+                    });
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::enemy::types::EnemyState {
+                        kind: kind2,
+                        health: health2,
+                        max_health: max_health2,
+                        attack: attack2,
+                        defense: defense2,
+                        exp_reward: exp_reward2,
+                        speed: speed2,
+                        pos: pos2,
+                        last_pos: last_pos2,
+                        current_behavior: current_behavior2,
+                        is_alive: is_alive2,
+                        channel_turns: channel_turns2,
+                        boss_phase: boss_phase2,
+                    } = result0;
+                    *ptr1.add(0).cast::<u8>() = (kind2.clone() as i32) as u8;
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(health2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(max_health2);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(attack2);
+                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(defense2);
+                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(exp_reward2);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(speed2);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x3,
+                        y: y3,
+                    } = pos2;
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(x3);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(y3);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x4,
+                        y: y4,
+                    } = last_pos2;
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(x4);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(y4);
+                    *ptr1.add(44).cast::<u8>() = (current_behavior2.clone() as i32)
+                        as u8;
+                    *ptr1.add(45).cast::<u8>() = (match is_alive2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(48).cast::<i32>() = _rt::as_i32(channel_turns2);
+                    *ptr1.add(52).cast::<u8>() = (boss_phase2.clone() as i32) as u8;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_get_attack_damage_cabi<T: Guest>(
                     arg0: i32,
                     arg1: i32,
@@ -521,6 +1270,11 @@ pub mod exports {
                     arg7: i32,
                     arg8: i32,
                     arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::get_attack_damage(super::super::super::super::exports::docs::enemy::types::EnemyState {
@@ -532,70 +1286,321 @@ pub mod exports {
                         attack: arg3 as u32,
                         defense: arg4 as u32,
                         exp_reward: arg5 as u32,
+                        speed: arg6 as u32,
                         pos: super::super::super::super::exports::docs::enemy::types::Position {
-                            x: arg6,
-                            y: arg7,
+                            x: arg7,
+                            y: arg8,
+                        },
+                        last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg9,
+                            y: arg10,
                         },
                         current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                            arg8 as u8,
+                            arg11 as u8,
+                        ),
+                        is_alive: _rt::bool_lift(arg12 as u8),
+                        channel_turns: arg13 as u32,
+                        boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                            arg14 as u8,
                         ),
-                        is_alive: _rt::bool_lift(arg9 as u8),
                     });
                     _rt::as_i32(result0)
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_use_special_ability_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::use_special_ability(super::super::super::super::exports::docs::enemy::types::EnemyState {
+                        kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                            arg0 as u8,
+                        ),
+                        health: arg1 as u32,
+                        max_health: arg2 as u32,
+                        attack: arg3 as u32,
+                        defense: arg4 as u32,
+                        exp_reward: arg5 as u32,
+                        speed: arg6 as u32,
+                        pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg7,
+                            y: arg8,
+                        },
+                        last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg9,
+                            y: arg10,
+                        },
+                        current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
+                            arg11 as u8,
+                        ),
+                        is_alive: _rt::bool_lift(arg12 as u8),
+                        channel_turns: arg13 as u32,
+                        boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                            arg14 as u8,
+                        ),
+                    });
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t2_0, t2_1) = result0;
+                    let super::super::super::super::exports::docs::enemy::types::EnemyState {
+                        kind: kind3,
+                        health: health3,
+                        max_health: max_health3,
+                        attack: attack3,
+                        defense: defense3,
+                        exp_reward: exp_reward3,
+                        speed: speed3,
+                        pos: pos3,
+                        last_pos: last_pos3,
+                        current_behavior: current_behavior3,
+                        is_alive: is_alive3,
+                        channel_turns: channel_turns3,
+                        boss_phase: boss_phase3,
+                    } = t2_0;
+                    *ptr1.add(0).cast::<u8>() = (kind3.clone() as i32) as u8;
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(health3);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(max_health3);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(attack3);
+                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(defense3);
+                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(exp_reward3);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(speed3);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x4,
+                        y: y4,
+                    } = pos3;
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(x4);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(y4);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x5,
+                        y: y5,
+                    } = last_pos3;
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(x5);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(y5);
+                    *ptr1.add(44).cast::<u8>() = (current_behavior3.clone() as i32)
+                        as u8;
+                    *ptr1.add(45).cast::<u8>() = (match is_alive3 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(48).cast::<i32>() = _rt::as_i32(channel_turns3);
+                    *ptr1.add(52).cast::<u8>() = (boss_phase3.clone() as i32) as u8;
+                    match t2_1 {
+                        Some(e) => {
+                            *ptr1.add(56).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::exports::docs::enemy::types::AbilityUse {
+                                ability: ability6,
+                                inflicts_status: inflicts_status6,
+                            } = e;
+                            match ability6 {
+                                super::super::super::super::exports::docs::enemy::types::EnemyAbility::FireBreath(e) => {
+                                    *ptr1.add(60).cast::<u8>() = (0i32) as u8;
+                                    *ptr1.add(64).cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                            match inflicts_status6 {
+                                Some(e) => {
+                                    *ptr1.add(68).cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::enemy::types::StatusEffect::Burning(e) => {
+                                            *ptr1.add(72).cast::<u8>() = (0i32) as u8;
+                                            let super::super::super::super::exports::docs::enemy::types::Burning {
+                                                turns_remaining: turns_remaining7,
+                                                damage_per_turn: damage_per_turn7,
+                                            } = e;
+                                            *ptr1.add(76).cast::<i32>() = _rt::as_i32(turns_remaining7);
+                                            *ptr1.add(80).cast::<i32>() = _rt::as_i32(damage_per_turn7);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr1.add(68).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1.add(56).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_enemy_threat_level_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = i32::from(*arg0.add(8).cast::<u8>());
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0.add(20).cast::<i32>();
+                    let l6 = *arg0.add(24).cast::<i32>();
+                    let l7 = *arg0.add(28).cast::<i32>();
+                    let l8 = *arg0.add(32).cast::<i32>();
+                    let l9 = *arg0.add(36).cast::<i32>();
+                    let l10 = *arg0.add(40).cast::<i32>();
+                    let l11 = *arg0.add(44).cast::<i32>();
+                    let l12 = *arg0.add(48).cast::<i32>();
+                    let l13 = i32::from(*arg0.add(52).cast::<u8>());
+                    let l14 = i32::from(*arg0.add(53).cast::<u8>());
+                    let l15 = *arg0.add(56).cast::<i32>();
+                    let l16 = i32::from(*arg0.add(60).cast::<u8>());
+                    _rt::cabi_dealloc(arg0, 64, 4);
+                    let result17 = T::get_enemy_threat_level(
+                        super::super::super::super::exports::docs::enemy::types::PlayerStats {
+                            health: l0 as u32,
+                            attack: l1 as u32,
+                        },
+                        super::super::super::super::exports::docs::enemy::types::EnemyState {
+                            kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                                l2 as u8,
+                            ),
+                            health: l3 as u32,
+                            max_health: l4 as u32,
+                            attack: l5 as u32,
+                            defense: l6 as u32,
+                            exp_reward: l7 as u32,
+                            speed: l8 as u32,
+                            pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: l9,
+                                y: l10,
+                            },
+                            last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: l11,
+                                y: l12,
+                            },
+                            current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
+                                l13 as u8,
+                            ),
+                            is_alive: _rt::bool_lift(l14 as u8),
+                            channel_turns: l15 as u32,
+                            boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                                l16 as u8,
+                            ),
+                        },
+                    );
+                    result17.clone() as i32
+                }
                 pub trait Guest {
                     /// Calculate the enemy's next move based on player position.
                     ///
-                    /// Returns the new position the enemy wants to move to.
+                    /// Returns the new position the enemy wants to move to. Chasing enemies
+                    /// within 15 tiles follow an A* path over the walkable grid (indexed
+                    /// grid[y][x]); farther targets fall back to a greedy step. Wandering
+                    /// enemies mix the turn number into their direction so they do not
+                    /// all move in lockstep.
                     fn calculate_move(
                         enemy: EnemyState,
                         player_pos: Position,
+                        grid: _rt::Vec<_rt::Vec<bool>>,
+                        turn_number: u32,
                     ) -> Position;
+                    /// Move the enemy to a new position, remembering where it came from.
+                    fn move_enemy(enemy: EnemyState, new_pos: Position) -> EnemyState;
                     /// Determine if the enemy should attack based on distance.
-                    fn should_attack(enemy: EnemyState, player_pos: Position) -> bool;
+                    ///
+                    /// Wizards attack from up to 5 tiles away when no blocked tile of the
+                    /// walkable grid (indexed grid[y][x]) lies between them and the player.
+                    fn should_attack(
+                        enemy: EnemyState,
+                        player_pos: Position,
+                        grid: _rt::Vec<_rt::Vec<bool>>,
+                    ) -> bool;
                     /// Update the enemy's behavior based on its current state.
+                    ///
+                    /// A boss at or below half health reports boss-rampage.
                     fn update_behavior(enemy: EnemyState) -> Behavior;
+                    /// Enrage a boss at or below half health, doubling attack and adding 10 defense.
+                    ///
+                    /// Returns the enemy unchanged if it is not a boss or is already enraged.
+                    fn maybe_enter_phase_two(enemy: EnemyState) -> EnemyState;
                     /// Calculate attack damage this enemy will deal.
                     fn get_attack_damage(enemy: EnemyState) -> u32;
+                    /// Advance the enemy's special ability by one turn.
+                    ///
+                    /// A dragon guards while channeling for two turns, then breathes fire
+                    /// on the third. Returns the updated enemy and the ability that fired.
+                    fn use_special_ability(
+                        enemy: EnemyState,
+                    ) -> (EnemyState, Option<AbilityUse>);
+                    /// Rate how dangerous the enemy is to the player.
+                    fn get_enemy_threat_level(
+                        player_stats: PlayerStats,
+                        enemy: EnemyState,
+                    ) -> ThreatLevel;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_enemy_ai_0_1_0_cabi {
                     ($ty:ident with_types_in $($path_to_types:tt)*) => {
                         const _ : () = { #[unsafe (export_name =
                         "docs:enemy/ai@0.1.0#calculate-move")] unsafe extern "C" fn
-                        export_calculate_move(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32, arg10 : i32, arg11 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_calculate_move_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
-                        arg11) } } #[unsafe (export_name =
+                        export_calculate_move(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_calculate_move_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name = "docs:enemy/ai@0.1.0#move-enemy")]
+                        unsafe extern "C" fn export_move_enemy(arg0 : * mut u8,) -> * mut
+                        u8 { unsafe { $($path_to_types)*:: _export_move_enemy_cabi::<$ty
+                        > (arg0) } } #[unsafe (export_name =
                         "docs:enemy/ai@0.1.0#should-attack")] unsafe extern "C" fn
-                        export_should_attack(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32, arg10 : i32, arg11 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_should_attack_cabi::<$ty > (arg0,
+                        export_should_attack(arg0 : * mut u8,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_should_attack_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name = "docs:enemy/ai@0.1.0#update-behavior")]
+                        unsafe extern "C" fn export_update_behavior(arg0 : i32, arg1 :
+                        i32, arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32,
+                        arg7 : i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32,
+                        arg12 : i32, arg13 : i32, arg14 : i32,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_update_behavior_cabi::<$ty > (arg0,
                         arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
-                        arg11) } } #[unsafe (export_name =
-                        "docs:enemy/ai@0.1.0#update-behavior")] unsafe extern "C" fn
-                        export_update_behavior(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32,) -> i32 { unsafe { $($path_to_types)*::
-                        _export_update_behavior_cabi::<$ty > (arg0, arg1, arg2, arg3,
-                        arg4, arg5, arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+                        arg11, arg12, arg13, arg14) } } #[unsafe (export_name =
+                        "docs:enemy/ai@0.1.0#maybe-enter-phase-two")] unsafe extern "C"
+                        fn export_maybe_enter_phase_two(arg0 : i32, arg1 : i32, arg2 :
+                        i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32,
+                        arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32,
+                        arg13 : i32, arg14 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_maybe_enter_phase_two_cabi::<$ty >
+                        (arg0, arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9,
+                        arg10, arg11, arg12, arg13, arg14) } } #[unsafe (export_name =
                         "docs:enemy/ai@0.1.0#get-attack-damage")] unsafe extern "C" fn
                         export_get_attack_damage(arg0 : i32, arg1 : i32, arg2 : i32, arg3
                         : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 :
-                        i32, arg9 : i32,) -> i32 { unsafe { $($path_to_types)*::
+                        i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 :
+                        i32, arg14 : i32,) -> i32 { unsafe { $($path_to_types)*::
                         _export_get_attack_damage_cabi::<$ty > (arg0, arg1, arg2, arg3,
-                        arg4, arg5, arg6, arg7, arg8, arg9) } } };
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13,
+                        arg14) } } #[unsafe (export_name =
+                        "docs:enemy/ai@0.1.0#use-special-ability")] unsafe extern "C" fn
+                        export_use_special_ability(arg0 : i32, arg1 : i32, arg2 : i32,
+                        arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8
+                        : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 :
+                        i32, arg14 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_use_special_ability_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13,
+                        arg14) } } #[unsafe (export_name =
+                        "docs:enemy/ai@0.1.0#get-enemy-threat-level")] unsafe extern "C"
+                        fn export_get_enemy_threat_level(arg0 : * mut u8,) -> i32 {
+                        unsafe { $($path_to_types)*::
+                        _export_get_enemy_threat_level_cabi::<$ty > (arg0) } } };
                     };
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_docs_enemy_ai_0_1_0_cabi;
                 #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 8]);
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 84]);
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 8],
+                    [::core::mem::MaybeUninit::uninit(); 84],
                 );
             }
             /// Enemy damage and health interface.
@@ -606,6 +1611,7 @@ pub mod exports {
                 static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
                 pub type EnemyState = super::super::super::super::exports::docs::enemy::types::EnemyState;
+                pub type LootRarity = super::super::super::super::exports::docs::enemy::types::LootRarity;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_take_damage_cabi<T: Guest>(
@@ -620,6 +1626,11 @@ pub mod exports {
                     arg8: i32,
                     arg9: i32,
                     arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
+                    arg15: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::take_damage(
@@ -632,16 +1643,25 @@ pub mod exports {
                             attack: arg3 as u32,
                             defense: arg4 as u32,
                             exp_reward: arg5 as u32,
+                            speed: arg6 as u32,
                             pos: super::super::super::super::exports::docs::enemy::types::Position {
-                                x: arg6,
-                                y: arg7,
+                                x: arg7,
+                                y: arg8,
+                            },
+                            last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: arg9,
+                                y: arg10,
                             },
                             current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                                arg8 as u8,
+                                arg11 as u8,
+                            ),
+                            is_alive: _rt::bool_lift(arg12 as u8),
+                            channel_turns: arg13 as u32,
+                            boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                                arg14 as u8,
                             ),
-                            is_alive: _rt::bool_lift(arg9 as u8),
                         },
-                        arg10 as u32,
+                        arg15 as u32,
                     );
                     let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::enemy::types::EnemyState {
@@ -651,9 +1671,13 @@ pub mod exports {
                         attack: attack2,
                         defense: defense2,
                         exp_reward: exp_reward2,
+                        speed: speed2,
                         pos: pos2,
+                        last_pos: last_pos2,
                         current_behavior: current_behavior2,
                         is_alive: is_alive2,
+                        channel_turns: channel_turns2,
+                        boss_phase: boss_phase2,
                     } = result0;
                     *ptr1.add(0).cast::<u8>() = (kind2.clone() as i32) as u8;
                     *ptr1.add(4).cast::<i32>() = _rt::as_i32(health2);
@@ -661,18 +1685,27 @@ pub mod exports {
                     *ptr1.add(12).cast::<i32>() = _rt::as_i32(attack2);
                     *ptr1.add(16).cast::<i32>() = _rt::as_i32(defense2);
                     *ptr1.add(20).cast::<i32>() = _rt::as_i32(exp_reward2);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(speed2);
                     let super::super::super::super::exports::docs::enemy::types::Position {
                         x: x3,
                         y: y3,
                     } = pos2;
-                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(x3);
-                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(y3);
-                    *ptr1.add(32).cast::<u8>() = (current_behavior2.clone() as i32)
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(x3);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(y3);
+                    let super::super::super::super::exports::docs::enemy::types::Position {
+                        x: x4,
+                        y: y4,
+                    } = last_pos2;
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(x4);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(y4);
+                    *ptr1.add(44).cast::<u8>() = (current_behavior2.clone() as i32)
                         as u8;
-                    *ptr1.add(33).cast::<u8>() = (match is_alive2 {
+                    *ptr1.add(45).cast::<u8>() = (match is_alive2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1.add(48).cast::<i32>() = _rt::as_i32(channel_turns2);
+                    *ptr1.add(52).cast::<u8>() = (boss_phase2.clone() as i32) as u8;
                     ptr1
                 }
                 #[doc(hidden)]
@@ -688,6 +1721,11 @@ pub mod exports {
                     arg7: i32,
                     arg8: i32,
                     arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::is_defeated(super::super::super::super::exports::docs::enemy::types::EnemyState {
@@ -699,14 +1737,23 @@ pub mod exports {
                         attack: arg3 as u32,
                         defense: arg4 as u32,
                         exp_reward: arg5 as u32,
+                        speed: arg6 as u32,
                         pos: super::super::super::super::exports::docs::enemy::types::Position {
-                            x: arg6,
-                            y: arg7,
+                            x: arg7,
+                            y: arg8,
+                        },
+                        last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg9,
+                            y: arg10,
                         },
                         current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                            arg8 as u8,
+                            arg11 as u8,
+                        ),
+                        is_alive: _rt::bool_lift(arg12 as u8),
+                        channel_turns: arg13 as u32,
+                        boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                            arg14 as u8,
                         ),
-                        is_alive: _rt::bool_lift(arg9 as u8),
                     });
                     match result0 {
                         true => 1,
@@ -726,6 +1773,11 @@ pub mod exports {
                     arg7: i32,
                     arg8: i32,
                     arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
                     let result0 = T::get_exp_reward(super::super::super::super::exports::docs::enemy::types::EnemyState {
@@ -737,26 +1789,125 @@ pub mod exports {
                         attack: arg3 as u32,
                         defense: arg4 as u32,
                         exp_reward: arg5 as u32,
+                        speed: arg6 as u32,
                         pos: super::super::super::super::exports::docs::enemy::types::Position {
-                            x: arg6,
-                            y: arg7,
+                            x: arg7,
+                            y: arg8,
+                        },
+                        last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                            x: arg9,
+                            y: arg10,
                         },
                         current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
-                            arg8 as u8,
+                            arg11 as u8,
+                        ),
+                        is_alive: _rt::bool_lift(arg12 as u8),
+                        channel_turns: arg13 as u32,
+                        boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                            arg14 as u8,
                         ),
-                        is_alive: _rt::bool_lift(arg9 as u8),
                     });
                     _rt::as_i32(result0)
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_loot_drops_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_loot_drops(arg0 as u32, arg1 as u32);
+                    result0.clone() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_generate_loot_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: i32,
+                    arg15: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::generate_loot(
+                        super::super::super::super::exports::docs::enemy::types::EnemyState {
+                            kind: super::super::super::super::exports::docs::enemy::types::EnemyKind::_lift(
+                                arg0 as u8,
+                            ),
+                            health: arg1 as u32,
+                            max_health: arg2 as u32,
+                            attack: arg3 as u32,
+                            defense: arg4 as u32,
+                            exp_reward: arg5 as u32,
+                            speed: arg6 as u32,
+                            pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: arg7,
+                                y: arg8,
+                            },
+                            last_pos: super::super::super::super::exports::docs::enemy::types::Position {
+                                x: arg9,
+                                y: arg10,
+                            },
+                            current_behavior: super::super::super::super::exports::docs::enemy::types::Behavior::_lift(
+                                arg11 as u8,
+                            ),
+                            is_alive: _rt::bool_lift(arg12 as u8),
+                            channel_turns: arg13 as u32,
+                            boss_phase: super::super::super::super::exports::docs::enemy::types::BossPhase::_lift(
+                                arg14 as u8,
+                            ),
+                        },
+                        arg15 as u32,
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec2 = (result0).into_boxed_slice();
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    ::core::mem::forget(vec2);
+                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len2;
+                    *ptr1.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_generate_loot<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
                 pub trait Guest {
                     /// Apply damage to an enemy.
                     ///
-                    /// Returns updated enemy state after damage calculation.
+                    /// Returns updated enemy state after damage calculation. A boss
+                    /// knocked to half health enters phase two.
                     fn take_damage(enemy: EnemyState, raw_damage: u32) -> EnemyState;
                     /// Check if the enemy is defeated.
                     fn is_defeated(enemy: EnemyState) -> bool;
                     /// Get the experience reward for defeating this enemy.
                     fn get_exp_reward(enemy: EnemyState) -> u32;
+                    /// Get the rarity of a loot drop from a percent roll.
+                    ///
+                    /// A common drop is promoted to uncommon when player-luck is above 10.
+                    fn get_loot_drops(roll: u32, player_luck: u32) -> LootRarity;
+                    /// Roll the items an enemy drops when defeated, as inventory item IDs.
+                    ///
+                    /// The same enemy kind and seed always drop the same items.
+                    fn generate_loot(enemy: EnemyState, seed: u32) -> _rt::Vec<u32>;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_enemy_damage_0_1_0_cabi {
@@ -765,30 +1916,52 @@ pub mod exports {
                         "docs:enemy/damage@0.1.0#take-damage")] unsafe extern "C" fn
                         export_take_damage(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
                         i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32, arg10 : i32,) -> * mut u8 { unsafe {
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 : i32,
+                        arg14 : i32, arg15 : i32,) -> * mut u8 { unsafe {
                         $($path_to_types)*:: _export_take_damage_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10) } }
-                        #[unsafe (export_name = "docs:enemy/damage@0.1.0#is-defeated")]
-                        unsafe extern "C" fn export_is_defeated(arg0 : i32, arg1 : i32,
-                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
-                        : i32, arg8 : i32, arg9 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_is_defeated_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9) } }
-                        #[unsafe (export_name =
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13, arg14, arg15) } } #[unsafe (export_name =
+                        "docs:enemy/damage@0.1.0#is-defeated")] unsafe extern "C" fn
+                        export_is_defeated(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 : i32,
+                        arg14 : i32,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_is_defeated_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13, arg14)
+                        } } #[unsafe (export_name =
                         "docs:enemy/damage@0.1.0#get-exp-reward")] unsafe extern "C" fn
                         export_get_exp_reward(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
                         i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
-                        arg9 : i32,) -> i32 { unsafe { $($path_to_types)*::
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 : i32,
+                        arg14 : i32,) -> i32 { unsafe { $($path_to_types)*::
                         _export_get_exp_reward_cabi::<$ty > (arg0, arg1, arg2, arg3,
-                        arg4, arg5, arg6, arg7, arg8, arg9) } } };
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13,
+                        arg14) } } #[unsafe (export_name =
+                        "docs:enemy/damage@0.1.0#get-loot-drops")] unsafe extern "C" fn
+                        export_get_loot_drops(arg0 : i32, arg1 : i32,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_get_loot_drops_cabi::<$ty > (arg0,
+                        arg1) } } #[unsafe (export_name =
+                        "docs:enemy/damage@0.1.0#generate-loot")] unsafe extern "C" fn
+                        export_generate_loot(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : i32, arg13 : i32,
+                        arg14 : i32, arg15 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_generate_loot_cabi::<$ty > (arg0,
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13, arg14, arg15) } } #[unsafe (export_name =
+                        "cabi_post_docs:enemy/damage@0.1.0#generate-loot")] unsafe extern
+                        "C" fn _post_return_generate_loot(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_generate_loot::<$ty > (arg0) }
+                        } };
                     };
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_docs_enemy_damage_0_1_0_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 36]);
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea([::core::mem::MaybeUninit<u8>; 56]);
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 36],
+                    [::core::mem::MaybeUninit::uninit(); 56],
                 );
             }
         }
@@ -860,6 +2033,14 @@ mod _rt {
             self as i32
         }
     }
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    pub use alloc_crate::string::String;
     pub unsafe fn bool_lift(val: u8) -> bool {
         if cfg!(debug_assertions) {
             match val {
@@ -871,6 +2052,9 @@ mod _rt {
             val != 0
         }
     }
+    pub use alloc_crate::vec::Vec;
+    extern crate alloc as alloc_crate;
+    pub use alloc_crate::alloc;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -916,31 +2100,54 @@ pub(crate) use __export_enemy_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1060] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa8\x07\x01A\x02\x01\
-A\x0c\x01B\x08\x01m\x06\x05slime\x08skeleton\x03bat\x06goblin\x0bdark-knight\x04\
-boss\x04\0\x0aenemy-kind\x03\0\0\x01m\x05\x06wander\x05chase\x05guard\x04flee\x0c\
-boss-pattern\x04\0\x08behavior\x03\0\x02\x01r\x02\x01xz\x01yz\x04\0\x08position\x03\
-\0\x04\x01r\x09\x04kind\x01\x06healthy\x0amax-healthy\x06attacky\x07defensey\x0a\
-exp-rewardy\x03pos\x05\x10current-behavior\x03\x08is-alive\x7f\x04\0\x0benemy-st\
-ate\x03\0\x06\x04\0\x16docs:enemy/types@0.1.0\x05\0\x02\x03\0\0\x0aenemy-kind\x02\
-\x03\0\0\x0benemy-state\x02\x03\0\0\x08position\x01B\x0c\x02\x03\x02\x01\x01\x04\
-\0\x0aenemy-kind\x03\0\0\x02\x03\x02\x01\x02\x04\0\x0benemy-state\x03\0\x02\x02\x03\
-\x02\x01\x03\x04\0\x08position\x03\0\x04\x01@\x02\x04kind\x01\x03pos\x05\0\x03\x04\
-\0\x0bspawn-enemy\x01\x06\x01@\x01\x03pos\x05\0\x03\x04\0\x0aspawn-boss\x01\x07\x01\
-@\x01\x04kind\x01\0\x03\x04\0\x0eget-base-stats\x01\x08\x04\0\x16docs:enemy/spaw\
-n@0.1.0\x05\x04\x02\x03\0\0\x08behavior\x01B\x0e\x02\x03\x02\x01\x02\x04\0\x0ben\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2067] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x97\x0f\x01A\x02\x01\
+A\x11\x01B\x1b\x01m\x09\x05slime\x08skeleton\x03bat\x06goblin\x0bdark-knight\x04\
+boss\x06dragon\x05thief\x06wizard\x04\0\x0aenemy-kind\x03\0\0\x01r\x02\x0fturns-\
+remainingy\x0fdamage-per-turny\x04\0\x07burning\x03\0\x02\x01q\x01\x07burning\x01\
+\x03\0\x04\0\x0dstatus-effect\x03\0\x04\x01q\x01\x0bfire-breath\x01y\0\x04\0\x0d\
+enemy-ability\x03\0\x06\x01k\x05\x01r\x02\x07ability\x07\x0finflicts-status\x08\x04\
+\0\x0bability-use\x03\0\x09\x01m\x07\x06wander\x05chase\x05guard\x04flee\x0cboss\
+-pattern\x0cboss-rampage\x06ranged\x04\0\x08behavior\x03\0\x0b\x01m\x02\x09phase\
+-one\x09phase-two\x04\0\x0aboss-phase\x03\0\x0d\x01r\x02\x01xz\x01yz\x04\0\x08po\
+sition\x03\0\x0f\x01m\x03\x06common\x08uncommon\x04rare\x04\0\x0bloot-rarity\x03\
+\0\x11\x01m\x04\x04safe\x0amanageable\x09dangerous\x06deadly\x04\0\x0cthreat-lev\
+el\x03\0\x13\x01r\x02\x06healthy\x06attacky\x04\0\x0cplayer-stats\x03\0\x15\x01r\
+\x03\x04firey\x03icey\x09lightningy\x04\0\x0bresistances\x03\0\x17\x01r\x0d\x04k\
+ind\x01\x06healthy\x0amax-healthy\x06attacky\x07defensey\x0aexp-rewardy\x05speed\
+y\x03pos\x10\x08last-pos\x10\x10current-behavior\x0c\x08is-alive\x7f\x0dchannel-\
+turnsy\x0aboss-phase\x0e\x04\0\x0benemy-state\x03\0\x19\x04\0\x16docs:enemy/type\
+s@0.1.0\x05\0\x02\x03\0\0\x0aenemy-kind\x02\x03\0\0\x0benemy-state\x02\x03\0\0\x08\
+position\x02\x03\0\0\x0bresistances\x01B\x13\x02\x03\x02\x01\x01\x04\0\x0aenemy-\
+kind\x03\0\0\x02\x03\x02\x01\x02\x04\0\x0benemy-state\x03\0\x02\x02\x03\x02\x01\x03\
+\x04\0\x08position\x03\0\x04\x02\x03\x02\x01\x04\x04\0\x0bresistances\x03\0\x06\x01\
+@\x02\x04kind\x01\x03pos\x05\0\x03\x04\0\x0bspawn-enemy\x01\x08\x01@\x01\x03pos\x05\
+\0\x03\x04\0\x0aspawn-boss\x01\x09\x01@\x01\x04kind\x01\0\x03\x04\0\x0eget-base-\
+stats\x01\x0a\x01@\x01\x04kind\x01\0\x07\x04\0\x0fget-resistances\x01\x0b\x01ks\x01\
+@\x01\x04kind\x01\0\x0c\x04\0\x0dget-home-area\x01\x0d\x04\0\x16docs:enemy/spawn\
+@0.1.0\x05\x05\x02\x03\0\0\x08behavior\x02\x03\0\0\x0cplayer-stats\x02\x03\0\0\x0c\
+threat-level\x02\x03\0\0\x0bability-use\x01B\x20\x02\x03\x02\x01\x02\x04\0\x0ben\
 emy-state\x03\0\0\x02\x03\x02\x01\x03\x04\0\x08position\x03\0\x02\x02\x03\x02\x01\
-\x05\x04\0\x08behavior\x03\0\x04\x01@\x02\x05enemy\x01\x0aplayer-pos\x03\0\x03\x04\
-\0\x0ecalculate-move\x01\x06\x01@\x02\x05enemy\x01\x0aplayer-pos\x03\0\x7f\x04\0\
-\x0dshould-attack\x01\x07\x01@\x01\x05enemy\x01\0\x05\x04\0\x0fupdate-behavior\x01\
-\x08\x01@\x01\x05enemy\x01\0y\x04\0\x11get-attack-damage\x01\x09\x04\0\x13docs:e\
-nemy/ai@0.1.0\x05\x06\x01B\x08\x02\x03\x02\x01\x02\x04\0\x0benemy-state\x03\0\0\x01\
-@\x02\x05enemy\x01\x0araw-damagey\0\x01\x04\0\x0btake-damage\x01\x02\x01@\x01\x05\
-enemy\x01\0\x7f\x04\0\x0bis-defeated\x01\x03\x01@\x01\x05enemy\x01\0y\x04\0\x0eg\
-et-exp-reward\x01\x04\x04\0\x17docs:enemy/damage@0.1.0\x05\x07\x04\0\x16docs:ene\
-my/enemy@0.1.0\x04\0\x0b\x0b\x01\0\x05enemy\x03\0\0\0G\x09producers\x01\x0cproce\
-ssed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+\x06\x04\0\x08behavior\x03\0\x04\x02\x03\x02\x01\x07\x04\0\x0cplayer-stats\x03\0\
+\x06\x02\x03\x02\x01\x08\x04\0\x0cthreat-level\x03\0\x08\x02\x03\x02\x01\x09\x04\
+\0\x0bability-use\x03\0\x0a\x01p\x7f\x01p\x0c\x01@\x04\x05enemy\x01\x0aplayer-po\
+s\x03\x04grid\x0d\x0bturn-numbery\0\x03\x04\0\x0ecalculate-move\x01\x0e\x01@\x02\
+\x05enemy\x01\x07new-pos\x03\0\x01\x04\0\x0amove-enemy\x01\x0f\x01@\x03\x05enemy\
+\x01\x0aplayer-pos\x03\x04grid\x0d\0\x7f\x04\0\x0dshould-attack\x01\x10\x01@\x01\
+\x05enemy\x01\0\x05\x04\0\x0fupdate-behavior\x01\x11\x01@\x01\x05enemy\x01\0\x01\
+\x04\0\x15maybe-enter-phase-two\x01\x12\x01@\x01\x05enemy\x01\0y\x04\0\x11get-at\
+tack-damage\x01\x13\x01k\x0b\x01o\x02\x01\x14\x01@\x01\x05enemy\x01\0\x15\x04\0\x13\
+use-special-ability\x01\x16\x01@\x02\x0cplayer-stats\x07\x05enemy\x01\0\x09\x04\0\
+\x16get-enemy-threat-level\x01\x17\x04\0\x13docs:enemy/ai@0.1.0\x05\x0a\x02\x03\0\
+\0\x0bloot-rarity\x01B\x0f\x02\x03\x02\x01\x02\x04\0\x0benemy-state\x03\0\0\x02\x03\
+\x02\x01\x0b\x04\0\x0bloot-rarity\x03\0\x02\x01@\x02\x05enemy\x01\x0araw-damagey\
+\0\x01\x04\0\x0btake-damage\x01\x04\x01@\x01\x05enemy\x01\0\x7f\x04\0\x0bis-defe\
+ated\x01\x05\x01@\x01\x05enemy\x01\0y\x04\0\x0eget-exp-reward\x01\x06\x01@\x02\x04\
+rolly\x0bplayer-lucky\0\x03\x04\0\x0eget-loot-drops\x01\x07\x01py\x01@\x02\x05en\
+emy\x01\x04seedy\0\x08\x04\0\x0dgenerate-loot\x01\x09\x04\0\x17docs:enemy/damage\
+@0.1.0\x05\x0c\x04\0\x16docs:enemy/enemy@0.1.0\x04\0\x0b\x0b\x01\0\x05enemy\x03\0\
+\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bi\
+ndgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
@@ -29,10 +29,22 @@
 
 mod bindings;
 
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
 use bindings::exports::docs::enemy::ai::{Guest as AiGuest, Position as AiPosition};
 use bindings::exports::docs::enemy::damage::Guest as DamageGuest;
 use bindings::exports::docs::enemy::spawn::{Guest as SpawnGuest, Position as SpawnPosition};
-use bindings::exports::docs::enemy::types::{Behavior, EnemyKind, EnemyState, Position};
+use bindings::exports::docs::enemy::types::{
+    AbilityUse, Behavior, BossPhase, Burning, EnemyAbility, EnemyKind, EnemyState, LootRarity,
+    PlayerStats, Position, Resistances, StatusEffect, ThreatLevel,
+};
+
+/// Width and height of the walkability grid searched by A*.
+const GRID_SIZE: usize = 100;
+
+/// Farthest player distance at which chasing enemies use A*.
+const ASTAR_RANGE: u32 = 15;
 
 /// Chase distance threshold for AI decisions.
 const CHASE_DISTANCE: u32 = 5;
@@ -40,9 +52,84 @@ const CHASE_DISTANCE: u32 = 5;
 /// Attack range for melee enemies.
 const ATTACK_RANGE: u32 = 1;
 
+/// Closest distance a ranged enemy lets the player get before retreating.
+const RANGED_MIN_DISTANCE: u32 = 3;
+
+/// Attack range for ranged enemies, and the farthest they stay from the player.
+const RANGED_ATTACK_RANGE: u32 = 5;
+
 /// Flee health threshold percentage.
 const FLEE_THRESHOLD: u32 = 20;
 
+/// Health percentage at which a boss enters its second phase.
+const BOSS_PHASE_TWO_THRESHOLD: u32 = 50;
+
+/// Defense a boss gains on entering its second phase.
+const BOSS_PHASE_TWO_DEFENSE: u32 = 10;
+
+/// Rolls below this percent drop rare loot.
+const RARE_DROP_ROLL: u32 = 5;
+
+/// Rolls below this percent drop uncommon loot.
+const UNCOMMON_DROP_ROLL: u32 = 25;
+
+/// Luck above which common drops are promoted to uncommon.
+const LUCKY_DROP_THRESHOLD: u32 = 10;
+
+/// Turns a dragon channels before breathing fire.
+const DRAGON_CHANNEL_TURNS: u32 = 2;
+
+/// Damage of a dragon's fire breath.
+const FIRE_BREATH_DAMAGE: u32 = 30;
+
+/// Turns the player burns after a fire breath.
+const FIRE_BREATH_BURN_TURNS: u32 = 3;
+
+/// Burn damage per turn after a fire breath.
+const FIRE_BREATH_BURN_DAMAGE: u32 = 5;
+
+/// Home area of dragons, matching the game engine's area 3.
+const DRAGON_HOME_AREA: &str = "Death Mountain";
+
+/// Inventory item ID of the Health Potion.
+const HEALTH_POTION_ID: u32 = 201;
+
+/// Inventory item ID of the Full Health Potion.
+const FULL_HEALTH_POTION_ID: u32 = 202;
+
+/// Inventory item ID of the Attack Boost.
+const ATTACK_BOOST_ID: u32 = 203;
+
+/// Inventory item ID of the Steel Sword.
+const STEEL_SWORD_ID: u32 = 2;
+
+/// Inventory item ID of the Master Sword.
+const MASTER_SWORD_ID: u32 = 3;
+
+/// Inventory item ID of the Chain Mail.
+const CHAIN_MAIL_ID: u32 = 103;
+
+/// Inventory item ID of the Magic Map.
+const MAGIC_MAP_ID: u32 = 211;
+
+/// How an enemy measures whether the player is in attack range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AttackRange {
+    /// Manhattan distance; diagonal neighbours are out of reach.
+    Orthogonal,
+    /// Chebyshev distance; diagonal neighbours are in reach.
+    Diagonal,
+    /// Chebyshev distance up to the ranged attack range, with line of sight.
+    Ranged,
+}
+
+/// Items an enemy kind can drop when defeated.
+#[derive(Debug, Clone, PartialEq)]
+struct LootTable {
+    /// Possible drops as (item ID, chance in permille).
+    drops: Vec<(u32, u32)>,
+}
+
 /// Component structure for enemy functionality.
 struct Component;
 
@@ -65,6 +152,9 @@ fn base_health(kind: &EnemyKind) -> u32 {
         EnemyKind::Goblin => 40,
         EnemyKind::DarkKnight => 80,
         EnemyKind::Boss => 200,
+        EnemyKind::Dragon => 150,
+        EnemyKind::Thief => 25,
+        EnemyKind::Wizard => 35,
     }
 }
 
@@ -85,6 +175,9 @@ fn base_attack(kind: &EnemyKind) -> u32 {
         EnemyKind::Goblin => 10,
         EnemyKind::DarkKnight => 20,
         EnemyKind::Boss => 30,
+        EnemyKind::Dragon => 25,
+        EnemyKind::Thief => 2,
+        EnemyKind::Wizard => 18,
     }
 }
 
@@ -105,6 +198,9 @@ fn base_defense(kind: &EnemyKind) -> u32 {
         EnemyKind::Goblin => 4,
         EnemyKind::DarkKnight => 15,
         EnemyKind::Boss => 20,
+        EnemyKind::Dragon => 12,
+        EnemyKind::Thief => 1,
+        EnemyKind::Wizard => 3,
     }
 }
 
@@ -125,6 +221,77 @@ fn base_exp_reward(kind: &EnemyKind) -> u32 {
         EnemyKind::Goblin => 20,
         EnemyKind::DarkKnight => 50,
         EnemyKind::Boss => 100,
+        EnemyKind::Dragon => 75,
+        EnemyKind::Thief => 30,
+        EnemyKind::Wizard => 40,
+    }
+}
+
+/// Get base speed for an enemy kind.
+///
+/// # Arguments
+///
+/// * `kind` - The type of enemy
+///
+/// # Returns
+///
+/// * `u32` - Base speed
+fn base_speed(kind: &EnemyKind) -> u32 {
+    match kind {
+        EnemyKind::Slime => 5,
+        EnemyKind::Skeleton => 10,
+        EnemyKind::Bat => 15,
+        EnemyKind::Goblin => 12,
+        EnemyKind::DarkKnight => 8,
+        EnemyKind::Boss => 12,
+        EnemyKind::Dragon => 9,
+        EnemyKind::Thief => 16,
+        EnemyKind::Wizard => 11,
+    }
+}
+
+/// Get elemental resistances for an enemy kind.
+///
+/// # Arguments
+///
+/// * `kind` - The type of enemy
+///
+/// # Returns
+///
+/// * `Resistances` - Fire, ice and lightning resistance in percent
+fn base_resistances(kind: &EnemyKind) -> Resistances {
+    let (fire, ice, lightning) = match kind {
+        EnemyKind::Slime => (0, 50, 25),
+        EnemyKind::Skeleton => (75, 25, 25),
+        EnemyKind::Bat => (10, 10, 0),
+        EnemyKind::Goblin => (25, 25, 25),
+        EnemyKind::DarkKnight => (25, 25, 50),
+        EnemyKind::Boss => (50, 50, 50),
+        EnemyKind::Dragon => (100, 0, 25),
+        EnemyKind::Thief => (0, 0, 0),
+        EnemyKind::Wizard => (50, 50, 50),
+    };
+    Resistances {
+        fire,
+        ice,
+        lightning,
+    }
+}
+
+/// Get the attack range type for an enemy kind.
+///
+/// # Arguments
+///
+/// * `kind` - The type of enemy
+///
+/// # Returns
+///
+/// * `AttackRange` - How the enemy measures attack range
+fn attack_range_type(kind: &EnemyKind) -> AttackRange {
+    match kind {
+        EnemyKind::DarkKnight | EnemyKind::Boss | EnemyKind::Dragon => AttackRange::Diagonal,
+        EnemyKind::Wizard => AttackRange::Ranged,
+        _ => AttackRange::Orthogonal,
     }
 }
 
@@ -142,12 +309,84 @@ fn default_behavior(kind: &EnemyKind) -> Behavior {
         EnemyKind::Slime => Behavior::Wander,
         EnemyKind::Skeleton => Behavior::Guard,
         EnemyKind::Bat => Behavior::Chase,
-        EnemyKind::Goblin => Behavior::Chase,
+        EnemyKind::Goblin | EnemyKind::Thief => Behavior::Chase,
         EnemyKind::DarkKnight => Behavior::Guard,
-        EnemyKind::Boss => Behavior::BossPattern,
+        EnemyKind::Boss | EnemyKind::Dragon => Behavior::BossPattern,
+        EnemyKind::Wizard => Behavior::Ranged,
     }
 }
 
+/// Get the area an enemy kind calls home.
+///
+/// # Arguments
+///
+/// * `kind` - The type of enemy
+///
+/// # Returns
+///
+/// * `Option<&'static str>` - Home area name, if the kind has one
+fn home_area(kind: &EnemyKind) -> Option<&'static str> {
+    match kind {
+        EnemyKind::Dragon => Some(DRAGON_HOME_AREA),
+        _ => None,
+    }
+}
+
+/// Get the channel turns an enemy kind needs before its special ability.
+///
+/// # Arguments
+///
+/// * `kind` - The type of enemy
+///
+/// # Returns
+///
+/// * `u32` - Channel turns (0 for kinds without an ability)
+fn base_channel_turns(kind: &EnemyKind) -> u32 {
+    match kind {
+        EnemyKind::Dragon => DRAGON_CHANNEL_TURNS,
+        _ => 0,
+    }
+}
+
+/// Advance an enemy's special ability by one turn.
+///
+/// A dragon guards while its channel counts down, then breathes fire
+/// and starts channeling again.
+///
+/// # Arguments
+///
+/// * `enemy` - Enemy state
+///
+/// # Returns
+///
+/// * `(EnemyState, Option<AbilityUse>)` - Updated enemy and the ability fired
+fn use_special_ability(enemy: EnemyState) -> (EnemyState, Option<AbilityUse>) {
+    if enemy.kind != EnemyKind::Dragon {
+        return (enemy, None);
+    }
+    if enemy.channel_turns > 0 {
+        let channeling = EnemyState {
+            channel_turns: enemy.channel_turns - 1,
+            current_behavior: Behavior::Guard,
+            ..enemy
+        };
+        return (channeling, None);
+    }
+    let fire_breath = AbilityUse {
+        ability: EnemyAbility::FireBreath(FIRE_BREATH_DAMAGE),
+        inflicts_status: Some(StatusEffect::Burning(Burning {
+            turns_remaining: FIRE_BREATH_BURN_TURNS,
+            damage_per_turn: FIRE_BREATH_BURN_DAMAGE,
+        })),
+    };
+    let recharging = EnemyState {
+        channel_turns: DRAGON_CHANNEL_TURNS,
+        current_behavior: Behavior::BossPattern,
+        ..enemy
+    };
+    (recharging, Some(fire_breath))
+}
+
 /// Create base enemy state from kind and position.
 ///
 /// # Arguments
@@ -167,9 +406,13 @@ fn create_enemy_state(kind: EnemyKind, pos: Position) -> EnemyState {
         attack: base_attack(&kind),
         defense: base_defense(&kind),
         exp_reward: base_exp_reward(&kind),
+        speed: base_speed(&kind),
+        last_pos: Position { x: pos.x, y: pos.y },
         pos,
         current_behavior: default_behavior(&kind),
         is_alive: true,
+        channel_turns: base_channel_turns(&kind),
+        boss_phase: BossPhase::PhaseOne,
     }
 }
 
@@ -190,9 +433,90 @@ fn create_boss_state(pos: Position) -> EnemyState {
         attack: 30,
         defense: 20,
         exp_reward: 100,
+        speed: base_speed(&EnemyKind::Boss),
+        last_pos: Position { x: pos.x, y: pos.y },
         pos,
         current_behavior: Behavior::BossPattern,
         is_alive: true,
+        channel_turns: 0,
+        boss_phase: BossPhase::PhaseOne,
+    }
+}
+
+/// Enrage a boss once its health falls to half.
+///
+/// # Arguments
+///
+/// * `enemy` - Enemy state
+///
+/// # Returns
+///
+/// * `EnemyState` - Boss in phase two with doubled attack and extra
+///   defense, or the enemy unchanged
+fn maybe_enter_phase_two(enemy: EnemyState) -> EnemyState {
+    let enraged = enemy.kind == EnemyKind::Boss
+        && enemy.boss_phase == BossPhase::PhaseOne
+        && u64::from(enemy.health) * 100
+            <= u64::from(enemy.max_health) * u64::from(BOSS_PHASE_TWO_THRESHOLD);
+    if !enraged {
+        return enemy;
+    }
+    EnemyState {
+        attack: enemy.attack * 2,
+        defense: enemy.defense + BOSS_PHASE_TWO_DEFENSE,
+        current_behavior: Behavior::BossRampage,
+        boss_phase: BossPhase::PhaseTwo,
+        ..enemy
+    }
+}
+
+/// Calculate rampage movement, charging two tiles toward the player.
+///
+/// # Arguments
+///
+/// * `enemy` - Enemy state
+/// * `player_pos` - Player position
+///
+/// # Returns
+///
+/// * `AiPosition` - New position two steps toward the player
+fn rampage_movement(enemy: &EnemyState, player_pos: &AiPosition) -> AiPosition {
+    let first = chase_movement(enemy, player_pos);
+    let charging = EnemyState {
+        pos: Position {
+            x: first.x,
+            y: first.y,
+        },
+        ..*enemy
+    };
+    chase_movement(&charging, player_pos)
+}
+
+/// Rate how dangerous an enemy is to the player.
+///
+/// An enemy is deadly if three hits defeat the player or if it would
+/// defeat the player before the player defeats it.
+///
+/// # Arguments
+///
+/// * `player_stats` - Player health and attack
+/// * `enemy` - Enemy state
+///
+/// # Returns
+///
+/// * `ThreatLevel` - Threat the enemy poses
+fn get_enemy_threat_level(player_stats: &PlayerStats, enemy: &EnemyState) -> ThreatLevel {
+    let damage_to_enemy = player_stats.attack.saturating_sub(enemy.defense).max(1);
+    let hits_to_win = enemy.health.div_ceil(damage_to_enemy);
+    let hits_to_lose = player_stats.health.div_ceil(enemy.attack.max(1));
+    if enemy.attack * 3 > player_stats.health || hits_to_win > hits_to_lose {
+        ThreatLevel::Deadly
+    } else if enemy.attack * 5 > player_stats.health {
+        ThreatLevel::Dangerous
+    } else if enemy.attack * 10 > player_stats.health {
+        ThreatLevel::Manageable
+    } else {
+        ThreatLevel::Safe
     }
 }
 
@@ -240,6 +564,22 @@ fn manhattan_distance(enemy_pos: &Position, player_pos: &AiPosition) -> u32 {
     h_dist + v_dist
 }
 
+/// Calculate Chebyshev distance between positions.
+///
+/// # Arguments
+///
+/// * `enemy_pos` - Enemy position
+/// * `player_pos` - Player position
+///
+/// # Returns
+///
+/// * `u32` - Chebyshev distance
+fn chebyshev_distance(enemy_pos: &Position, player_pos: &AiPosition) -> u32 {
+    let h_dist = horizontal_distance(enemy_pos.x, player_pos.x);
+    let v_dist = vertical_distance(enemy_pos.y, player_pos.y);
+    h_dist.max(v_dist)
+}
+
 /// Calculate step toward player X coordinate.
 ///
 /// # Arguments
@@ -299,6 +639,189 @@ fn chase_movement(enemy: &EnemyState, player_pos: &AiPosition) -> AiPosition {
     }
 }
 
+/// Check whether a grid tile can be walked on.
+///
+/// # Arguments
+///
+/// * `grid` - Walkability grid indexed `[y][x]`
+/// * `pos` - Tile to check
+///
+/// # Returns
+///
+/// * `bool` - True if the tile is inside the grid and walkable
+fn is_open(grid: &[[bool; GRID_SIZE]; GRID_SIZE], pos: (i32, i32)) -> bool {
+    let size = GRID_SIZE as i32;
+    (0..size).contains(&pos.0) && (0..size).contains(&pos.1) && grid[pos.1 as usize][pos.0 as usize]
+}
+
+/// Find the shortest orthogonal path between two tiles with A*.
+///
+/// The goal tile is always enterable so enemies can path onto the player.
+///
+/// # Arguments
+///
+/// * `grid` - Walkability grid indexed `[y][x]`
+/// * `start` - Starting tile
+/// * `goal` - Target tile
+///
+/// # Returns
+///
+/// * `Vec<(i32, i32)>` - Path from start to goal inclusive, or empty if unreachable
+fn astar(
+    grid: &[[bool; GRID_SIZE]; GRID_SIZE],
+    start: (i32, i32),
+    goal: (i32, i32),
+) -> Vec<(i32, i32)> {
+    let heuristic = |(x, y): (i32, i32)| (x - goal.0).abs() + (y - goal.1).abs();
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+    let mut g_score: HashMap<(i32, i32), i32> = HashMap::new();
+    g_score.insert(start, 0);
+    open.push(Reverse((heuristic(start), start)));
+    while let Some(Reverse((_, current))) = open.pop() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut tile = current;
+            while let Some(&prev) = came_from.get(&tile) {
+                path.push(prev);
+                tile = prev;
+            }
+            path.reverse();
+            return path;
+        }
+        let g = g_score[&current];
+        for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+            let next = (current.0 + dx, current.1 + dy);
+            if next != goal && !is_open(grid, next) {
+                continue;
+            }
+            if g_score.get(&next).is_none_or(|&best| g + 1 < best) {
+                g_score.insert(next, g + 1);
+                came_from.insert(next, current);
+                open.push(Reverse((g + 1 + heuristic(next), next)));
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// Build a fixed-size walkability grid from tile rows.
+///
+/// Tiles missing from the rows are treated as blocked.
+///
+/// # Arguments
+///
+/// * `rows` - Walkable flags indexed `[y][x]`
+///
+/// # Returns
+///
+/// * `Box<[[bool; GRID_SIZE]; GRID_SIZE]>` - Walkability grid
+fn walkability_grid(rows: &[Vec<bool>]) -> Box<[[bool; GRID_SIZE]; GRID_SIZE]> {
+    let mut grid = Box::new([[false; GRID_SIZE]; GRID_SIZE]);
+    for (y, row) in rows.iter().take(GRID_SIZE).enumerate() {
+        for (x, &open) in row.iter().take(GRID_SIZE).enumerate() {
+            grid[y][x] = open;
+        }
+    }
+    grid
+}
+
+/// Calculate the next chase step along an A* path.
+///
+/// # Arguments
+///
+/// * `enemy` - Enemy state
+/// * `player_pos` - Player position
+/// * `rows` - Walkable flags indexed `[y][x]`
+///
+/// # Returns
+///
+/// * `Option<AiPosition>` - Next tile on the path, or None if there is no path
+fn pathfind_step(
+    enemy: &EnemyState,
+    player_pos: &AiPosition,
+    rows: &[Vec<bool>],
+) -> Option<AiPosition> {
+    let grid = walkability_grid(rows);
+    let path = astar(
+        &grid,
+        (enemy.pos.x, enemy.pos.y),
+        (player_pos.x, player_pos.y),
+    );
+    path.get(1).map(|&(x, y)| AiPosition { x, y })
+}
+
+/// Check whether the player can be seen from an enemy's tile.
+///
+/// Walks a Bresenham line between the two tiles; every tile strictly
+/// between them must be walkable.
+///
+/// # Arguments
+///
+/// * `enemy_pos` - Enemy position
+/// * `player_pos` - Player position
+/// * `rows` - Walkable flags indexed `[y][x]`; missing tiles block sight
+///
+/// # Returns
+///
+/// * `bool` - True if nothing blocks the line between enemy and player
+fn has_line_of_sight(enemy_pos: &Position, player_pos: &AiPosition, rows: &[Vec<bool>]) -> bool {
+    let open = |x: i32, y: i32| {
+        usize::try_from(y)
+            .ok()
+            .zip(usize::try_from(x).ok())
+            .and_then(|(y, x)| rows.get(y)?.get(x).copied())
+            .unwrap_or(false)
+    };
+    let (dx, dy) = (
+        (player_pos.x - enemy_pos.x).abs(),
+        -(player_pos.y - enemy_pos.y).abs(),
+    );
+    let (sx, sy) = (
+        step_toward_x(enemy_pos.x, player_pos.x),
+        step_toward_y(enemy_pos.y, player_pos.y),
+    );
+    let (mut x, mut y, mut err) = (enemy_pos.x, enemy_pos.y, dx + dy);
+    loop {
+        if (x, y) == (player_pos.x, player_pos.y) {
+            return true;
+        }
+        if (x, y) != (enemy_pos.x, enemy_pos.y) && !open(x, y) {
+            return false;
+        }
+        let doubled = 2 * err;
+        if doubled >= dy {
+            err += dy;
+            x += sx;
+        }
+        if doubled <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+}
+
+/// Calculate ranged movement position.
+///
+/// Ranged enemies back away when the player is too close, close in when
+/// the player is out of range and otherwise hold their ground.
+///
+/// # Arguments
+///
+/// * `enemy` - Enemy state
+/// * `player_pos` - Player position
+///
+/// # Returns
+///
+/// * `AiPosition` - New position keeping the player at range
+fn ranged_movement(enemy: &EnemyState, player_pos: &AiPosition) -> AiPosition {
+    match chebyshev_distance(&enemy.pos, player_pos) {
+        d if d < RANGED_MIN_DISTANCE => flee_movement(enemy, player_pos),
+        d if d > RANGED_ATTACK_RANGE => chase_movement(enemy, player_pos),
+        _ => guard_position(enemy),
+    }
+}
+
 /// Calculate flee movement position.
 ///
 /// # Arguments
@@ -323,13 +846,22 @@ fn flee_movement(enemy: &EnemyState, player_pos: &AiPosition) -> AiPosition {
 /// # Arguments
 ///
 /// * `enemy` - Enemy state
+/// * `turn_number` - Current turn, so the direction changes over time
 ///
 /// # Returns
 ///
 /// * `AiPosition` - Random wander position
-fn wander_movement(enemy: &EnemyState) -> AiPosition {
-    let offset = (enemy.pos.x + enemy.pos.y) % 4;
+fn wander_movement(enemy: &EnemyState, turn_number: u32) -> AiPosition {
+    let hash = enemy.pos.x.wrapping_mul(31)
+        ^ enemy.pos.y.wrapping_mul(17)
+        ^ (turn_number as i32).wrapping_mul(7);
+    let offset = hash.rem_euclid(4);
     let (dx, dy) = wander_offset(offset);
+    let (x, y) = (enemy.pos.x + dx, enemy.pos.y + dy);
+    if (x, y) != (enemy.last_pos.x, enemy.last_pos.y) {
+        return AiPosition { x, y };
+    }
+    let (dx, dy) = wander_offset((offset + 1) % 4);
     AiPosition {
         x: enemy.pos.x + dx,
         y: enemy.pos.y + dy,
@@ -376,19 +908,45 @@ fn guard_position(enemy: &EnemyState) -> AiPosition {
 ///
 /// * `enemy` - Enemy state
 /// * `player_pos` - Player position
+/// * `turn_number` - Current turn
 ///
 /// # Returns
 ///
 /// * `AiPosition` - New position based on behavior
-fn movement_by_behavior(enemy: &EnemyState, player_pos: &AiPosition) -> AiPosition {
+fn movement_by_behavior(
+    enemy: &EnemyState,
+    player_pos: &AiPosition,
+    turn_number: u32,
+) -> AiPosition {
     match enemy.current_behavior {
         Behavior::Chase => chase_movement(enemy, player_pos),
         Behavior::Flee => flee_movement(enemy, player_pos),
-        Behavior::Wander => wander_movement(enemy),
+        Behavior::Wander => wander_movement(enemy, turn_number),
         Behavior::Guard | Behavior::BossPattern => guard_position(enemy),
+        Behavior::BossRampage => rampage_movement(enemy, player_pos),
+        Behavior::Ranged => ranged_movement(enemy, player_pos),
     }
 }
 
+/// Calculate health as a percentage of the maximum.
+///
+/// Mirrors the player component's `health-percentage`.
+///
+/// # Arguments
+///
+/// * `current` - Current health
+/// * `max` - Maximum health
+///
+/// # Returns
+///
+/// * `u32` - Health percentage from 0 to 100
+fn health_percentage(current: u32, max: u32) -> u32 {
+    if max == 0 {
+        return 0;
+    }
+    (u64::from(current) * 100 / u64::from(max)).min(100) as u32
+}
+
 /// Check if enemy health is below flee threshold.
 ///
 /// # Arguments
@@ -399,8 +957,7 @@ fn movement_by_behavior(enemy: &EnemyState, player_pos: &AiPosition) -> AiPositi
 ///
 /// * `bool` - True if should flee
 fn is_low_health(enemy: &EnemyState) -> bool {
-    let health_percent = (enemy.health * 100) / enemy.max_health;
-    health_percent < FLEE_THRESHOLD
+    health_percentage(enemy.health, enemy.max_health) < FLEE_THRESHOLD
 }
 
 /// Determine new behavior based on state.
@@ -413,6 +970,9 @@ fn is_low_health(enemy: &EnemyState) -> bool {
 ///
 /// * `Behavior` - Updated behavior
 fn determine_behavior(enemy: &EnemyState) -> Behavior {
+    if enemy.boss_phase == BossPhase::PhaseTwo {
+        return Behavior::BossRampage;
+    }
     if is_low_health(enemy) && enemy.kind != EnemyKind::Boss {
         return Behavior::Flee;
     }
@@ -454,6 +1014,76 @@ fn apply_damage(enemy: EnemyState, damage: u32) -> EnemyState {
     }
 }
 
+/// Determine loot rarity from a percent roll and the player's luck.
+///
+/// # Arguments
+///
+/// * `roll` - Random roll, reduced to 0-99
+/// * `player_luck` - Player's luck stat
+///
+/// # Returns
+///
+/// * `LootRarity` - Rarity of the drop
+fn loot_rarity(roll: u32, player_luck: u32) -> LootRarity {
+    match roll % 100 {
+        r if r < RARE_DROP_ROLL => LootRarity::Rare,
+        r if r < UNCOMMON_DROP_ROLL => LootRarity::Uncommon,
+        _ if player_luck > LUCKY_DROP_THRESHOLD => LootRarity::Uncommon,
+        _ => LootRarity::Common,
+    }
+}
+
+/// Get the loot table for an enemy kind.
+///
+/// Item IDs match the inventory component.
+///
+/// # Arguments
+///
+/// * `kind` - Enemy kind
+///
+/// # Returns
+///
+/// * `LootTable` - Possible drops and their chances
+fn get_loot_table(kind: &EnemyKind) -> LootTable {
+    let drops = match kind {
+        EnemyKind::Slime => vec![(HEALTH_POTION_ID, 300)],
+        EnemyKind::Skeleton => vec![(STEEL_SWORD_ID, 200)],
+        EnemyKind::Bat => vec![(HEALTH_POTION_ID, 100)],
+        EnemyKind::Goblin => vec![(HEALTH_POTION_ID, 200), (ATTACK_BOOST_ID, 100)],
+        EnemyKind::DarkKnight => vec![(CHAIN_MAIL_ID, 250)],
+        EnemyKind::Boss => vec![(MASTER_SWORD_ID, 1000)],
+        EnemyKind::Dragon => vec![(FULL_HEALTH_POTION_ID, 1000)],
+        EnemyKind::Thief => vec![(MAGIC_MAP_ID, 150)],
+        EnemyKind::Wizard => vec![(FULL_HEALTH_POTION_ID, 250)],
+    };
+    LootTable { drops }
+}
+
+/// Roll which items of a loot table drop.
+///
+/// Each entry advances a linear congruential generator seeded with `seed`
+/// and drops when the roll falls below its chance.
+///
+/// # Arguments
+///
+/// * `table` - Loot table to roll
+/// * `seed` - Seed for the generator
+///
+/// # Returns
+///
+/// * `Vec<u32>` - IDs of the dropped items
+fn roll_loot(table: &LootTable, seed: u32) -> Vec<u32> {
+    let mut state = seed;
+    table
+        .drops
+        .iter()
+        .filter_map(|&(item_id, permille)| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            ((state >> 16) % 1000 < permille).then_some(item_id)
+        })
+        .collect()
+}
+
 /// Convert spawn position to types position.
 ///
 /// # Arguments
@@ -510,6 +1140,32 @@ impl SpawnGuest for Component {
         let pos = Position { x: 0, y: 0 };
         create_enemy_state(kind, pos)
     }
+
+    /// Get elemental resistances for an enemy kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Type of enemy
+    ///
+    /// # Returns
+    ///
+    /// * `Resistances` - Fire, ice and lightning resistance in percent
+    fn get_resistances(kind: EnemyKind) -> Resistances {
+        base_resistances(&kind)
+    }
+
+    /// Get the area an enemy kind calls home.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - Type of enemy
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - Home area name, or None for kinds that roam everywhere
+    fn get_home_area(kind: EnemyKind) -> Option<String> {
+        home_area(&kind).map(str::to_string)
+    }
 }
 
 impl AiGuest for Component {
@@ -519,12 +1175,46 @@ impl AiGuest for Component {
     ///
     /// * `enemy` - Enemy state
     /// * `player_pos` - Player position
+    /// * `grid` - Walkable flags indexed `[y][x]`
+    /// * `turn_number` - Current turn
     ///
     /// # Returns
     ///
     /// * `Position` - New position for enemy
-    fn calculate_move(enemy: EnemyState, player_pos: AiPosition) -> AiPosition {
-        movement_by_behavior(&enemy, &player_pos)
+    fn calculate_move(
+        enemy: EnemyState,
+        player_pos: AiPosition,
+        grid: Vec<Vec<bool>>,
+        turn_number: u32,
+    ) -> AiPosition {
+        let chasing = matches!(enemy.current_behavior, Behavior::Chase);
+        if chasing && manhattan_distance(&enemy.pos, &player_pos) <= ASTAR_RANGE {
+            if let Some(step) = pathfind_step(&enemy, &player_pos, &grid) {
+                return step;
+            }
+        }
+        movement_by_behavior(&enemy, &player_pos, turn_number)
+    }
+
+    /// Move the enemy and remember its previous position.
+    ///
+    /// # Arguments
+    ///
+    /// * `enemy` - Enemy state
+    /// * `new_pos` - Position to move to
+    ///
+    /// # Returns
+    ///
+    /// * `EnemyState` - Moved enemy state
+    fn move_enemy(enemy: EnemyState, new_pos: AiPosition) -> EnemyState {
+        EnemyState {
+            last_pos: enemy.pos,
+            pos: Position {
+                x: new_pos.x,
+                y: new_pos.y,
+            },
+            ..enemy
+        }
     }
 
     /// Determine if enemy should attack player.
@@ -533,13 +1223,20 @@ impl AiGuest for Component {
     ///
     /// * `enemy` - Enemy state
     /// * `player_pos` - Player position
+    /// * `grid` - Walkable flags indexed `[y][x]`, used for line of sight
     ///
     /// # Returns
     ///
     /// * `bool` - True if should attack
-    fn should_attack(enemy: EnemyState, player_pos: AiPosition) -> bool {
-        let distance = manhattan_distance(&enemy.pos, &player_pos);
-        distance <= ATTACK_RANGE
+    fn should_attack(enemy: EnemyState, player_pos: AiPosition, grid: Vec<Vec<bool>>) -> bool {
+        match attack_range_type(&enemy.kind) {
+            AttackRange::Orthogonal => manhattan_distance(&enemy.pos, &player_pos) <= ATTACK_RANGE,
+            AttackRange::Diagonal => chebyshev_distance(&enemy.pos, &player_pos) <= ATTACK_RANGE,
+            AttackRange::Ranged => {
+                chebyshev_distance(&enemy.pos, &player_pos) <= RANGED_ATTACK_RANGE
+                    && has_line_of_sight(&enemy.pos, &player_pos, &grid)
+            }
+        }
     }
 
     /// Update enemy behavior based on current state.
@@ -552,7 +1249,20 @@ impl AiGuest for Component {
     ///
     /// * `Behavior` - Updated behavior
     fn update_behavior(enemy: EnemyState) -> Behavior {
-        determine_behavior(&enemy)
+        determine_behavior(&maybe_enter_phase_two(enemy))
+    }
+
+    /// Enter the boss's second phase once it is at half health.
+    ///
+    /// # Arguments
+    ///
+    /// * `enemy` - Enemy state
+    ///
+    /// # Returns
+    ///
+    /// * `EnemyState` - Updated enemy state
+    fn maybe_enter_phase_two(enemy: EnemyState) -> EnemyState {
+        maybe_enter_phase_two(enemy)
     }
 
     /// Get attack damage for this enemy.
@@ -567,6 +1277,33 @@ impl AiGuest for Component {
     fn get_attack_damage(enemy: EnemyState) -> u32 {
         enemy.attack
     }
+
+    /// Advance the enemy's special ability by one turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `enemy` - Enemy state
+    ///
+    /// # Returns
+    ///
+    /// * `(EnemyState, Option<AbilityUse>)` - Updated enemy and the ability fired
+    fn use_special_ability(enemy: EnemyState) -> (EnemyState, Option<AbilityUse>) {
+        use_special_ability(enemy)
+    }
+
+    /// Rate how dangerous an enemy is to the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player_stats` - Player health and attack
+    /// * `enemy` - Enemy state
+    ///
+    /// # Returns
+    ///
+    /// * `ThreatLevel` - Threat the enemy poses
+    fn get_enemy_threat_level(player_stats: PlayerStats, enemy: EnemyState) -> ThreatLevel {
+        get_enemy_threat_level(&player_stats, &enemy)
+    }
 }
 
 impl DamageGuest for Component {
@@ -582,7 +1319,7 @@ impl DamageGuest for Component {
     /// * `EnemyState` - Updated enemy state
     fn take_damage(enemy: EnemyState, raw_damage: u32) -> EnemyState {
         let effective = calculate_effective_damage(raw_damage, enemy.defense);
-        apply_damage(enemy, effective)
+        maybe_enter_phase_two(apply_damage(enemy, effective))
     }
 
     /// Check if enemy is defeated.
@@ -610,6 +1347,34 @@ impl DamageGuest for Component {
     fn get_exp_reward(enemy: EnemyState) -> u32 {
         enemy.exp_reward
     }
+
+    /// Get the rarity of a loot drop.
+    ///
+    /// # Arguments
+    ///
+    /// * `roll` - Random roll
+    /// * `player_luck` - Player's luck stat
+    ///
+    /// # Returns
+    ///
+    /// * `LootRarity` - Rarity of the drop
+    fn get_loot_drops(roll: u32, player_luck: u32) -> LootRarity {
+        loot_rarity(roll, player_luck)
+    }
+
+    /// Roll the items a defeated enemy drops.
+    ///
+    /// # Arguments
+    ///
+    /// * `enemy` - Enemy state
+    /// * `seed` - Seed for the roll
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<u32>` - IDs of the dropped items
+    fn generate_loot(enemy: EnemyState, seed: u32) -> Vec<u32> {
+        roll_loot(&get_loot_table(&enemy.kind), seed)
+    }
 }
 
 #[cfg(test)]
@@ -637,6 +1402,16 @@ mod tests {
         assert_eq!(attack, 12);
     }
 
+    #[test]
+    /// Test skeletons resist fire and bats barely resist lightning.
+    fn test_base_resistances() {
+        let skeleton = base_resistances(&EnemyKind::Skeleton);
+        let bat = base_resistances(&EnemyKind::Bat);
+        assert!(skeleton.fire > skeleton.ice && skeleton.fire > skeleton.lightning);
+        assert_eq!(bat.lightning, 0);
+        assert_eq!(base_resistances(&EnemyKind::Dragon).fire, 100);
+    }
+
     #[test]
     /// Test dark knight base defense is correct.
     fn test_base_defense_dark_knight() {
@@ -800,7 +1575,11 @@ mod tests {
         let pos = Position { x: 5, y: 5 };
         let enemy = create_enemy_state(EnemyKind::Slime, pos);
         let player = AiPosition { x: 5, y: 6 };
-        assert!(<Component as AiGuest>::should_attack(enemy, player));
+        assert!(<Component as AiGuest>::should_attack(
+            enemy,
+            player,
+            corridor_rows()
+        ));
     }
 
     #[test]
@@ -809,7 +1588,11 @@ mod tests {
         let pos = Position { x: 0, y: 0 };
         let enemy = create_enemy_state(EnemyKind::Slime, pos);
         let player = AiPosition { x: 10, y: 10 };
-        assert!(!<Component as AiGuest>::should_attack(enemy, player));
+        assert!(!<Component as AiGuest>::should_attack(
+            enemy,
+            player,
+            corridor_rows()
+        ));
     }
 
     #[test]
@@ -856,4 +1639,355 @@ mod tests {
         let exp = <Component as DamageGuest>::get_exp_reward(enemy);
         assert_eq!(exp, 100);
     }
+
+    #[test]
+    /// Test high luck hits the uncommon threshold more often.
+    fn test_get_loot_drops_luck() {
+        let uncommon = |luck| {
+            (0..100)
+                .filter(|&r| {
+                    <Component as DamageGuest>::get_loot_drops(r, luck) == LootRarity::Uncommon
+                })
+                .count()
+        };
+        assert_eq!(uncommon(5), 20);
+        assert_eq!(uncommon(20), 95);
+        assert_eq!(loot_rarity(0, 20), LootRarity::Rare);
+    }
+
+    #[test]
+    /// Test bats are faster than a default-speed player.
+    fn test_base_speed_bat() {
+        let result = base_speed(&EnemyKind::Bat);
+        assert_eq!(result, 15);
+    }
+
+    #[test]
+    /// Test only diagonal-range enemies attack a diagonal neighbour.
+    fn test_should_attack_diagonal_range() {
+        let pos = Position { x: 5, y: 5 };
+        let player = AiPosition { x: 6, y: 6 };
+        assert_eq!(manhattan_distance(&pos, &player), 2);
+        assert_eq!(chebyshev_distance(&pos, &player), 1);
+        let skeleton = create_enemy_state(EnemyKind::Skeleton, Position { x: 5, y: 5 });
+        let knight = create_enemy_state(EnemyKind::DarkKnight, pos);
+        assert!(!<Component as AiGuest>::should_attack(
+            skeleton,
+            AiPosition { x: 6, y: 6 },
+            corridor_rows()
+        ));
+        assert!(<Component as AiGuest>::should_attack(
+            knight,
+            player,
+            corridor_rows()
+        ));
+        assert_eq!(attack_range_type(&EnemyKind::Boss), AttackRange::Diagonal);
+    }
+
+    #[test]
+    /// Test wandering enemies do not step straight back.
+    fn test_wander_movement_avoids_backtracking() {
+        let enemy = create_enemy_state(EnemyKind::Slime, Position { x: 4, y: 4 });
+        let next = wander_movement(&enemy, 0);
+        assert_eq!((next.x, next.y), (5, 4));
+        let moved = <Component as AiGuest>::move_enemy(enemy, next);
+        assert_eq!((moved.last_pos.x, moved.last_pos.y), (4, 4));
+        for turn in 0..8 {
+            let next = wander_movement(&moved, turn);
+            assert_ne!((next.x, next.y), (4, 4));
+        }
+    }
+
+    #[test]
+    /// Test wandering changes direction from turn to turn.
+    fn test_wander_movement_varies_by_turn() {
+        let enemy = create_enemy_state(EnemyKind::Slime, Position { x: 4, y: 4 });
+        let mut moves: Vec<(i32, i32)> = (1..=4)
+            .map(|turn| {
+                let next = wander_movement(&enemy, turn);
+                (next.x, next.y)
+            })
+            .collect();
+        moves.sort_unstable();
+        moves.dedup();
+        assert!(moves.len() >= 2);
+    }
+
+    #[test]
+    /// Test a boss is deadly to a level 1 player.
+    fn test_threat_level_boss_deadly() {
+        let player = PlayerStats {
+            health: 100,
+            attack: 10,
+        };
+        let boss = create_boss_state(Position { x: 0, y: 0 });
+        let threat = <Component as AiGuest>::get_enemy_threat_level(player, boss);
+        assert_eq!(threat, ThreatLevel::Deadly);
+    }
+
+    #[test]
+    /// Test threat levels follow the enemy's attack against player health.
+    fn test_threat_level_thresholds() {
+        let player = PlayerStats {
+            health: 100,
+            attack: 50,
+        };
+        let pos = Position { x: 0, y: 0 };
+        let mut enemy = create_enemy_state(EnemyKind::Slime, pos);
+        assert_eq!(get_enemy_threat_level(&player, &enemy), ThreatLevel::Safe);
+        enemy.attack = 12;
+        assert_eq!(
+            get_enemy_threat_level(&player, &enemy),
+            ThreatLevel::Manageable
+        );
+        enemy.attack = 25;
+        assert_eq!(
+            get_enemy_threat_level(&player, &enemy),
+            ThreatLevel::Dangerous
+        );
+        enemy.attack = 34;
+        assert_eq!(get_enemy_threat_level(&player, &enemy), ThreatLevel::Deadly);
+    }
+
+    #[test]
+    /// Test health percentage matches the player component.
+    fn test_health_percentage() {
+        assert_eq!(health_percentage(0, 100), 0);
+        assert_eq!(health_percentage(100, 100), 100);
+        assert_eq!(health_percentage(25, 100), 25);
+        assert_eq!(health_percentage(25, 0), 0);
+    }
+
+    #[test]
+    /// Test dragon stats and home area.
+    fn test_dragon_stats() {
+        let dragon = create_enemy_state(EnemyKind::Dragon, Position { x: 0, y: 0 });
+        assert_eq!(
+            (
+                dragon.health,
+                dragon.attack,
+                dragon.defense,
+                dragon.exp_reward
+            ),
+            (150, 25, 12, 75)
+        );
+        assert_eq!(dragon.current_behavior, Behavior::BossPattern);
+        assert_eq!(
+            <Component as SpawnGuest>::get_home_area(EnemyKind::Dragon),
+            Some("Death Mountain".to_string())
+        );
+        assert_eq!(
+            <Component as SpawnGuest>::get_home_area(EnemyKind::Slime),
+            None
+        );
+    }
+
+    #[test]
+    /// Test the dragon channels for two turns and breathes fire on the third.
+    fn test_dragon_fire_breath_channel() {
+        let dragon = create_enemy_state(EnemyKind::Dragon, Position { x: 0, y: 0 });
+        let (dragon, first) = use_special_ability(dragon);
+        assert!(first.is_none());
+        assert_eq!(dragon.current_behavior, Behavior::Guard);
+        let (dragon, second) = use_special_ability(dragon);
+        assert!(second.is_none());
+        assert_eq!(dragon.current_behavior, Behavior::Guard);
+        let (dragon, third) = use_special_ability(dragon);
+        let third = third.unwrap();
+        assert!(matches!(third.ability, EnemyAbility::FireBreath(30)));
+        assert!(matches!(
+            third.inflicts_status,
+            Some(StatusEffect::Burning(Burning {
+                turns_remaining: 3,
+                damage_per_turn: 5
+            }))
+        ));
+        assert_eq!(dragon.channel_turns, DRAGON_CHANNEL_TURNS);
+    }
+
+    #[test]
+    /// Test thief stats and behavior.
+    fn test_thief_stats() {
+        let thief = create_enemy_state(EnemyKind::Thief, Position { x: 0, y: 0 });
+        assert_eq!(
+            (thief.health, thief.attack, thief.defense, thief.exp_reward),
+            (25, 2, 1, 30)
+        );
+        assert_eq!(thief.current_behavior, Behavior::Chase);
+    }
+
+    #[test]
+    /// Test the boss enters phase two exactly at half health.
+    fn test_boss_phase_two_threshold() {
+        let boss = create_boss_state(Position { x: 0, y: 0 });
+        let above = maybe_enter_phase_two(EnemyState {
+            health: 101,
+            ..boss
+        });
+        assert_eq!(above.boss_phase, BossPhase::PhaseOne);
+        assert_eq!(above.attack, 30);
+        let half = maybe_enter_phase_two(EnemyState {
+            health: 100,
+            ..boss
+        });
+        assert_eq!(half.boss_phase, BossPhase::PhaseTwo);
+        assert_eq!((half.attack, half.defense), (60, 30));
+        assert_eq!(half.current_behavior, Behavior::BossRampage);
+        let again = maybe_enter_phase_two(half);
+        assert_eq!((again.attack, again.defense), (60, 30));
+    }
+
+    #[test]
+    /// Test update_behavior reports the rampage for a wounded boss.
+    fn test_update_behavior_boss_rampage() {
+        let boss = create_boss_state(Position { x: 0, y: 0 });
+        let wounded = EnemyState { health: 90, ..boss };
+        assert_eq!(
+            <Component as AiGuest>::update_behavior(boss),
+            Behavior::BossPattern
+        );
+        assert_eq!(
+            <Component as AiGuest>::update_behavior(wounded),
+            Behavior::BossRampage
+        );
+    }
+
+    #[test]
+    /// Test a rampaging boss moves two tiles toward the player.
+    fn test_boss_rampage_moves_two_tiles() {
+        let boss = maybe_enter_phase_two(EnemyState {
+            health: 50,
+            ..create_boss_state(Position { x: 0, y: 0 })
+        });
+        let target = AiPosition { x: 5, y: 0 };
+        let next = movement_by_behavior(&boss, &target, 1);
+        assert_eq!((next.x, next.y), (2, 0));
+    }
+
+    #[test]
+    /// Test non-boss enemies never enter phase two.
+    fn test_phase_two_boss_only() {
+        let goblin = EnemyState {
+            health: 1,
+            ..create_enemy_state(EnemyKind::Goblin, Position { x: 0, y: 0 })
+        };
+        assert_eq!(
+            maybe_enter_phase_two(goblin).boss_phase,
+            BossPhase::PhaseOne
+        );
+    }
+
+    /// Helper to build a 10x10 map walled off at x = 5 except for row 9.
+    fn corridor_rows() -> Vec<Vec<bool>> {
+        (0..10)
+            .map(|y| (0..10).map(|x| x != 5 || y == 9).collect())
+            .collect()
+    }
+
+    #[test]
+    /// Test A* routes around a wall through the corridor gap.
+    fn test_astar_around_wall() {
+        let grid = walkability_grid(&corridor_rows());
+        let path = astar(&grid, (3, 2), (7, 2));
+        assert_eq!(path.first(), Some(&(3, 2)));
+        assert_eq!(path.last(), Some(&(7, 2)));
+        assert!(path.contains(&(5, 9)));
+        assert!(path.iter().all(|&tile| is_open(&grid, tile)));
+        assert_eq!(path.len(), 19);
+    }
+
+    #[test]
+    /// Test A* returns an empty path when the goal is sealed off.
+    fn test_astar_unreachable() {
+        let rows: Vec<Vec<bool>> = (0..10).map(|_| (0..10).map(|x| x != 5).collect()).collect();
+        let grid = walkability_grid(&rows);
+        assert!(astar(&grid, (3, 2), (7, 2)).is_empty());
+    }
+
+    #[test]
+    /// Test a chasing enemy steps around a wall instead of into it.
+    fn test_calculate_move_chase_uses_astar() {
+        let bat = create_enemy_state(EnemyKind::Bat, Position { x: 4, y: 2 });
+        let player = AiPosition { x: 7, y: 2 };
+        let next = <Component as AiGuest>::calculate_move(bat, player, corridor_rows(), 1);
+        assert_eq!((next.x, next.y), (4, 3));
+        let far = AiPosition { x: 40, y: 2 };
+        let greedy = <Component as AiGuest>::calculate_move(bat, far, corridor_rows(), 1);
+        assert_eq!((greedy.x, greedy.y), (5, 2));
+    }
+
+    #[test]
+    /// Test the boss always drops the Master Sword.
+    fn test_boss_always_drops_master_sword() {
+        let boss = create_boss_state(Position { x: 0, y: 0 });
+        for seed in 0..200 {
+            let loot = <Component as DamageGuest>::generate_loot(boss, seed);
+            assert_eq!(loot, vec![MASTER_SWORD_ID]);
+        }
+    }
+
+    #[test]
+    /// Test a slime drops a potion about 30% of the time.
+    fn test_slime_potion_drop_rate() {
+        let table = get_loot_table(&EnemyKind::Slime);
+        let drops = (0..1000)
+            .filter(|&seed| roll_loot(&table, seed) == vec![HEALTH_POTION_ID])
+            .count();
+        assert!((250..=350).contains(&drops));
+        assert_eq!(roll_loot(&table, 7), roll_loot(&table, 7));
+    }
+
+    #[test]
+    /// Test a wizard attacks at range with a clear line of sight.
+    fn test_wizard_attacks_at_range() {
+        let wizard = create_enemy_state(EnemyKind::Wizard, Position { x: 0, y: 2 });
+        assert_eq!(wizard.current_behavior, Behavior::Ranged);
+        let player = AiPosition { x: 4, y: 2 };
+        assert!(has_line_of_sight(&wizard.pos, &player, &corridor_rows()));
+        assert!(<Component as AiGuest>::should_attack(
+            wizard,
+            player,
+            corridor_rows()
+        ));
+        assert_eq!(<Component as AiGuest>::get_attack_damage(wizard), 18);
+        let far = AiPosition { x: 0, y: 8 };
+        assert!(!<Component as AiGuest>::should_attack(
+            wizard,
+            far,
+            corridor_rows()
+        ));
+    }
+
+    #[test]
+    /// Test a wizard cannot attack through a wall.
+    fn test_wizard_blocked_by_wall() {
+        let wizard = create_enemy_state(EnemyKind::Wizard, Position { x: 3, y: 2 });
+        let player = AiPosition { x: 7, y: 4 };
+        assert!(!has_line_of_sight(&wizard.pos, &player, &corridor_rows()));
+        assert!(!<Component as AiGuest>::should_attack(
+            wizard,
+            player,
+            corridor_rows()
+        ));
+    }
+
+    #[test]
+    /// Test a wizard retreats from an adjacent player and holds at range.
+    fn test_wizard_retreats_when_close() {
+        let wizard = create_enemy_state(EnemyKind::Wizard, Position { x: 3, y: 4 });
+        let next = <Component as AiGuest>::calculate_move(
+            wizard,
+            AiPosition { x: 4, y: 4 },
+            corridor_rows(),
+            1,
+        );
+        assert_eq!((next.x, next.y), (2, 4));
+        let held = <Component as AiGuest>::calculate_move(
+            wizard,
+            AiPosition { x: 3, y: 8 },
+            corridor_rows(),
+            1,
+        );
+        assert_eq!((held.x, held.y), (3, 4));
+    }
 }
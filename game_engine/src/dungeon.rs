@@ -0,0 +1,291 @@
+//! # Dungeon Floors for the Game Engine
+//!
+//! This module generates dungeon floors procedurally so every run below a
+//! dungeon entrance has its own layout.
+//!
+//! ## Generation
+//!
+//! A 40x40 grid is split with a binary space partition until the floor has
+//! enough leaves, then a room is carved in each leaf. Whenever a leaf is
+//! split, the rooms of its two halves are joined by an L-shaped corridor,
+//! so every room can be reached from the entrance room (the first room).
+
+pub use crate::bindings::exports::docs::game_engine::types::DungeonFloor;
+use crate::bindings::exports::docs::game_engine::types::TileType;
+use crate::Rng;
+
+/// Width and height of a dungeon floor.
+pub const DUNGEON_SIZE: i32 = 40;
+
+/// Smallest width or height of a partition leaf.
+const MIN_LEAF_SIZE: i32 = 6;
+
+/// Smallest width or height of a room.
+const MIN_ROOM_SIZE: i32 = 3;
+
+/// A partition leaf as (x, y, width, height).
+type Leaf = (i32, i32, i32, i32);
+
+/// Roll a value in an inclusive range.
+///
+/// # Arguments
+///
+/// * `rng` - The generator to draw from
+/// * `low` - Smallest value
+/// * `high` - Largest value
+///
+/// # Returns
+///
+/// A value between `low` and `high`.
+fn roll(rng: &mut Rng, low: i32, high: i32) -> i32 {
+    low + (rng.next() % (high - low + 1) as u64) as i32
+}
+
+/// Get the range of room counts for a floor.
+///
+/// # Arguments
+///
+/// * `floor` - Dungeon floor number (1 = first floor)
+///
+/// # Returns
+///
+/// The smallest and largest room count as (min, max).
+fn room_count_range(floor: u32) -> (i32, i32) {
+    if floor <= 1 {
+        (5, 8)
+    } else {
+        (8, 12)
+    }
+}
+
+/// Split a leaf in two along its longer side.
+///
+/// # Arguments
+///
+/// * `rng` - The generator to draw from
+/// * `leaf` - The leaf as (x, y, width, height)
+///
+/// # Returns
+///
+/// The two halves, or `None` if the leaf is too small to split.
+fn split_leaf(rng: &mut Rng, (x, y, w, h): Leaf) -> Option<(Leaf, Leaf)> {
+    if w >= h && w >= MIN_LEAF_SIZE * 2 {
+        let cut = roll(rng, MIN_LEAF_SIZE, w - MIN_LEAF_SIZE);
+        Some(((x, y, cut, h), (x + cut, y, w - cut, h)))
+    } else if h >= MIN_LEAF_SIZE * 2 {
+        let cut = roll(rng, MIN_LEAF_SIZE, h - MIN_LEAF_SIZE);
+        Some(((x, y, w, cut), (x, y + cut, w, h - cut)))
+    } else {
+        None
+    }
+}
+
+/// Carve a room inside a leaf, leaving a one-tile margin.
+///
+/// # Arguments
+///
+/// * `rng` - The generator to draw from
+/// * `leaf` - The leaf as (x, y, width, height)
+///
+/// # Returns
+///
+/// The room as (x, y, width, height).
+fn carve_room(rng: &mut Rng, (x, y, w, h): Leaf) -> (i32, i32, u32, u32) {
+    let room_w = roll(rng, MIN_ROOM_SIZE, w - 2);
+    let room_h = roll(rng, MIN_ROOM_SIZE, h - 2);
+    let room_x = x + roll(rng, 1, w - room_w - 1);
+    let room_y = y + roll(rng, 1, h - room_h - 1);
+    (room_x, room_y, room_w as u32, room_h as u32)
+}
+
+/// Get the center tile of a room.
+///
+/// # Arguments
+///
+/// * `room` - The room as (x, y, width, height)
+///
+/// # Returns
+///
+/// The center as (x, y).
+pub fn room_center((x, y, w, h): (i32, i32, u32, u32)) -> (i32, i32) {
+    (x + w as i32 / 2, y + h as i32 / 2)
+}
+
+/// Generate a dungeon floor.
+///
+/// Floor 1 has 5-8 rooms; deeper floors have 8-12.
+///
+/// # Arguments
+///
+/// * `seed` - Seed for the layout
+/// * `floor` - Dungeon floor number (1 = first floor)
+///
+/// # Returns
+///
+/// The rooms and corridors of the floor.
+pub fn generate_dungeon(seed: u32, floor: u32) -> DungeonFloor {
+    let mut rng = Rng::new(((seed as u64) << 32) | floor as u64);
+    let (min_rooms, max_rooms) = room_count_range(floor);
+    let target = roll(&mut rng, min_rooms, max_rooms) as usize;
+    let mut leaves = vec![(1, 1, DUNGEON_SIZE - 2, DUNGEON_SIZE - 2)];
+    let mut links = Vec::new();
+    while leaves.len() < target {
+        let Some(index) = (0..leaves.len())
+            .filter(|&i| {
+                let (_, _, w, h) = leaves[i];
+                w >= MIN_LEAF_SIZE * 2 || h >= MIN_LEAF_SIZE * 2
+            })
+            .max_by_key(|&i| leaves[i].2 * leaves[i].3)
+        else {
+            break;
+        };
+        let Some((first, second)) = split_leaf(&mut rng, leaves[index]) else {
+            break;
+        };
+        leaves[index] = first;
+        leaves.push(second);
+        links.push((index, leaves.len() - 1));
+    }
+    let rooms: Vec<_> = leaves
+        .iter()
+        .map(|&leaf| carve_room(&mut rng, leaf))
+        .collect();
+    let mut corridors = Vec::new();
+    for (a, b) in links {
+        let (ax, ay) = room_center(rooms[a]);
+        let (bx, by) = room_center(rooms[b]);
+        corridors.push((ax, ay, bx, ay));
+        corridors.push((bx, ay, bx, by));
+    }
+    DungeonFloor { rooms, corridors }
+}
+
+/// Check whether a tile lies on a corridor segment.
+///
+/// # Arguments
+///
+/// * `corridor` - The segment as (x1, y1, x2, y2)
+/// * `x` - X coordinate
+/// * `y` - Y coordinate
+///
+/// # Returns
+///
+/// `true` if the tile is part of the corridor.
+fn on_corridor((x1, y1, x2, y2): (i32, i32, i32, i32), x: i32, y: i32) -> bool {
+    (x1.min(x2)..=x1.max(x2)).contains(&x) && (y1.min(y2)..=y1.max(y2)).contains(&y)
+}
+
+/// Get the tile at a position on a dungeon floor.
+///
+/// # Arguments
+///
+/// * `dungeon` - The dungeon floor
+/// * `x` - X coordinate
+/// * `y` - Y coordinate
+///
+/// # Returns
+///
+/// `TileType::DungeonEntrance` at the center of the entrance room (the
+/// way back up), `TileType::Grass` on room and corridor floors, and
+/// `TileType::Wall` everywhere else.
+pub fn dungeon_tile_at(dungeon: &DungeonFloor, x: i32, y: i32) -> TileType {
+    if dungeon.rooms.first().map(|&room| room_center(room)) == Some((x, y)) {
+        return TileType::DungeonEntrance;
+    }
+    let in_room = dungeon.rooms.iter().any(|&(rx, ry, w, h)| {
+        (rx..rx + w as i32).contains(&x) && (ry..ry + h as i32).contains(&y)
+    });
+    if in_room || dungeon.corridors.iter().any(|&c| on_corridor(c, x, y)) {
+        TileType::Grass
+    } else {
+        TileType::Wall
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Collect every open tile reachable from a start tile.
+    fn reachable(dungeon: &DungeonFloor, start: (i32, i32)) -> Vec<bool> {
+        let index = |(x, y): (i32, i32)| (y * DUNGEON_SIZE + x) as usize;
+        let mut seen = vec![false; (DUNGEON_SIZE * DUNGEON_SIZE) as usize];
+        let mut queue = VecDeque::from([start]);
+        seen[index(start)] = true;
+        while let Some((x, y)) = queue.pop_front() {
+            for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+                let next = (x + dx, y + dy);
+                let inside =
+                    (0..DUNGEON_SIZE).contains(&next.0) && (0..DUNGEON_SIZE).contains(&next.1);
+                if inside
+                    && !seen[index(next)]
+                    && !matches!(dungeon_tile_at(dungeon, next.0, next.1), TileType::Wall)
+                {
+                    seen[index(next)] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Test room counts per floor.
+    ///
+    /// Verifies that floor 1 has 5-8 rooms and floor 2 has 8-12, all
+    /// inside the grid.
+    #[test]
+    fn test_room_counts() {
+        for seed in 0..50 {
+            let first = generate_dungeon(seed, 1);
+            let second = generate_dungeon(seed, 2);
+            assert!((5..=8).contains(&first.rooms.len()));
+            assert!((8..=12).contains(&second.rooms.len()));
+            for &(x, y, w, h) in first.rooms.iter().chain(&second.rooms) {
+                assert!(x > 0 && y > 0);
+                assert!(x + (w as i32) < DUNGEON_SIZE && y + (h as i32) < DUNGEON_SIZE);
+            }
+        }
+    }
+
+    /// Test every room is reachable.
+    ///
+    /// Verifies that a path exists from the entrance room to every other room.
+    #[test]
+    fn test_rooms_connected() {
+        for seed in 0..50 {
+            for floor in 1..=2 {
+                let dungeon = generate_dungeon(seed, floor);
+                let seen = reachable(&dungeon, room_center(dungeon.rooms[0]));
+                for &room in &dungeon.rooms {
+                    let (x, y) = room_center(room);
+                    assert!(seen[(y * DUNGEON_SIZE + x) as usize]);
+                }
+            }
+        }
+    }
+
+    /// Test dungeon tiles.
+    ///
+    /// Verifies that the entrance room center leads back up, rooms are
+    /// floor and the border is wall.
+    #[test]
+    fn test_dungeon_tile_at() {
+        let dungeon = generate_dungeon(7, 1);
+        let (ex, ey) = room_center(dungeon.rooms[0]);
+        assert!(matches!(
+            dungeon_tile_at(&dungeon, ex, ey),
+            TileType::DungeonEntrance
+        ));
+        let (rx, ry, _, _) = dungeon.rooms[1];
+        assert!(matches!(dungeon_tile_at(&dungeon, rx, ry), TileType::Grass));
+        assert!(matches!(dungeon_tile_at(&dungeon, 0, 0), TileType::Wall));
+        let again = generate_dungeon(7, 1);
+        assert_eq!(again.rooms, dungeon.rooms);
+        assert_eq!(again.corridors, dungeon.corridors);
+    }
+}
@@ -0,0 +1,301 @@
+//! # Scripted Events for the Game Engine
+//!
+//! This module provides a table of per-position scripted events so that
+//! special locations can be described as data instead of hardcoded checks.
+//!
+//! ## Triggers
+//!
+//! - `OnEnter`: the player moves onto the position
+//! - `OnInteract`: the player interacts while standing on the position
+//! - `OnKill`: an enemy of the given kind is defeated at the position
+
+use crate::bindings::exports::docs::game_engine::types::GameState;
+
+/// Kinds of enemies that scripted events can reference.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnemyKind {
+    /// Weak enemy, easy to defeat.
+    Slime,
+    /// Medium enemy with bones.
+    Skeleton,
+    /// Fast flying enemy.
+    Bat,
+    /// Strong melee enemy.
+    Goblin,
+    /// Powerful elite enemy.
+    DarkKnight,
+    /// Boss enemy.
+    Boss,
+}
+
+/// Condition that fires a scripted event.
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventTrigger {
+    /// Player moves onto the position.
+    OnEnter,
+    /// Player interacts on the position.
+    OnInteract,
+    /// An enemy of the given kind is defeated on the position.
+    OnKill {
+        /// Kind of enemy that must be defeated.
+        enemy_kind: EnemyKind,
+    },
+}
+
+/// Effect applied when a scripted event fires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventEffect {
+    /// Spawn an enemy and start combat.
+    SpawnEnemy {
+        /// Kind of enemy to spawn.
+        kind: EnemyKind,
+    },
+    /// Show a message to the player.
+    SetMessage(&'static str),
+    /// Give the player an inventory item.
+    GiveItem {
+        /// Inventory item ID.
+        id: u32,
+    },
+    /// Move the player to a dungeon floor.
+    TransitionFloor(i32),
+}
+
+/// A scripted event bound to a map position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameEvent {
+    /// Unique event ID, recorded once the event has fired.
+    pub id: u32,
+    /// Position that hosts the event.
+    pub position: (i32, i32),
+    /// Condition that fires the event.
+    pub trigger: EventTrigger,
+    /// Effect of the event.
+    pub effect: EventEffect,
+}
+
+/// All scripted events in the world.
+pub const SCRIPTED_EVENTS: &[GameEvent] = &[
+    GameEvent {
+        id: 1,
+        position: (25, 25),
+        trigger: EventTrigger::OnEnter,
+        effect: EventEffect::SpawnEnemy {
+            kind: EnemyKind::Skeleton,
+        },
+    },
+    GameEvent {
+        id: 2,
+        position: (25, 25),
+        trigger: EventTrigger::OnInteract,
+        effect: EventEffect::GiveItem { id: 201 },
+    },
+    GameEvent {
+        id: 3,
+        position: (75, 75),
+        trigger: EventTrigger::OnEnter,
+        effect: EventEffect::TransitionFloor(1),
+    },
+    GameEvent {
+        id: 4,
+        position: (75, 75),
+        trigger: EventTrigger::OnKill {
+            enemy_kind: EnemyKind::Boss,
+        },
+        effect: EventEffect::GiveItem { id: 3 },
+    },
+    GameEvent {
+        id: 5,
+        position: (75, 75),
+        trigger: EventTrigger::OnKill {
+            enemy_kind: EnemyKind::DarkKnight,
+        },
+        effect: EventEffect::SetMessage("The dark knight's armor crumbles to dust."),
+    },
+    GameEvent {
+        id: 6,
+        position: (90, 10),
+        trigger: EventTrigger::OnEnter,
+        effect: EventEffect::SpawnEnemy {
+            kind: EnemyKind::Goblin,
+        },
+    },
+    GameEvent {
+        id: 7,
+        position: (10, 90),
+        trigger: EventTrigger::OnEnter,
+        effect: EventEffect::SpawnEnemy {
+            kind: EnemyKind::Bat,
+        },
+    },
+    GameEvent {
+        id: 8,
+        position: (60, 40),
+        trigger: EventTrigger::OnEnter,
+        effect: EventEffect::SpawnEnemy {
+            kind: EnemyKind::Slime,
+        },
+    },
+    GameEvent {
+        id: 9,
+        position: (50, 50),
+        trigger: EventTrigger::OnInteract,
+        effect: EventEffect::SetMessage("The shopkeeper greets you warmly."),
+    },
+];
+
+/// Get a display name for an enemy kind.
+///
+/// # Arguments
+///
+/// * `kind` - The enemy kind
+///
+/// # Returns
+///
+/// The enemy's name.
+pub fn enemy_kind_name(kind: EnemyKind) -> &'static str {
+    match kind {
+        EnemyKind::Slime => "Slime",
+        EnemyKind::Skeleton => "Skeleton",
+        EnemyKind::Bat => "Bat",
+        EnemyKind::Goblin => "Goblin",
+        EnemyKind::DarkKnight => "Dark Knight",
+        EnemyKind::Boss => "Boss",
+    }
+}
+
+/// Find the events fired at the player's position.
+///
+/// # Arguments
+///
+/// * `state` - The current game state
+/// * `trigger` - The trigger that occurred
+///
+/// # Returns
+///
+/// Every matching event, in table order.
+pub fn fired_scripted_events(state: &GameState, trigger: EventTrigger) -> Vec<&'static GameEvent> {
+    let position = (state.player_x, state.player_y);
+    SCRIPTED_EVENTS
+        .iter()
+        .filter(|event| event.position == position && event.trigger == trigger)
+        .collect()
+}
+
+/// Collect the effects of events fired at the player's position.
+///
+/// # Arguments
+///
+/// * `state` - The current game state
+/// * `trigger` - The trigger that occurred
+///
+/// # Returns
+///
+/// The effects of every matching event, in table order.
+pub fn check_scripted_events(state: &GameState, trigger: EventTrigger) -> Vec<EventEffect> {
+    fired_scripted_events(state, trigger)
+        .into_iter()
+        .map(|event| event.effect)
+        .collect()
+}
+
+/// Describe what a scripted event does.
+///
+/// # Arguments
+///
+/// * `event` - The event to describe
+///
+/// # Returns
+///
+/// A short description of the event's trigger and effect.
+pub fn describe_event(event: &GameEvent) -> String {
+    let trigger = match event.trigger {
+        EventTrigger::OnEnter => "Entering".to_string(),
+        EventTrigger::OnInteract => "Interacting".to_string(),
+        EventTrigger::OnKill { enemy_kind } => {
+            format!("Defeating a {}", enemy_kind_name(enemy_kind))
+        }
+    };
+    let effect = match event.effect {
+        EventEffect::SpawnEnemy { kind } => format!("spawns a {}", enemy_kind_name(kind)),
+        EventEffect::SetMessage(text) => format!("shows \"{}\"", text),
+        EventEffect::GiveItem { id } => format!("gives item #{}", id),
+        EventEffect::TransitionFloor(floor) => format!("leads to floor {}", floor),
+    };
+    format!("{} {}", trigger, effect)
+}
+
+/// Check if any scripted event is bound to a position.
+///
+/// # Arguments
+///
+/// * `x` - X coordinate
+/// * `y` - Y coordinate
+///
+/// # Returns
+///
+/// `true` if at least one event uses this position.
+pub fn has_scripted_event(x: i32, y: i32) -> bool {
+    SCRIPTED_EVENTS.iter().any(|event| event.position == (x, y))
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test the event table size.
+    ///
+    /// Verifies that at least five events are defined.
+    #[test]
+    fn test_scripted_events_defined() {
+        assert!(SCRIPTED_EVENTS.len() >= 5);
+    }
+
+    /// Test event IDs are unique.
+    ///
+    /// Verifies that no two scripted events share an ID.
+    #[test]
+    fn test_event_ids_unique() {
+        for (i, event) in SCRIPTED_EVENTS.iter().enumerate() {
+            assert!(SCRIPTED_EVENTS[i + 1..]
+                .iter()
+                .all(|other| other.id != event.id));
+        }
+    }
+
+    /// Test kill triggers match on enemy kind.
+    ///
+    /// Verifies that only the matching enemy kind is treated as equal.
+    #[test]
+    fn test_on_kill_trigger_kind() {
+        let boss = EventTrigger::OnKill {
+            enemy_kind: EnemyKind::Boss,
+        };
+        let slime = EventTrigger::OnKill {
+            enemy_kind: EnemyKind::Slime,
+        };
+        assert_ne!(boss, slime);
+        assert!(has_scripted_event(75, 75));
+        assert!(!has_scripted_event(60, 60));
+    }
+
+    /// Test event descriptions.
+    ///
+    /// Verifies that descriptions mention the trigger and the effect.
+    #[test]
+    fn test_describe_event() {
+        assert_eq!(
+            describe_event(&SCRIPTED_EVENTS[0]),
+            "Entering spawns a Skeleton"
+        );
+        assert_eq!(
+            describe_event(&SCRIPTED_EVENTS[3]),
+            "Defeating a Boss gives item #3"
+        );
+    }
+}
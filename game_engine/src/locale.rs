@@ -0,0 +1,504 @@
+//! # Localization for the Game Engine
+//!
+//! This module provides keyed lookups for all player-facing engine messages
+//! so they can be displayed in more than one language.
+//!
+//! ## Supported Languages
+//!
+//! - `en`: English (default and fallback)
+//! - `es`: Spanish
+
+/// Keys identifying each unique player-facing message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocaleKey {
+    /// Player moved north.
+    MovedNorth,
+    /// Player moved south.
+    MovedSouth,
+    /// Player moved east.
+    MovedEast,
+    /// Player moved west.
+    MovedWest,
+    /// Player moved north-east.
+    MovedNorthEast,
+    /// Player moved south-east.
+    MovedSouthEast,
+    /// Player moved south-west.
+    MovedSouthWest,
+    /// Player moved north-west.
+    MovedNorthWest,
+    /// Player ran north.
+    RanNorth,
+    /// Player ran south.
+    RanSouth,
+    /// Player ran east.
+    RanEast,
+    /// Player ran west.
+    RanWest,
+    /// Not enough stamina to run.
+    OutOfStamina,
+    /// Movement blocked by impassable terrain.
+    PathBlocked,
+    /// Not enough movement points for the destination tile.
+    TooExhausted,
+    /// Carrying too much weight to move at all.
+    Overloaded,
+    /// Player set the tile ahead on fire.
+    FireHazardPlaced,
+    /// Fire hazards need the Fire Rod.
+    NeedFireRod,
+    /// Attacking is not possible in the current phase.
+    CannotAttackNow,
+    /// Player swung their sword.
+    AttackSwing,
+    /// Player used an item.
+    ItemUsed,
+    /// Item use attempted with an empty inventory.
+    NoItems,
+    /// Player interacted with the environment.
+    Interacted,
+    /// Interacting again too soon.
+    NeedAMoment,
+    /// Player took a defensive stance.
+    StanceTaken,
+    /// Player crossed water with the HookShot.
+    HookshotSwing,
+    /// Attacking or using items while holding a defensive stance.
+    HoldingStance,
+    /// Player lost their turn to a stun.
+    Stunned,
+    /// Moving while frozen.
+    Frozen,
+    /// Inventory is being opened.
+    InventoryOpening,
+    /// Player waited a turn.
+    Waited,
+    /// Player quit the game.
+    ThanksForPlaying,
+    /// Player ran out of health.
+    PlayerFell,
+    /// Player won by defeating the boss.
+    BossVictory,
+    /// Action attempted after the game was won.
+    GameWon,
+    /// Player moved within the boss warning radius.
+    BossPresence,
+    /// Player moved next to the boss.
+    BossStandBefore,
+    /// Player prestiged back to level 1.
+    Prestiged,
+    /// Prestige requirements are not met.
+    CannotPrestige,
+    /// Player paused the game.
+    GamePaused,
+    /// Action attempted while the game is paused.
+    GameIsPaused,
+    /// Player resumed the game.
+    GameResumed,
+    /// Resume attempted while the game is not paused.
+    NotPaused,
+    /// Player confirmed a pending level up.
+    LevelUpConfirmed,
+    /// Level up confirmation attempted without one pending.
+    NoLevelUpPending,
+    /// Player entered the shop.
+    ShopWelcome,
+    /// Player bought an item.
+    ShopBought,
+    /// Player picked an item the shop does not sell.
+    ShopNoSuchItem,
+    /// Player tried to buy away from a shop.
+    NotShopping,
+    /// A scripted event spawned an enemy.
+    EventEnemyAppears,
+    /// A scripted event gave the player an item.
+    EventItemReceived,
+    /// A scripted event moved the player to another floor.
+    EventFloorChanged,
+    /// Player cannot afford an item.
+    ShopNotEnoughGold,
+    /// Health label in the status line.
+    StatusHp,
+    /// Level label in the status line.
+    StatusLevel,
+    /// Area label in the status line.
+    StatusArea,
+    /// Turn label in the status line.
+    StatusTurn,
+    /// Experience label in the status line.
+    StatusXp,
+    /// Compass label in the status line.
+    StatusCompass,
+    /// Marker for areas with harsh terrain.
+    StatusHarshTerrain,
+    /// Marker for carrying more than the weight capacity.
+    StatusEncumbered,
+    /// Marker for encounters being held off after fleeing.
+    StatusSafe,
+    /// Notice that a level up is waiting to be confirmed.
+    StatusLevelUpReady,
+    /// Shown instead of experience once the level cap is reached.
+    StatusMaxLevel,
+    /// Marker for holding a defensive stance.
+    StatusDefending,
+    /// Boss label in the status line.
+    StatusBoss,
+    /// Distance unit after the boss label.
+    StatusTilesAway,
+    /// Heading of the compass event list.
+    CompassReading,
+    /// Compass found no events in the area.
+    CompassNothing,
+    /// Marker for events that have already fired.
+    EventTriggered,
+    /// Help screen title.
+    HelpTitle,
+    /// Help line for movement.
+    HelpMovement,
+    /// Help line for combat.
+    HelpCombat,
+    /// Help line for items.
+    HelpItems,
+    /// Help line for other commands.
+    HelpOther,
+}
+
+/// English message table.
+pub const STRINGS_EN: &[(LocaleKey, &str)] = &[
+    (LocaleKey::MovedNorth, "You move north."),
+    (LocaleKey::MovedSouth, "You move south."),
+    (LocaleKey::MovedEast, "You move east."),
+    (LocaleKey::MovedWest, "You move west."),
+    (LocaleKey::MovedNorthEast, "You move north-east."),
+    (LocaleKey::MovedSouthEast, "You move south-east."),
+    (LocaleKey::MovedSouthWest, "You move south-west."),
+    (LocaleKey::MovedNorthWest, "You move north-west."),
+    (LocaleKey::RanNorth, "You run north."),
+    (LocaleKey::RanSouth, "You run south."),
+    (LocaleKey::RanEast, "You run east."),
+    (LocaleKey::RanWest, "You run west."),
+    (LocaleKey::OutOfStamina, "You are too winded to run!"),
+    (LocaleKey::PathBlocked, "You can't go that way!"),
+    (LocaleKey::FireHazardPlaced, "Flames spring up ahead!"),
+    (LocaleKey::NeedFireRod, "You need the Fire Rod equipped!"),
+    (LocaleKey::TooExhausted, "Too exhausted to push through!"),
+    (LocaleKey::Overloaded, "You are carrying too much to move!"),
+    (LocaleKey::CannotAttackNow, "You can't attack right now!"),
+    (LocaleKey::AttackSwing, "You swing your sword!"),
+    (LocaleKey::ItemUsed, "You use an item."),
+    (LocaleKey::NoItems, "You have no items to use."),
+    (LocaleKey::Interacted, "You interact with the environment."),
+    (LocaleKey::NeedAMoment, "You need a moment."),
+    (LocaleKey::StanceTaken, "You raise your guard."),
+    (
+        LocaleKey::HookshotSwing,
+        "You swing across on your hookshot!",
+    ),
+    (
+        LocaleKey::HoldingStance,
+        "You are holding a defensive stance!",
+    ),
+    (LocaleKey::Stunned, "You are stunned and cannot act!"),
+    (LocaleKey::Frozen, "You are frozen in place!"),
+    (LocaleKey::InventoryOpening, "Opening inventory..."),
+    (LocaleKey::Waited, "You wait..."),
+    (LocaleKey::ThanksForPlaying, "Thanks for playing!"),
+    (LocaleKey::PlayerFell, "You collapse. Game over."),
+    (
+        LocaleKey::BossVictory,
+        "The boss has fallen. Victory is yours!",
+    ),
+    (LocaleKey::GameWon, "The game is already won."),
+    (
+        LocaleKey::BossPresence,
+        "You sense a dark presence nearby...",
+    ),
+    (LocaleKey::BossStandBefore, "YOU STAND BEFORE GANON!"),
+    (
+        LocaleKey::Prestiged,
+        "You prestige and grow permanently stronger!",
+    ),
+    (
+        LocaleKey::CannotPrestige,
+        "Reach the level cap while exploring to prestige!",
+    ),
+    (LocaleKey::GamePaused, "Game paused."),
+    (LocaleKey::GameIsPaused, "Game is paused."),
+    (LocaleKey::GameResumed, "Game resumed."),
+    (LocaleKey::NotPaused, "The game is not paused."),
+    (LocaleKey::LevelUpConfirmed, "LEVEL UP! You feel stronger."),
+    (LocaleKey::NoLevelUpPending, "No level up is ready."),
+    (
+        LocaleKey::ShopWelcome,
+        "Welcome to the shop! Choose an item to buy.",
+    ),
+    (LocaleKey::ShopBought, "You bought"),
+    (LocaleKey::ShopNoSuchItem, "The shop doesn't sell that."),
+    (LocaleKey::NotShopping, "You are not in a shop."),
+    (LocaleKey::EventEnemyAppears, "An enemy appears:"),
+    (LocaleKey::EventItemReceived, "You received item"),
+    (LocaleKey::EventFloorChanged, "You arrive on floor"),
+    (LocaleKey::ShopNotEnoughGold, "You don't have enough gold!"),
+    (LocaleKey::StatusHp, "HP"),
+    (LocaleKey::StatusLevel, "Lvl"),
+    (LocaleKey::StatusArea, "Area"),
+    (LocaleKey::StatusTurn, "Turn"),
+    (LocaleKey::StatusXp, "XP"),
+    (LocaleKey::StatusCompass, "Nearest dungeon"),
+    (LocaleKey::StatusHarshTerrain, "(Harsh terrain)"),
+    (LocaleKey::StatusEncumbered, "(Encumbered)"),
+    (LocaleKey::StatusSafe, "(Safe)"),
+    (LocaleKey::StatusLevelUpReady, "*** LEVEL UP READY! ***"),
+    (LocaleKey::StatusMaxLevel, "MAX LEVEL"),
+    (LocaleKey::StatusDefending, "[DEFENDING]"),
+    (LocaleKey::StatusBoss, "Boss"),
+    (LocaleKey::StatusTilesAway, "tiles away"),
+    (LocaleKey::CompassReading, "The compass reveals events in"),
+    (
+        LocaleKey::CompassNothing,
+        "The compass finds nothing special here.",
+    ),
+    (LocaleKey::EventTriggered, "(done)"),
+    (LocaleKey::HelpTitle, "=== LEGEND OF WASM: HELP ==="),
+    (
+        LocaleKey::HelpMovement,
+        "Movement: n/s/e/w - Move in direction",
+    ),
+    (
+        LocaleKey::HelpCombat,
+        "Combat: a - Attack with equipped weapon",
+    ),
+    (
+        LocaleKey::HelpItems,
+        "Items: u - Use item, i - Open inventory",
+    ),
+    (
+        LocaleKey::HelpOther,
+        "Other: x - Interact, . - Wait, q - Quit",
+    ),
+];
+
+/// Spanish message table.
+pub const STRINGS_ES: &[(LocaleKey, &str)] = &[
+    (LocaleKey::MovedNorth, "Te mueves al norte."),
+    (LocaleKey::MovedSouth, "Te mueves al sur."),
+    (LocaleKey::MovedEast, "Te mueves al este."),
+    (LocaleKey::MovedWest, "Te mueves al oeste."),
+    (LocaleKey::MovedNorthEast, "Te mueves al noreste."),
+    (LocaleKey::MovedSouthEast, "Te mueves al sureste."),
+    (LocaleKey::MovedSouthWest, "Te mueves al suroeste."),
+    (LocaleKey::MovedNorthWest, "Te mueves al noroeste."),
+    (LocaleKey::RanNorth, "Corres al norte."),
+    (LocaleKey::RanSouth, "Corres al sur."),
+    (LocaleKey::RanEast, "Corres al este."),
+    (LocaleKey::RanWest, "Corres al oeste."),
+    (LocaleKey::OutOfStamina, "¡Estás sin aliento para correr!"),
+    (LocaleKey::PathBlocked, "¡No puedes ir por ahí!"),
+    (LocaleKey::FireHazardPlaced, "¡Brotan llamas delante de ti!"),
+    (
+        LocaleKey::NeedFireRod,
+        "¡Necesitas tener equipada la Vara de Fuego!",
+    ),
+    (
+        LocaleKey::TooExhausted,
+        "¡Estás demasiado agotado para avanzar!",
+    ),
+    (
+        LocaleKey::Overloaded,
+        "¡Llevas demasiado peso para moverte!",
+    ),
+    (LocaleKey::CannotAttackNow, "¡No puedes atacar ahora!"),
+    (LocaleKey::AttackSwing, "¡Blandes tu espada!"),
+    (LocaleKey::ItemUsed, "Usas un objeto."),
+    (LocaleKey::NoItems, "No tienes objetos que usar."),
+    (LocaleKey::Interacted, "Interactúas con el entorno."),
+    (LocaleKey::NeedAMoment, "Necesitas un momento."),
+    (LocaleKey::StanceTaken, "Levantas tu guardia."),
+    (
+        LocaleKey::HookshotSwing,
+        "¡Cruzas balanceándote con tu gancho!",
+    ),
+    (
+        LocaleKey::HoldingStance,
+        "¡Mantienes una postura defensiva!",
+    ),
+    (LocaleKey::Stunned, "¡Estás aturdido y no puedes actuar!"),
+    (LocaleKey::Frozen, "¡Estás congelado en el sitio!"),
+    (LocaleKey::InventoryOpening, "Abriendo inventario..."),
+    (LocaleKey::Waited, "Esperas..."),
+    (LocaleKey::ThanksForPlaying, "¡Gracias por jugar!"),
+    (LocaleKey::PlayerFell, "Te desplomas. Fin del juego."),
+    (
+        LocaleKey::BossVictory,
+        "El jefe ha caído. ¡La victoria es tuya!",
+    ),
+    (LocaleKey::GameWon, "La partida ya está ganada."),
+    (
+        LocaleKey::BossPresence,
+        "Sientes una presencia oscura cerca...",
+    ),
+    (LocaleKey::BossStandBefore, "¡ESTÁS ANTE GANON!"),
+    (
+        LocaleKey::Prestiged,
+        "¡Renaces y te vuelves más fuerte para siempre!",
+    ),
+    (
+        LocaleKey::CannotPrestige,
+        "¡Alcanza el nivel máximo explorando para renacer!",
+    ),
+    (LocaleKey::GamePaused, "Juego en pausa."),
+    (LocaleKey::GameIsPaused, "El juego está en pausa."),
+    (LocaleKey::GameResumed, "Juego reanudado."),
+    (LocaleKey::NotPaused, "El juego no está en pausa."),
+    (
+        LocaleKey::LevelUpConfirmed,
+        "¡SUBES DE NIVEL! Te sientes más fuerte.",
+    ),
+    (LocaleKey::NoLevelUpPending, "No hay subida de nivel lista."),
+    (
+        LocaleKey::ShopWelcome,
+        "¡Bienvenido a la tienda! Elige un objeto para comprar.",
+    ),
+    (LocaleKey::ShopBought, "Compraste"),
+    (LocaleKey::ShopNoSuchItem, "La tienda no vende eso."),
+    (LocaleKey::NotShopping, "No estás en una tienda."),
+    (LocaleKey::EventEnemyAppears, "Aparece un enemigo:"),
+    (LocaleKey::EventItemReceived, "Recibiste el objeto"),
+    (LocaleKey::EventFloorChanged, "Llegas al piso"),
+    (LocaleKey::ShopNotEnoughGold, "¡No tienes suficiente oro!"),
+    (LocaleKey::StatusHp, "PV"),
+    (LocaleKey::StatusLevel, "Nv"),
+    (LocaleKey::StatusArea, "Zona"),
+    (LocaleKey::StatusTurn, "Turno"),
+    (LocaleKey::StatusXp, "EXP"),
+    (LocaleKey::StatusCompass, "Mazmorra más cercana"),
+    (LocaleKey::StatusHarshTerrain, "(Terreno hostil)"),
+    (LocaleKey::StatusEncumbered, "(Sobrecargado)"),
+    (LocaleKey::StatusSafe, "(A salvo)"),
+    (
+        LocaleKey::StatusLevelUpReady,
+        "*** ¡SUBIDA DE NIVEL LISTA! ***",
+    ),
+    (LocaleKey::StatusMaxLevel, "NIVEL MÁXIMO"),
+    (LocaleKey::StatusDefending, "[DEFENDIENDO]"),
+    (LocaleKey::StatusBoss, "Jefe"),
+    (LocaleKey::StatusTilesAway, "casillas"),
+    (LocaleKey::CompassReading, "La brújula revela eventos en"),
+    (
+        LocaleKey::CompassNothing,
+        "La brújula no encuentra nada especial aquí.",
+    ),
+    (LocaleKey::EventTriggered, "(hecho)"),
+    (LocaleKey::HelpTitle, "=== LEGEND OF WASM: AYUDA ==="),
+    (
+        LocaleKey::HelpMovement,
+        "Movimiento: n/s/e/w - Moverse en una dirección",
+    ),
+    (
+        LocaleKey::HelpCombat,
+        "Combate: a - Atacar con el arma equipada",
+    ),
+    (
+        LocaleKey::HelpItems,
+        "Objetos: u - Usar objeto, i - Abrir inventario",
+    ),
+    (
+        LocaleKey::HelpOther,
+        "Otros: x - Interactuar, . - Esperar, q - Salir",
+    ),
+];
+
+/// Get the message table for a language code.
+///
+/// # Arguments
+///
+/// * `lang` - Language code such as `en` or `es`
+///
+/// # Returns
+///
+/// The matching table, or the English table for unknown languages.
+fn table_for(lang: &str) -> &'static [(LocaleKey, &'static str)] {
+    match lang {
+        "es" => STRINGS_ES,
+        _ => STRINGS_EN,
+    }
+}
+
+/// Look up a message in a table.
+///
+/// # Arguments
+///
+/// * `table` - The table to search
+/// * `key` - The message key
+///
+/// # Returns
+///
+/// The message text if the table contains the key.
+fn lookup(table: &'static [(LocaleKey, &'static str)], key: LocaleKey) -> Option<&'static str> {
+    table.iter().find(|(k, _)| *k == key).map(|(_, text)| *text)
+}
+
+/// Translate a message key into the given language.
+///
+/// Falls back to English when the language or key is not available.
+///
+/// # Arguments
+///
+/// * `key` - The message key
+/// * `lang` - Language code such as `en` or `es`
+///
+/// # Returns
+///
+/// The translated message text.
+pub fn translate(key: LocaleKey, lang: &str) -> &'static str {
+    lookup(table_for(lang), key)
+        .or_else(|| lookup(STRINGS_EN, key))
+        .unwrap_or("")
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test Spanish translation of a movement message.
+    ///
+    /// Verifies that the Spanish table returns Spanish text.
+    #[test]
+    fn test_translate_spanish_moved_north() {
+        assert_eq!(
+            translate(LocaleKey::MovedNorth, "es"),
+            "Te mueves al norte."
+        );
+    }
+
+    /// Test English translation of a movement message.
+    ///
+    /// Verifies that the English table returns English text.
+    #[test]
+    fn test_translate_english_moved_north() {
+        assert_eq!(translate(LocaleKey::MovedNorth, "en"), "You move north.");
+    }
+
+    /// Test fallback for unknown languages.
+    ///
+    /// Verifies that unsupported languages fall back to English.
+    #[test]
+    fn test_translate_unknown_language() {
+        assert_eq!(translate(LocaleKey::Waited, "xx"), "You wait...");
+    }
+
+    /// Test that both tables cover the same keys.
+    ///
+    /// Verifies that every English key has a Spanish translation.
+    #[test]
+    fn test_tables_complete() {
+        assert_eq!(STRINGS_EN.len(), STRINGS_ES.len());
+        for (key, _) in STRINGS_EN {
+            assert!(lookup(STRINGS_ES, *key).is_some());
+        }
+    }
+}
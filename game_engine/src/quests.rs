@@ -0,0 +1,268 @@
+//! # Quests for the Game Engine
+//!
+//! This module provides a table of built-in quests and the logic to start,
+//! progress and complete them against a `GameState`.
+//!
+//! ## Goals
+//!
+//! - `Kills`: defeat a number of enemies of one kind
+//! - `Gold`: gain the given amount of gold after taking the quest
+
+use crate::bindings::exports::docs::game_engine::types::{GameState, Quest, QuestStatus};
+use crate::events::EnemyKind;
+
+/// Enemy kinds in the order of their IDs, matching the enemy component.
+const ENEMY_KINDS: [EnemyKind; 6] = [
+    EnemyKind::Slime,
+    EnemyKind::Skeleton,
+    EnemyKind::Bat,
+    EnemyKind::Goblin,
+    EnemyKind::DarkKnight,
+    EnemyKind::Boss,
+];
+
+/// What a quest asks the player to do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuestGoal {
+    /// Defeat enemies of a kind.
+    Kills {
+        /// Kind of enemy to defeat.
+        kind: EnemyKind,
+        /// Number of enemies to defeat.
+        count: u32,
+    },
+    /// Gain this much gold after taking the quest.
+    Gold(u32),
+}
+
+/// A built-in quest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuestDef {
+    /// Unique quest ID.
+    pub id: u32,
+    /// Quest name.
+    pub name: &'static str,
+    /// What the player must do.
+    pub description: &'static str,
+    /// Goal that completes the quest.
+    pub goal: QuestGoal,
+    /// Gold paid on completion.
+    pub reward_gold: u32,
+}
+
+/// All built-in quests.
+pub const QUESTS: &[QuestDef] = &[
+    QuestDef {
+        id: 1,
+        name: "Slime Trouble",
+        description: "Slay 3 slimes.",
+        goal: QuestGoal::Kills {
+            kind: EnemyKind::Slime,
+            count: 3,
+        },
+        reward_gold: 50,
+    },
+    QuestDef {
+        id: 2,
+        name: "The Final Battle",
+        description: "Defeat the boss.",
+        goal: QuestGoal::Kills {
+            kind: EnemyKind::Boss,
+            count: 1,
+        },
+        reward_gold: 500,
+    },
+    QuestDef {
+        id: 3,
+        name: "Treasure Hunter",
+        description: "Collect 100 gold.",
+        goal: QuestGoal::Gold(100),
+        reward_gold: 25,
+    },
+];
+
+/// Look up a built-in quest.
+///
+/// # Arguments
+///
+/// * `quest_id` - The quest ID
+///
+/// # Returns
+///
+/// The quest definition, or `None` if the ID is unknown.
+pub fn find_quest(quest_id: u32) -> Option<&'static QuestDef> {
+    QUESTS.iter().find(|quest| quest.id == quest_id)
+}
+
+/// Get the enemy kind for an enemy kind ID.
+///
+/// # Arguments
+///
+/// * `enemy_kind_id` - Index of the kind in the enemy component's enum
+///
+/// # Returns
+///
+/// The enemy kind, or `None` if the ID is unknown.
+pub fn enemy_kind_from_id(enemy_kind_id: u32) -> Option<EnemyKind> {
+    ENEMY_KINDS.get(enemy_kind_id as usize).copied()
+}
+
+/// Start a quest, adding it to the active quests.
+///
+/// Unknown quests and quests already taken are ignored.
+///
+/// # Arguments
+///
+/// * `state` - The game state to update
+/// * `quest_id` - The quest ID
+pub fn start_quest(state: &mut GameState, quest_id: u32) {
+    let Some(def) = find_quest(quest_id) else {
+        return;
+    };
+    if state.active_quests.iter().any(|quest| quest.id == quest_id) {
+        return;
+    }
+    let required_kills = match def.goal {
+        QuestGoal::Kills { count, .. } => count,
+        QuestGoal::Gold(_) => 0,
+    };
+    state.active_quests.push(Quest {
+        id: def.id,
+        name: def.name.to_string(),
+        description: def.description.to_string(),
+        status: QuestStatus::InProgress,
+        required_kills,
+        kills_so_far: 0,
+        start_gold: state.player_gold,
+    });
+}
+
+/// Count a defeated enemy towards matching quests in progress.
+///
+/// # Arguments
+///
+/// * `state` - The game state to update
+/// * `enemy_kind_id` - Index of the defeated enemy's kind
+pub fn report_kill(state: &mut GameState, enemy_kind_id: u32) {
+    let Some(kind) = enemy_kind_from_id(enemy_kind_id) else {
+        return;
+    };
+    for quest in &mut state.active_quests {
+        let matches = matches!(
+            find_quest(quest.id).map(|def| def.goal),
+            Some(QuestGoal::Kills { kind: target, .. }) if target == kind
+        );
+        if matches && quest.status == QuestStatus::InProgress {
+            quest.kills_so_far = (quest.kills_so_far + 1).min(quest.required_kills);
+        }
+    }
+}
+
+/// Complete a quest whose goal is met and pay its reward.
+///
+/// # Arguments
+///
+/// * `state` - The game state to update
+/// * `quest_id` - The quest ID
+///
+/// # Returns
+///
+/// The gold reward, or 0 if the quest is not in progress or its goal is unmet.
+pub fn complete_quest(state: &mut GameState, quest_id: u32) -> u32 {
+    let Some(def) = find_quest(quest_id) else {
+        return 0;
+    };
+    let gold = state.player_gold;
+    let Some(quest) = state
+        .active_quests
+        .iter_mut()
+        .find(|quest| quest.id == quest_id && quest.status == QuestStatus::InProgress)
+    else {
+        return 0;
+    };
+    let goal_met = match def.goal {
+        QuestGoal::Kills { count, .. } => quest.kills_so_far >= count,
+        QuestGoal::Gold(amount) => gold.saturating_sub(quest.start_gold) >= amount,
+    };
+    if !goal_met {
+        return 0;
+    }
+    quest.status = QuestStatus::Complete;
+    state.player_gold = state.player_gold.saturating_add(def.reward_gold);
+    def.reward_gold
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test starting a quest.
+    ///
+    /// Verifies that a quest starts in progress and is only added once.
+    #[test]
+    fn test_start_quest() {
+        let mut state = crate::new_game_with_seed_impl(1);
+        start_quest(&mut state, 1);
+        start_quest(&mut state, 1);
+        start_quest(&mut state, 99);
+        assert_eq!(state.active_quests.len(), 1);
+        assert_eq!(state.active_quests[0].status, QuestStatus::InProgress);
+        assert_eq!(state.active_quests[0].required_kills, 3);
+    }
+
+    /// Test completing a kill quest.
+    ///
+    /// Verifies that the reward is paid and the status set to complete
+    /// only once enough matching enemies are defeated.
+    #[test]
+    fn test_complete_kill_quest() {
+        let mut state = crate::new_game_with_seed_impl(1);
+        let gold = state.player_gold;
+        start_quest(&mut state, 1);
+        report_kill(&mut state, 0);
+        report_kill(&mut state, 5);
+        assert_eq!(complete_quest(&mut state, 1), 0);
+        report_kill(&mut state, 0);
+        report_kill(&mut state, 0);
+        report_kill(&mut state, 0);
+        assert_eq!(state.active_quests[0].kills_so_far, 3);
+        assert_eq!(complete_quest(&mut state, 1), 50);
+        assert_eq!(state.active_quests[0].status, QuestStatus::Complete);
+        assert_eq!(state.player_gold, gold + 50);
+        assert_eq!(complete_quest(&mut state, 1), 0);
+    }
+
+    /// Test completing a gold quest.
+    ///
+    /// Verifies that the gold quest counts only gold gained after it was
+    /// taken, so a new game's starting gold does not complete it.
+    #[test]
+    fn test_complete_gold_quest() {
+        let mut state = crate::new_game_with_seed_impl(1);
+        assert_eq!(state.player_gold, 100);
+        start_quest(&mut state, 3);
+        assert_eq!(complete_quest(&mut state, 3), 0);
+        state.player_gold = 199;
+        assert_eq!(complete_quest(&mut state, 3), 0);
+        state.player_gold = 200;
+        assert_eq!(complete_quest(&mut state, 3), 25);
+        assert_eq!(state.active_quests[0].status, QuestStatus::Complete);
+        assert_eq!(state.player_gold, 225);
+    }
+
+    /// Test the quest reward cannot overflow the player's gold.
+    ///
+    /// Verifies that the reward saturates at the largest gold amount.
+    #[test]
+    fn test_quest_reward_saturates() {
+        let mut state = crate::new_game_with_seed_impl(1);
+        start_quest(&mut state, 3);
+        state.player_gold = u32::MAX;
+        assert_eq!(complete_quest(&mut state, 3), 25);
+        assert_eq!(state.player_gold, u32::MAX);
+    }
+}
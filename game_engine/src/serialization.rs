@@ -0,0 +1,504 @@
+//! # Save Format for the Game Engine
+//!
+//! This module converts a `GameState` to and from a compact binary format
+//! so a session can be saved and loaded later.
+//!
+//! ## Layout
+//!
+//! - 4 bytes: magic `0x4C4F5741` ("LOWA")
+//! - 1 byte: format version
+//! - Every field of `GameState` in declaration order, little-endian
+//!
+//! Strings and lists are prefixed with their length as a `u32`, enums are
+//! stored as their variant index and options as a presence byte.
+
+use crate::bindings::exports::docs::game_engine::types::{
+    BattleReward, Direction, DungeonFloor, GamePhase, GameState, Quest, QuestStatus, StatusEffect,
+    WorldEvent,
+};
+
+/// Magic number opening every save ("LOWA").
+const MAGIC: u32 = 0x4C4F_5741;
+
+/// Current save format version.
+const FORMAT_VERSION: u8 = 9;
+
+/// Game phases in encoding order.
+const PHASES: [GamePhase; 8] = [
+    GamePhase::Exploration,
+    GamePhase::Combat,
+    GamePhase::Inventory,
+    GamePhase::Dialogue,
+    GamePhase::Paused,
+    GamePhase::GameOver,
+    GamePhase::Victory,
+    GamePhase::Shopping,
+];
+
+/// Directions in encoding order.
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::South,
+    Direction::East,
+    Direction::West,
+];
+
+/// World events in encoding order.
+const WORLD_EVENTS: [WorldEvent; 4] = [
+    WorldEvent::GoblinRaid,
+    WorldEvent::MerchantVisit,
+    WorldEvent::HealingRain,
+    WorldEvent::Eclipse,
+];
+
+/// Status effects in encoding order.
+const STATUS_EFFECTS: [StatusEffect; 4] = [
+    StatusEffect::Poison,
+    StatusEffect::Burn,
+    StatusEffect::Stun,
+    StatusEffect::Freeze,
+];
+
+/// Quest statuses in encoding order.
+const QUEST_STATUSES: [QuestStatus; 4] = [
+    QuestStatus::NotStarted,
+    QuestStatus::InProgress,
+    QuestStatus::Complete,
+    QuestStatus::Failed,
+];
+
+/// Appends encoded values to a byte buffer.
+struct Writer(Vec<u8>);
+
+impl Writer {
+    /// Write a single byte.
+    fn u8(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    /// Write a boolean as one byte.
+    fn bool(&mut self, value: bool) {
+        self.u8(value as u8);
+    }
+
+    /// Write an unsigned 32-bit integer.
+    fn u32(&mut self, value: u32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Write a signed 32-bit integer.
+    fn i32(&mut self, value: i32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Write an unsigned 64-bit integer.
+    fn u64(&mut self, value: u64) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Write a character as its code point.
+    fn char(&mut self, value: char) {
+        self.u32(value as u32);
+    }
+
+    /// Write a collection length.
+    fn len(&mut self, len: usize) {
+        self.u32(len as u32);
+    }
+
+    /// Write a length-prefixed UTF-8 string.
+    fn str(&mut self, value: &str) {
+        self.len(value.len());
+        self.0.extend_from_slice(value.as_bytes());
+    }
+
+    /// Write an enum value as its index in `all`.
+    fn variant<T: PartialEq>(&mut self, value: &T, all: &[T]) {
+        let index = all.iter().position(|v| v == value).unwrap_or_default();
+        self.u8(index as u8);
+    }
+}
+
+/// Reads encoded values from a byte slice.
+struct Reader<'a> {
+    /// Bytes being decoded.
+    bytes: &'a [u8],
+    /// Offset of the next unread byte.
+    pos: usize,
+}
+
+impl Reader<'_> {
+    /// Read the next `n` bytes.
+    fn take(&mut self, n: usize) -> Result<&[u8], &'static str> {
+        let end = self.pos.checked_add(n).ok_or("Truncated save")?;
+        let slice = self.bytes.get(self.pos..end).ok_or("Truncated save")?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Read the next `N` bytes as an array.
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], &'static str> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    /// Read a single byte.
+    fn u8(&mut self) -> Result<u8, &'static str> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    /// Read a boolean byte.
+    fn bool(&mut self) -> Result<bool, &'static str> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err("Invalid boolean"),
+        }
+    }
+
+    /// Read an unsigned 32-bit integer.
+    fn u32(&mut self) -> Result<u32, &'static str> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    /// Read a signed 32-bit integer.
+    fn i32(&mut self) -> Result<i32, &'static str> {
+        Ok(i32::from_le_bytes(self.array()?))
+    }
+
+    /// Read an unsigned 64-bit integer.
+    fn u64(&mut self) -> Result<u64, &'static str> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    /// Read a character code point.
+    fn char(&mut self) -> Result<char, &'static str> {
+        char::from_u32(self.u32()?).ok_or("Invalid character")
+    }
+
+    /// Read a collection length.
+    fn len(&mut self) -> Result<usize, &'static str> {
+        Ok(self.u32()? as usize)
+    }
+
+    /// Read a length-prefixed UTF-8 string.
+    fn string(&mut self) -> Result<String, &'static str> {
+        let len = self.len()?;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| "Invalid string")
+    }
+
+    /// Read an enum value stored as its index in `all`.
+    fn variant<T: Copy>(&mut self, all: &[T]) -> Result<T, &'static str> {
+        let index = self.u8()? as usize;
+        all.get(index).copied().ok_or("Invalid enum value")
+    }
+
+    /// Read a length-prefixed list, decoding each element with `item`.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, &'static str>,
+    ) -> Result<Vec<T>, &'static str> {
+        let len = self.len()?;
+        (0..len).map(|_| item(self)).collect()
+    }
+}
+
+/// Serialize a game state to the save format.
+///
+/// # Arguments
+///
+/// * `state` - The game state to save
+///
+/// # Returns
+///
+/// The encoded bytes, starting with the magic number and version.
+pub fn serialize_game_state(state: &GameState) -> Vec<u8> {
+    let mut w = Writer(Vec::new());
+    w.0.extend_from_slice(&MAGIC.to_be_bytes());
+    w.u8(FORMAT_VERSION);
+    w.variant(&state.phase, &PHASES);
+    w.variant(&state.resume_phase, &PHASES);
+    w.i32(state.player_x);
+    w.i32(state.player_y);
+    w.u32(state.player_health);
+    w.u32(state.player_max_health);
+    w.u32(state.player_attack);
+    w.u32(state.player_defense);
+    w.u32(state.player_level);
+    w.u32(state.player_exp);
+    w.u32(state.max_player_level);
+    w.bool(state.pending_level_up);
+    w.u32(state.enemies_defeated);
+    w.bool(state.boss_defeated);
+    w.i32(state.boss_position.0);
+    w.i32(state.boss_position.1);
+    w.str(&state.current_area);
+    w.u32(state.turn_number);
+    w.u32(state.movement_points);
+    w.u32(state.player_gold);
+    w.u32(state.equipped_armor);
+    w.u32(state.equipped_weapon);
+    w.variant(&state.facing, &DIRECTIONS);
+    w.len(state.fire_hazards.len());
+    for &(x, y, turns) in &state.fire_hazards {
+        w.i32(x);
+        w.i32(y);
+        w.u32(turns);
+    }
+    w.len(state.map_annotations.len());
+    for &(x, y, symbol, turns) in &state.map_annotations {
+        w.i32(x);
+        w.i32(y);
+        w.char(symbol);
+        w.u32(turns);
+    }
+    w.bool(state.active_event.is_some());
+    if let Some((event, turns)) = state.active_event {
+        w.variant(&event, &WORLD_EVENTS);
+        w.u32(turns);
+    }
+    w.u32(state.prestige_level);
+    w.i32(state.dungeon_floor);
+    w.bool(state.dungeon_layout.is_some());
+    if let Some(layout) = &state.dungeon_layout {
+        w.len(layout.rooms.len());
+        for &(x, y, width, height) in &layout.rooms {
+            w.i32(x);
+            w.i32(y);
+            w.u32(width);
+            w.u32(height);
+        }
+        w.len(layout.corridors.len());
+        for &(x1, y1, x2, y2) in &layout.corridors {
+            w.i32(x1);
+            w.i32(y1);
+            w.i32(x2);
+            w.i32(y2);
+        }
+    }
+    w.u64(state.global_turn);
+    w.u64(state.world_seed);
+    w.u32(state.encounter_cooldown);
+    w.u32(state.flee_cooldown);
+    w.u32(state.interact_cooldown);
+    w.bool(state.defensive_stance_active);
+    w.u32(state.defensive_stance_turns);
+    w.bool(state.player_has_hookshot);
+    w.u32(state.carried_weight);
+    w.u32(state.item_count);
+    w.len(state.triggered_events.len());
+    for &id in &state.triggered_events {
+        w.u32(id);
+    }
+    w.u64(state.combat_start_turn);
+    w.bool(state.pending_reward.is_some());
+    if let Some(reward) = &state.pending_reward {
+        w.u32(reward.exp_gained);
+        w.len(reward.items_dropped.len());
+        for item in &reward.items_dropped {
+            w.str(item);
+        }
+        w.u32(reward.gold_gained);
+        w.u32(reward.turns_taken);
+    }
+    w.len(state.movement_history.len());
+    for &(x, y) in &state.movement_history {
+        w.i32(x);
+        w.i32(y);
+    }
+    w.len(state.active_effects.len());
+    for &(effect, turns) in &state.active_effects {
+        w.variant(&effect, &STATUS_EFFECTS);
+        w.u32(turns);
+    }
+    w.len(state.active_quests.len());
+    for quest in &state.active_quests {
+        w.u32(quest.id);
+        w.str(&quest.name);
+        w.str(&quest.description);
+        w.variant(&quest.status, &QUEST_STATUSES);
+        w.u32(quest.required_kills);
+        w.u32(quest.kills_so_far);
+        w.u32(quest.start_gold);
+    }
+    w.u32(state.stamina);
+    w.u32(state.max_stamina);
+    w.u32(state.shield_hp);
+    w.str(&state.language);
+    w.0
+}
+
+/// Deserialize a game state from the save format.
+///
+/// # Arguments
+///
+/// * `bytes` - Bytes produced by `serialize_game_state`
+///
+/// # Returns
+///
+/// The decoded state, or an error if the magic number, version or
+/// contents are invalid.
+pub fn deserialize_game_state(bytes: &[u8]) -> Result<GameState, &'static str> {
+    let mut r = Reader { bytes, pos: 0 };
+    if u32::from_be_bytes(r.array()?) != MAGIC {
+        return Err("Not a save file");
+    }
+    if r.u8()? != FORMAT_VERSION {
+        return Err("Unsupported save version");
+    }
+    let state = GameState {
+        phase: r.variant(&PHASES)?,
+        resume_phase: r.variant(&PHASES)?,
+        player_x: r.i32()?,
+        player_y: r.i32()?,
+        player_health: r.u32()?,
+        player_max_health: r.u32()?,
+        player_attack: r.u32()?,
+        player_defense: r.u32()?,
+        player_level: r.u32()?,
+        player_exp: r.u32()?,
+        max_player_level: r.u32()?,
+        pending_level_up: r.bool()?,
+        enemies_defeated: r.u32()?,
+        boss_defeated: r.bool()?,
+        boss_position: (r.i32()?, r.i32()?),
+        current_area: r.string()?,
+        turn_number: r.u32()?,
+        movement_points: r.u32()?,
+        player_gold: r.u32()?,
+        equipped_armor: r.u32()?,
+        equipped_weapon: r.u32()?,
+        facing: r.variant(&DIRECTIONS)?,
+        fire_hazards: r.list(|r| Ok((r.i32()?, r.i32()?, r.u32()?)))?,
+        map_annotations: r.list(|r| Ok((r.i32()?, r.i32()?, r.char()?, r.u32()?)))?,
+        active_event: if r.bool()? {
+            Some((r.variant(&WORLD_EVENTS)?, r.u32()?))
+        } else {
+            None
+        },
+        prestige_level: r.u32()?,
+        dungeon_floor: r.i32()?,
+        dungeon_layout: if r.bool()? {
+            Some(DungeonFloor {
+                rooms: r.list(|r| Ok((r.i32()?, r.i32()?, r.u32()?, r.u32()?)))?,
+                corridors: r.list(|r| Ok((r.i32()?, r.i32()?, r.i32()?, r.i32()?)))?,
+            })
+        } else {
+            None
+        },
+        global_turn: r.u64()?,
+        world_seed: r.u64()?,
+        encounter_cooldown: r.u32()?,
+        flee_cooldown: r.u32()?,
+        interact_cooldown: r.u32()?,
+        defensive_stance_active: r.bool()?,
+        defensive_stance_turns: r.u32()?,
+        player_has_hookshot: r.bool()?,
+        carried_weight: r.u32()?,
+        item_count: r.u32()?,
+        triggered_events: r.list(|r| r.u32())?,
+        combat_start_turn: r.u64()?,
+        pending_reward: if r.bool()? {
+            Some(BattleReward {
+                exp_gained: r.u32()?,
+                items_dropped: r.list(|r| r.string())?,
+                gold_gained: r.u32()?,
+                turns_taken: r.u32()?,
+            })
+        } else {
+            None
+        },
+        movement_history: r.list(|r| Ok((r.i32()?, r.i32()?)))?,
+        active_effects: r.list(|r| Ok((r.variant(&STATUS_EFFECTS)?, r.u32()?)))?,
+        active_quests: r.list(|r| {
+            Ok(Quest {
+                id: r.u32()?,
+                name: r.string()?,
+                description: r.string()?,
+                status: r.variant(&QUEST_STATUSES)?,
+                required_kills: r.u32()?,
+                kills_so_far: r.u32()?,
+                start_gold: r.u32()?,
+            })
+        })?,
+        stamina: r.u32()?,
+        max_stamina: r.u32()?,
+        shield_hp: r.u32()?,
+        language: r.string()?,
+    };
+    if r.pos != bytes.len() {
+        return Err("Trailing bytes after save");
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a state that exercises every optional and list field.
+    fn busy_state() -> GameState {
+        let mut state = crate::new_game_with_seed_impl(42);
+        state.current_area = "Death Mountain".to_string();
+        state.fire_hazards = vec![(3, 4, 2)];
+        state.map_annotations = vec![(5, 6, '?', 3)];
+        state.active_event = Some((WorldEvent::Eclipse, 7));
+        state.dungeon_floor = 1;
+        state.dungeon_layout = Some(crate::dungeon::generate_dungeon(42, 1));
+        state.triggered_events = vec![1, 4];
+        state.pending_reward = Some(BattleReward {
+            exp_gained: 30,
+            items_dropped: vec!["Health Potion".to_string()],
+            gold_gained: 15,
+            turns_taken: 4,
+        });
+        state.active_effects = vec![(StatusEffect::Poison, 2)];
+        crate::quests::start_quest(&mut state, 1);
+        state.language = "es".to_string();
+        state
+    }
+
+    /// Test the save header.
+    ///
+    /// Verifies that saves open with the "LOWA" magic and version byte.
+    #[test]
+    fn test_serialize_header() {
+        let bytes = serialize_game_state(&busy_state());
+        assert_eq!(&bytes[..4], b"LOWA");
+        assert_eq!(bytes[4], FORMAT_VERSION);
+    }
+
+    /// Test a save round trip.
+    ///
+    /// Verifies that every field survives serialization unchanged.
+    #[test]
+    fn test_round_trip() {
+        let state = busy_state();
+        let loaded = deserialize_game_state(&serialize_game_state(&state)).unwrap();
+        assert_eq!(format!("{:?}", loaded), format!("{:?}", state));
+    }
+
+    /// Test rejecting invalid saves.
+    ///
+    /// Verifies that bad magic, versions and truncated data are errors.
+    #[test]
+    fn test_deserialize_errors() {
+        let bytes = serialize_game_state(&busy_state());
+        assert_eq!(
+            deserialize_game_state(b"NOPE").err(),
+            Some("Not a save file")
+        );
+        let mut wrong_version = bytes.clone();
+        wrong_version[4] = FORMAT_VERSION + 1;
+        assert_eq!(
+            deserialize_game_state(&wrong_version).err(),
+            Some("Unsupported save version")
+        );
+        assert_eq!(
+            deserialize_game_state(&bytes[..bytes.len() - 1]).err(),
+            Some("Truncated save")
+        );
+    }
+}
@@ -27,6 +27,8 @@ pub mod exports {
                     KeyItem,
                     /// Collectible treasures.
                     Treasure,
+                    /// Rings and charms worn for passive effects.
+                    Accessory,
                 }
                 impl ::core::fmt::Debug for ItemCategory {
                     fn fmt(
@@ -49,6 +51,9 @@ pub mod exports {
                             ItemCategory::Treasure => {
                                 f.debug_tuple("ItemCategory::Treasure").finish()
                             }
+                            ItemCategory::Accessory => {
+                                f.debug_tuple("ItemCategory::Accessory").finish()
+                            }
                         }
                     }
                 }
@@ -64,6 +69,7 @@ pub mod exports {
                             2 => ItemCategory::Consumable,
                             3 => ItemCategory::KeyItem,
                             4 => ItemCategory::Treasure,
+                            5 => ItemCategory::Accessory,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
@@ -190,6 +196,14 @@ pub mod exports {
                     DefenseBoost,
                     /// Antidote for poison.
                     Antidote,
+                    /// Coats the equipped weapon in poison.
+                    PoisonedBlade,
+                    /// Lifts the curse of an equipped item.
+                    CurseStone,
+                    /// Reveals the stats of an unidentified item.
+                    ScrollOfIdentify,
+                    /// Reveals the whole area around the player.
+                    MagicMap,
                 }
                 impl ::core::fmt::Debug for ConsumableType {
                     fn fmt(
@@ -212,6 +226,18 @@ pub mod exports {
                             ConsumableType::Antidote => {
                                 f.debug_tuple("ConsumableType::Antidote").finish()
                             }
+                            ConsumableType::PoisonedBlade => {
+                                f.debug_tuple("ConsumableType::PoisonedBlade").finish()
+                            }
+                            ConsumableType::CurseStone => {
+                                f.debug_tuple("ConsumableType::CurseStone").finish()
+                            }
+                            ConsumableType::ScrollOfIdentify => {
+                                f.debug_tuple("ConsumableType::ScrollOfIdentify").finish()
+                            }
+                            ConsumableType::MagicMap => {
+                                f.debug_tuple("ConsumableType::MagicMap").finish()
+                            }
                         }
                     }
                 }
@@ -227,10 +253,132 @@ pub mod exports {
                             2 => ConsumableType::AttackBoost,
                             3 => ConsumableType::DefenseBoost,
                             4 => ConsumableType::Antidote,
+                            5 => ConsumableType::PoisonedBlade,
+                            6 => ConsumableType::CurseStone,
+                            7 => ConsumableType::ScrollOfIdentify,
+                            8 => ConsumableType::MagicMap,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
                 }
+                /// Debuff inflicted by equipping a cursed item.
+                #[derive(Clone, Copy)]
+                pub enum CurseEffect {
+                    /// Lowers attack by the given amount.
+                    AttackReduction(u32),
+                    /// Lowers defense by the given amount.
+                    DefenseReduction(u32),
+                    /// Lowers maximum health by the given amount.
+                    MaxHealthReduction(u32),
+                }
+                impl ::core::fmt::Debug for CurseEffect {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            CurseEffect::AttackReduction(e) => {
+                                f.debug_tuple("CurseEffect::AttackReduction")
+                                    .field(e)
+                                    .finish()
+                            }
+                            CurseEffect::DefenseReduction(e) => {
+                                f.debug_tuple("CurseEffect::DefenseReduction")
+                                    .field(e)
+                                    .finish()
+                            }
+                            CurseEffect::MaxHealthReduction(e) => {
+                                f.debug_tuple("CurseEffect::MaxHealthReduction")
+                                    .field(e)
+                                    .finish()
+                            }
+                        }
+                    }
+                }
+                /// Magical upgrade applied to an item.
+                #[derive(Clone, Copy)]
+                pub enum Enchantment {
+                    /// Adds fire damage to the item's attack bonus.
+                    FireDamage(u32),
+                    /// Slows struck enemies.
+                    FrostSlow,
+                    /// Heals the wielder by the given amount on hit.
+                    LifeSteal(u32),
+                    /// Lets the wielder strike from further away.
+                    ExtraReach,
+                }
+                impl ::core::fmt::Debug for Enchantment {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Enchantment::FireDamage(e) => {
+                                f.debug_tuple("Enchantment::FireDamage").field(e).finish()
+                            }
+                            Enchantment::FrostSlow => {
+                                f.debug_tuple("Enchantment::FrostSlow").finish()
+                            }
+                            Enchantment::LifeSteal(e) => {
+                                f.debug_tuple("Enchantment::LifeSteal").field(e).finish()
+                            }
+                            Enchantment::ExtraReach => {
+                                f.debug_tuple("Enchantment::ExtraReach").finish()
+                            }
+                        }
+                    }
+                }
+                /// Gem that can be socketed into equipment.
+                #[derive(Clone, Copy)]
+                pub enum GemType {
+                    /// Adds the given attack bonus.
+                    RubyGem(u32),
+                    /// Adds the given defense bonus.
+                    SapphireGem(u32),
+                    /// Adds the given maximum health bonus.
+                    EmeraldGem(u32),
+                }
+                impl ::core::fmt::Debug for GemType {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            GemType::RubyGem(e) => {
+                                f.debug_tuple("GemType::RubyGem").field(e).finish()
+                            }
+                            GemType::SapphireGem(e) => {
+                                f.debug_tuple("GemType::SapphireGem").field(e).finish()
+                            }
+                            GemType::EmeraldGem(e) => {
+                                f.debug_tuple("GemType::EmeraldGem").field(e).finish()
+                            }
+                        }
+                    }
+                }
+                /// Player stats affected by item curses.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct PlayerStats {
+                    /// Attack stat.
+                    pub attack: u32,
+                    /// Defense stat.
+                    pub defense: u32,
+                    /// Maximum health.
+                    pub max_health: u32,
+                }
+                impl ::core::fmt::Debug for PlayerStats {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("PlayerStats")
+                            .field("attack", &self.attack)
+                            .field("defense", &self.defense)
+                            .field("max-health", &self.max_health)
+                            .finish()
+                    }
+                }
                 /// A single inventory item.
                 #[derive(Clone)]
                 pub struct Item {
@@ -242,6 +390,8 @@ pub mod exports {
                     pub category: ItemCategory,
                     /// Attack bonus (for weapons).
                     pub attack_bonus: u32,
+                    /// Critical hit chance in percent (for weapons).
+                    pub crit_chance: u32,
                     /// Defense bonus (for armor).
                     pub defense_bonus: u32,
                     /// Healing amount (for consumables).
@@ -250,6 +400,20 @@ pub mod exports {
                     pub quantity: u32,
                     /// Whether this item is currently equipped.
                     pub is_equipped: bool,
+                    /// Whether this item carries a curse.
+                    pub is_cursed: bool,
+                    /// Debuff applied when the item is equipped.
+                    pub curse_effect: Option<CurseEffect>,
+                    /// Enchantments applied to the item (at most 2).
+                    pub enchantments: _rt::Vec<Enchantment>,
+                    /// Number of gem sockets on the item.
+                    pub gem_slots: u8,
+                    /// Gems socketed into the item.
+                    pub socketed_gems: _rt::Vec<GemType>,
+                    /// Whether the item's stats are known.
+                    pub identified: bool,
+                    /// Carrying weight of a single unit of the item.
+                    pub weight: u32,
                 }
                 impl ::core::fmt::Debug for Item {
                     fn fmt(
@@ -261,27 +425,50 @@ pub mod exports {
                             .field("name", &self.name)
                             .field("category", &self.category)
                             .field("attack-bonus", &self.attack_bonus)
+                            .field("crit-chance", &self.crit_chance)
                             .field("defense-bonus", &self.defense_bonus)
                             .field("heal-amount", &self.heal_amount)
                             .field("quantity", &self.quantity)
                             .field("is-equipped", &self.is_equipped)
+                            .field("is-cursed", &self.is_cursed)
+                            .field("curse-effect", &self.curse_effect)
+                            .field("enchantments", &self.enchantments)
+                            .field("gem-slots", &self.gem_slots)
+                            .field("socketed-gems", &self.socketed_gems)
+                            .field("identified", &self.identified)
+                            .field("weight", &self.weight)
                             .finish()
                     }
                 }
                 /// Player's complete inventory state.
-                #[repr(C)]
-                #[derive(Clone, Copy)]
+                #[derive(Clone)]
                 pub struct InventoryState {
                     /// Currently equipped weapon ID (0 = none).
                     pub equipped_weapon: u32,
+                    /// Currently equipped off-hand weapon ID (0 = none).
+                    pub equipped_offhand: u32,
                     /// Currently equipped armor ID (0 = none).
                     pub equipped_armor: u32,
+                    /// Currently equipped ring slot item ID (0 = none).
+                    pub equipped_ring: u32,
                     /// Total number of items in inventory.
                     pub item_count: u32,
+                    /// IDs of carried items, in the order they were added.
+                    pub item_ids: _rt::Vec<u32>,
                     /// Maximum inventory capacity.
                     pub max_capacity: u32,
                     /// Gold/rupees currency.
                     pub gold: u32,
+                    /// Whether the player is poisoned.
+                    pub is_poisoned: bool,
+                    /// Turns of poison left to tick.
+                    pub poison_turns_remaining: u32,
+                    /// Debuff the equipped weapon's curse actually applied, until lifted.
+                    pub weapon_curse: Option<CurseEffect>,
+                    /// Debuff the equipped armor's curse actually applied, until lifted.
+                    pub armor_curse: Option<CurseEffect>,
+                    /// Hits left on which the equipped weapon poisons its target.
+                    pub weapon_poison_hits: u32,
                 }
                 impl ::core::fmt::Debug for InventoryState {
                     fn fmt(
@@ -290,10 +477,62 @@ pub mod exports {
                     ) -> ::core::fmt::Result {
                         f.debug_struct("InventoryState")
                             .field("equipped-weapon", &self.equipped_weapon)
+                            .field("equipped-offhand", &self.equipped_offhand)
                             .field("equipped-armor", &self.equipped_armor)
+                            .field("equipped-ring", &self.equipped_ring)
                             .field("item-count", &self.item_count)
+                            .field("item-ids", &self.item_ids)
                             .field("max-capacity", &self.max_capacity)
                             .field("gold", &self.gold)
+                            .field("is-poisoned", &self.is_poisoned)
+                            .field(
+                                "poison-turns-remaining",
+                                &self.poison_turns_remaining,
+                            )
+                            .field("weapon-curse", &self.weapon_curse)
+                            .field("armor-curse", &self.armor_curse)
+                            .field("weapon-poison-hits", &self.weapon_poison_hits)
+                            .finish()
+                    }
+                }
+                /// Copy of an inventory taken to undo later changes.
+                #[derive(Clone)]
+                pub struct InventorySnapshot {
+                    /// Inventory state at the time of the snapshot.
+                    pub state: InventoryState,
+                    /// Carried items as (item ID, quantity).
+                    pub item_counts: _rt::Vec<(u32, u32)>,
+                }
+                impl ::core::fmt::Debug for InventorySnapshot {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("InventorySnapshot")
+                            .field("state", &self.state)
+                            .field("item-counts", &self.item_counts)
+                            .finish()
+                    }
+                }
+                /// Changes between two inventory snapshots.
+                #[derive(Clone)]
+                pub struct InventoryDiff {
+                    /// IDs of items gained or stacked higher.
+                    pub added: _rt::Vec<u32>,
+                    /// IDs of items lost or stacked lower.
+                    pub removed: _rt::Vec<u32>,
+                    /// Change in gold.
+                    pub gold_delta: i32,
+                }
+                impl ::core::fmt::Debug for InventoryDiff {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("InventoryDiff")
+                            .field("added", &self.added)
+                            .field("removed", &self.removed)
+                            .field("gold-delta", &self.gold_delta)
                             .finish()
                     }
                 }
@@ -310,6 +549,10 @@ pub mod exports {
                     pub defense_boost: u32,
                     /// Message describing the result.
                     pub message: _rt::String,
+                    /// Whether using the item revealed its identity.
+                    pub identified: bool,
+                    /// Name of the area revealed on the map, if any.
+                    pub reveals_area: Option<_rt::String>,
                 }
                 impl ::core::fmt::Debug for UseResult {
                     fn fmt(
@@ -322,6 +565,8 @@ pub mod exports {
                             .field("attack-boost", &self.attack_boost)
                             .field("defense-boost", &self.defense_boost)
                             .field("message", &self.message)
+                            .field("identified", &self.identified)
+                            .field("reveals-area", &self.reveals_area)
                             .finish()
                     }
                 }
@@ -345,6 +590,8 @@ pub mod exports {
                 pub type WeaponType = super::super::super::super::exports::docs::inventory::types::WeaponType;
                 pub type ArmorType = super::super::super::super::exports::docs::inventory::types::ArmorType;
                 pub type ConsumableType = super::super::super::super::exports::docs::inventory::types::ConsumableType;
+                pub type Enchantment = super::super::super::super::exports::docs::inventory::types::Enchantment;
+                pub type GemType = super::super::super::super::exports::docs::inventory::types::GemType;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_create_weapon_cabi<T: Guest>(
@@ -362,10 +609,18 @@ pub mod exports {
                         name: name2,
                         category: category2,
                         attack_bonus: attack_bonus2,
+                        crit_chance: crit_chance2,
                         defense_bonus: defense_bonus2,
                         heal_amount: heal_amount2,
                         quantity: quantity2,
                         is_equipped: is_equipped2,
+                        is_cursed: is_cursed2,
+                        curse_effect: curse_effect2,
+                        enchantments: enchantments2,
+                        gem_slots: gem_slots2,
+                        socketed_gems: socketed_gems2,
+                        identified: identified2,
+                        weight: weight2,
                     } = result0;
                     *ptr1.add(0).cast::<i32>() = _rt::as_i32(id2);
                     let vec3 = (name2.into_bytes()).into_boxed_slice();
@@ -382,19 +637,160 @@ pub mod exports {
                         .cast::<i32>() = _rt::as_i32(attack_bonus2);
                     *ptr1
                         .add(8 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
+                        .cast::<i32>() = _rt::as_i32(crit_chance2);
                     *ptr1
                         .add(12 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(heal_amount2);
+                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
                     *ptr1
                         .add(16 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(quantity2);
+                        .cast::<i32>() = _rt::as_i32(heal_amount2);
                     *ptr1
                         .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(quantity2);
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
                         .cast::<u8>() = (match is_equipped2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1
+                        .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_cursed2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match curse_effect2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    let vec4 = enchantments2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result4;
+                    *ptr1
+                        .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (_rt::as_i32(gem_slots2)) as u8;
+                    let vec5 = socketed_gems2;
+                    let len5 = vec5.len();
+                    let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec5.len() * 8,
+                        4,
+                    );
+                    let result5 = if layout5.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout5);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec5.into_iter().enumerate() {
+                        let base = result5.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr1
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result5;
+                    *ptr1
+                        .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weight2);
                     ptr1
                 }
                 #[doc(hidden)]
@@ -407,6 +803,24 @@ pub mod exports {
                         .add(2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
                     _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let l5 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l5;
+                    let len7 = l6;
+                    _rt::cabi_dealloc(base7, len7 * 8, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -423,10 +837,18 @@ pub mod exports {
                         name: name2,
                         category: category2,
                         attack_bonus: attack_bonus2,
+                        crit_chance: crit_chance2,
                         defense_bonus: defense_bonus2,
                         heal_amount: heal_amount2,
                         quantity: quantity2,
                         is_equipped: is_equipped2,
+                        is_cursed: is_cursed2,
+                        curse_effect: curse_effect2,
+                        enchantments: enchantments2,
+                        gem_slots: gem_slots2,
+                        socketed_gems: socketed_gems2,
+                        identified: identified2,
+                        weight: weight2,
                     } = result0;
                     *ptr1.add(0).cast::<i32>() = _rt::as_i32(id2);
                     let vec3 = (name2.into_bytes()).into_boxed_slice();
@@ -443,19 +865,160 @@ pub mod exports {
                         .cast::<i32>() = _rt::as_i32(attack_bonus2);
                     *ptr1
                         .add(8 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
+                        .cast::<i32>() = _rt::as_i32(crit_chance2);
                     *ptr1
                         .add(12 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(heal_amount2);
+                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
                     *ptr1
                         .add(16 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(quantity2);
+                        .cast::<i32>() = _rt::as_i32(heal_amount2);
                     *ptr1
                         .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(quantity2);
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
                         .cast::<u8>() = (match is_equipped2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1
+                        .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_cursed2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match curse_effect2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    let vec4 = enchantments2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result4;
+                    *ptr1
+                        .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (_rt::as_i32(gem_slots2)) as u8;
+                    let vec5 = socketed_gems2;
+                    let len5 = vec5.len();
+                    let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec5.len() * 8,
+                        4,
+                    );
+                    let result5 = if layout5.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout5);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec5.into_iter().enumerate() {
+                        let base = result5.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr1
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result5;
+                    *ptr1
+                        .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weight2);
                     ptr1
                 }
                 #[doc(hidden)]
@@ -468,6 +1031,24 @@ pub mod exports {
                         .add(2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
                     _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let l5 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l5;
+                    let len7 = l6;
+                    _rt::cabi_dealloc(base7, len7 * 8, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -488,10 +1069,18 @@ pub mod exports {
                         name: name2,
                         category: category2,
                         attack_bonus: attack_bonus2,
+                        crit_chance: crit_chance2,
                         defense_bonus: defense_bonus2,
                         heal_amount: heal_amount2,
                         quantity: quantity2,
                         is_equipped: is_equipped2,
+                        is_cursed: is_cursed2,
+                        curse_effect: curse_effect2,
+                        enchantments: enchantments2,
+                        gem_slots: gem_slots2,
+                        socketed_gems: socketed_gems2,
+                        identified: identified2,
+                        weight: weight2,
                     } = result0;
                     *ptr1.add(0).cast::<i32>() = _rt::as_i32(id2);
                     let vec3 = (name2.into_bytes()).into_boxed_slice();
@@ -508,19 +1097,160 @@ pub mod exports {
                         .cast::<i32>() = _rt::as_i32(attack_bonus2);
                     *ptr1
                         .add(8 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
+                        .cast::<i32>() = _rt::as_i32(crit_chance2);
                     *ptr1
                         .add(12 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(heal_amount2);
+                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
                     *ptr1
                         .add(16 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(quantity2);
+                        .cast::<i32>() = _rt::as_i32(heal_amount2);
                     *ptr1
                         .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(quantity2);
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
                         .cast::<u8>() = (match is_equipped2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1
+                        .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_cursed2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match curse_effect2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    let vec4 = enchantments2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result4;
+                    *ptr1
+                        .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (_rt::as_i32(gem_slots2)) as u8;
+                    let vec5 = socketed_gems2;
+                    let len5 = vec5.len();
+                    let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec5.len() * 8,
+                        4,
+                    );
+                    let result5 = if layout5.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout5);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec5.into_iter().enumerate() {
+                        let base = result5.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr1
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result5;
+                    *ptr1
+                        .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weight2);
                     ptr1
                 }
                 #[doc(hidden)]
@@ -533,6 +1263,24 @@ pub mod exports {
                         .add(2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
                     _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let l5 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l5;
+                    let len7 = l6;
+                    _rt::cabi_dealloc(base7, len7 * 8, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -547,10 +1295,18 @@ pub mod exports {
                         name: name2,
                         category: category2,
                         attack_bonus: attack_bonus2,
+                        crit_chance: crit_chance2,
                         defense_bonus: defense_bonus2,
                         heal_amount: heal_amount2,
                         quantity: quantity2,
                         is_equipped: is_equipped2,
+                        is_cursed: is_cursed2,
+                        curse_effect: curse_effect2,
+                        enchantments: enchantments2,
+                        gem_slots: gem_slots2,
+                        socketed_gems: socketed_gems2,
+                        identified: identified2,
+                        weight: weight2,
                     } = result0;
                     *ptr1.add(0).cast::<i32>() = _rt::as_i32(id2);
                     let vec3 = (name2.into_bytes()).into_boxed_slice();
@@ -567,19 +1323,160 @@ pub mod exports {
                         .cast::<i32>() = _rt::as_i32(attack_bonus2);
                     *ptr1
                         .add(8 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
+                        .cast::<i32>() = _rt::as_i32(crit_chance2);
                     *ptr1
                         .add(12 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(heal_amount2);
+                        .cast::<i32>() = _rt::as_i32(defense_bonus2);
                     *ptr1
                         .add(16 + 3 * ::core::mem::size_of::<*const u8>())
-                        .cast::<i32>() = _rt::as_i32(quantity2);
+                        .cast::<i32>() = _rt::as_i32(heal_amount2);
                     *ptr1
                         .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(quantity2);
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
                         .cast::<u8>() = (match is_equipped2 {
                         true => 1,
                         false => 0,
                     }) as u8;
+                    *ptr1
+                        .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_cursed2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match curse_effect2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr1
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    let vec4 = enchantments2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result4;
+                    *ptr1
+                        .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (_rt::as_i32(gem_slots2)) as u8;
+                    let vec5 = socketed_gems2;
+                    let len5 = vec5.len();
+                    let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec5.len() * 8,
+                        4,
+                    );
+                    let result5 = if layout5.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout5);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec5.into_iter().enumerate() {
+                        let base = result5.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                    }
+                    *ptr1
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len5;
+                    *ptr1
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result5;
+                    *ptr1
+                        .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weight2);
                     ptr1
                 }
                 #[doc(hidden)]
@@ -592,474 +1489,11359 @@ pub mod exports {
                         .add(2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
                     _rt::cabi_dealloc(l0, l1, 1);
-                }
-                pub trait Guest {
-                    /// Create a weapon item.
-                    fn create_weapon(weapon: WeaponType) -> Item;
-                    /// Create an armor item.
-                    fn create_armor(armor: ArmorType) -> Item;
-                    /// Create a consumable item.
-                    fn create_consumable(
-                        consumable: ConsumableType,
-                        quantity: u32,
-                    ) -> Item;
-                    /// Get item stats by ID.
-                    fn get_item_stats(item_id: u32) -> Item;
+                    let l2 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let l5 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l5;
+                    let len7 = l6;
+                    _rt::cabi_dealloc(base7, len7 * 8, 4);
                 }
                 #[doc(hidden)]
-                macro_rules! __export_docs_inventory_items_0_1_0_cabi {
-                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
-                        const _ : () = { #[unsafe (export_name =
-                        "docs:inventory/items@0.1.0#create-weapon")] unsafe extern "C" fn
-                        export_create_weapon(arg0 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_create_weapon_cabi::<$ty > (arg0) }
-                        } #[unsafe (export_name =
-                        "cabi_post_docs:inventory/items@0.1.0#create-weapon")] unsafe
-                        extern "C" fn _post_return_create_weapon(arg0 : * mut u8,) {
-                        unsafe { $($path_to_types)*:: __post_return_create_weapon::<$ty >
-                        (arg0) } } #[unsafe (export_name =
-                        "docs:inventory/items@0.1.0#create-armor")] unsafe extern "C" fn
-                        export_create_armor(arg0 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_create_armor_cabi::<$ty > (arg0) } }
-                        #[unsafe (export_name =
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_identify_item_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len3 = l2;
+                    let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                    let l4 = i32::from(
+                        *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l5 = *arg0
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l6 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l7 = *arg0
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = i32::from(
+                        *arg0
+                            .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l12 = i32::from(
+                        *arg0
+                            .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base22 = l17;
+                    let len22 = l18;
+                    let mut result22 = _rt::Vec::with_capacity(len22);
+                    for i in 0..len22 {
+                        let base = base22.add(i * 8);
+                        let e22 = {
+                            let l19 = i32::from(*base.add(0).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *base.add(4).cast::<i32>();
+                                    let e = l20 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l21 = *base.add(4).cast::<i32>();
+                                    let e = l21 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result22.push(e22);
+                    }
+                    _rt::cabi_dealloc(base22, len22 * 8, 4);
+                    let l23 = i32::from(
+                        *arg0
+                            .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l24;
+                    let len30 = l25;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30.add(i * 8);
+                        let e30 = {
+                            let l26 = i32::from(*base.add(0).cast::<u8>());
+                            match l26 {
+                                0 => {
+                                    let l27 = *base.add(4).cast::<i32>();
+                                    let e = l27 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l28 = *base.add(4).cast::<i32>();
+                                    let e = l28 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l29 = *base.add(4).cast::<i32>();
+                                    let e = l29 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 8, 4);
+                    let l31 = i32::from(
+                        *arg0
+                            .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *arg0
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        48 + 8 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result33 = T::identify_item(super::super::super::super::exports::docs::inventory::types::Item {
+                        id: l0 as u32,
+                        name: _rt::string_lift(bytes3),
+                        category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                            l4 as u8,
+                        ),
+                        attack_bonus: l5 as u32,
+                        crit_chance: l6 as u32,
+                        defense_bonus: l7 as u32,
+                        heal_amount: l8 as u32,
+                        quantity: l9 as u32,
+                        is_equipped: _rt::bool_lift(l10 as u8),
+                        is_cursed: _rt::bool_lift(l11 as u8),
+                        curse_effect: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        enchantments: result22,
+                        gem_slots: l23 as u8,
+                        socketed_gems: result30,
+                        identified: _rt::bool_lift(l31 as u8),
+                        weight: l32 as u32,
+                    });
+                    let ptr34 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::Item {
+                        id: id35,
+                        name: name35,
+                        category: category35,
+                        attack_bonus: attack_bonus35,
+                        crit_chance: crit_chance35,
+                        defense_bonus: defense_bonus35,
+                        heal_amount: heal_amount35,
+                        quantity: quantity35,
+                        is_equipped: is_equipped35,
+                        is_cursed: is_cursed35,
+                        curse_effect: curse_effect35,
+                        enchantments: enchantments35,
+                        gem_slots: gem_slots35,
+                        socketed_gems: socketed_gems35,
+                        identified: identified35,
+                        weight: weight35,
+                    } = result33;
+                    *ptr34.add(0).cast::<i32>() = _rt::as_i32(id35);
+                    let vec36 = (name35.into_bytes()).into_boxed_slice();
+                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                    let len36 = vec36.len();
+                    ::core::mem::forget(vec36);
+                    *ptr34
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len36;
+                    *ptr34.add(::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr36
+                        .cast_mut();
+                    *ptr34.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (category35
+                        .clone() as i32) as u8;
+                    *ptr34
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(attack_bonus35);
+                    *ptr34
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(crit_chance35);
+                    *ptr34
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(defense_bonus35);
+                    *ptr34
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(heal_amount35);
+                    *ptr34
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(quantity35);
+                    *ptr34
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_equipped35 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr34
+                        .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_cursed35 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match curse_effect35 {
+                        Some(e) => {
+                            *ptr34
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr34
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr34
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr34
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr34
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr34
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr34
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr34
+                                .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    let vec37 = enchantments35;
+                    let len37 = vec37.len();
+                    let layout37 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec37.len() * 8,
+                        4,
+                    );
+                    let result37 = if layout37.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout37).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout37);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec37.into_iter().enumerate() {
+                        let base = result37.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                }
+                            };
+                        }
+                    }
+                    *ptr34
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len37;
+                    *ptr34
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result37;
+                    *ptr34
+                        .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (_rt::as_i32(gem_slots35)) as u8;
+                    let vec38 = socketed_gems35;
+                    let len38 = vec38.len();
+                    let layout38 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec38.len() * 8,
+                        4,
+                    );
+                    let result38 = if layout38.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout38).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout38);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec38.into_iter().enumerate() {
+                        let base = result38.add(i * 8);
+                        {
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                    }
+                    *ptr34
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len38;
+                    *ptr34
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result38;
+                    *ptr34
+                        .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified35 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr34
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weight35);
+                    ptr34
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_identify_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l3 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base4 = l2;
+                    let len4 = l3;
+                    _rt::cabi_dealloc(base4, len4 * 8, 4);
+                    let l5 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base7 = l5;
+                    let len7 = l6;
+                    _rt::cabi_dealloc(base7, len7 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_format_inventory_table_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base33 = arg0;
+                    let len33 = arg1;
+                    let mut result33 = _rt::Vec::with_capacity(len33);
+                    for i in 0..len33 {
+                        let base = base33
+                            .add(i * (48 + 8 * ::core::mem::size_of::<*const u8>()));
+                        let e33 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            let l4 = i32::from(
+                                *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l5 = *base
+                                .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l6 = *base
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l7 = *base
+                                .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l8 = *base
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l9 = *base
+                                .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l10 = i32::from(
+                                *base
+                                    .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l11 = i32::from(
+                                *base
+                                    .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l12 = i32::from(
+                                *base
+                                    .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l17 = *base
+                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l18 = *base
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l17;
+                            let len22 = l18;
+                            let mut result22 = _rt::Vec::with_capacity(len22);
+                            for i in 0..len22 {
+                                let base = base22.add(i * 8);
+                                let e22 = {
+                                    let l19 = i32::from(*base.add(0).cast::<u8>());
+                                    match l19 {
+                                        0 => {
+                                            let l20 = *base.add(4).cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                        }
+                                        2 => {
+                                            let l21 = *base.add(4).cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                                e,
+                                            )
+                                        }
+                                        3 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result22.push(e22);
+                            }
+                            _rt::cabi_dealloc(base22, len22 * 8, 4);
+                            let l23 = i32::from(
+                                *base
+                                    .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l24 = *base
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l25 = *base
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base30 = l24;
+                            let len30 = l25;
+                            let mut result30 = _rt::Vec::with_capacity(len30);
+                            for i in 0..len30 {
+                                let base = base30.add(i * 8);
+                                let e30 = {
+                                    let l26 = i32::from(*base.add(0).cast::<u8>());
+                                    match l26 {
+                                        0 => {
+                                            let l27 = *base.add(4).cast::<i32>();
+                                            let e = l27 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l28 = *base.add(4).cast::<i32>();
+                                            let e = l28 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l29 = *base.add(4).cast::<i32>();
+                                            let e = l29 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result30.push(e30);
+                            }
+                            _rt::cabi_dealloc(base30, len30 * 8, 4);
+                            let l31 = i32::from(
+                                *base
+                                    .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l32 = *base
+                                .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            super::super::super::super::exports::docs::inventory::types::Item {
+                                id: l0 as u32,
+                                name: _rt::string_lift(bytes3),
+                                category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                    l4 as u8,
+                                ),
+                                attack_bonus: l5 as u32,
+                                crit_chance: l6 as u32,
+                                defense_bonus: l7 as u32,
+                                heal_amount: l8 as u32,
+                                quantity: l9 as u32,
+                                is_equipped: _rt::bool_lift(l10 as u8),
+                                is_cursed: _rt::bool_lift(l11 as u8),
+                                curse_effect: match l12 {
+                                    0 => None,
+                                    1 => {
+                                        let l13 = i32::from(
+                                            *base
+                                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l13 {
+                                            0 => {
+                                                let l14 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l14 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l15 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l15 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l16 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l16 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                enchantments: result22,
+                                gem_slots: l23 as u8,
+                                socketed_gems: result30,
+                                identified: _rt::bool_lift(l31 as u8),
+                                weight: l32 as u32,
+                            }
+                        };
+                        result33.push(e33);
+                    }
+                    _rt::cabi_dealloc(
+                        base33,
+                        len33 * (48 + 8 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result34 = T::format_inventory_table(result33);
+                    let ptr35 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec36 = (result34.into_bytes()).into_boxed_slice();
+                    let ptr36 = vec36.as_ptr().cast::<u8>();
+                    let len36 = vec36.len();
+                    ::core::mem::forget(vec36);
+                    *ptr35.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len36;
+                    *ptr35.add(0).cast::<*mut u8>() = ptr36.cast_mut();
+                    ptr35
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_format_inventory_table<T: Guest>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_total_inventory_weight_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base33 = arg0;
+                    let len33 = arg1;
+                    let mut result33 = _rt::Vec::with_capacity(len33);
+                    for i in 0..len33 {
+                        let base = base33
+                            .add(i * (48 + 8 * ::core::mem::size_of::<*const u8>()));
+                        let e33 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            let l4 = i32::from(
+                                *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l5 = *base
+                                .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l6 = *base
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l7 = *base
+                                .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l8 = *base
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l9 = *base
+                                .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l10 = i32::from(
+                                *base
+                                    .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l11 = i32::from(
+                                *base
+                                    .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l12 = i32::from(
+                                *base
+                                    .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l17 = *base
+                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l18 = *base
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l17;
+                            let len22 = l18;
+                            let mut result22 = _rt::Vec::with_capacity(len22);
+                            for i in 0..len22 {
+                                let base = base22.add(i * 8);
+                                let e22 = {
+                                    let l19 = i32::from(*base.add(0).cast::<u8>());
+                                    match l19 {
+                                        0 => {
+                                            let l20 = *base.add(4).cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                        }
+                                        2 => {
+                                            let l21 = *base.add(4).cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                                e,
+                                            )
+                                        }
+                                        3 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result22.push(e22);
+                            }
+                            _rt::cabi_dealloc(base22, len22 * 8, 4);
+                            let l23 = i32::from(
+                                *base
+                                    .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l24 = *base
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l25 = *base
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base30 = l24;
+                            let len30 = l25;
+                            let mut result30 = _rt::Vec::with_capacity(len30);
+                            for i in 0..len30 {
+                                let base = base30.add(i * 8);
+                                let e30 = {
+                                    let l26 = i32::from(*base.add(0).cast::<u8>());
+                                    match l26 {
+                                        0 => {
+                                            let l27 = *base.add(4).cast::<i32>();
+                                            let e = l27 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l28 = *base.add(4).cast::<i32>();
+                                            let e = l28 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l29 = *base.add(4).cast::<i32>();
+                                            let e = l29 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result30.push(e30);
+                            }
+                            _rt::cabi_dealloc(base30, len30 * 8, 4);
+                            let l31 = i32::from(
+                                *base
+                                    .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l32 = *base
+                                .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            super::super::super::super::exports::docs::inventory::types::Item {
+                                id: l0 as u32,
+                                name: _rt::string_lift(bytes3),
+                                category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                    l4 as u8,
+                                ),
+                                attack_bonus: l5 as u32,
+                                crit_chance: l6 as u32,
+                                defense_bonus: l7 as u32,
+                                heal_amount: l8 as u32,
+                                quantity: l9 as u32,
+                                is_equipped: _rt::bool_lift(l10 as u8),
+                                is_cursed: _rt::bool_lift(l11 as u8),
+                                curse_effect: match l12 {
+                                    0 => None,
+                                    1 => {
+                                        let l13 = i32::from(
+                                            *base
+                                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l13 {
+                                            0 => {
+                                                let l14 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l14 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l15 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l15 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l16 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l16 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                enchantments: result22,
+                                gem_slots: l23 as u8,
+                                socketed_gems: result30,
+                                identified: _rt::bool_lift(l31 as u8),
+                                weight: l32 as u32,
+                            }
+                        };
+                        result33.push(e33);
+                    }
+                    _rt::cabi_dealloc(
+                        base33,
+                        len33 * (48 + 8 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result34 = T::total_inventory_weight(result33);
+                    _rt::as_i32(result34)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_enchant_item_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len3 = l2;
+                    let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                    let l4 = i32::from(
+                        *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l5 = *arg0
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l6 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l7 = *arg0
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = i32::from(
+                        *arg0
+                            .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l12 = i32::from(
+                        *arg0
+                            .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base22 = l17;
+                    let len22 = l18;
+                    let mut result22 = _rt::Vec::with_capacity(len22);
+                    for i in 0..len22 {
+                        let base = base22.add(i * 8);
+                        let e22 = {
+                            let l19 = i32::from(*base.add(0).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *base.add(4).cast::<i32>();
+                                    let e = l20 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l21 = *base.add(4).cast::<i32>();
+                                    let e = l21 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result22.push(e22);
+                    }
+                    _rt::cabi_dealloc(base22, len22 * 8, 4);
+                    let l23 = i32::from(
+                        *arg0
+                            .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l24;
+                    let len30 = l25;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30.add(i * 8);
+                        let e30 = {
+                            let l26 = i32::from(*base.add(0).cast::<u8>());
+                            match l26 {
+                                0 => {
+                                    let l27 = *base.add(4).cast::<i32>();
+                                    let e = l27 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l28 = *base.add(4).cast::<i32>();
+                                    let e = l28 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l29 = *base.add(4).cast::<i32>();
+                                    let e = l29 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 8, 4);
+                    let l31 = i32::from(
+                        *arg0
+                            .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *arg0
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l33 = i32::from(
+                        *arg0
+                            .add(48 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 8 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result36 = T::enchant_item(
+                        super::super::super::super::exports::docs::inventory::types::Item {
+                            id: l0 as u32,
+                            name: _rt::string_lift(bytes3),
+                            category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                l4 as u8,
+                            ),
+                            attack_bonus: l5 as u32,
+                            crit_chance: l6 as u32,
+                            defense_bonus: l7 as u32,
+                            heal_amount: l8 as u32,
+                            quantity: l9 as u32,
+                            is_equipped: _rt::bool_lift(l10 as u8),
+                            is_cursed: _rt::bool_lift(l11 as u8),
+                            curse_effect: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            enchantments: result22,
+                            gem_slots: l23 as u8,
+                            socketed_gems: result30,
+                            identified: _rt::bool_lift(l31 as u8),
+                            weight: l32 as u32,
+                        },
+                        match l33 {
+                            0 => {
+                                let l34 = *arg0
+                                    .add(52 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>();
+                                let e = l34 as u32;
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                    e,
+                                )
+                            }
+                            1 => {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                            }
+                            2 => {
+                                let l35 = *arg0
+                                    .add(52 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>();
+                                let e = l35 as u32;
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                    e,
+                                )
+                            }
+                            3 => {
+                                super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr37 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result36 {
+                        Ok(e) => {
+                            *ptr37.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::exports::docs::inventory::types::Item {
+                                id: id38,
+                                name: name38,
+                                category: category38,
+                                attack_bonus: attack_bonus38,
+                                crit_chance: crit_chance38,
+                                defense_bonus: defense_bonus38,
+                                heal_amount: heal_amount38,
+                                quantity: quantity38,
+                                is_equipped: is_equipped38,
+                                is_cursed: is_cursed38,
+                                curse_effect: curse_effect38,
+                                enchantments: enchantments38,
+                                gem_slots: gem_slots38,
+                                socketed_gems: socketed_gems38,
+                                identified: identified38,
+                                weight: weight38,
+                            } = e;
+                            *ptr37
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(id38);
+                            let vec39 = (name38.into_bytes()).into_boxed_slice();
+                            let ptr39 = vec39.as_ptr().cast::<u8>();
+                            let len39 = vec39.len();
+                            ::core::mem::forget(vec39);
+                            *ptr37
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len39;
+                            *ptr37
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr39.cast_mut();
+                            *ptr37
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (category38.clone() as i32) as u8;
+                            *ptr37
+                                .add(4 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack_bonus38);
+                            *ptr37
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(crit_chance38);
+                            *ptr37
+                                .add(12 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense_bonus38);
+                            *ptr37
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(heal_amount38);
+                            *ptr37
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(quantity38);
+                            *ptr37
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_equipped38 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr37
+                                .add(25 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_cursed38 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            match curse_effect38 {
+                                Some(e) => {
+                                    *ptr37
+                                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr37
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr37
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr37
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr37
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr37
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr37
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr37
+                                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec40 = enchantments38;
+                            let len40 = vec40.len();
+                            let layout40 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec40.len() * 8,
+                                4,
+                            );
+                            let result40 = if layout40.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout40).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout40);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec40.into_iter().enumerate() {
+                                let base = result40.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr37
+                                .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len40;
+                            *ptr37
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result40;
+                            *ptr37
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (_rt::as_i32(gem_slots38)) as u8;
+                            let vec41 = socketed_gems38;
+                            let len41 = vec41.len();
+                            let layout41 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec41.len() * 8,
+                                4,
+                            );
+                            let result41 = if layout41.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout41).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout41);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec41.into_iter().enumerate() {
+                                let base = result41.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr37
+                                .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len41;
+                            *ptr37
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result41;
+                            *ptr37
+                                .add(40 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match identified38 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr37
+                                .add(44 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weight38);
+                        }
+                        Err(e) => {
+                            *ptr37.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec42 = (e.into_bytes()).into_boxed_slice();
+                            let ptr42 = vec42.as_ptr().cast::<u8>();
+                            let len42 = vec42.len();
+                            ::core::mem::forget(vec42);
+                            *ptr37
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len42;
+                            *ptr37
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr42.cast_mut();
+                        }
+                    };
+                    ptr37
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_enchant_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            _rt::cabi_dealloc(base5, len5 * 8, 4);
+                            let l6 = *arg0
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *arg0
+                                .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base8 = l6;
+                            let len8 = l7;
+                            _rt::cabi_dealloc(base8, len8 * 8, 4);
+                        }
+                        _ => {
+                            let l9 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l10 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l9, l10, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_socket_gem_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len3 = l2;
+                    let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                    let l4 = i32::from(
+                        *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l5 = *arg0
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l6 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l7 = *arg0
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = i32::from(
+                        *arg0
+                            .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l12 = i32::from(
+                        *arg0
+                            .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base22 = l17;
+                    let len22 = l18;
+                    let mut result22 = _rt::Vec::with_capacity(len22);
+                    for i in 0..len22 {
+                        let base = base22.add(i * 8);
+                        let e22 = {
+                            let l19 = i32::from(*base.add(0).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *base.add(4).cast::<i32>();
+                                    let e = l20 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l21 = *base.add(4).cast::<i32>();
+                                    let e = l21 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result22.push(e22);
+                    }
+                    _rt::cabi_dealloc(base22, len22 * 8, 4);
+                    let l23 = i32::from(
+                        *arg0
+                            .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l24;
+                    let len30 = l25;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30.add(i * 8);
+                        let e30 = {
+                            let l26 = i32::from(*base.add(0).cast::<u8>());
+                            match l26 {
+                                0 => {
+                                    let l27 = *base.add(4).cast::<i32>();
+                                    let e = l27 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l28 = *base.add(4).cast::<i32>();
+                                    let e = l28 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l29 = *base.add(4).cast::<i32>();
+                                    let e = l29 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 8, 4);
+                    let l31 = i32::from(
+                        *arg0
+                            .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *arg0
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l33 = i32::from(
+                        *arg0
+                            .add(48 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 8 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result37 = T::socket_gem(
+                        super::super::super::super::exports::docs::inventory::types::Item {
+                            id: l0 as u32,
+                            name: _rt::string_lift(bytes3),
+                            category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                l4 as u8,
+                            ),
+                            attack_bonus: l5 as u32,
+                            crit_chance: l6 as u32,
+                            defense_bonus: l7 as u32,
+                            heal_amount: l8 as u32,
+                            quantity: l9 as u32,
+                            is_equipped: _rt::bool_lift(l10 as u8),
+                            is_cursed: _rt::bool_lift(l11 as u8),
+                            curse_effect: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            enchantments: result22,
+                            gem_slots: l23 as u8,
+                            socketed_gems: result30,
+                            identified: _rt::bool_lift(l31 as u8),
+                            weight: l32 as u32,
+                        },
+                        match l33 {
+                            0 => {
+                                let l34 = *arg0
+                                    .add(52 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>();
+                                let e = l34 as u32;
+                                super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                    e,
+                                )
+                            }
+                            1 => {
+                                let l35 = *arg0
+                                    .add(52 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>();
+                                let e = l35 as u32;
+                                super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                    e,
+                                )
+                            }
+                            2 => {
+                                let l36 = *arg0
+                                    .add(52 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i32>();
+                                let e = l36 as u32;
+                                super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                    e,
+                                )
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    );
+                    let ptr38 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result37 {
+                        Ok(e) => {
+                            *ptr38.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::exports::docs::inventory::types::Item {
+                                id: id39,
+                                name: name39,
+                                category: category39,
+                                attack_bonus: attack_bonus39,
+                                crit_chance: crit_chance39,
+                                defense_bonus: defense_bonus39,
+                                heal_amount: heal_amount39,
+                                quantity: quantity39,
+                                is_equipped: is_equipped39,
+                                is_cursed: is_cursed39,
+                                curse_effect: curse_effect39,
+                                enchantments: enchantments39,
+                                gem_slots: gem_slots39,
+                                socketed_gems: socketed_gems39,
+                                identified: identified39,
+                                weight: weight39,
+                            } = e;
+                            *ptr38
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(id39);
+                            let vec40 = (name39.into_bytes()).into_boxed_slice();
+                            let ptr40 = vec40.as_ptr().cast::<u8>();
+                            let len40 = vec40.len();
+                            ::core::mem::forget(vec40);
+                            *ptr38
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len40;
+                            *ptr38
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr40.cast_mut();
+                            *ptr38
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (category39.clone() as i32) as u8;
+                            *ptr38
+                                .add(4 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack_bonus39);
+                            *ptr38
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(crit_chance39);
+                            *ptr38
+                                .add(12 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense_bonus39);
+                            *ptr38
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(heal_amount39);
+                            *ptr38
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(quantity39);
+                            *ptr38
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_equipped39 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr38
+                                .add(25 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_cursed39 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            match curse_effect39 {
+                                Some(e) => {
+                                    *ptr38
+                                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr38
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr38
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr38
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr38
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr38
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr38
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr38
+                                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec41 = enchantments39;
+                            let len41 = vec41.len();
+                            let layout41 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec41.len() * 8,
+                                4,
+                            );
+                            let result41 = if layout41.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout41).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout41);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec41.into_iter().enumerate() {
+                                let base = result41.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr38
+                                .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len41;
+                            *ptr38
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result41;
+                            *ptr38
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (_rt::as_i32(gem_slots39)) as u8;
+                            let vec42 = socketed_gems39;
+                            let len42 = vec42.len();
+                            let layout42 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec42.len() * 8,
+                                4,
+                            );
+                            let result42 = if layout42.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout42).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout42);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec42.into_iter().enumerate() {
+                                let base = result42.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr38
+                                .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len42;
+                            *ptr38
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result42;
+                            *ptr38
+                                .add(40 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match identified39 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr38
+                                .add(44 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weight39);
+                        }
+                        Err(e) => {
+                            *ptr38.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec43 = (e.into_bytes()).into_boxed_slice();
+                            let ptr43 = vec43.as_ptr().cast::<u8>();
+                            let len43 = vec43.len();
+                            ::core::mem::forget(vec43);
+                            *ptr38
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len43;
+                            *ptr38
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr43.cast_mut();
+                        }
+                    };
+                    ptr38
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_socket_gem<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            _rt::cabi_dealloc(base5, len5 * 8, 4);
+                            let l6 = *arg0
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *arg0
+                                .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base8 = l6;
+                            let len8 = l7;
+                            _rt::cabi_dealloc(base8, len8 * 8, 4);
+                        }
+                        _ => {
+                            let l9 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l10 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l9, l10, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_dismantle_item_cabi<T: Guest>(
+                    arg0: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::dismantle_item(arg0 as u32);
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec3 = result0;
+                    let len3 = vec3.len();
+                    let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec3.len() * 8,
+                        4,
+                    );
+                    let result3 = if layout3.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout3);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec3.into_iter().enumerate() {
+                        let base = result3.add(i * 8);
+                        {
+                            let (t2_0, t2_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t2_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t2_1);
+                        }
+                    }
+                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+                    *ptr1.add(0).cast::<*mut u8>() = result3;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_dismantle_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_combine_items_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg0;
+                    let len2 = arg1;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::combine_items(result2);
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::exports::docs::inventory::types::Item {
+                                id: id5,
+                                name: name5,
+                                category: category5,
+                                attack_bonus: attack_bonus5,
+                                crit_chance: crit_chance5,
+                                defense_bonus: defense_bonus5,
+                                heal_amount: heal_amount5,
+                                quantity: quantity5,
+                                is_equipped: is_equipped5,
+                                is_cursed: is_cursed5,
+                                curse_effect: curse_effect5,
+                                enchantments: enchantments5,
+                                gem_slots: gem_slots5,
+                                socketed_gems: socketed_gems5,
+                                identified: identified5,
+                                weight: weight5,
+                            } = e;
+                            *ptr4
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(id5);
+                            let vec6 = (name5.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr4
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *ptr4
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr6.cast_mut();
+                            *ptr4
+                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (category5.clone() as i32) as u8;
+                            *ptr4
+                                .add(4 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack_bonus5);
+                            *ptr4
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(crit_chance5);
+                            *ptr4
+                                .add(12 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense_bonus5);
+                            *ptr4
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(heal_amount5);
+                            *ptr4
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(quantity5);
+                            *ptr4
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_equipped5 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr4
+                                .add(25 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_cursed5 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            match curse_effect5 {
+                                Some(e) => {
+                                    *ptr4
+                                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr4
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr4
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr4
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr4
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr4
+                                                .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr4
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr4
+                                        .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec7 = enchantments5;
+                            let len7 = vec7.len();
+                            let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec7.len() * 8,
+                                4,
+                            );
+                            let result7 = if layout7.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout7);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec7.into_iter().enumerate() {
+                                let base = result7.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr4
+                                .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr4
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result7;
+                            *ptr4
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (_rt::as_i32(gem_slots5)) as u8;
+                            let vec8 = socketed_gems5;
+                            let len8 = vec8.len();
+                            let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec8.len() * 8,
+                                4,
+                            );
+                            let result8 = if layout8.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout8);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec8.into_iter().enumerate() {
+                                let base = result8.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr4
+                                .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len8;
+                            *ptr4
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result8;
+                            *ptr4
+                                .add(40 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match identified5 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr4
+                                .add(44 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weight5);
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec9 = (e.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr4
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len9;
+                            *ptr4
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr9.cast_mut();
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_combine_items<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base5 = l3;
+                            let len5 = l4;
+                            _rt::cabi_dealloc(base5, len5 * 8, 4);
+                            let l6 = *arg0
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *arg0
+                                .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base8 = l6;
+                            let len8 = l7;
+                            _rt::cabi_dealloc(base8, len8 * 8, 4);
+                        }
+                        _ => {
+                            let l9 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l10 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l9, l10, 1);
+                        }
+                    }
+                }
+                pub trait Guest {
+                    /// Create a weapon item.
+                    fn create_weapon(weapon: WeaponType) -> Item;
+                    /// Create an armor item.
+                    fn create_armor(armor: ArmorType) -> Item;
+                    /// Create a consumable item.
+                    fn create_consumable(
+                        consumable: ConsumableType,
+                        quantity: u32,
+                    ) -> Item;
+                    /// Get item stats by ID.
+                    ///
+                    /// Unidentified items report zero for every bonus.
+                    fn get_item_stats(item_id: u32) -> Item;
+                    /// Reveal an unidentified item with a Scroll of Identify.
+                    fn identify_item(item: Item) -> Item;
+                    /// Format items as a table, hiding the stats of unidentified items.
+                    fn format_inventory_table(items: _rt::Vec<Item>) -> _rt::String;
+                    /// Sum the weight of all carried items, counting each unit.
+                    fn total_inventory_weight(items: _rt::Vec<Item>) -> u32;
+                    /// Add an enchantment to an item.
+                    ///
+                    /// Fails when the item's enchantment slots are full.
+                    fn enchant_item(
+                        item: Item,
+                        enchantment: Enchantment,
+                    ) -> Result<Item, _rt::String>;
+                    /// Socket a gem into an item.
+                    ///
+                    /// Fails when every gem slot on the item is filled.
+                    fn socket_gem(item: Item, gem: GemType) -> Result<Item, _rt::String>;
+                    /// Break an item into crafting materials as (material-id, quantity).
+                    ///
+                    /// Items that cannot be dismantled, such as consumables, give an empty list.
+                    fn dismantle_item(item_id: u32) -> _rt::Vec<(u32, u32)>;
+                    /// Craft an item from (item-id, quantity) ingredients, such as materials.
+                    ///
+                    /// Fails when no recipe uses exactly these ingredients.
+                    fn combine_items(
+                        ingredients: _rt::Vec<(u32, u32)>,
+                    ) -> Result<Item, _rt::String>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_docs_inventory_items_0_1_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#create-weapon")] unsafe extern "C" fn
+                        export_create_weapon(arg0 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_create_weapon_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#create-weapon")] unsafe
+                        extern "C" fn _post_return_create_weapon(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_create_weapon::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#create-armor")] unsafe extern "C" fn
+                        export_create_armor(arg0 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_create_armor_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
                         "cabi_post_docs:inventory/items@0.1.0#create-armor")] unsafe
                         extern "C" fn _post_return_create_armor(arg0 : * mut u8,) {
                         unsafe { $($path_to_types)*:: __post_return_create_armor::<$ty >
                         (arg0) } } #[unsafe (export_name =
-                        "docs:inventory/items@0.1.0#create-consumable")] unsafe extern
-                        "C" fn export_create_consumable(arg0 : i32, arg1 : i32,) -> * mut
-                        u8 { unsafe { $($path_to_types)*::
-                        _export_create_consumable_cabi::<$ty > (arg0, arg1) } } #[unsafe
+                        "docs:inventory/items@0.1.0#create-consumable")] unsafe extern
+                        "C" fn export_create_consumable(arg0 : i32, arg1 : i32,) -> * mut
+                        u8 { unsafe { $($path_to_types)*::
+                        _export_create_consumable_cabi::<$ty > (arg0, arg1) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#create-consumable")] unsafe
+                        extern "C" fn _post_return_create_consumable(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_create_consumable::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/items@0.1.0#get-item-stats")]
+                        unsafe extern "C" fn export_get_item_stats(arg0 : i32,) -> * mut
+                        u8 { unsafe { $($path_to_types)*::
+                        _export_get_item_stats_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#get-item-stats")] unsafe
+                        extern "C" fn _post_return_get_item_stats(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_get_item_stats::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#identify-item")] unsafe extern "C" fn
+                        export_identify_item(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_identify_item_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#identify-item")] unsafe
+                        extern "C" fn _post_return_identify_item(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_identify_item::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#format-inventory-table")] unsafe
+                        extern "C" fn export_format_inventory_table(arg0 : * mut u8, arg1
+                        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_format_inventory_table_cabi::<$ty > (arg0, arg1) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#format-inventory-table")]
+                        unsafe extern "C" fn _post_return_format_inventory_table(arg0 : *
+                        mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_format_inventory_table::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "docs:inventory/items@0.1.0#total-inventory-weight")] unsafe
+                        extern "C" fn export_total_inventory_weight(arg0 : * mut u8, arg1
+                        : usize,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_total_inventory_weight_cabi::<$ty > (arg0, arg1) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#enchant-item")] unsafe extern "C" fn
+                        export_enchant_item(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_enchant_item_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#enchant-item")] unsafe
+                        extern "C" fn _post_return_enchant_item(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_enchant_item::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#socket-gem")] unsafe extern "C" fn
+                        export_socket_gem(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_socket_gem_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#socket-gem")] unsafe extern
+                        "C" fn _post_return_socket_gem(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_socket_gem::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#dismantle-item")] unsafe extern "C"
+                        fn export_dismantle_item(arg0 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_dismantle_item_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#dismantle-item")] unsafe
+                        extern "C" fn _post_return_dismantle_item(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_dismantle_item::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/items@0.1.0#combine-items")] unsafe extern "C" fn
+                        export_combine_items(arg0 : * mut u8, arg1 : usize,) -> * mut u8
+                        { unsafe { $($path_to_types)*:: _export_combine_items_cabi::<$ty
+                        > (arg0, arg1) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/items@0.1.0#combine-items")] unsafe
+                        extern "C" fn _post_return_combine_items(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_combine_items::<$ty >
+                        (arg0) } } };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_docs_inventory_items_0_1_0_cabi;
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 48 + 9 * ::core::mem::size_of::<*const u8>()],
+                );
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 48
+                        + 9 * ::core::mem::size_of::<*const u8>()],
+                );
+            }
+            /// Inventory management interface.
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod management {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type InventoryState = super::super::super::super::exports::docs::inventory::types::InventoryState;
+                pub type Item = super::super::super::super::exports::docs::inventory::types::Item;
+                pub type PlayerStats = super::super::super::super::exports::docs::inventory::types::PlayerStats;
+                pub type InventorySnapshot = super::super::super::super::exports::docs::inventory::types::InventorySnapshot;
+                pub type InventoryDiff = super::super::super::super::exports::docs::inventory::types::InventoryDiff;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_create_inventory_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::create_inventory();
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon2,
+                        equipped_offhand: equipped_offhand2,
+                        equipped_armor: equipped_armor2,
+                        equipped_ring: equipped_ring2,
+                        item_count: item_count2,
+                        item_ids: item_ids2,
+                        max_capacity: max_capacity2,
+                        gold: gold2,
+                        is_poisoned: is_poisoned2,
+                        poison_turns_remaining: poison_turns_remaining2,
+                        weapon_curse: weapon_curse2,
+                        armor_curse: armor_curse2,
+                        weapon_poison_hits: weapon_poison_hits2,
+                    } = result0;
+                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(equipped_armor2);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(equipped_ring2);
+                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(item_count2);
+                    let vec3 = (item_ids2).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr1
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len3;
+                    *ptr1
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr3.cast_mut();
+                    *ptr1
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity2);
+                    *ptr1
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold2);
+                    *ptr1
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining2);
+                    match weapon_curse2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr1
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr1
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr1
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr1
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr1
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr1
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr1
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr1
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr1
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr1
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr1
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits2);
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_create_inventory<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_item_cabi<T: Guest>(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::add_item(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_add_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_remove_item_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::remove_item(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_remove_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_has_item_cabi<T: Guest>(arg0: *mut u8) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::has_item(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    match result24 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_item_count_of_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::item_count_of(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    _rt::as_i32(result24)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_drop_item_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::drop_item(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t26_0, t26_1) = result24;
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon27,
+                        equipped_offhand: equipped_offhand27,
+                        equipped_armor: equipped_armor27,
+                        equipped_ring: equipped_ring27,
+                        item_count: item_count27,
+                        item_ids: item_ids27,
+                        max_capacity: max_capacity27,
+                        gold: gold27,
+                        is_poisoned: is_poisoned27,
+                        poison_turns_remaining: poison_turns_remaining27,
+                        weapon_curse: weapon_curse27,
+                        armor_curse: armor_curse27,
+                        weapon_poison_hits: weapon_poison_hits27,
+                    } = t26_0;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon27);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand27);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor27);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring27);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count27);
+                    let vec28 = (item_ids27).into_boxed_slice();
+                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                    let len28 = vec28.len();
+                    ::core::mem::forget(vec28);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len28;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr28.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity27);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold27);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned27 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining27);
+                    match weapon_curse27 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse27 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits27);
+                    *ptr25
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match t26_1 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_drop_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_weapon_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::equip_weapon(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_weapon<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_weapon_with_stats_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l25 = *arg0
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l26 = *arg0
+                        .add(68 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        72 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result27 = T::equip_weapon_with_stats(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        super::super::super::super::exports::docs::inventory::types::PlayerStats {
+                            attack: l23 as u32,
+                            defense: l24 as u32,
+                            max_health: l25 as u32,
+                        },
+                        l26 as u32,
+                    );
+                    let ptr28 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t29_0, t29_1) = result27;
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon30,
+                        equipped_offhand: equipped_offhand30,
+                        equipped_armor: equipped_armor30,
+                        equipped_ring: equipped_ring30,
+                        item_count: item_count30,
+                        item_ids: item_ids30,
+                        max_capacity: max_capacity30,
+                        gold: gold30,
+                        is_poisoned: is_poisoned30,
+                        poison_turns_remaining: poison_turns_remaining30,
+                        weapon_curse: weapon_curse30,
+                        armor_curse: armor_curse30,
+                        weapon_poison_hits: weapon_poison_hits30,
+                    } = t29_0;
+                    *ptr28.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon30);
+                    *ptr28.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand30);
+                    *ptr28.add(8).cast::<i32>() = _rt::as_i32(equipped_armor30);
+                    *ptr28.add(12).cast::<i32>() = _rt::as_i32(equipped_ring30);
+                    *ptr28.add(16).cast::<i32>() = _rt::as_i32(item_count30);
+                    let vec31 = (item_ids30).into_boxed_slice();
+                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                    let len31 = vec31.len();
+                    ::core::mem::forget(vec31);
+                    *ptr28
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len31;
+                    *ptr28
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr31.cast_mut();
+                    *ptr28
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity30);
+                    *ptr28
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold30);
+                    *ptr28
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned30 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr28
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining30);
+                    match weapon_curse30 {
+                        Some(e) => {
+                            *ptr28
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr28
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr28
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr28
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr28
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr28
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr28
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr28
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse30 {
+                        Some(e) => {
+                            *ptr28
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr28
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr28
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr28
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr28
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr28
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr28
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr28
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr28
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits30);
+                    let super::super::super::super::exports::docs::inventory::types::PlayerStats {
+                        attack: attack32,
+                        defense: defense32,
+                        max_health: max_health32,
+                    } = t29_1;
+                    *ptr28
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(attack32);
+                    *ptr28
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(defense32);
+                    *ptr28
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_health32);
+                    ptr28
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_weapon_with_stats<T: Guest>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_offhand_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::equip_offhand(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_offhand<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_armor_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::equip_armor(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_armor<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_armor_with_stats_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l25 = *arg0
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l26 = *arg0
+                        .add(68 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        72 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result27 = T::equip_armor_with_stats(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        super::super::super::super::exports::docs::inventory::types::PlayerStats {
+                            attack: l23 as u32,
+                            defense: l24 as u32,
+                            max_health: l25 as u32,
+                        },
+                        l26 as u32,
+                    );
+                    let ptr28 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t29_0, t29_1) = result27;
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon30,
+                        equipped_offhand: equipped_offhand30,
+                        equipped_armor: equipped_armor30,
+                        equipped_ring: equipped_ring30,
+                        item_count: item_count30,
+                        item_ids: item_ids30,
+                        max_capacity: max_capacity30,
+                        gold: gold30,
+                        is_poisoned: is_poisoned30,
+                        poison_turns_remaining: poison_turns_remaining30,
+                        weapon_curse: weapon_curse30,
+                        armor_curse: armor_curse30,
+                        weapon_poison_hits: weapon_poison_hits30,
+                    } = t29_0;
+                    *ptr28.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon30);
+                    *ptr28.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand30);
+                    *ptr28.add(8).cast::<i32>() = _rt::as_i32(equipped_armor30);
+                    *ptr28.add(12).cast::<i32>() = _rt::as_i32(equipped_ring30);
+                    *ptr28.add(16).cast::<i32>() = _rt::as_i32(item_count30);
+                    let vec31 = (item_ids30).into_boxed_slice();
+                    let ptr31 = vec31.as_ptr().cast::<u8>();
+                    let len31 = vec31.len();
+                    ::core::mem::forget(vec31);
+                    *ptr28
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len31;
+                    *ptr28
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr31.cast_mut();
+                    *ptr28
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity30);
+                    *ptr28
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold30);
+                    *ptr28
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned30 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr28
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining30);
+                    match weapon_curse30 {
+                        Some(e) => {
+                            *ptr28
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr28
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr28
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr28
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr28
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr28
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr28
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr28
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse30 {
+                        Some(e) => {
+                            *ptr28
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr28
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr28
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr28
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr28
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr28
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr28
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr28
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr28
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits30);
+                    let super::super::super::super::exports::docs::inventory::types::PlayerStats {
+                        attack: attack32,
+                        defense: defense32,
+                        max_health: max_health32,
+                    } = t29_1;
+                    *ptr28
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(attack32);
+                    *ptr28
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(defense32);
+                    *ptr28
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_health32);
+                    ptr28
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_armor_with_stats<T: Guest>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_ring_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::equip_ring(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_ring<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_is_hookshot_equipped_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = T::is_hookshot_equipped(super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: l0 as u32,
+                        equipped_offhand: l1 as u32,
+                        equipped_armor: l2 as u32,
+                        equipped_ring: l3 as u32,
+                        item_count: l4 as u32,
+                        item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                        max_capacity: l8 as u32,
+                        gold: l9 as u32,
+                        is_poisoned: _rt::bool_lift(l10 as u8),
+                        poison_turns_remaining: l11 as u32,
+                        weapon_curse: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        armor_curse: match l17 {
+                            0 => None,
+                            1 => {
+                                let l18 = i32::from(
+                                    *arg0
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l18 {
+                                    0 => {
+                                        let l19 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l19 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l20 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l20 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l21 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l21 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        weapon_poison_hits: l22 as u32,
+                    });
+                    match result23 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_add_gold_cabi<T: Guest>(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::add_gold(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_add_gold<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_spend_gold_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::spend_gold(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_spend_gold<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_is_full_cabi<T: Guest>(arg0: *mut u8) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = T::is_full(super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: l0 as u32,
+                        equipped_offhand: l1 as u32,
+                        equipped_armor: l2 as u32,
+                        equipped_ring: l3 as u32,
+                        item_count: l4 as u32,
+                        item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                        max_capacity: l8 as u32,
+                        gold: l9 as u32,
+                        is_poisoned: _rt::bool_lift(l10 as u8),
+                        poison_turns_remaining: l11 as u32,
+                        weapon_curse: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        armor_curse: match l17 {
+                            0 => None,
+                            1 => {
+                                let l18 = i32::from(
+                                    *arg0
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l18 {
+                                    0 => {
+                                        let l19 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l19 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l20 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l20 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l21 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l21 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        weapon_poison_hits: l22 as u32,
+                    });
+                    match result23 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_take_snapshot_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l24 = *arg0
+                        .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base58 = l23;
+                    let len58 = l24;
+                    let mut result58 = _rt::Vec::with_capacity(len58);
+                    for i in 0..len58 {
+                        let base = base58
+                            .add(i * (48 + 8 * ::core::mem::size_of::<*const u8>()));
+                        let e58 = {
+                            let l25 = *base.add(0).cast::<i32>();
+                            let l26 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l27 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len28 = l27;
+                            let bytes28 = _rt::Vec::from_raw_parts(
+                                l26.cast(),
+                                len28,
+                                len28,
+                            );
+                            let l29 = i32::from(
+                                *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l30 = *base
+                                .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l31 = *base
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l32 = *base
+                                .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l33 = *base
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l34 = *base
+                                .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l35 = i32::from(
+                                *base
+                                    .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l36 = i32::from(
+                                *base
+                                    .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l37 = i32::from(
+                                *base
+                                    .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l42 = *base
+                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l43 = *base
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base47 = l42;
+                            let len47 = l43;
+                            let mut result47 = _rt::Vec::with_capacity(len47);
+                            for i in 0..len47 {
+                                let base = base47.add(i * 8);
+                                let e47 = {
+                                    let l44 = i32::from(*base.add(0).cast::<u8>());
+                                    match l44 {
+                                        0 => {
+                                            let l45 = *base.add(4).cast::<i32>();
+                                            let e = l45 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                        }
+                                        2 => {
+                                            let l46 = *base.add(4).cast::<i32>();
+                                            let e = l46 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                                e,
+                                            )
+                                        }
+                                        3 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result47.push(e47);
+                            }
+                            _rt::cabi_dealloc(base47, len47 * 8, 4);
+                            let l48 = i32::from(
+                                *base
+                                    .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l49 = *base
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l50 = *base
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base55 = l49;
+                            let len55 = l50;
+                            let mut result55 = _rt::Vec::with_capacity(len55);
+                            for i in 0..len55 {
+                                let base = base55.add(i * 8);
+                                let e55 = {
+                                    let l51 = i32::from(*base.add(0).cast::<u8>());
+                                    match l51 {
+                                        0 => {
+                                            let l52 = *base.add(4).cast::<i32>();
+                                            let e = l52 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l53 = *base.add(4).cast::<i32>();
+                                            let e = l53 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l54 = *base.add(4).cast::<i32>();
+                                            let e = l54 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result55.push(e55);
+                            }
+                            _rt::cabi_dealloc(base55, len55 * 8, 4);
+                            let l56 = i32::from(
+                                *base
+                                    .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l57 = *base
+                                .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            super::super::super::super::exports::docs::inventory::types::Item {
+                                id: l25 as u32,
+                                name: _rt::string_lift(bytes28),
+                                category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                    l29 as u8,
+                                ),
+                                attack_bonus: l30 as u32,
+                                crit_chance: l31 as u32,
+                                defense_bonus: l32 as u32,
+                                heal_amount: l33 as u32,
+                                quantity: l34 as u32,
+                                is_equipped: _rt::bool_lift(l35 as u8),
+                                is_cursed: _rt::bool_lift(l36 as u8),
+                                curse_effect: match l37 {
+                                    0 => None,
+                                    1 => {
+                                        let l38 = i32::from(
+                                            *base
+                                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l38 {
+                                            0 => {
+                                                let l39 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l39 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l40 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l40 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l41 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l41 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                enchantments: result47,
+                                gem_slots: l48 as u8,
+                                socketed_gems: result55,
+                                identified: _rt::bool_lift(l56 as u8),
+                                weight: l57 as u32,
+                            }
+                        };
+                        result58.push(e58);
+                    }
+                    _rt::cabi_dealloc(
+                        base58,
+                        len58 * (48 + 8 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 6 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result59 = T::take_snapshot(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        result58,
+                    );
+                    let ptr60 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventorySnapshot {
+                        state: state61,
+                        item_counts: item_counts61,
+                    } = result59;
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon62,
+                        equipped_offhand: equipped_offhand62,
+                        equipped_armor: equipped_armor62,
+                        equipped_ring: equipped_ring62,
+                        item_count: item_count62,
+                        item_ids: item_ids62,
+                        max_capacity: max_capacity62,
+                        gold: gold62,
+                        is_poisoned: is_poisoned62,
+                        poison_turns_remaining: poison_turns_remaining62,
+                        weapon_curse: weapon_curse62,
+                        armor_curse: armor_curse62,
+                        weapon_poison_hits: weapon_poison_hits62,
+                    } = state61;
+                    *ptr60.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon62);
+                    *ptr60.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand62);
+                    *ptr60.add(8).cast::<i32>() = _rt::as_i32(equipped_armor62);
+                    *ptr60.add(12).cast::<i32>() = _rt::as_i32(equipped_ring62);
+                    *ptr60.add(16).cast::<i32>() = _rt::as_i32(item_count62);
+                    let vec63 = (item_ids62).into_boxed_slice();
+                    let ptr63 = vec63.as_ptr().cast::<u8>();
+                    let len63 = vec63.len();
+                    ::core::mem::forget(vec63);
+                    *ptr60
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len63;
+                    *ptr60
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr63.cast_mut();
+                    *ptr60
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity62);
+                    *ptr60
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold62);
+                    *ptr60
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned62 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr60
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining62);
+                    match weapon_curse62 {
+                        Some(e) => {
+                            *ptr60
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr60
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr60
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr60
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr60
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr60
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr60
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr60
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse62 {
+                        Some(e) => {
+                            *ptr60
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr60
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr60
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr60
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr60
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr60
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr60
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr60
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr60
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits62);
+                    let vec65 = item_counts61;
+                    let len65 = vec65.len();
+                    let layout65 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec65.len() * 8,
+                        4,
+                    );
+                    let result65 = if layout65.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout65).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout65);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec65.into_iter().enumerate() {
+                        let base = result65.add(i * 8);
+                        {
+                            let (t64_0, t64_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t64_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t64_1);
+                        }
+                    }
+                    *ptr60
+                        .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len65;
+                    *ptr60
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = result65;
+                    ptr60
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_take_snapshot<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                    let l3 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base5 = l3;
+                    let len5 = l4;
+                    _rt::cabi_dealloc(base5, len5 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_diff_snapshots_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l24 = *arg0
+                        .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base27 = l23;
+                    let len27 = l24;
+                    let mut result27 = _rt::Vec::with_capacity(len27);
+                    for i in 0..len27 {
+                        let base = base27.add(i * 8);
+                        let e27 = {
+                            let l25 = *base.add(0).cast::<i32>();
+                            let l26 = *base.add(4).cast::<i32>();
+                            (l25 as u32, l26 as u32)
+                        };
+                        result27.push(e27);
+                    }
+                    _rt::cabi_dealloc(base27, len27 * 8, 4);
+                    let l28 = *arg0
+                        .add(56 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l29 = *arg0
+                        .add(60 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l30 = *arg0
+                        .add(64 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l31 = *arg0
+                        .add(68 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l32 = *arg0
+                        .add(72 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l33 = *arg0
+                        .add(72 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l34 = *arg0
+                        .add(72 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len35 = l34;
+                    let l36 = *arg0
+                        .add(72 + 9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l37 = *arg0
+                        .add(76 + 9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l38 = i32::from(
+                        *arg0
+                            .add(80 + 9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l39 = *arg0
+                        .add(84 + 9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l40 = i32::from(
+                        *arg0
+                            .add(88 + 9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l45 = i32::from(
+                        *arg0
+                            .add(100 + 9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l50 = *arg0
+                        .add(112 + 9 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l51 = *arg0
+                        .add(112 + 10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l52 = *arg0
+                        .add(112 + 11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base55 = l51;
+                    let len55 = l52;
+                    let mut result55 = _rt::Vec::with_capacity(len55);
+                    for i in 0..len55 {
+                        let base = base55.add(i * 8);
+                        let e55 = {
+                            let l53 = *base.add(0).cast::<i32>();
+                            let l54 = *base.add(4).cast::<i32>();
+                            (l53 as u32, l54 as u32)
+                        };
+                        result55.push(e55);
+                    }
+                    _rt::cabi_dealloc(base55, len55 * 8, 4);
+                    _rt::cabi_dealloc(
+                        arg0,
+                        112 + 12 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result56 = T::diff_snapshots(
+                        super::super::super::super::exports::docs::inventory::types::InventorySnapshot {
+                            state: super::super::super::super::exports::docs::inventory::types::InventoryState {
+                                equipped_weapon: l0 as u32,
+                                equipped_offhand: l1 as u32,
+                                equipped_armor: l2 as u32,
+                                equipped_ring: l3 as u32,
+                                item_count: l4 as u32,
+                                item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                                max_capacity: l8 as u32,
+                                gold: l9 as u32,
+                                is_poisoned: _rt::bool_lift(l10 as u8),
+                                poison_turns_remaining: l11 as u32,
+                                weapon_curse: match l12 {
+                                    0 => None,
+                                    1 => {
+                                        let l13 = i32::from(
+                                            *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l13 {
+                                            0 => {
+                                                let l14 = *arg0
+                                                    .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l14 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l15 = *arg0
+                                                    .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l15 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l16 = *arg0
+                                                    .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l16 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                armor_curse: match l17 {
+                                    0 => None,
+                                    1 => {
+                                        let l18 = i32::from(
+                                            *arg0
+                                                .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l18 {
+                                            0 => {
+                                                let l19 = *arg0
+                                                    .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l19 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l20 = *arg0
+                                                    .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l20 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l21 = *arg0
+                                                    .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l21 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                weapon_poison_hits: l22 as u32,
+                            },
+                            item_counts: result27,
+                        },
+                        super::super::super::super::exports::docs::inventory::types::InventorySnapshot {
+                            state: super::super::super::super::exports::docs::inventory::types::InventoryState {
+                                equipped_weapon: l28 as u32,
+                                equipped_offhand: l29 as u32,
+                                equipped_armor: l30 as u32,
+                                equipped_ring: l31 as u32,
+                                item_count: l32 as u32,
+                                item_ids: _rt::Vec::from_raw_parts(
+                                    l33.cast(),
+                                    len35,
+                                    len35,
+                                ),
+                                max_capacity: l36 as u32,
+                                gold: l37 as u32,
+                                is_poisoned: _rt::bool_lift(l38 as u8),
+                                poison_turns_remaining: l39 as u32,
+                                weapon_curse: match l40 {
+                                    0 => None,
+                                    1 => {
+                                        let l41 = i32::from(
+                                            *arg0
+                                                .add(92 + 9 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l41 {
+                                            0 => {
+                                                let l42 = *arg0
+                                                    .add(96 + 9 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l42 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l43 = *arg0
+                                                    .add(96 + 9 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l43 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l44 = *arg0
+                                                    .add(96 + 9 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l44 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                armor_curse: match l45 {
+                                    0 => None,
+                                    1 => {
+                                        let l46 = i32::from(
+                                            *arg0
+                                                .add(104 + 9 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l46 {
+                                            0 => {
+                                                let l47 = *arg0
+                                                    .add(108 + 9 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l47 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l48 = *arg0
+                                                    .add(108 + 9 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l48 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l49 = *arg0
+                                                    .add(108 + 9 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l49 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                weapon_poison_hits: l50 as u32,
+                            },
+                            item_counts: result55,
+                        },
+                    );
+                    let ptr57 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryDiff {
+                        added: added58,
+                        removed: removed58,
+                        gold_delta: gold_delta58,
+                    } = result56;
+                    let vec59 = (added58).into_boxed_slice();
+                    let ptr59 = vec59.as_ptr().cast::<u8>();
+                    let len59 = vec59.len();
+                    ::core::mem::forget(vec59);
+                    *ptr57.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len59;
+                    *ptr57.add(0).cast::<*mut u8>() = ptr59.cast_mut();
+                    let vec60 = (removed58).into_boxed_slice();
+                    let ptr60 = vec60.as_ptr().cast::<u8>();
+                    let len60 = vec60.len();
+                    ::core::mem::forget(vec60);
+                    *ptr57
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len60;
+                    *ptr57
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr60.cast_mut();
+                    *ptr57.add(4 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
+                        gold_delta58,
+                    );
+                    ptr57
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_diff_snapshots<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                    let l3 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base5 = l3;
+                    let len5 = l4;
+                    _rt::cabi_dealloc(base5, len5 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calculate_optimal_gear_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base33 = arg0;
+                    let len33 = arg1;
+                    let mut result33 = _rt::Vec::with_capacity(len33);
+                    for i in 0..len33 {
+                        let base = base33
+                            .add(i * (48 + 8 * ::core::mem::size_of::<*const u8>()));
+                        let e33 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            let l4 = i32::from(
+                                *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l5 = *base
+                                .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l6 = *base
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l7 = *base
+                                .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l8 = *base
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l9 = *base
+                                .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            let l10 = i32::from(
+                                *base
+                                    .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l11 = i32::from(
+                                *base
+                                    .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l12 = i32::from(
+                                *base
+                                    .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l17 = *base
+                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l18 = *base
+                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base22 = l17;
+                            let len22 = l18;
+                            let mut result22 = _rt::Vec::with_capacity(len22);
+                            for i in 0..len22 {
+                                let base = base22.add(i * 8);
+                                let e22 = {
+                                    let l19 = i32::from(*base.add(0).cast::<u8>());
+                                    match l19 {
+                                        0 => {
+                                            let l20 = *base.add(4).cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                        }
+                                        2 => {
+                                            let l21 = *base.add(4).cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                                e,
+                                            )
+                                        }
+                                        3 => {
+                                            super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result22.push(e22);
+                            }
+                            _rt::cabi_dealloc(base22, len22 * 8, 4);
+                            let l23 = i32::from(
+                                *base
+                                    .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l24 = *base
+                                .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l25 = *base
+                                .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base30 = l24;
+                            let len30 = l25;
+                            let mut result30 = _rt::Vec::with_capacity(len30);
+                            for i in 0..len30 {
+                                let base = base30.add(i * 8);
+                                let e30 = {
+                                    let l26 = i32::from(*base.add(0).cast::<u8>());
+                                    match l26 {
+                                        0 => {
+                                            let l27 = *base.add(4).cast::<i32>();
+                                            let e = l27 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l28 = *base.add(4).cast::<i32>();
+                                            let e = l28 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l29 = *base.add(4).cast::<i32>();
+                                            let e = l29 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                result30.push(e30);
+                            }
+                            _rt::cabi_dealloc(base30, len30 * 8, 4);
+                            let l31 = i32::from(
+                                *base
+                                    .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l32 = *base
+                                .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>();
+                            super::super::super::super::exports::docs::inventory::types::Item {
+                                id: l0 as u32,
+                                name: _rt::string_lift(bytes3),
+                                category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                    l4 as u8,
+                                ),
+                                attack_bonus: l5 as u32,
+                                crit_chance: l6 as u32,
+                                defense_bonus: l7 as u32,
+                                heal_amount: l8 as u32,
+                                quantity: l9 as u32,
+                                is_equipped: _rt::bool_lift(l10 as u8),
+                                is_cursed: _rt::bool_lift(l11 as u8),
+                                curse_effect: match l12 {
+                                    0 => None,
+                                    1 => {
+                                        let l13 = i32::from(
+                                            *base
+                                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let e = match l13 {
+                                            0 => {
+                                                let l14 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l14 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                    e,
+                                                )
+                                            }
+                                            1 => {
+                                                let l15 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l15 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                    e,
+                                                )
+                                            }
+                                            2 => {
+                                                let l16 = *base
+                                                    .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                let e = l16 as u32;
+                                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                    e,
+                                                )
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                enchantments: result22,
+                                gem_slots: l23 as u8,
+                                socketed_gems: result30,
+                                identified: _rt::bool_lift(l31 as u8),
+                                weight: l32 as u32,
+                            }
+                        };
+                        result33.push(e33);
+                    }
+                    _rt::cabi_dealloc(
+                        base33,
+                        len33 * (48 + 8 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result34 = T::calculate_optimal_gear(result33, arg2 as u32);
+                    let ptr35 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t36_0, t36_1) = result34;
+                    match t36_0 {
+                        Some(e) => {
+                            *ptr35.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr35.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                        None => {
+                            *ptr35.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match t36_1 {
+                        Some(e) => {
+                            *ptr35.add(8).cast::<u8>() = (1i32) as u8;
+                            *ptr35.add(12).cast::<i32>() = _rt::as_i32(e);
+                        }
+                        None => {
+                            *ptr35.add(8).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr35
+                }
+                pub trait Guest {
+                    /// Create a new empty inventory.
+                    fn create_inventory() -> InventoryState;
+                    /// Add an item to the inventory.
+                    ///
+                    /// Returns updated inventory state and success flag.
+                    fn add_item(inv: InventoryState, item_id: u32) -> InventoryState;
+                    /// Remove the first carried item with the given ID.
+                    ///
+                    /// Returns the inventory unchanged when no such item is carried.
+                    fn remove_item(inv: InventoryState, item_id: u32) -> InventoryState;
+                    /// Check if at least one item with the given ID is carried.
+                    fn has_item(inv: InventoryState, item_id: u32) -> bool;
+                    /// Count the carried items with the given ID.
+                    fn item_count_of(inv: InventoryState, item_id: u32) -> u32;
+                    /// Drop an item, unequipping it first from every slot that holds it.
+                    ///
+                    /// The flag is false when the item is unknown, not carried, or the
+                    /// equipped weapon or armor while it is cursed.
+                    fn drop_item(
+                        inv: InventoryState,
+                        item_id: u32,
+                    ) -> (InventoryState, bool);
+                    /// Equip a weapon by item ID.
+                    ///
+                    /// A cursed weapon cannot be swapped out until its curse is lifted.
+                    fn equip_weapon(inv: InventoryState, item_id: u32) -> InventoryState;
+                    /// Equip a weapon and apply its curse, if any, to the player's stats.
+                    ///
+                    /// Non-weapons and swaps away from a cursed weapon leave both unchanged.
+                    fn equip_weapon_with_stats(
+                        inv: InventoryState,
+                        stats: PlayerStats,
+                        item_id: u32,
+                    ) -> (InventoryState, PlayerStats);
+                    /// Equip an off-hand weapon by item ID for dual wielding.
+                    ///
+                    /// Non-weapons are ignored; item ID 0 empties the off hand.
+                    fn equip_offhand(
+                        inv: InventoryState,
+                        item_id: u32,
+                    ) -> InventoryState;
+                    /// Equip armor by item ID.
+                    ///
+                    /// Cursed armor cannot be swapped out until its curse is lifted.
+                    fn equip_armor(inv: InventoryState, item_id: u32) -> InventoryState;
+                    /// Equip armor and apply its curse, if any, to the player's stats.
+                    ///
+                    /// Non-armor and swaps away from cursed armor leave both unchanged.
+                    fn equip_armor_with_stats(
+                        inv: InventoryState,
+                        stats: PlayerStats,
+                        item_id: u32,
+                    ) -> (InventoryState, PlayerStats);
+                    /// Equip a ring slot item, such as the HookShot (id 302), by item ID.
+                    ///
+                    /// Items that are not accessories are ignored; item ID 0 empties the slot.
+                    fn equip_ring(inv: InventoryState, item_id: u32) -> InventoryState;
+                    /// Check if the HookShot is equipped in the ring slot.
+                    fn is_hookshot_equipped(inv: InventoryState) -> bool;
+                    /// Add gold to the inventory.
+                    fn add_gold(inv: InventoryState, amount: u32) -> InventoryState;
+                    /// Spend gold from the inventory.
+                    ///
+                    /// Returns updated state; gold unchanged if insufficient funds.
+                    fn spend_gold(inv: InventoryState, amount: u32) -> InventoryState;
+                    /// Check if inventory is full.
+                    fn is_full(inv: InventoryState) -> bool;
+                    /// Take a snapshot of the inventory and the items it holds.
+                    fn take_snapshot(
+                        inv: InventoryState,
+                        items: _rt::Vec<Item>,
+                    ) -> InventorySnapshot;
+                    /// List the item and gold changes between two snapshots.
+                    fn diff_snapshots(
+                        before: InventorySnapshot,
+                        after: InventorySnapshot,
+                    ) -> InventoryDiff;
+                    /// Recommend the best weapon and armor among the available items.
+                    ///
+                    /// Returns (weapon-id, armor-id); ties prefer the lower ID.
+                    fn calculate_optimal_gear(
+                        available_items: _rt::Vec<Item>,
+                        player_level: u32,
+                    ) -> (Option<u32>, Option<u32>);
+                }
+                #[doc(hidden)]
+                macro_rules! __export_docs_inventory_management_0_1_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#create-inventory")] unsafe
+                        extern "C" fn export_create_inventory() -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_create_inventory_cabi::<$ty > () } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#create-inventory")]
+                        unsafe extern "C" fn _post_return_create_inventory(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        __post_return_create_inventory::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/management@0.1.0#add-item")]
+                        unsafe extern "C" fn export_add_item(arg0 : * mut u8,) -> * mut
+                        u8 { unsafe { $($path_to_types)*:: _export_add_item_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#add-item")] unsafe
+                        extern "C" fn _post_return_add_item(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_add_item::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#remove-item")] unsafe extern "C"
+                        fn export_remove_item(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_remove_item_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#remove-item")] unsafe
+                        extern "C" fn _post_return_remove_item(arg0 : * mut u8,) { unsafe
+                        { $($path_to_types)*:: __post_return_remove_item::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#has-item")] unsafe extern "C" fn
+                        export_has_item(arg0 : * mut u8,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_has_item_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#item-count-of")] unsafe extern
+                        "C" fn export_item_count_of(arg0 : * mut u8,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_item_count_of_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#drop-item")] unsafe extern "C"
+                        fn export_drop_item(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_drop_item_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#drop-item")] unsafe
+                        extern "C" fn _post_return_drop_item(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_drop_item::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#equip-weapon")] unsafe extern
+                        "C" fn export_equip_weapon(arg0 : * mut u8,) -> * mut u8 { unsafe
+                        { $($path_to_types)*:: _export_equip_weapon_cabi::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#equip-weapon")] unsafe
+                        extern "C" fn _post_return_equip_weapon(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_equip_weapon::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#equip-weapon-with-stats")]
+                        unsafe extern "C" fn export_equip_weapon_with_stats(arg0 : * mut
+                        u8,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_equip_weapon_with_stats_cabi::<$ty > (arg0) } } #[unsafe
                         (export_name =
-                        "cabi_post_docs:inventory/items@0.1.0#create-consumable")] unsafe
-                        extern "C" fn _post_return_create_consumable(arg0 : * mut u8,) {
-                        unsafe { $($path_to_types)*::
-                        __post_return_create_consumable::<$ty > (arg0) } } #[unsafe
-                        (export_name = "docs:inventory/items@0.1.0#get-item-stats")]
-                        unsafe extern "C" fn export_get_item_stats(arg0 : i32,) -> * mut
-                        u8 { unsafe { $($path_to_types)*::
-                        _export_get_item_stats_cabi::<$ty > (arg0) } } #[unsafe
+                        "cabi_post_docs:inventory/management@0.1.0#equip-weapon-with-stats")]
+                        unsafe extern "C" fn _post_return_equip_weapon_with_stats(arg0 :
+                        * mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_equip_weapon_with_stats::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/management@0.1.0#equip-offhand")]
+                        unsafe extern "C" fn export_equip_offhand(arg0 : * mut u8,) -> *
+                        mut u8 { unsafe { $($path_to_types)*::
+                        _export_equip_offhand_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#equip-offhand")]
+                        unsafe extern "C" fn _post_return_equip_offhand(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*:: __post_return_equip_offhand::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#equip-armor")] unsafe extern "C"
+                        fn export_equip_armor(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_equip_armor_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#equip-armor")] unsafe
+                        extern "C" fn _post_return_equip_armor(arg0 : * mut u8,) { unsafe
+                        { $($path_to_types)*:: __post_return_equip_armor::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#equip-armor-with-stats")] unsafe
+                        extern "C" fn export_equip_armor_with_stats(arg0 : * mut u8,) ->
+                        * mut u8 { unsafe { $($path_to_types)*::
+                        _export_equip_armor_with_stats_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#equip-armor-with-stats")]
+                        unsafe extern "C" fn _post_return_equip_armor_with_stats(arg0 : *
+                        mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_equip_armor_with_stats::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/management@0.1.0#equip-ring")]
+                        unsafe extern "C" fn export_equip_ring(arg0 : * mut u8,) -> * mut
+                        u8 { unsafe { $($path_to_types)*:: _export_equip_ring_cabi::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#equip-ring")] unsafe
+                        extern "C" fn _post_return_equip_ring(arg0 : * mut u8,) { unsafe
+                        { $($path_to_types)*:: __post_return_equip_ring::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#is-hookshot-equipped")] unsafe
+                        extern "C" fn export_is_hookshot_equipped(arg0 : * mut u8,) ->
+                        i32 { unsafe { $($path_to_types)*::
+                        _export_is_hookshot_equipped_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/management@0.1.0#add-gold")]
+                        unsafe extern "C" fn export_add_gold(arg0 : * mut u8,) -> * mut
+                        u8 { unsafe { $($path_to_types)*:: _export_add_gold_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#add-gold")] unsafe
+                        extern "C" fn _post_return_add_gold(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_add_gold::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#spend-gold")] unsafe extern "C"
+                        fn export_spend_gold(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_spend_gold_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#spend-gold")] unsafe
+                        extern "C" fn _post_return_spend_gold(arg0 : * mut u8,) { unsafe
+                        { $($path_to_types)*:: __post_return_spend_gold::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#is-full")] unsafe extern "C" fn
+                        export_is_full(arg0 : * mut u8,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_is_full_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#take-snapshot")] unsafe extern
+                        "C" fn export_take_snapshot(arg0 : * mut u8,) -> * mut u8 {
+                        unsafe { $($path_to_types)*:: _export_take_snapshot_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#take-snapshot")]
+                        unsafe extern "C" fn _post_return_take_snapshot(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*:: __post_return_take_snapshot::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/management@0.1.0#diff-snapshots")] unsafe extern
+                        "C" fn export_diff_snapshots(arg0 : * mut u8,) -> * mut u8 {
+                        unsafe { $($path_to_types)*:: _export_diff_snapshots_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/management@0.1.0#diff-snapshots")]
+                        unsafe extern "C" fn _post_return_diff_snapshots(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        __post_return_diff_snapshots::<$ty > (arg0) } } #[unsafe
                         (export_name =
-                        "cabi_post_docs:inventory/items@0.1.0#get-item-stats")] unsafe
-                        extern "C" fn _post_return_get_item_stats(arg0 : * mut u8,) {
-                        unsafe { $($path_to_types)*:: __post_return_get_item_stats::<$ty
-                        > (arg0) } } };
+                        "docs:inventory/management@0.1.0#calculate-optimal-gear")] unsafe
+                        extern "C" fn export_calculate_optimal_gear(arg0 : * mut u8, arg1
+                        : usize, arg2 : i32,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_calculate_optimal_gear_cabi::<$ty > (arg0, arg1, arg2) }
+                        } };
                     };
                 }
                 #[doc(hidden)]
-                pub(crate) use __export_docs_inventory_items_0_1_0_cabi;
+                pub(crate) use __export_docs_inventory_management_0_1_0_cabi;
                 #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
                 #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
                 struct _RetArea(
                     [::core::mem::MaybeUninit<
                         u8,
-                    >; 24 + 3 * ::core::mem::size_of::<*const u8>()],
+                    >; 64 + 5 * ::core::mem::size_of::<*const u8>()],
                 );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 24
-                        + 3 * ::core::mem::size_of::<*const u8>()],
+                    [::core::mem::MaybeUninit::uninit(); 64
+                        + 5 * ::core::mem::size_of::<*const u8>()],
                 );
             }
-            /// Inventory management interface.
+            /// Item usage interface.
             #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
-            pub mod management {
+            pub mod usage {
                 #[used]
                 #[doc(hidden)]
                 static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
+                pub type UseResult = super::super::super::super::exports::docs::inventory::types::UseResult;
+                pub type PlayerStats = super::super::super::super::exports::docs::inventory::types::PlayerStats;
+                pub type Item = super::super::super::super::exports::docs::inventory::types::Item;
                 pub type InventoryState = super::super::super::super::exports::docs::inventory::types::InventoryState;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_create_inventory_cabi<T: Guest>() -> *mut u8 {
+                pub unsafe fn _export_use_item_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::create_inventory();
+                    let result0 = T::use_item(arg0 as u32, arg1 as u32, arg2 as u32);
                     let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
+                    let super::super::super::super::exports::docs::inventory::types::UseResult {
+                        success: success2,
+                        health_restored: health_restored2,
+                        attack_boost: attack_boost2,
+                        defense_boost: defense_boost2,
+                        message: message2,
+                        identified: identified2,
+                        reveals_area: reveals_area2,
                     } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
+                    *ptr1.add(0).cast::<u8>() = (match success2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(health_restored2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(attack_boost2);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense_boost2);
+                    let vec3 = (message2.into_bytes()).into_boxed_slice();
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    ::core::mem::forget(vec3);
+                    *ptr1
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len3;
+                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
+                    *ptr1
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified2 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match reveals_area2 {
+                        Some(e) => {
+                            *ptr1
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec4 = (e.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr1
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len4;
+                            *ptr1
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr4.cast_mut();
+                        }
+                        None => {
+                            *ptr1
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
                     ptr1
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_add_item_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
+                pub unsafe fn __post_return_use_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(16).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = i32::from(
+                        *arg0
+                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l2 {
+                        0 => {}
+                        _ => {
+                            let l3 = *arg0
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_use_magic_map_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result1 = T::use_magic_map(_rt::string_lift(bytes0));
+                    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::UseResult {
+                        success: success3,
+                        health_restored: health_restored3,
+                        attack_boost: attack_boost3,
+                        defense_boost: defense_boost3,
+                        message: message3,
+                        identified: identified3,
+                        reveals_area: reveals_area3,
+                    } = result1;
+                    *ptr2.add(0).cast::<u8>() = (match success3 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr2.add(4).cast::<i32>() = _rt::as_i32(health_restored3);
+                    *ptr2.add(8).cast::<i32>() = _rt::as_i32(attack_boost3);
+                    *ptr2.add(12).cast::<i32>() = _rt::as_i32(defense_boost3);
+                    let vec4 = (message3.into_bytes()).into_boxed_slice();
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    ::core::mem::forget(vec4);
+                    *ptr2
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr2.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                    *ptr2
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified3 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match reveals_area3 {
+                        Some(e) => {
+                            *ptr2
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr2
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr2
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr5.cast_mut();
+                        }
+                        None => {
+                            *ptr2
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_use_magic_map<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(16).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l0, l1, 1);
+                    let l2 = i32::from(
+                        *arg0
+                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l2 {
+                        0 => {}
+                        _ => {
+                            let l3 = *arg0
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_use_curse_stone_cabi<T: Guest>(
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::add_item(
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l25 = *arg0
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l26 = *arg0
+                        .add(68 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        72 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result27 = T::use_curse_stone(
                         super::super::super::super::exports::docs::inventory::types::InventoryState {
-                            equipped_weapon: arg0 as u32,
-                            equipped_armor: arg1 as u32,
-                            item_count: arg2 as u32,
-                            max_capacity: arg3 as u32,
-                            gold: arg4 as u32,
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        super::super::super::super::exports::docs::inventory::types::PlayerStats {
+                            attack: l23 as u32,
+                            defense: l24 as u32,
+                            max_health: l25 as u32,
                         },
-                        arg5 as u32,
+                        l26 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
-                    ptr1
+                    let ptr28 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result27 {
+                        Some(e) => {
+                            *ptr28.add(0).cast::<u8>() = (1i32) as u8;
+                            let (t29_0, t29_1) = e;
+                            let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                                equipped_weapon: equipped_weapon30,
+                                equipped_offhand: equipped_offhand30,
+                                equipped_armor: equipped_armor30,
+                                equipped_ring: equipped_ring30,
+                                item_count: item_count30,
+                                item_ids: item_ids30,
+                                max_capacity: max_capacity30,
+                                gold: gold30,
+                                is_poisoned: is_poisoned30,
+                                poison_turns_remaining: poison_turns_remaining30,
+                                weapon_curse: weapon_curse30,
+                                armor_curse: armor_curse30,
+                                weapon_poison_hits: weapon_poison_hits30,
+                            } = t29_0;
+                            *ptr28
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_weapon30);
+                            *ptr28
+                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_offhand30);
+                            *ptr28
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_armor30);
+                            *ptr28
+                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_ring30);
+                            *ptr28
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(item_count30);
+                            let vec31 = (item_ids30).into_boxed_slice();
+                            let ptr31 = vec31.as_ptr().cast::<u8>();
+                            let len31 = vec31.len();
+                            ::core::mem::forget(vec31);
+                            *ptr28
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len31;
+                            *ptr28
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr31.cast_mut();
+                            *ptr28
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_capacity30);
+                            *ptr28
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(gold30);
+                            *ptr28
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_poisoned30 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr28
+                                .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(poison_turns_remaining30);
+                            match weapon_curse30 {
+                                Some(e) => {
+                                    *ptr28
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr28
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr28
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr28
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr28
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr28
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr28
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr28
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match armor_curse30 {
+                                Some(e) => {
+                                    *ptr28
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr28
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr28
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr28
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr28
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr28
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr28
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr28
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *ptr28
+                                .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weapon_poison_hits30);
+                            let super::super::super::super::exports::docs::inventory::types::PlayerStats {
+                                attack: attack32,
+                                defense: defense32,
+                                max_health: max_health32,
+                            } = t29_1;
+                            *ptr28
+                                .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack32);
+                            *ptr28
+                                .add(60 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense32);
+                            *ptr28
+                                .add(64 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_health32);
+                        }
+                        None => {
+                            *ptr28.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr28
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_remove_item_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
+                pub unsafe fn __post_return_use_curse_stone<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 4, 4);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_use_identify_scroll_cabi<T: Guest>(
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::remove_item(
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(56 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len26 = l25;
+                    let bytes26 = _rt::Vec::from_raw_parts(l24.cast(), len26, len26);
+                    let l27 = i32::from(
+                        *arg0
+                            .add(56 + 7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l28 = *arg0
+                        .add(60 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l29 = *arg0
+                        .add(64 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l30 = *arg0
+                        .add(68 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l31 = *arg0
+                        .add(72 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l32 = *arg0
+                        .add(76 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l33 = i32::from(
+                        *arg0
+                            .add(80 + 7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l34 = i32::from(
+                        *arg0
+                            .add(81 + 7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l35 = i32::from(
+                        *arg0
+                            .add(84 + 7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l40 = *arg0
+                        .add(96 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l41 = *arg0
+                        .add(96 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base45 = l40;
+                    let len45 = l41;
+                    let mut result45 = _rt::Vec::with_capacity(len45);
+                    for i in 0..len45 {
+                        let base = base45.add(i * 8);
+                        let e45 = {
+                            let l42 = i32::from(*base.add(0).cast::<u8>());
+                            match l42 {
+                                0 => {
+                                    let l43 = *base.add(4).cast::<i32>();
+                                    let e = l43 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l44 = *base.add(4).cast::<i32>();
+                                    let e = l44 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result45.push(e45);
+                    }
+                    _rt::cabi_dealloc(base45, len45 * 8, 4);
+                    let l46 = i32::from(
+                        *arg0
+                            .add(96 + 9 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l47 = *arg0
+                        .add(96 + 10 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l48 = *arg0
+                        .add(96 + 11 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base53 = l47;
+                    let len53 = l48;
+                    let mut result53 = _rt::Vec::with_capacity(len53);
+                    for i in 0..len53 {
+                        let base = base53.add(i * 8);
+                        let e53 = {
+                            let l49 = i32::from(*base.add(0).cast::<u8>());
+                            match l49 {
+                                0 => {
+                                    let l50 = *base.add(4).cast::<i32>();
+                                    let e = l50 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l51 = *base.add(4).cast::<i32>();
+                                    let e = l51 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l52 = *base.add(4).cast::<i32>();
+                                    let e = l52 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result53.push(e53);
+                    }
+                    _rt::cabi_dealloc(base53, len53 * 8, 4);
+                    let l54 = i32::from(
+                        *arg0
+                            .add(96 + 12 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l55 = *arg0
+                        .add(100 + 12 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        104 + 12 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result56 = T::use_identify_scroll(
                         super::super::super::super::exports::docs::inventory::types::InventoryState {
-                            equipped_weapon: arg0 as u32,
-                            equipped_armor: arg1 as u32,
-                            item_count: arg2 as u32,
-                            max_capacity: arg3 as u32,
-                            gold: arg4 as u32,
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        super::super::super::super::exports::docs::inventory::types::Item {
+                            id: l23 as u32,
+                            name: _rt::string_lift(bytes26),
+                            category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                l27 as u8,
+                            ),
+                            attack_bonus: l28 as u32,
+                            crit_chance: l29 as u32,
+                            defense_bonus: l30 as u32,
+                            heal_amount: l31 as u32,
+                            quantity: l32 as u32,
+                            is_equipped: _rt::bool_lift(l33 as u8),
+                            is_cursed: _rt::bool_lift(l34 as u8),
+                            curse_effect: match l35 {
+                                0 => None,
+                                1 => {
+                                    let l36 = i32::from(
+                                        *arg0
+                                            .add(88 + 7 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l36 {
+                                        0 => {
+                                            let l37 = *arg0
+                                                .add(92 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l37 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l38 = *arg0
+                                                .add(92 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l38 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l39 = *arg0
+                                                .add(92 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l39 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            enchantments: result45,
+                            gem_slots: l46 as u8,
+                            socketed_gems: result53,
+                            identified: _rt::bool_lift(l54 as u8),
+                            weight: l55 as u32,
                         },
-                        arg5 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr57 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result56 {
+                        Some(e) => {
+                            *ptr57.add(0).cast::<u8>() = (1i32) as u8;
+                            let (t58_0, t58_1) = e;
+                            let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                                equipped_weapon: equipped_weapon59,
+                                equipped_offhand: equipped_offhand59,
+                                equipped_armor: equipped_armor59,
+                                equipped_ring: equipped_ring59,
+                                item_count: item_count59,
+                                item_ids: item_ids59,
+                                max_capacity: max_capacity59,
+                                gold: gold59,
+                                is_poisoned: is_poisoned59,
+                                poison_turns_remaining: poison_turns_remaining59,
+                                weapon_curse: weapon_curse59,
+                                armor_curse: armor_curse59,
+                                weapon_poison_hits: weapon_poison_hits59,
+                            } = t58_0;
+                            *ptr57
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_weapon59);
+                            *ptr57
+                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_offhand59);
+                            *ptr57
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_armor59);
+                            *ptr57
+                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_ring59);
+                            *ptr57
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(item_count59);
+                            let vec60 = (item_ids59).into_boxed_slice();
+                            let ptr60 = vec60.as_ptr().cast::<u8>();
+                            let len60 = vec60.len();
+                            ::core::mem::forget(vec60);
+                            *ptr57
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len60;
+                            *ptr57
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr60.cast_mut();
+                            *ptr57
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_capacity59);
+                            *ptr57
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(gold59);
+                            *ptr57
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_poisoned59 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr57
+                                .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(poison_turns_remaining59);
+                            match weapon_curse59 {
+                                Some(e) => {
+                                    *ptr57
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr57
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr57
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr57
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr57
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr57
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr57
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr57
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match armor_curse59 {
+                                Some(e) => {
+                                    *ptr57
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr57
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr57
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr57
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr57
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr57
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr57
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr57
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *ptr57
+                                .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weapon_poison_hits59);
+                            let super::super::super::super::exports::docs::inventory::types::Item {
+                                id: id61,
+                                name: name61,
+                                category: category61,
+                                attack_bonus: attack_bonus61,
+                                crit_chance: crit_chance61,
+                                defense_bonus: defense_bonus61,
+                                heal_amount: heal_amount61,
+                                quantity: quantity61,
+                                is_equipped: is_equipped61,
+                                is_cursed: is_cursed61,
+                                curse_effect: curse_effect61,
+                                enchantments: enchantments61,
+                                gem_slots: gem_slots61,
+                                socketed_gems: socketed_gems61,
+                                identified: identified61,
+                                weight: weight61,
+                            } = t58_1;
+                            *ptr57
+                                .add(56 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(id61);
+                            let vec62 = (name61.into_bytes()).into_boxed_slice();
+                            let ptr62 = vec62.as_ptr().cast::<u8>();
+                            let len62 = vec62.len();
+                            ::core::mem::forget(vec62);
+                            *ptr57
+                                .add(56 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len62;
+                            *ptr57
+                                .add(56 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr62.cast_mut();
+                            *ptr57
+                                .add(56 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (category61.clone() as i32) as u8;
+                            *ptr57
+                                .add(60 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack_bonus61);
+                            *ptr57
+                                .add(64 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(crit_chance61);
+                            *ptr57
+                                .add(68 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense_bonus61);
+                            *ptr57
+                                .add(72 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(heal_amount61);
+                            *ptr57
+                                .add(76 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(quantity61);
+                            *ptr57
+                                .add(80 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_equipped61 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr57
+                                .add(81 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_cursed61 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            match curse_effect61 {
+                                Some(e) => {
+                                    *ptr57
+                                        .add(84 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr57
+                                                .add(88 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr57
+                                                .add(92 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr57
+                                                .add(88 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr57
+                                                .add(92 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr57
+                                                .add(88 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr57
+                                                .add(92 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr57
+                                        .add(84 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec63 = enchantments61;
+                            let len63 = vec63.len();
+                            let layout63 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec63.len() * 8,
+                                4,
+                            );
+                            let result63 = if layout63.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout63).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout63);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec63.into_iter().enumerate() {
+                                let base = result63.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr57
+                                .add(96 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len63;
+                            *ptr57
+                                .add(96 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result63;
+                            *ptr57
+                                .add(96 + 10 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (_rt::as_i32(gem_slots61)) as u8;
+                            let vec64 = socketed_gems61;
+                            let len64 = vec64.len();
+                            let layout64 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec64.len() * 8,
+                                4,
+                            );
+                            let result64 = if layout64.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout64).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout64);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec64.into_iter().enumerate() {
+                                let base = result64.add(i * 8);
+                                {
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(4).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                            }
+                            *ptr57
+                                .add(96 + 12 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len64;
+                            *ptr57
+                                .add(96 + 11 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result64;
+                            *ptr57
+                                .add(96 + 13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match identified61 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr57
+                                .add(100 + 13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weight61);
+                        }
+                        None => {
+                            *ptr57.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr57
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_use_identify_scroll<T: Guest>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 4, 4);
+                            let l4 = *arg0
+                                .add(56 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l5 = *arg0
+                                .add(56 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l4, l5, 1);
+                            let l6 = *arg0
+                                .add(96 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *arg0
+                                .add(96 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base8 = l6;
+                            let len8 = l7;
+                            _rt::cabi_dealloc(base8, len8 * 8, 4);
+                            let l9 = *arg0
+                                .add(96 + 11 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l10 = *arg0
+                                .add(96 + 12 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base11 = l9;
+                            let len11 = l10;
+                            _rt::cabi_dealloc(base11, len11 * 8, 4);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_use_antidote_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = T::use_antidote(super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: l0 as u32,
+                        equipped_offhand: l1 as u32,
+                        equipped_armor: l2 as u32,
+                        equipped_ring: l3 as u32,
+                        item_count: l4 as u32,
+                        item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                        max_capacity: l8 as u32,
+                        gold: l9 as u32,
+                        is_poisoned: _rt::bool_lift(l10 as u8),
+                        poison_turns_remaining: l11 as u32,
+                        weapon_curse: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        armor_curse: match l17 {
+                            0 => None,
+                            1 => {
+                                let l18 = i32::from(
+                                    *arg0
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l18 {
+                                    0 => {
+                                        let l19 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l19 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l20 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l20 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l21 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l21 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        weapon_poison_hits: l22 as u32,
+                    });
+                    let ptr24 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t25_0, t25_1) = result23;
                     let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
-                    ptr1
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = t25_0;
+                    *ptr24.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr24.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr24.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr24.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr24.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr24
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr24
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr24
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr24
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr24
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr24
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr24
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr24
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr24
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr24
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr24
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    let super::super::super::super::exports::docs::inventory::types::UseResult {
+                        success: success28,
+                        health_restored: health_restored28,
+                        attack_boost: attack_boost28,
+                        defense_boost: defense_boost28,
+                        message: message28,
+                        identified: identified28,
+                        reveals_area: reveals_area28,
+                    } = t25_1;
+                    *ptr24
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match success28 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr24
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(health_restored28);
+                    *ptr24
+                        .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(attack_boost28);
+                    *ptr24
+                        .add(68 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(defense_boost28);
+                    let vec29 = (message28.into_bytes()).into_boxed_slice();
+                    let ptr29 = vec29.as_ptr().cast::<u8>();
+                    let len29 = vec29.len();
+                    ::core::mem::forget(vec29);
+                    *ptr24
+                        .add(72 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len29;
+                    *ptr24
+                        .add(72 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr29.cast_mut();
+                    *ptr24
+                        .add(72 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match identified28 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    match reveals_area28 {
+                        Some(e) => {
+                            *ptr24
+                                .add(72 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let vec30 = (e.into_bytes()).into_boxed_slice();
+                            let ptr30 = vec30.as_ptr().cast::<u8>();
+                            let len30 = vec30.len();
+                            ::core::mem::forget(vec30);
+                            *ptr24
+                                .add(72 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len30;
+                            *ptr24
+                                .add(72 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr30.cast_mut();
+                        }
+                        None => {
+                            *ptr24
+                                .add(72 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr24
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_use_antidote<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                    let l3 = *arg0
+                        .add(72 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l4 = *arg0
+                        .add(72 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    _rt::cabi_dealloc(l3, l4, 1);
+                    let l5 = i32::from(
+                        *arg0
+                            .add(72 + 7 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l5 {
+                        0 => {}
+                        _ => {
+                            let l6 = *arg0
+                                .add(72 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l7 = *arg0
+                                .add(72 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l6, l7, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_use_poisoned_blade_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = T::use_poisoned_blade(super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: l0 as u32,
+                        equipped_offhand: l1 as u32,
+                        equipped_armor: l2 as u32,
+                        equipped_ring: l3 as u32,
+                        item_count: l4 as u32,
+                        item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                        max_capacity: l8 as u32,
+                        gold: l9 as u32,
+                        is_poisoned: _rt::bool_lift(l10 as u8),
+                        poison_turns_remaining: l11 as u32,
+                        weapon_curse: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        armor_curse: match l17 {
+                            0 => None,
+                            1 => {
+                                let l18 = i32::from(
+                                    *arg0
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l18 {
+                                    0 => {
+                                        let l19 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l19 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l20 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l20 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l21 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l21 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        weapon_poison_hits: l22 as u32,
+                    });
+                    let ptr24 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result23 {
+                        Some(e) => {
+                            *ptr24.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                                equipped_weapon: equipped_weapon25,
+                                equipped_offhand: equipped_offhand25,
+                                equipped_armor: equipped_armor25,
+                                equipped_ring: equipped_ring25,
+                                item_count: item_count25,
+                                item_ids: item_ids25,
+                                max_capacity: max_capacity25,
+                                gold: gold25,
+                                is_poisoned: is_poisoned25,
+                                poison_turns_remaining: poison_turns_remaining25,
+                                weapon_curse: weapon_curse25,
+                                armor_curse: armor_curse25,
+                                weapon_poison_hits: weapon_poison_hits25,
+                            } = e;
+                            *ptr24
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_weapon25);
+                            *ptr24
+                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_offhand25);
+                            *ptr24
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_armor25);
+                            *ptr24
+                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_ring25);
+                            *ptr24
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(item_count25);
+                            let vec26 = (item_ids25).into_boxed_slice();
+                            let ptr26 = vec26.as_ptr().cast::<u8>();
+                            let len26 = vec26.len();
+                            ::core::mem::forget(vec26);
+                            *ptr24
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len26;
+                            *ptr24
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr26.cast_mut();
+                            *ptr24
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_capacity25);
+                            *ptr24
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(gold25);
+                            *ptr24
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_poisoned25 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr24
+                                .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(poison_turns_remaining25);
+                            match weapon_curse25 {
+                                Some(e) => {
+                                    *ptr24
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr24
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr24
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr24
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr24
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr24
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr24
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr24
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match armor_curse25 {
+                                Some(e) => {
+                                    *ptr24
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr24
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr24
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr24
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr24
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr24
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr24
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr24
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *ptr24
+                                .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weapon_poison_hits25);
+                        }
+                        None => {
+                            *ptr24.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr24
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_use_poisoned_blade<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 4, 4);
+                        }
+                    }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_equip_weapon_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
+                pub unsafe fn _export_strike_with_weapon_cabi<T: Guest>(
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::equip_weapon(
-                        super::super::super::super::exports::docs::inventory::types::InventoryState {
-                            equipped_weapon: arg0 as u32,
-                            equipped_armor: arg1 as u32,
-                            item_count: arg2 as u32,
-                            max_capacity: arg3 as u32,
-                            gold: arg4 as u32,
-                        },
-                        arg5 as u32,
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = T::strike_with_weapon(super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: l0 as u32,
+                        equipped_offhand: l1 as u32,
+                        equipped_armor: l2 as u32,
+                        equipped_ring: l3 as u32,
+                        item_count: l4 as u32,
+                        item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                        max_capacity: l8 as u32,
+                        gold: l9 as u32,
+                        is_poisoned: _rt::bool_lift(l10 as u8),
+                        poison_turns_remaining: l11 as u32,
+                        weapon_curse: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        armor_curse: match l17 {
+                            0 => None,
+                            1 => {
+                                let l18 = i32::from(
+                                    *arg0
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l18 {
+                                    0 => {
+                                        let l19 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l19 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l20 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l20 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l21 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l21 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        weapon_poison_hits: l22 as u32,
+                    });
+                    let ptr24 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t25_0, t25_1) = result23;
                     let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
-                    ptr1
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = t25_0;
+                    *ptr24.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr24.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr24.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr24.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr24.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr24
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr24
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr24
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr24
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr24
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr24
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr24
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr24
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr24
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr24
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr24
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    *ptr24
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match t25_1 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    ptr24
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_equip_armor_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                ) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::equip_armor(
-                        super::super::super::super::exports::docs::inventory::types::InventoryState {
-                            equipped_weapon: arg0 as u32,
-                            equipped_armor: arg1 as u32,
-                            item_count: arg2 as u32,
-                            max_capacity: arg3 as u32,
-                            gold: arg4 as u32,
-                        },
-                        arg5 as u32,
-                    );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
-                    ptr1
+                pub unsafe fn __post_return_strike_with_weapon<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_add_gold_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
+                pub unsafe fn _export_apply_poison_cabi<T: Guest>(
+                    arg0: *mut u8,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::add_gold(
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::apply_poison(
                         super::super::super::super::exports::docs::inventory::types::InventoryState {
-                            equipped_weapon: arg0 as u32,
-                            equipped_armor: arg1 as u32,
-                            item_count: arg2 as u32,
-                            max_capacity: arg3 as u32,
-                            gold: arg4 as u32,
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
                         },
-                        arg5 as u32,
+                        l23 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr25 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
-                    ptr1
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = result24;
+                    *ptr25.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr25.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr25.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr25.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr25.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr25
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr25
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr25
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr25
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr25
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr25
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr25
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr25
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr25
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr25
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    ptr25
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_spend_gold_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                    arg5: i32,
-                ) -> *mut u8 {
-                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::spend_gold(
-                        super::super::super::super::exports::docs::inventory::types::InventoryState {
-                            equipped_weapon: arg0 as u32,
-                            equipped_armor: arg1 as u32,
-                            item_count: arg2 as u32,
-                            max_capacity: arg3 as u32,
-                            gold: arg4 as u32,
-                        },
-                        arg5 as u32,
-                    );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: equipped_weapon2,
-                        equipped_armor: equipped_armor2,
-                        item_count: item_count2,
-                        max_capacity: max_capacity2,
-                        gold: gold2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(equipped_armor2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(item_count2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(max_capacity2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(gold2);
-                    ptr1
+                pub unsafe fn __post_return_apply_poison<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_is_full_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                    arg3: i32,
-                    arg4: i32,
-                ) -> i32 {
+                pub unsafe fn _export_tick_poison_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::is_full(super::super::super::super::exports::docs::inventory::types::InventoryState {
-                        equipped_weapon: arg0 as u32,
-                        equipped_armor: arg1 as u32,
-                        item_count: arg2 as u32,
-                        max_capacity: arg3 as u32,
-                        gold: arg4 as u32,
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result23 = T::tick_poison(super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: l0 as u32,
+                        equipped_offhand: l1 as u32,
+                        equipped_armor: l2 as u32,
+                        equipped_ring: l3 as u32,
+                        item_count: l4 as u32,
+                        item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                        max_capacity: l8 as u32,
+                        gold: l9 as u32,
+                        is_poisoned: _rt::bool_lift(l10 as u8),
+                        poison_turns_remaining: l11 as u32,
+                        weapon_curse: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        armor_curse: match l17 {
+                            0 => None,
+                            1 => {
+                                let l18 = i32::from(
+                                    *arg0
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l18 {
+                                    0 => {
+                                        let l19 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l19 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l20 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l20 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l21 = *arg0
+                                            .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l21 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        weapon_poison_hits: l22 as u32,
                     });
-                    match result0 {
+                    let ptr24 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t25_0, t25_1) = result23;
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon26,
+                        equipped_offhand: equipped_offhand26,
+                        equipped_armor: equipped_armor26,
+                        equipped_ring: equipped_ring26,
+                        item_count: item_count26,
+                        item_ids: item_ids26,
+                        max_capacity: max_capacity26,
+                        gold: gold26,
+                        is_poisoned: is_poisoned26,
+                        poison_turns_remaining: poison_turns_remaining26,
+                        weapon_curse: weapon_curse26,
+                        armor_curse: armor_curse26,
+                        weapon_poison_hits: weapon_poison_hits26,
+                    } = t25_0;
+                    *ptr24.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon26);
+                    *ptr24.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand26);
+                    *ptr24.add(8).cast::<i32>() = _rt::as_i32(equipped_armor26);
+                    *ptr24.add(12).cast::<i32>() = _rt::as_i32(equipped_ring26);
+                    *ptr24.add(16).cast::<i32>() = _rt::as_i32(item_count26);
+                    let vec27 = (item_ids26).into_boxed_slice();
+                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                    let len27 = vec27.len();
+                    ::core::mem::forget(vec27);
+                    *ptr24
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len27;
+                    *ptr24
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr27.cast_mut();
+                    *ptr24
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity26);
+                    *ptr24
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold26);
+                    *ptr24
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned26 {
                         true => 1,
                         false => 0,
-                    }
-                }
-                pub trait Guest {
-                    /// Create a new empty inventory.
-                    fn create_inventory() -> InventoryState;
-                    /// Add an item to the inventory.
-                    ///
-                    /// Returns updated inventory state and success flag.
-                    fn add_item(inv: InventoryState, item_id: u32) -> InventoryState;
-                    /// Remove an item from the inventory.
-                    fn remove_item(inv: InventoryState, item_id: u32) -> InventoryState;
-                    /// Equip a weapon by item ID.
-                    fn equip_weapon(inv: InventoryState, item_id: u32) -> InventoryState;
-                    /// Equip armor by item ID.
-                    fn equip_armor(inv: InventoryState, item_id: u32) -> InventoryState;
-                    /// Add gold to the inventory.
-                    fn add_gold(inv: InventoryState, amount: u32) -> InventoryState;
-                    /// Spend gold from the inventory.
-                    ///
-                    /// Returns updated state; gold unchanged if insufficient funds.
-                    fn spend_gold(inv: InventoryState, amount: u32) -> InventoryState;
-                    /// Check if inventory is full.
-                    fn is_full(inv: InventoryState) -> bool;
-                }
-                #[doc(hidden)]
-                macro_rules! __export_docs_inventory_management_0_1_0_cabi {
-                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
-                        const _ : () = { #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#create-inventory")] unsafe
-                        extern "C" fn export_create_inventory() -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_create_inventory_cabi::<$ty > () } }
-                        #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#add-item")] unsafe extern "C" fn
-                        export_add_item(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32,
-                        arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_add_item_cabi::<$ty > (arg0, arg1,
-                        arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#remove-item")] unsafe extern "C"
-                        fn export_remove_item(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_remove_item_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#equip-weapon")] unsafe extern
-                        "C" fn export_equip_weapon(arg0 : i32, arg1 : i32, arg2 : i32,
-                        arg3 : i32, arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_equip_weapon_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#equip-armor")] unsafe extern "C"
-                        fn export_equip_armor(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_equip_armor_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#add-gold")] unsafe extern "C" fn
-                        export_add_gold(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32,
-                        arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_add_gold_cabi::<$ty > (arg0, arg1,
-                        arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#spend-gold")] unsafe extern "C"
-                        fn export_spend_gold(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32,) -> * mut u8 { unsafe {
-                        $($path_to_types)*:: _export_spend_gold_cabi::<$ty > (arg0, arg1,
-                        arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:inventory/management@0.1.0#is-full")] unsafe extern "C" fn
-                        export_is_full(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32,
-                        arg4 : i32,) -> i32 { unsafe { $($path_to_types)*::
-                        _export_is_full_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4) } }
-                        };
+                    }) as u8;
+                    *ptr24
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining26);
+                    match weapon_curse26 {
+                        Some(e) => {
+                            *ptr24
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr24
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr24
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr24
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse26 {
+                        Some(e) => {
+                            *ptr24
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr24
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr24
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr24
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
                     };
+                    *ptr24
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits26);
+                    *ptr24
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(t25_1);
+                    ptr24
                 }
                 #[doc(hidden)]
-                pub(crate) use __export_docs_inventory_management_0_1_0_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 20]);
-                static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 20],
-                );
-            }
-            /// Item usage interface.
-            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
-            pub mod usage {
-                #[used]
-                #[doc(hidden)]
-                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
-                use super::super::super::super::_rt;
-                pub type UseResult = super::super::super::super::exports::docs::inventory::types::UseResult;
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_tick_poison<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_use_item_cabi<T: Guest>(
-                    arg0: i32,
-                    arg1: i32,
-                    arg2: i32,
-                ) -> *mut u8 {
+                pub unsafe fn _export_get_total_attack_bonus_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::use_item(arg0 as u32, arg1 as u32, arg2 as u32);
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
-                    let super::super::super::super::exports::docs::inventory::types::UseResult {
-                        success: success2,
-                        health_restored: health_restored2,
-                        attack_boost: attack_boost2,
-                        defense_boost: defense_boost2,
-                        message: message2,
-                    } = result0;
-                    *ptr1.add(0).cast::<u8>() = (match success2 {
-                        true => 1,
-                        false => 0,
-                    }) as u8;
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(health_restored2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(attack_boost2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense_boost2);
-                    let vec3 = (message2.into_bytes()).into_boxed_slice();
-                    let ptr3 = vec3.as_ptr().cast::<u8>();
-                    let len3 = vec3.len();
-                    ::core::mem::forget(vec3);
-                    *ptr1
-                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
-                        .cast::<usize>() = len3;
-                    *ptr1.add(16).cast::<*mut u8>() = ptr3.cast_mut();
-                    ptr1
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len3 = l2;
+                    let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                    let l4 = i32::from(
+                        *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l5 = *arg0
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l6 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l7 = *arg0
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = i32::from(
+                        *arg0
+                            .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l12 = i32::from(
+                        *arg0
+                            .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base22 = l17;
+                    let len22 = l18;
+                    let mut result22 = _rt::Vec::with_capacity(len22);
+                    for i in 0..len22 {
+                        let base = base22.add(i * 8);
+                        let e22 = {
+                            let l19 = i32::from(*base.add(0).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *base.add(4).cast::<i32>();
+                                    let e = l20 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l21 = *base.add(4).cast::<i32>();
+                                    let e = l21 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result22.push(e22);
+                    }
+                    _rt::cabi_dealloc(base22, len22 * 8, 4);
+                    let l23 = i32::from(
+                        *arg0
+                            .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l24;
+                    let len30 = l25;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30.add(i * 8);
+                        let e30 = {
+                            let l26 = i32::from(*base.add(0).cast::<u8>());
+                            match l26 {
+                                0 => {
+                                    let l27 = *base.add(4).cast::<i32>();
+                                    let e = l27 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l28 = *base.add(4).cast::<i32>();
+                                    let e = l28 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l29 = *base.add(4).cast::<i32>();
+                                    let e = l29 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 8, 4);
+                    let l31 = i32::from(
+                        *arg0
+                            .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *arg0
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l33 = *arg0
+                        .add(48 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        48 + 9 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result34 = T::get_total_attack_bonus(
+                        super::super::super::super::exports::docs::inventory::types::Item {
+                            id: l0 as u32,
+                            name: _rt::string_lift(bytes3),
+                            category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                                l4 as u8,
+                            ),
+                            attack_bonus: l5 as u32,
+                            crit_chance: l6 as u32,
+                            defense_bonus: l7 as u32,
+                            heal_amount: l8 as u32,
+                            quantity: l9 as u32,
+                            is_equipped: _rt::bool_lift(l10 as u8),
+                            is_cursed: _rt::bool_lift(l11 as u8),
+                            curse_effect: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            enchantments: result22,
+                            gem_slots: l23 as u8,
+                            socketed_gems: result30,
+                            identified: _rt::bool_lift(l31 as u8),
+                            weight: l32 as u32,
+                        },
+                        l33 as u32,
+                    );
+                    _rt::as_i32(result34)
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn __post_return_use_item<T: Guest>(arg0: *mut u8) {
-                    let l0 = *arg0.add(16).cast::<*mut u8>();
+                pub unsafe fn _export_get_item_attack_bonus_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
                     let l1 = *arg0
-                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
                         .cast::<usize>();
-                    _rt::cabi_dealloc(l0, l1, 1);
+                    let len3 = l2;
+                    let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                    let l4 = i32::from(
+                        *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l5 = *arg0
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l6 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l7 = *arg0
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = i32::from(
+                        *arg0
+                            .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l12 = i32::from(
+                        *arg0
+                            .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base22 = l17;
+                    let len22 = l18;
+                    let mut result22 = _rt::Vec::with_capacity(len22);
+                    for i in 0..len22 {
+                        let base = base22.add(i * 8);
+                        let e22 = {
+                            let l19 = i32::from(*base.add(0).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *base.add(4).cast::<i32>();
+                                    let e = l20 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l21 = *base.add(4).cast::<i32>();
+                                    let e = l21 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result22.push(e22);
+                    }
+                    _rt::cabi_dealloc(base22, len22 * 8, 4);
+                    let l23 = i32::from(
+                        *arg0
+                            .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l24;
+                    let len30 = l25;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30.add(i * 8);
+                        let e30 = {
+                            let l26 = i32::from(*base.add(0).cast::<u8>());
+                            match l26 {
+                                0 => {
+                                    let l27 = *base.add(4).cast::<i32>();
+                                    let e = l27 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l28 = *base.add(4).cast::<i32>();
+                                    let e = l28 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l29 = *base.add(4).cast::<i32>();
+                                    let e = l29 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 8, 4);
+                    let l31 = i32::from(
+                        *arg0
+                            .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *arg0
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        48 + 8 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result33 = T::get_item_attack_bonus(super::super::super::super::exports::docs::inventory::types::Item {
+                        id: l0 as u32,
+                        name: _rt::string_lift(bytes3),
+                        category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                            l4 as u8,
+                        ),
+                        attack_bonus: l5 as u32,
+                        crit_chance: l6 as u32,
+                        defense_bonus: l7 as u32,
+                        heal_amount: l8 as u32,
+                        quantity: l9 as u32,
+                        is_equipped: _rt::bool_lift(l10 as u8),
+                        is_cursed: _rt::bool_lift(l11 as u8),
+                        curse_effect: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        enchantments: result22,
+                        gem_slots: l23 as u8,
+                        socketed_gems: result30,
+                        identified: _rt::bool_lift(l31 as u8),
+                        weight: l32 as u32,
+                    });
+                    _rt::as_i32(result33)
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
-                pub unsafe fn _export_get_total_attack_bonus_cabi<T: Guest>(
-                    arg0: i32,
+                pub unsafe fn _export_get_item_defense_bonus_cabi<T: Guest>(
+                    arg0: *mut u8,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::get_total_attack_bonus(arg0 as u32);
-                    _rt::as_i32(result0)
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l2 = *arg0
+                        .add(2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len3 = l2;
+                    let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                    let l4 = i32::from(
+                        *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                    );
+                    let l5 = *arg0
+                        .add(4 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l6 = *arg0
+                        .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l7 = *arg0
+                        .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = i32::from(
+                        *arg0
+                            .add(25 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l12 = i32::from(
+                        *arg0
+                            .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = *arg0
+                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l18 = *arg0
+                        .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base22 = l17;
+                    let len22 = l18;
+                    let mut result22 = _rt::Vec::with_capacity(len22);
+                    for i in 0..len22 {
+                        let base = base22.add(i * 8);
+                        let e22 = {
+                            let l19 = i32::from(*base.add(0).cast::<u8>());
+                            match l19 {
+                                0 => {
+                                    let l20 = *base.add(4).cast::<i32>();
+                                    let e = l20 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FireDamage(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::FrostSlow
+                                }
+                                2 => {
+                                    let l21 = *base.add(4).cast::<i32>();
+                                    let e = l21 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::LifeSteal(
+                                        e,
+                                    )
+                                }
+                                3 => {
+                                    super::super::super::super::exports::docs::inventory::types::Enchantment::ExtraReach
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result22.push(e22);
+                    }
+                    _rt::cabi_dealloc(base22, len22 * 8, 4);
+                    let l23 = i32::from(
+                        *arg0
+                            .add(40 + 5 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l24 = *arg0
+                        .add(40 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l25 = *arg0
+                        .add(40 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l24;
+                    let len30 = l25;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30.add(i * 8);
+                        let e30 = {
+                            let l26 = i32::from(*base.add(0).cast::<u8>());
+                            match l26 {
+                                0 => {
+                                    let l27 = *base.add(4).cast::<i32>();
+                                    let e = l27 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::RubyGem(
+                                        e,
+                                    )
+                                }
+                                1 => {
+                                    let l28 = *base.add(4).cast::<i32>();
+                                    let e = l28 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::SapphireGem(
+                                        e,
+                                    )
+                                }
+                                2 => {
+                                    let l29 = *base.add(4).cast::<i32>();
+                                    let e = l29 as u32;
+                                    super::super::super::super::exports::docs::inventory::types::GemType::EmeraldGem(
+                                        e,
+                                    )
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            }
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(base30, len30 * 8, 4);
+                    let l31 = i32::from(
+                        *arg0
+                            .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *arg0
+                        .add(44 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        48 + 8 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result33 = T::get_item_defense_bonus(super::super::super::super::exports::docs::inventory::types::Item {
+                        id: l0 as u32,
+                        name: _rt::string_lift(bytes3),
+                        category: super::super::super::super::exports::docs::inventory::types::ItemCategory::_lift(
+                            l4 as u8,
+                        ),
+                        attack_bonus: l5 as u32,
+                        crit_chance: l6 as u32,
+                        defense_bonus: l7 as u32,
+                        heal_amount: l8 as u32,
+                        quantity: l9 as u32,
+                        is_equipped: _rt::bool_lift(l10 as u8),
+                        is_cursed: _rt::bool_lift(l11 as u8),
+                        curse_effect: match l12 {
+                            0 => None,
+                            1 => {
+                                let l13 = i32::from(
+                                    *arg0
+                                        .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let e = match l13 {
+                                    0 => {
+                                        let l14 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l14 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                            e,
+                                        )
+                                    }
+                                    1 => {
+                                        let l15 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l15 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                            e,
+                                        )
+                                    }
+                                    2 => {
+                                        let l16 = *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        let e = l16 as u32;
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                            e,
+                                        )
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                        enchantments: result22,
+                        gem_slots: l23 as u8,
+                        socketed_gems: result30,
+                        identified: _rt::bool_lift(l31 as u8),
+                        weight: l32 as u32,
+                    });
+                    _rt::as_i32(result33)
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -1079,8 +12861,53 @@ pub mod exports {
                         current_health: u32,
                         max_health: u32,
                     ) -> UseResult;
+                    /// Read a Magic Map to reveal the named area the player is in.
+                    fn use_magic_map(area_name: _rt::String) -> UseResult;
+                    /// Use a carried Curse Stone to lift an equipped cursed item's debuff.
+                    ///
+                    /// Returns none if no Curse Stone is carried or the item is not the
+                    /// equipped, cursed weapon or armor.
+                    fn use_curse_stone(
+                        inv: InventoryState,
+                        stats: PlayerStats,
+                        cursed_item_id: u32,
+                    ) -> Option<(InventoryState, PlayerStats)>;
+                    /// Read a carried Scroll of Identify to reveal a carried mystery item.
+                    ///
+                    /// Returns none if no scroll is carried or the item is not a carried,
+                    /// unidentified item.
+                    fn use_identify_scroll(
+                        inv: InventoryState,
+                        item: Item,
+                    ) -> Option<(InventoryState, Item)>;
+                    /// Drink a carried Antidote, curing any poison on the player.
+                    ///
+                    /// Fails and leaves the inventory unchanged if no Antidote is carried.
+                    fn use_antidote(inv: InventoryState) -> (InventoryState, UseResult);
+                    /// Coat the equipped weapon with a carried Poisoned Blade for 3 hits.
+                    ///
+                    /// Returns none if no Poisoned Blade is carried or no weapon is equipped.
+                    fn use_poisoned_blade(inv: InventoryState) -> Option<InventoryState>;
+                    /// Spend one poisoned hit of the equipped weapon.
+                    ///
+                    /// The flag is true when the hit poisons its target.
+                    fn strike_with_weapon(inv: InventoryState) -> (InventoryState, bool);
+                    /// Poison the player, extending any poison already running.
+                    fn apply_poison(
+                        inv: InventoryState,
+                        duration: u32,
+                    ) -> InventoryState;
+                    /// Tick poison for one turn, returning the damage dealt.
+                    fn tick_poison(inv: InventoryState) -> (InventoryState, u32);
                     /// Get the total attack bonus from equipped items.
-                    fn get_total_attack_bonus(weapon_id: u32) -> u32;
+                    ///
+                    /// The weapon counts its fire damage enchantments and gems. The
+                    /// off-hand weapon (0 = none) adds 60% of its attack bonus.
+                    fn get_total_attack_bonus(weapon: Item, offhand_id: u32) -> u32;
+                    /// Get an item's attack bonus including fire damage enchantments and gems.
+                    fn get_item_attack_bonus(item: Item) -> u32;
+                    /// Get an item's defense bonus including socketed gems.
+                    fn get_item_defense_bonus(item: Item) -> u32;
                     /// Get the total defense bonus from equipped items.
                     fn get_total_defense_bonus(armor_id: u32) -> u32;
                 }
@@ -1096,11 +12923,88 @@ pub mod exports {
                         "C" fn _post_return_use_item(arg0 : * mut u8,) { unsafe {
                         $($path_to_types)*:: __post_return_use_item::<$ty > (arg0) } }
                         #[unsafe (export_name =
-                        "docs:inventory/usage@0.1.0#get-total-attack-bonus")] unsafe
-                        extern "C" fn export_get_total_attack_bonus(arg0 : i32,) -> i32 {
+                        "docs:inventory/usage@0.1.0#use-magic-map")] unsafe extern "C" fn
+                        export_use_magic_map(arg0 : * mut u8, arg1 : usize,) -> * mut u8
+                        { unsafe { $($path_to_types)*:: _export_use_magic_map_cabi::<$ty
+                        > (arg0, arg1) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#use-magic-map")] unsafe
+                        extern "C" fn _post_return_use_magic_map(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_use_magic_map::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/usage@0.1.0#use-curse-stone")] unsafe extern "C"
+                        fn export_use_curse_stone(arg0 : * mut u8,) -> * mut u8 { unsafe
+                        { $($path_to_types)*:: _export_use_curse_stone_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#use-curse-stone")] unsafe
+                        extern "C" fn _post_return_use_curse_stone(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_use_curse_stone::<$ty
+                        > (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/usage@0.1.0#use-identify-scroll")] unsafe extern
+                        "C" fn export_use_identify_scroll(arg0 : * mut u8,) -> * mut u8 {
+                        unsafe { $($path_to_types)*::
+                        _export_use_identify_scroll_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#use-identify-scroll")]
+                        unsafe extern "C" fn _post_return_use_identify_scroll(arg0 : *
+                        mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_use_identify_scroll::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/usage@0.1.0#use-antidote")] unsafe
+                        extern "C" fn export_use_antidote(arg0 : * mut u8,) -> * mut u8 {
+                        unsafe { $($path_to_types)*:: _export_use_antidote_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#use-antidote")] unsafe
+                        extern "C" fn _post_return_use_antidote(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_use_antidote::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/usage@0.1.0#use-poisoned-blade")] unsafe extern
+                        "C" fn export_use_poisoned_blade(arg0 : * mut u8,) -> * mut u8 {
                         unsafe { $($path_to_types)*::
+                        _export_use_poisoned_blade_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#use-poisoned-blade")]
+                        unsafe extern "C" fn _post_return_use_poisoned_blade(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        __post_return_use_poisoned_blade::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/usage@0.1.0#strike-with-weapon")]
+                        unsafe extern "C" fn export_strike_with_weapon(arg0 : * mut u8,)
+                        -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_strike_with_weapon_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#strike-with-weapon")]
+                        unsafe extern "C" fn _post_return_strike_with_weapon(arg0 : * mut
+                        u8,) { unsafe { $($path_to_types)*::
+                        __post_return_strike_with_weapon::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/usage@0.1.0#apply-poison")] unsafe
+                        extern "C" fn export_apply_poison(arg0 : * mut u8,) -> * mut u8 {
+                        unsafe { $($path_to_types)*:: _export_apply_poison_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#apply-poison")] unsafe
+                        extern "C" fn _post_return_apply_poison(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_apply_poison::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:inventory/usage@0.1.0#tick-poison")] unsafe extern "C" fn
+                        export_tick_poison(arg0 : * mut u8,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_tick_poison_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/usage@0.1.0#tick-poison")] unsafe
+                        extern "C" fn _post_return_tick_poison(arg0 : * mut u8,) { unsafe
+                        { $($path_to_types)*:: __post_return_tick_poison::<$ty > (arg0) }
+                        } #[unsafe (export_name =
+                        "docs:inventory/usage@0.1.0#get-total-attack-bonus")] unsafe
+                        extern "C" fn export_get_total_attack_bonus(arg0 : * mut u8,) ->
+                        i32 { unsafe { $($path_to_types)*::
                         _export_get_total_attack_bonus_cabi::<$ty > (arg0) } } #[unsafe
                         (export_name =
+                        "docs:inventory/usage@0.1.0#get-item-attack-bonus")] unsafe
+                        extern "C" fn export_get_item_attack_bonus(arg0 : * mut u8,) ->
+                        i32 { unsafe { $($path_to_types)*::
+                        _export_get_item_attack_bonus_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
+                        "docs:inventory/usage@0.1.0#get-item-defense-bonus")] unsafe
+                        extern "C" fn export_get_item_defense_bonus(arg0 : * mut u8,) ->
+                        i32 { unsafe { $($path_to_types)*::
+                        _export_get_item_defense_bonus_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name =
                         "docs:inventory/usage@0.1.0#get-total-defense-bonus")] unsafe
                         extern "C" fn export_get_total_defense_bonus(arg0 : i32,) -> i32
                         { unsafe { $($path_to_types)*::
@@ -1114,11 +13018,928 @@ pub mod exports {
                 struct _RetArea(
                     [::core::mem::MaybeUninit<
                         u8,
-                    >; 16 + 2 * ::core::mem::size_of::<*const u8>()],
+                    >; 104 + 13 * ::core::mem::size_of::<*const u8>()],
+                );
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 104
+                        + 13 * ::core::mem::size_of::<*const u8>()],
+                );
+            }
+            /// Merchant shop interface.
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod shop {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type InventoryState = super::super::super::super::exports::docs::inventory::types::InventoryState;
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_merchant_catalog_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::merchant_catalog();
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec3 = result0;
+                    let len3 = vec3.len();
+                    let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec3.len() * 8,
+                        4,
+                    );
+                    let result3 = if layout3.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout3);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec3.into_iter().enumerate() {
+                        let base = result3.add(i * 8);
+                        {
+                            let (t2_0, t2_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t2_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t2_1);
+                        }
+                    }
+                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+                    *ptr1.add(0).cast::<*mut u8>() = result3;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_merchant_catalog<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_can_afford_cabi<T: Guest>(arg0: *mut u8) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        56 + 5 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result24 = T::can_afford(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                    );
+                    match result24 {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_buy_item_cabi<T: Guest>(arg0: *mut u8) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        64 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result25 = T::buy_item(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                        l24 as u32,
+                    );
+                    let ptr26 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result25 {
+                        Some(e) => {
+                            *ptr26.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                                equipped_weapon: equipped_weapon27,
+                                equipped_offhand: equipped_offhand27,
+                                equipped_armor: equipped_armor27,
+                                equipped_ring: equipped_ring27,
+                                item_count: item_count27,
+                                item_ids: item_ids27,
+                                max_capacity: max_capacity27,
+                                gold: gold27,
+                                is_poisoned: is_poisoned27,
+                                poison_turns_remaining: poison_turns_remaining27,
+                                weapon_curse: weapon_curse27,
+                                armor_curse: armor_curse27,
+                                weapon_poison_hits: weapon_poison_hits27,
+                            } = e;
+                            *ptr26
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_weapon27);
+                            *ptr26
+                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_offhand27);
+                            *ptr26
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_armor27);
+                            *ptr26
+                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(equipped_ring27);
+                            *ptr26
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(item_count27);
+                            let vec28 = (item_ids27).into_boxed_slice();
+                            let ptr28 = vec28.as_ptr().cast::<u8>();
+                            let len28 = vec28.len();
+                            ::core::mem::forget(vec28);
+                            *ptr26
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len28;
+                            *ptr26
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr28.cast_mut();
+                            *ptr26
+                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_capacity27);
+                            *ptr26
+                                .add(20 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(gold27);
+                            *ptr26
+                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (match is_poisoned27 {
+                                true => 1,
+                                false => 0,
+                            }) as u8;
+                            *ptr26
+                                .add(28 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(poison_turns_remaining27);
+                            match weapon_curse27 {
+                                Some(e) => {
+                                    *ptr26
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr26
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr26
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr26
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr26
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr26
+                                                .add(36 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr26
+                                                .add(40 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr26
+                                        .add(32 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match armor_curse27 {
+                                Some(e) => {
+                                    *ptr26
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    match e {
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                            *ptr26
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            *ptr26
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                            *ptr26
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr26
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                            *ptr26
+                                                .add(48 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            *ptr26
+                                                .add(52 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                    };
+                                }
+                                None => {
+                                    *ptr26
+                                        .add(44 + 4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *ptr26
+                                .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(weapon_poison_hits27);
+                        }
+                        None => {
+                            *ptr26.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr26
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_buy_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 4, 4);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_sell_item_cabi<T: Guest>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let l0 = *arg0.add(0).cast::<i32>();
+                    let l1 = *arg0.add(4).cast::<i32>();
+                    let l2 = *arg0.add(8).cast::<i32>();
+                    let l3 = *arg0.add(12).cast::<i32>();
+                    let l4 = *arg0.add(16).cast::<i32>();
+                    let l5 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l6 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len7 = l6;
+                    let l8 = *arg0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l9 = *arg0
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l10 = i32::from(
+                        *arg0
+                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l11 = *arg0
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l12 = i32::from(
+                        *arg0
+                            .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l17 = i32::from(
+                        *arg0
+                            .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l22 = *arg0
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l23 = *arg0
+                        .add(56 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    let l24 = *arg0
+                        .add(60 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>();
+                    _rt::cabi_dealloc(
+                        arg0,
+                        64 + 4 * ::core::mem::size_of::<*const u8>(),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result25 = T::sell_item(
+                        super::super::super::super::exports::docs::inventory::types::InventoryState {
+                            equipped_weapon: l0 as u32,
+                            equipped_offhand: l1 as u32,
+                            equipped_armor: l2 as u32,
+                            equipped_ring: l3 as u32,
+                            item_count: l4 as u32,
+                            item_ids: _rt::Vec::from_raw_parts(l5.cast(), len7, len7),
+                            max_capacity: l8 as u32,
+                            gold: l9 as u32,
+                            is_poisoned: _rt::bool_lift(l10 as u8),
+                            poison_turns_remaining: l11 as u32,
+                            weapon_curse: match l12 {
+                                0 => None,
+                                1 => {
+                                    let l13 = i32::from(
+                                        *arg0
+                                            .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l13 {
+                                        0 => {
+                                            let l14 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l14 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l15 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l15 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l16 = *arg0
+                                                .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l16 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            armor_curse: match l17 {
+                                0 => None,
+                                1 => {
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    let e = match l18 {
+                                        0 => {
+                                            let l19 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l19 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(
+                                                e,
+                                            )
+                                        }
+                                        1 => {
+                                            let l20 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l20 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(
+                                                e,
+                                            )
+                                        }
+                                        2 => {
+                                            let l21 = *arg0
+                                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>();
+                                            let e = l21 as u32;
+                                            super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(
+                                                e,
+                                            )
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            weapon_poison_hits: l22 as u32,
+                        },
+                        l23 as u32,
+                        l24 as u32,
+                    );
+                    let ptr26 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::inventory::types::InventoryState {
+                        equipped_weapon: equipped_weapon27,
+                        equipped_offhand: equipped_offhand27,
+                        equipped_armor: equipped_armor27,
+                        equipped_ring: equipped_ring27,
+                        item_count: item_count27,
+                        item_ids: item_ids27,
+                        max_capacity: max_capacity27,
+                        gold: gold27,
+                        is_poisoned: is_poisoned27,
+                        poison_turns_remaining: poison_turns_remaining27,
+                        weapon_curse: weapon_curse27,
+                        armor_curse: armor_curse27,
+                        weapon_poison_hits: weapon_poison_hits27,
+                    } = result25;
+                    *ptr26.add(0).cast::<i32>() = _rt::as_i32(equipped_weapon27);
+                    *ptr26.add(4).cast::<i32>() = _rt::as_i32(equipped_offhand27);
+                    *ptr26.add(8).cast::<i32>() = _rt::as_i32(equipped_armor27);
+                    *ptr26.add(12).cast::<i32>() = _rt::as_i32(equipped_ring27);
+                    *ptr26.add(16).cast::<i32>() = _rt::as_i32(item_count27);
+                    let vec28 = (item_ids27).into_boxed_slice();
+                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                    let len28 = vec28.len();
+                    ::core::mem::forget(vec28);
+                    *ptr26
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len28;
+                    *ptr26
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>() = ptr28.cast_mut();
+                    *ptr26
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(max_capacity27);
+                    *ptr26
+                        .add(20 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(gold27);
+                    *ptr26
+                        .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<u8>() = (match is_poisoned27 {
+                        true => 1,
+                        false => 0,
+                    }) as u8;
+                    *ptr26
+                        .add(28 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(poison_turns_remaining27);
+                    match weapon_curse27 {
+                        Some(e) => {
+                            *ptr26
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr26
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr26
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr26
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr26
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr26
+                                        .add(36 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr26
+                                        .add(40 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr26
+                                .add(32 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    match armor_curse27 {
+                        Some(e) => {
+                            *ptr26
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            match e {
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::AttackReduction(e) => {
+                                    *ptr26
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    *ptr26
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::DefenseReduction(e) => {
+                                    *ptr26
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    *ptr26
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                super::super::super::super::exports::docs::inventory::types::CurseEffect::MaxHealthReduction(e) => {
+                                    *ptr26
+                                        .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    *ptr26
+                                        .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr26
+                                .add(44 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    *ptr26
+                        .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i32>() = _rt::as_i32(weapon_poison_hits27);
+                    ptr26
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_sell_item<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 4, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_sell_price_cabi<T: Guest>(arg0: i32) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::sell_price(arg0 as u32);
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            *ptr1.add(4).cast::<i32>() = _rt::as_i32(e);
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                pub trait Guest {
+                    /// Get the merchant's catalog as (item ID, price) pairs.
+                    fn merchant_catalog() -> _rt::Vec<(u32, u32)>;
+                    /// Check if the inventory holds enough gold for a price.
+                    fn can_afford(inv: InventoryState, price: u32) -> bool;
+                    /// Buy an item, spending gold and adding it to the inventory.
+                    ///
+                    /// Returns none when the merchant doesn't sell the item at that price,
+                    /// gold is insufficient or the inventory is full.
+                    fn buy_item(
+                        inv: InventoryState,
+                        item_id: u32,
+                        price: u32,
+                    ) -> Option<InventoryState>;
+                    /// Sell a carried item for gold.
+                    ///
+                    /// Returns the inventory unchanged if the item is not carried.
+                    fn sell_item(
+                        inv: InventoryState,
+                        item_id: u32,
+                        sell_price: u32,
+                    ) -> InventoryState;
+                    /// Get the gold the merchant pays for an item, half its catalog price.
+                    ///
+                    /// Returns none for items the merchant doesn't stock.
+                    fn sell_price(item_id: u32) -> Option<u32>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_docs_inventory_shop_0_1_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name =
+                        "docs:inventory/shop@0.1.0#merchant-catalog")] unsafe extern "C"
+                        fn export_merchant_catalog() -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_merchant_catalog_cabi::<$ty > () } }
+                        #[unsafe (export_name =
+                        "cabi_post_docs:inventory/shop@0.1.0#merchant-catalog")] unsafe
+                        extern "C" fn _post_return_merchant_catalog(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_merchant_catalog::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:inventory/shop@0.1.0#can-afford")] unsafe
+                        extern "C" fn export_can_afford(arg0 : * mut u8,) -> i32 { unsafe
+                        { $($path_to_types)*:: _export_can_afford_cabi::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:inventory/shop@0.1.0#buy-item")]
+                        unsafe extern "C" fn export_buy_item(arg0 : * mut u8,) -> * mut
+                        u8 { unsafe { $($path_to_types)*:: _export_buy_item_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/shop@0.1.0#buy-item")] unsafe extern
+                        "C" fn _post_return_buy_item(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_buy_item::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:inventory/shop@0.1.0#sell-item")]
+                        unsafe extern "C" fn export_sell_item(arg0 : * mut u8,) -> * mut
+                        u8 { unsafe { $($path_to_types)*:: _export_sell_item_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "cabi_post_docs:inventory/shop@0.1.0#sell-item")] unsafe extern
+                        "C" fn _post_return_sell_item(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_sell_item::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:inventory/shop@0.1.0#sell-price")]
+                        unsafe extern "C" fn export_sell_price(arg0 : i32,) -> * mut u8 {
+                        unsafe { $($path_to_types)*:: _export_sell_price_cabi::<$ty >
+                        (arg0) } } };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_docs_inventory_shop_0_1_0_cabi;
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 56 + 5 * ::core::mem::size_of::<*const u8>()],
                 );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 16
-                        + 2 * ::core::mem::size_of::<*const u8>()],
+                    [::core::mem::MaybeUninit::uninit(); 56
+                        + 5 * ::core::mem::size_of::<*const u8>()],
                 );
             }
         }
@@ -1128,6 +13949,7 @@ pub mod exports {
 mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
+    pub use alloc_crate::vec::Vec;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -1198,6 +14020,31 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     extern crate alloc as alloc_crate;
     pub use alloc_crate::alloc;
 }
@@ -1236,6 +14083,9 @@ macro_rules! __export_inventory_impl {
         $($path_to_types_root)*::
         exports::docs::inventory::usage::__export_docs_inventory_usage_0_1_0_cabi!($ty
         with_types_in $($path_to_types_root)*:: exports::docs::inventory::usage);
+        $($path_to_types_root)*::
+        exports::docs::inventory::shop::__export_docs_inventory_shop_0_1_0_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::docs::inventory::shop);
     };
 }
 #[doc(inline)]
@@ -1246,40 +14096,90 @@ pub(crate) use __export_inventory_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1581] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xad\x0b\x01A\x02\x01\
-A\x0e\x01B\x0e\x01m\x05\x06weapon\x05armor\x0aconsumable\x08key-item\x08treasure\
-\x04\0\x0ditem-category\x03\0\0\x01m\x05\x0cwooden-sword\x0bsteel-sword\x0cmaste\
-r-sword\x03bow\x08fire-rod\x04\0\x0bweapon-type\x03\0\x02\x01m\x05\x0bcloth-tuni\
-c\x0dleather-armor\x0achain-mail\x06shield\x0amagic-robe\x04\0\x0aarmor-type\x03\
-\0\x04\x01m\x05\x0dhealth-potion\x12full-health-potion\x0cattack-boost\x0ddefens\
-e-boost\x08antidote\x04\0\x0fconsumable-type\x03\0\x06\x01r\x08\x02idy\x04names\x08\
-category\x01\x0cattack-bonusy\x0ddefense-bonusy\x0bheal-amounty\x08quantityy\x0b\
-is-equipped\x7f\x04\0\x04item\x03\0\x08\x01r\x05\x0fequipped-weapony\x0eequipped\
--armory\x0aitem-county\x0cmax-capacityy\x04goldy\x04\0\x0finventory-state\x03\0\x0a\
-\x01r\x05\x07success\x7f\x0fhealth-restoredy\x0cattack-boosty\x0ddefense-boosty\x07\
-messages\x04\0\x0ause-result\x03\0\x0c\x04\0\x1adocs:inventory/types@0.1.0\x05\0\
-\x02\x03\0\0\x04item\x02\x03\0\0\x0bweapon-type\x02\x03\0\0\x0aarmor-type\x02\x03\
-\0\0\x0fconsumable-type\x01B\x10\x02\x03\x02\x01\x01\x04\0\x04item\x03\0\0\x02\x03\
-\x02\x01\x02\x04\0\x0bweapon-type\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x0aarmor-t\
-ype\x03\0\x04\x02\x03\x02\x01\x04\x04\0\x0fconsumable-type\x03\0\x06\x01@\x01\x06\
-weapon\x03\0\x01\x04\0\x0dcreate-weapon\x01\x08\x01@\x01\x05armor\x05\0\x01\x04\0\
-\x0ccreate-armor\x01\x09\x01@\x02\x0aconsumable\x07\x08quantityy\0\x01\x04\0\x11\
-create-consumable\x01\x0a\x01@\x01\x07item-idy\0\x01\x04\0\x0eget-item-stats\x01\
-\x0b\x04\0\x1adocs:inventory/items@0.1.0\x05\x05\x02\x03\0\0\x0finventory-state\x01\
-B\x10\x02\x03\x02\x01\x06\x04\0\x0finventory-state\x03\0\0\x02\x03\x02\x01\x01\x04\
-\0\x04item\x03\0\x02\x01@\0\0\x01\x04\0\x10create-inventory\x01\x04\x01@\x02\x03\
-inv\x01\x07item-idy\0\x01\x04\0\x08add-item\x01\x05\x04\0\x0bremove-item\x01\x05\
-\x04\0\x0cequip-weapon\x01\x05\x04\0\x0bequip-armor\x01\x05\x01@\x02\x03inv\x01\x06\
-amounty\0\x01\x04\0\x08add-gold\x01\x06\x04\0\x0aspend-gold\x01\x06\x01@\x01\x03\
-inv\x01\0\x7f\x04\0\x07is-full\x01\x07\x04\0\x1fdocs:inventory/management@0.1.0\x05\
-\x07\x02\x03\0\0\x0ause-result\x01B\x08\x02\x03\x02\x01\x08\x04\0\x0ause-result\x03\
-\0\0\x01@\x03\x07item-idy\x0ecurrent-healthy\x0amax-healthy\0\x01\x04\0\x08use-i\
-tem\x01\x02\x01@\x01\x09weapon-idy\0y\x04\0\x16get-total-attack-bonus\x01\x03\x01\
-@\x01\x08armor-idy\0y\x04\0\x17get-total-defense-bonus\x01\x04\x04\0\x1adocs:inv\
-entory/usage@0.1.0\x05\x09\x04\0\x1edocs:inventory/inventory@0.1.0\x04\0\x0b\x0f\
-\x01\0\x09inventory\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-compo\
-nent\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 3859] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x93\x1d\x01A\x02\x01\
+A\x15\x01B!\x01m\x06\x06weapon\x05armor\x0aconsumable\x08key-item\x08treasure\x09\
+accessory\x04\0\x0ditem-category\x03\0\0\x01m\x05\x0cwooden-sword\x0bsteel-sword\
+\x0cmaster-sword\x03bow\x08fire-rod\x04\0\x0bweapon-type\x03\0\x02\x01m\x05\x0bc\
+loth-tunic\x0dleather-armor\x0achain-mail\x06shield\x0amagic-robe\x04\0\x0aarmor\
+-type\x03\0\x04\x01m\x09\x0dhealth-potion\x12full-health-potion\x0cattack-boost\x0d\
+defense-boost\x08antidote\x0epoisoned-blade\x0bcurse-stone\x12scroll-of-identify\
+\x09magic-map\x04\0\x0fconsumable-type\x03\0\x06\x01q\x03\x10attack-reduction\x01\
+y\0\x11defense-reduction\x01y\0\x14max-health-reduction\x01y\0\x04\0\x0ccurse-ef\
+fect\x03\0\x08\x01q\x04\x0bfire-damage\x01y\0\x0afrost-slow\0\0\x0alife-steal\x01\
+y\0\x0bextra-reach\0\0\x04\0\x0benchantment\x03\0\x0a\x01q\x03\x08ruby-gem\x01y\0\
+\x0csapphire-gem\x01y\0\x0bemerald-gem\x01y\0\x04\0\x08gem-type\x03\0\x0c\x01r\x03\
+\x06attacky\x07defensey\x0amax-healthy\x04\0\x0cplayer-stats\x03\0\x0e\x01k\x09\x01\
+p\x0b\x01p\x0d\x01r\x10\x02idy\x04names\x08category\x01\x0cattack-bonusy\x0bcrit\
+-chancey\x0ddefense-bonusy\x0bheal-amounty\x08quantityy\x0bis-equipped\x7f\x09is\
+-cursed\x7f\x0ccurse-effect\x10\x0cenchantments\x11\x09gem-slots}\x0dsocketed-ge\
+ms\x12\x0aidentified\x7f\x06weighty\x04\0\x04item\x03\0\x13\x01py\x01r\x0d\x0feq\
+uipped-weapony\x10equipped-offhandy\x0eequipped-armory\x0dequipped-ringy\x0aitem\
+-county\x08item-ids\x15\x0cmax-capacityy\x04goldy\x0bis-poisoned\x7f\x16poison-t\
+urns-remainingy\x0cweapon-curse\x10\x0barmor-curse\x10\x12weapon-poison-hitsy\x04\
+\0\x0finventory-state\x03\0\x16\x01o\x02yy\x01p\x18\x01r\x02\x05state\x17\x0bite\
+m-counts\x19\x04\0\x12inventory-snapshot\x03\0\x1a\x01r\x03\x05added\x15\x07remo\
+ved\x15\x0agold-deltaz\x04\0\x0einventory-diff\x03\0\x1c\x01ks\x01r\x07\x07succe\
+ss\x7f\x0fhealth-restoredy\x0cattack-boosty\x0ddefense-boosty\x07messages\x0aide\
+ntified\x7f\x0creveals-area\x1e\x04\0\x0ause-result\x03\0\x1f\x04\0\x1adocs:inve\
+ntory/types@0.1.0\x05\0\x02\x03\0\0\x04item\x02\x03\0\0\x0bweapon-type\x02\x03\0\
+\0\x0aarmor-type\x02\x03\0\0\x0fconsumable-type\x02\x03\0\0\x0benchantment\x02\x03\
+\0\0\x08gem-type\x01B&\x02\x03\x02\x01\x01\x04\0\x04item\x03\0\0\x02\x03\x02\x01\
+\x02\x04\0\x0bweapon-type\x03\0\x02\x02\x03\x02\x01\x03\x04\0\x0aarmor-type\x03\0\
+\x04\x02\x03\x02\x01\x04\x04\0\x0fconsumable-type\x03\0\x06\x02\x03\x02\x01\x05\x04\
+\0\x0benchantment\x03\0\x08\x02\x03\x02\x01\x06\x04\0\x08gem-type\x03\0\x0a\x01@\
+\x01\x06weapon\x03\0\x01\x04\0\x0dcreate-weapon\x01\x0c\x01@\x01\x05armor\x05\0\x01\
+\x04\0\x0ccreate-armor\x01\x0d\x01@\x02\x0aconsumable\x07\x08quantityy\0\x01\x04\
+\0\x11create-consumable\x01\x0e\x01@\x01\x07item-idy\0\x01\x04\0\x0eget-item-sta\
+ts\x01\x0f\x01@\x01\x04item\x01\0\x01\x04\0\x0didentify-item\x01\x10\x01p\x01\x01\
+@\x01\x05items\x11\0s\x04\0\x16format-inventory-table\x01\x12\x01@\x01\x05items\x11\
+\0y\x04\0\x16total-inventory-weight\x01\x13\x01j\x01\x01\x01s\x01@\x02\x04item\x01\
+\x0benchantment\x09\0\x14\x04\0\x0cenchant-item\x01\x15\x01@\x02\x04item\x01\x03\
+gem\x0b\0\x14\x04\0\x0asocket-gem\x01\x16\x01o\x02yy\x01p\x17\x01@\x01\x07item-i\
+dy\0\x18\x04\0\x0edismantle-item\x01\x19\x01@\x01\x0bingredients\x18\0\x14\x04\0\
+\x0dcombine-items\x01\x1a\x04\0\x1adocs:inventory/items@0.1.0\x05\x07\x02\x03\0\0\
+\x0finventory-state\x02\x03\0\0\x0cplayer-stats\x02\x03\0\0\x12inventory-snapsho\
+t\x02\x03\0\0\x0einventory-diff\x01B-\x02\x03\x02\x01\x08\x04\0\x0finventory-sta\
+te\x03\0\0\x02\x03\x02\x01\x01\x04\0\x04item\x03\0\x02\x02\x03\x02\x01\x09\x04\0\
+\x0cplayer-stats\x03\0\x04\x02\x03\x02\x01\x0a\x04\0\x12inventory-snapshot\x03\0\
+\x06\x02\x03\x02\x01\x0b\x04\0\x0einventory-diff\x03\0\x08\x01@\0\0\x01\x04\0\x10\
+create-inventory\x01\x0a\x01@\x02\x03inv\x01\x07item-idy\0\x01\x04\0\x08add-item\
+\x01\x0b\x04\0\x0bremove-item\x01\x0b\x01@\x02\x03inv\x01\x07item-idy\0\x7f\x04\0\
+\x08has-item\x01\x0c\x01@\x02\x03inv\x01\x07item-idy\0y\x04\0\x0ditem-count-of\x01\
+\x0d\x01o\x02\x01\x7f\x01@\x02\x03inv\x01\x07item-idy\0\x0e\x04\0\x09drop-item\x01\
+\x0f\x04\0\x0cequip-weapon\x01\x0b\x01o\x02\x01\x05\x01@\x03\x03inv\x01\x05stats\
+\x05\x07item-idy\0\x10\x04\0\x17equip-weapon-with-stats\x01\x11\x04\0\x0dequip-o\
+ffhand\x01\x0b\x04\0\x0bequip-armor\x01\x0b\x04\0\x16equip-armor-with-stats\x01\x11\
+\x04\0\x0aequip-ring\x01\x0b\x01@\x01\x03inv\x01\0\x7f\x04\0\x14is-hookshot-equi\
+pped\x01\x12\x01@\x02\x03inv\x01\x06amounty\0\x01\x04\0\x08add-gold\x01\x13\x04\0\
+\x0aspend-gold\x01\x13\x04\0\x07is-full\x01\x12\x01p\x03\x01@\x02\x03inv\x01\x05\
+items\x14\0\x07\x04\0\x0dtake-snapshot\x01\x15\x01@\x02\x06before\x07\x05after\x07\
+\0\x09\x04\0\x0ediff-snapshots\x01\x16\x01ky\x01o\x02\x17\x17\x01@\x02\x0favaila\
+ble-items\x14\x0cplayer-levely\0\x18\x04\0\x16calculate-optimal-gear\x01\x19\x04\
+\0\x1fdocs:inventory/management@0.1.0\x05\x0c\x02\x03\0\0\x0ause-result\x01B)\x02\
+\x03\x02\x01\x0d\x04\0\x0ause-result\x03\0\0\x02\x03\x02\x01\x09\x04\0\x0cplayer\
+-stats\x03\0\x02\x02\x03\x02\x01\x01\x04\0\x04item\x03\0\x04\x02\x03\x02\x01\x08\
+\x04\0\x0finventory-state\x03\0\x06\x01@\x03\x07item-idy\x0ecurrent-healthy\x0am\
+ax-healthy\0\x01\x04\0\x08use-item\x01\x08\x01@\x01\x09area-names\0\x01\x04\0\x0d\
+use-magic-map\x01\x09\x01o\x02\x07\x03\x01k\x0a\x01@\x03\x03inv\x07\x05stats\x03\
+\x0ecursed-item-idy\0\x0b\x04\0\x0fuse-curse-stone\x01\x0c\x01o\x02\x07\x05\x01k\
+\x0d\x01@\x02\x03inv\x07\x04item\x05\0\x0e\x04\0\x13use-identify-scroll\x01\x0f\x01\
+o\x02\x07\x01\x01@\x01\x03inv\x07\0\x10\x04\0\x0cuse-antidote\x01\x11\x01k\x07\x01\
+@\x01\x03inv\x07\0\x12\x04\0\x12use-poisoned-blade\x01\x13\x01o\x02\x07\x7f\x01@\
+\x01\x03inv\x07\0\x14\x04\0\x12strike-with-weapon\x01\x15\x01@\x02\x03inv\x07\x08\
+durationy\0\x07\x04\0\x0capply-poison\x01\x16\x01o\x02\x07y\x01@\x01\x03inv\x07\0\
+\x17\x04\0\x0btick-poison\x01\x18\x01@\x02\x06weapon\x05\x0aoffhand-idy\0y\x04\0\
+\x16get-total-attack-bonus\x01\x19\x01@\x01\x04item\x05\0y\x04\0\x15get-item-att\
+ack-bonus\x01\x1a\x04\0\x16get-item-defense-bonus\x01\x1a\x01@\x01\x08armor-idy\0\
+y\x04\0\x17get-total-defense-bonus\x01\x1b\x04\0\x1adocs:inventory/usage@0.1.0\x05\
+\x0e\x01B\x10\x02\x03\x02\x01\x08\x04\0\x0finventory-state\x03\0\0\x01o\x02yy\x01\
+p\x02\x01@\0\0\x03\x04\0\x10merchant-catalog\x01\x04\x01@\x02\x03inv\x01\x05pric\
+ey\0\x7f\x04\0\x0acan-afford\x01\x05\x01k\x01\x01@\x03\x03inv\x01\x07item-idy\x05\
+pricey\0\x06\x04\0\x08buy-item\x01\x07\x01@\x03\x03inv\x01\x07item-idy\x0asell-p\
+ricey\0\x01\x04\0\x09sell-item\x01\x08\x01ky\x01@\x01\x07item-idy\0\x09\x04\0\x0a\
+sell-price\x01\x0a\x04\0\x19docs:inventory/shop@0.1.0\x05\x0f\x04\0\x1edocs:inve\
+ntory/inventory@0.1.0\x04\0\x0b\x0f\x01\0\x09inventory\x03\0\0\0G\x09producers\x01\
+\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
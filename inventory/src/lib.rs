@@ -30,15 +30,97 @@
 mod bindings;
 
 use bindings::exports::docs::inventory::items::{Guest as ItemsGuest, Item as ItemsItem};
-use bindings::exports::docs::inventory::management::{Guest as ManagementGuest, InventoryState};
+use bindings::exports::docs::inventory::management::{
+    Guest as ManagementGuest, InventoryDiff, InventorySnapshot, InventoryState, PlayerStats,
+};
+use bindings::exports::docs::inventory::shop::Guest as ShopGuest;
 use bindings::exports::docs::inventory::types::{
-    ArmorType, ConsumableType, Item, ItemCategory, UseResult, WeaponType,
+    ArmorType, ConsumableType, CurseEffect, Enchantment, GemType, Item, ItemCategory, UseResult,
+    WeaponType,
 };
 use bindings::exports::docs::inventory::usage::Guest as UsageGuest;
 
 /// Default maximum inventory capacity.
 const DEFAULT_MAX_CAPACITY: u32 = 20;
 
+/// Percentage of an off-hand weapon's attack bonus applied when dual wielding.
+const OFFHAND_BONUS_PERCENT: u32 = 60;
+
+/// Maximum number of enchantments an item can hold.
+const MAX_ENCHANTMENTS: usize = 2;
+
+/// Gem slots on armor.
+const ARMOR_GEM_SLOTS: u8 = 2;
+
+/// Item ID of the Curse Stone.
+const CURSE_STONE_ID: u32 = 209;
+
+/// Item ID of the Scroll of Identify.
+const SCROLL_OF_IDENTIFY_ID: u32 = 210;
+
+/// Damage dealt by each tick of poison.
+const POISON_TICK_DAMAGE: u32 = 5;
+
+/// Item ID of the Antidote.
+const ANTIDOTE_ID: u32 = 205;
+
+/// Item ID of the Poisoned Blade coating.
+const POISONED_BLADE_ID: u32 = 206;
+
+/// Hits a Poisoned Blade coating lasts.
+const POISONED_BLADE_HITS: u32 = 3;
+
+/// Item ID of the Magic Map.
+const MAGIC_MAP_ID: u32 = 211;
+
+/// Item ID of the Four-Leaf Clover ring.
+const FOUR_LEAF_CLOVER_ID: u32 = 212;
+
+/// Item ID of the HookShot, worn in the ring slot.
+const HOOKSHOT_ID: u32 = 302;
+
+/// Item ID of the Iron Ingot material.
+const IRON_INGOT_ID: u32 = 3001;
+
+/// Item ID of the Magic Gem material.
+const MAGIC_GEM_ID: u32 = 3002;
+
+/// Names of the crafting materials, indexed from item ID 3001.
+const MATERIAL_NAMES: [&str; 10] = [
+    "Iron Ingot",
+    "Magic Gem",
+    "Wood Plank",
+    "Leather Strip",
+    "Cloth Scrap",
+    "Fire Crystal",
+    "Steel Plate",
+    "Bowstring",
+    "Silk Thread",
+    "Chain Link",
+];
+
+/// Crafting recipes as (item ID, materials as (material ID, quantity)).
+///
+/// Dismantling an item gives back the materials of its recipe.
+const RECIPES: &[(u32, &[(u32, u32)])] = &[
+    (1, &[(IRON_INGOT_ID, 1)]),
+    (2, &[(IRON_INGOT_ID, 1), (3007, 1)]),
+    (3, &[(IRON_INGOT_ID, 2), (MAGIC_GEM_ID, 1)]),
+    (4, &[(3003, 1), (3008, 1)]),
+    (5, &[(3003, 1), (3006, 1)]),
+    (101, &[(3005, 2)]),
+    (102, &[(3004, 2)]),
+    (103, &[(3010, 2)]),
+    (104, &[(IRON_INGOT_ID, 1), (3003, 1)]),
+    (105, &[(MAGIC_GEM_ID, 1), (3009, 1)]),
+];
+
+/// A merchant NPC selling a fixed set of items.
+struct Merchant {
+    /// Items for sale as (item ID, price).
+    item_catalog: Vec<(u32, u32)>,
+}
+
 /// Component structure for inventory functionality.
 struct Component;
 
@@ -101,6 +183,60 @@ fn weapon_attack(weapon: &WeaponType) -> u32 {
     }
 }
 
+/// Get weapon critical hit chance.
+///
+/// # Arguments
+///
+/// * `weapon` - Weapon type
+///
+/// # Returns
+///
+/// * `u32` - Crit chance in percent
+fn weapon_crit_chance(weapon: &WeaponType) -> u32 {
+    match weapon {
+        WeaponType::WoodenSword => 0,
+        WeaponType::SteelSword => 5,
+        WeaponType::MasterSword => 15,
+        WeaponType::Bow => 10,
+        WeaponType::FireRod => 5,
+    }
+}
+
+/// Get weapon weight.
+///
+/// # Arguments
+///
+/// * `weapon` - Weapon type
+///
+/// # Returns
+///
+/// * `u32` - Carrying weight
+fn weapon_weight(weapon: &WeaponType) -> u32 {
+    match weapon {
+        WeaponType::WoodenSword => 8,
+        WeaponType::SteelSword => 12,
+        WeaponType::MasterSword => 15,
+        WeaponType::Bow => 10,
+        WeaponType::FireRod => 15,
+    }
+}
+
+/// Get weapon gem slot count.
+///
+/// # Arguments
+///
+/// * `weapon` - Weapon type
+///
+/// # Returns
+///
+/// * `u8` - Number of gem slots
+fn weapon_gem_slots(weapon: &WeaponType) -> u8 {
+    match weapon {
+        WeaponType::WoodenSword => 0,
+        _ => 1,
+    }
+}
+
 /// Get armor ID based on type.
 ///
 /// # Arguments
@@ -158,6 +294,42 @@ fn armor_defense(armor: &ArmorType) -> u32 {
     }
 }
 
+/// Get armor weight.
+///
+/// # Arguments
+///
+/// * `armor` - Armor type
+///
+/// # Returns
+///
+/// * `u32` - Carrying weight
+fn armor_weight(armor: &ArmorType) -> u32 {
+    match armor {
+        ArmorType::ClothTunic => 10,
+        ArmorType::LeatherArmor => 14,
+        ArmorType::ChainMail => 20,
+        ArmorType::Shield => 16,
+        ArmorType::MagicRobe => 10,
+    }
+}
+
+/// Get consumable weight.
+///
+/// # Arguments
+///
+/// * `consumable` - Consumable type
+///
+/// # Returns
+///
+/// * `u32` - Carrying weight of one unit
+fn consumable_weight(consumable: &ConsumableType) -> u32 {
+    match consumable {
+        ConsumableType::FullHealthPotion => 2,
+        ConsumableType::CurseStone => 3,
+        _ => 1,
+    }
+}
+
 /// Get consumable ID based on type.
 ///
 /// # Arguments
@@ -174,6 +346,10 @@ fn consumable_id(consumable: &ConsumableType) -> u32 {
         ConsumableType::AttackBoost => 203,
         ConsumableType::DefenseBoost => 204,
         ConsumableType::Antidote => 205,
+        ConsumableType::PoisonedBlade => 206,
+        ConsumableType::CurseStone => 209,
+        ConsumableType::ScrollOfIdentify => SCROLL_OF_IDENTIFY_ID,
+        ConsumableType::MagicMap => MAGIC_MAP_ID,
     }
 }
 
@@ -193,6 +369,10 @@ fn consumable_name(consumable: &ConsumableType) -> String {
         ConsumableType::AttackBoost => "Attack Boost".to_string(),
         ConsumableType::DefenseBoost => "Defense Boost".to_string(),
         ConsumableType::Antidote => "Antidote".to_string(),
+        ConsumableType::PoisonedBlade => "Poisoned Blade".to_string(),
+        ConsumableType::CurseStone => "Curse Stone".to_string(),
+        ConsumableType::ScrollOfIdentify => "Scroll of Identify".to_string(),
+        ConsumableType::MagicMap => "Magic Map".to_string(),
     }
 }
 
@@ -228,10 +408,18 @@ fn create_weapon_item(weapon: &WeaponType) -> Item {
         name: weapon_name(weapon),
         category: ItemCategory::Weapon,
         attack_bonus: weapon_attack(weapon),
+        crit_chance: weapon_crit_chance(weapon),
         defense_bonus: 0,
         heal_amount: 0,
         quantity: 1,
         is_equipped: false,
+        is_cursed: false,
+        curse_effect: None,
+        enchantments: Vec::new(),
+        gem_slots: weapon_gem_slots(weapon),
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: weapon_weight(weapon),
     }
 }
 
@@ -250,10 +438,18 @@ fn create_armor_item(armor: &ArmorType) -> Item {
         name: armor_name(armor),
         category: ItemCategory::Armor,
         attack_bonus: 0,
+        crit_chance: 0,
         defense_bonus: armor_defense(armor),
         heal_amount: 0,
         quantity: 1,
         is_equipped: false,
+        is_cursed: false,
+        curse_effect: None,
+        enchantments: Vec::new(),
+        gem_slots: ARMOR_GEM_SLOTS,
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: armor_weight(armor),
     }
 }
 
@@ -273,228 +469,1042 @@ fn create_consumable_item(consumable: &ConsumableType, quantity: u32) -> Item {
         name: consumable_name(consumable),
         category: ItemCategory::Consumable,
         attack_bonus: 0,
+        crit_chance: 0,
         defense_bonus: 0,
         heal_amount: consumable_heal(consumable),
         quantity,
         is_equipped: false,
+        is_cursed: false,
+        curse_effect: None,
+        enchantments: Vec::new(),
+        gem_slots: 0,
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: consumable_weight(consumable),
     }
 }
 
-/// Create default inventory state.
+/// Create the Four-Leaf Clover ring accessory.
 ///
 /// # Returns
 ///
-/// * `InventoryState` - Fresh inventory state
-fn create_default_inventory() -> InventoryState {
-    InventoryState {
-        equipped_weapon: 0,
-        equipped_armor: 0,
-        item_count: 0,
-        max_capacity: DEFAULT_MAX_CAPACITY,
-        gold: 0,
+/// * `Item` - Four-Leaf Clover item
+fn create_four_leaf_clover() -> Item {
+    Item {
+        id: FOUR_LEAF_CLOVER_ID,
+        name: "Four-Leaf Clover".to_string(),
+        category: ItemCategory::Accessory,
+        attack_bonus: 0,
+        crit_chance: 0,
+        defense_bonus: 0,
+        heal_amount: 0,
+        quantity: 1,
+        is_equipped: false,
+        is_cursed: false,
+        curse_effect: None,
+        enchantments: Vec::new(),
+        gem_slots: 0,
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: 1,
     }
 }
 
-/// Calculate health after healing.
+/// Create the HookShot accessory.
+///
+/// # Returns
+///
+/// * `Item` - HookShot item
+fn create_hookshot() -> Item {
+    Item {
+        id: HOOKSHOT_ID,
+        name: "HookShot".to_string(),
+        weight: 2,
+        ..create_four_leaf_clover()
+    }
+}
+
+/// Check if the HookShot is equipped.
 ///
 /// # Arguments
 ///
-/// * `current` - Current health
-/// * `heal` - Amount to heal
-/// * `max` - Maximum health
+/// * `inv` - Current inventory state
 ///
 /// # Returns
 ///
-/// * `u32` - Health after healing
-fn calculate_healed_health(current: u32, heal: u32, max: u32) -> u32 {
-    (current + heal).min(max)
+/// * `bool` - True if the ring slot holds the HookShot
+fn is_hookshot_equipped(inv: &InventoryState) -> bool {
+    inv.equipped_ring == HOOKSHOT_ID
 }
 
-/// Calculate health restored from healing.
+/// Create a crafting material.
+///
+/// Materials use IDs 3001-3010 and have no combat stats.
 ///
 /// # Arguments
 ///
-/// * `current` - Current health
-/// * `heal` - Amount to heal
-/// * `max` - Maximum health
+/// * `item_id` - Material item ID
 ///
 /// # Returns
 ///
-/// * `u32` - Actual amount restored
-fn calculate_health_restored(current: u32, heal: u32, max: u32) -> u32 {
-    let new_health = calculate_healed_health(current, heal, max);
-    new_health - current
+/// * `Item` - Created material item
+fn create_material_item(item_id: u32) -> Item {
+    Item {
+        id: item_id,
+        name: MATERIAL_NAMES[(item_id - IRON_INGOT_ID) as usize].to_string(),
+        category: ItemCategory::Treasure,
+        attack_bonus: 0,
+        crit_chance: 0,
+        defense_bonus: 0,
+        heal_amount: 0,
+        quantity: 1,
+        is_equipped: false,
+        is_cursed: false,
+        curse_effect: None,
+        enchantments: Vec::new(),
+        gem_slots: 0,
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: 1,
+    }
 }
 
-/// Create successful heal result.
+/// Break an item into the materials of its recipe.
 ///
 /// # Arguments
 ///
-/// * `restored` - Health restored
+/// * `item_id` - Item ID to dismantle
 ///
 /// # Returns
 ///
-/// * `UseResult` - Success result
-fn create_heal_result(restored: u32) -> UseResult {
-    UseResult {
-        success: true,
-        health_restored: restored,
-        attack_boost: 0,
-        defense_boost: 0,
-        message: format!("Restored {} health!", restored),
-    }
+/// * `Vec<(u32, u32)>` - Materials as (material ID, quantity), empty if it has no recipe
+fn dismantle_item(item_id: u32) -> Vec<(u32, u32)> {
+    RECIPES
+        .iter()
+        .find(|(id, _)| *id == item_id)
+        .map_or(Vec::new(), |(_, materials)| materials.to_vec())
 }
 
-/// Create attack boost result.
+/// Craft an item from ingredients.
+///
+/// Ingredients may be given in any order and repeated IDs are added together.
 ///
 /// # Arguments
 ///
-/// * `boost` - Attack boost amount
+/// * `ingredients` - Ingredients as (item ID, quantity)
 ///
 /// # Returns
 ///
-/// * `UseResult` - Success result
-fn create_attack_boost_result(boost: u32) -> UseResult {
-    UseResult {
-        success: true,
-        health_restored: 0,
-        attack_boost: boost,
-        defense_boost: 0,
-        message: format!("Attack increased by {}!", boost),
+/// * `Result<Item, String>` - Crafted item, or an error if no recipe matches
+fn combine_items(ingredients: &[(u32, u32)]) -> Result<Item, String> {
+    let mut merged: Vec<(u32, u32)> = Vec::new();
+    for &(id, quantity) in ingredients.iter().filter(|(_, quantity)| *quantity > 0) {
+        match merged.iter_mut().find(|(merged_id, _)| *merged_id == id) {
+            Some(entry) => entry.1 += quantity,
+            None => merged.push((id, quantity)),
+        }
     }
+    merged.sort_unstable();
+    RECIPES
+        .iter()
+        .find(|(_, materials)| {
+            let mut recipe = materials.to_vec();
+            recipe.sort_unstable();
+            recipe == merged
+        })
+        .map(|(id, _)| get_item_by_id(*id))
+        .ok_or_else(|| "No recipe uses these ingredients".to_string())
 }
 
-/// Create defense boost result.
+/// Create a cursed item.
+///
+/// Cursed items use IDs 401-405 and trade a strong bonus for a debuff.
 ///
 /// # Arguments
 ///
-/// * `boost` - Defense boost amount
+/// * `item_id` - Cursed item ID
 ///
 /// # Returns
 ///
-/// * `UseResult` - Success result
-fn create_defense_boost_result(boost: u32) -> UseResult {
-    UseResult {
-        success: true,
-        health_restored: 0,
-        attack_boost: 0,
-        defense_boost: boost,
-        message: format!("Defense increased by {}!", boost),
+/// * `Item` - Created cursed item
+fn create_cursed_item(item_id: u32) -> Item {
+    let (name, category, attack_bonus, defense_bonus, curse) = match item_id {
+        401 => (
+            "Cursed Blade",
+            ItemCategory::Weapon,
+            30,
+            0,
+            CurseEffect::DefenseReduction(10),
+        ),
+        402 => (
+            "Bloodthirst Axe",
+            ItemCategory::Weapon,
+            35,
+            0,
+            CurseEffect::MaxHealthReduction(25),
+        ),
+        403 => (
+            "Doom Rod",
+            ItemCategory::Weapon,
+            40,
+            0,
+            CurseEffect::DefenseReduction(15),
+        ),
+        404 => (
+            "Hexed Mail",
+            ItemCategory::Armor,
+            0,
+            20,
+            CurseEffect::AttackReduction(8),
+        ),
+        _ => (
+            "Shadow Shield",
+            ItemCategory::Armor,
+            0,
+            25,
+            CurseEffect::MaxHealthReduction(20),
+        ),
+    };
+    Item {
+        id: item_id,
+        name: name.to_string(),
+        category,
+        attack_bonus,
+        crit_chance: 0,
+        defense_bonus,
+        heal_amount: 0,
+        quantity: 1,
+        is_equipped: false,
+        is_cursed: true,
+        curse_effect: Some(curse),
+        enchantments: Vec::new(),
+        gem_slots: 0,
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: cursed_weight(&category),
     }
 }
 
-/// Create antidote result.
+/// Get the weight of a cursed item.
+///
+/// # Arguments
+///
+/// * `category` - Category of the cursed item
 ///
 /// # Returns
 ///
-/// * `UseResult` - Success result
-fn create_antidote_result() -> UseResult {
-    UseResult {
-        success: true,
-        health_restored: 0,
-        attack_boost: 0,
-        defense_boost: 0,
-        message: "Cured poison!".to_string(),
+/// * `u32` - Carrying weight
+fn cursed_weight(category: &ItemCategory) -> u32 {
+    match category {
+        ItemCategory::Weapon => 14,
+        _ => 18,
     }
 }
 
-/// Create unknown item result.
+/// Get the real item ID hidden behind a mystery item.
+///
+/// # Arguments
+///
+/// * `item_id` - Item ID, a mystery item for IDs 501-505
 ///
 /// # Returns
 ///
-/// * `UseResult` - Failure result
-fn create_unknown_item_result() -> UseResult {
-    UseResult {
-        success: false,
-        health_restored: 0,
-        attack_boost: 0,
-        defense_boost: 0,
-        message: "Unknown item!".to_string(),
+/// * `u32` - ID of the item it turns out to be, or `item_id` unchanged
+///   when it is not a mystery item
+fn mystery_true_id(item_id: u32) -> u32 {
+    match item_id {
+        501 => 5,
+        502 => 201,
+        503 => 103,
+        504 => 202,
+        505 => 401,
+        _ => item_id,
     }
 }
 
-/// Convert Item to ItemsItem (they're the same type).
+/// Get the display name of an unidentified item.
 ///
 /// # Arguments
 ///
-/// * `item` - Source item
+/// * `category` - Category of the hidden item
 ///
 /// # Returns
 ///
-/// * `ItemsItem` - Same item
-fn to_items_item(item: Item) -> ItemsItem {
-    item
+/// * `String` - Placeholder name
+fn unidentified_name(category: &ItemCategory) -> String {
+    match category {
+        ItemCategory::Weapon => "Unidentified Weapon".to_string(),
+        ItemCategory::Armor => "Unidentified Armor".to_string(),
+        ItemCategory::Consumable => "Unidentified Potion".to_string(),
+        _ => "Unidentified Item".to_string(),
+    }
 }
 
-impl ItemsGuest for Component {
-    /// Create a weapon item.
-    ///
-    /// # Arguments
-    ///
-    /// * `weapon` - Weapon type to create
-    ///
-    /// # Returns
-    ///
-    /// * `Item` - Created weapon item
-    fn create_weapon(weapon: WeaponType) -> ItemsItem {
-        let item = create_weapon_item(&weapon);
-        to_items_item(item)
+/// Create a mystery item whose stats are hidden until identified.
+///
+/// # Arguments
+///
+/// * `item_id` - Mystery item ID (501-505)
+///
+/// # Returns
+///
+/// * `Item` - Unidentified item carrying its real stats
+fn create_mystery_item(item_id: u32) -> Item {
+    let real = get_item_by_id(mystery_true_id(item_id));
+    Item {
+        id: item_id,
+        name: unidentified_name(&real.category),
+        identified: false,
+        ..real
     }
+}
 
-    /// Create an armor item.
-    ///
-    /// # Arguments
-    ///
-    /// * `armor` - Armor type to create
-    ///
-    /// # Returns
-    ///
-    /// * `Item` - Created armor item
-    fn create_armor(armor: ArmorType) -> ItemsItem {
-        let item = create_armor_item(&armor);
-        to_items_item(item)
+/// Hide the stats of an unidentified item.
+///
+/// # Arguments
+///
+/// * `item` - Item to obfuscate
+///
+/// # Returns
+///
+/// * `Item` - Item with bonuses zeroed when unidentified
+fn obfuscate_item(item: Item) -> Item {
+    if item.identified {
+        return item;
     }
-
-    /// Create a consumable item.
-    ///
-    /// # Arguments
-    ///
-    /// * `consumable` - Consumable type to create
-    /// * `quantity` - Initial quantity
-    ///
-    /// # Returns
-    ///
-    /// * `Item` - Created consumable item
-    fn create_consumable(consumable: ConsumableType, quantity: u32) -> ItemsItem {
-        let item = create_consumable_item(&consumable, quantity);
-        to_items_item(item)
+    Item {
+        attack_bonus: 0,
+        crit_chance: 0,
+        defense_bonus: 0,
+        heal_amount: 0,
+        is_cursed: false,
+        curse_effect: None,
+        ..item
     }
+}
 
-    /// Get item stats by ID.
-    ///
-    /// # Arguments
-    ///
-    /// * `item_id` - Item ID to look up
-    ///
-    /// # Returns
-    ///
-    /// * `Item` - Item with stats
-    fn get_item_stats(item_id: u32) -> ItemsItem {
-        let item = get_item_by_id(item_id);
-        to_items_item(item)
+/// Format a stat cell, hiding it for unidentified items.
+///
+/// # Arguments
+///
+/// * `item` - Item the stat belongs to
+/// * `value` - Stat value
+///
+/// # Returns
+///
+/// * `String` - Stat text or `???`
+fn stat_cell(item: &Item, value: u32) -> String {
+    if item.identified {
+        value.to_string()
+    } else {
+        "???".to_string()
     }
 }
 
-/// Get item by ID (helper function).
+/// Apply a curse debuff to player stats.
+///
+/// Stats never drop below zero, so the debuff applied can be smaller
+/// than the curse.
 ///
 /// # Arguments
 ///
-/// * `item_id` - Item ID to look up
+/// * `stats` - Player stats to modify
+/// * `curse` - Curse effect to apply
 ///
 /// # Returns
 ///
-/// * `Item` - Item with stats
-fn get_item_by_id(item_id: u32) -> Item {
+/// * `CurseEffect` - Debuff actually applied
+fn apply_curse(stats: &mut PlayerStats, curse: &CurseEffect) -> CurseEffect {
+    match *curse {
+        CurseEffect::AttackReduction(n) => {
+            let applied = n.min(stats.attack);
+            stats.attack -= applied;
+            CurseEffect::AttackReduction(applied)
+        }
+        CurseEffect::DefenseReduction(n) => {
+            let applied = n.min(stats.defense);
+            stats.defense -= applied;
+            CurseEffect::DefenseReduction(applied)
+        }
+        CurseEffect::MaxHealthReduction(n) => {
+            let applied = n.min(stats.max_health);
+            stats.max_health -= applied;
+            CurseEffect::MaxHealthReduction(applied)
+        }
+    }
+}
+
+/// Remove a curse debuff from player stats.
+///
+/// # Arguments
+///
+/// * `stats` - Player stats to restore
+/// * `curse` - Debuff that was applied by the curse
+fn remove_curse(stats: &mut PlayerStats, curse: &CurseEffect) {
+    match *curse {
+        CurseEffect::AttackReduction(n) => stats.attack = stats.attack.saturating_add(n),
+        CurseEffect::DefenseReduction(n) => stats.defense = stats.defense.saturating_add(n),
+        CurseEffect::MaxHealthReduction(n) => stats.max_health = stats.max_health.saturating_add(n),
+    }
+}
+
+/// Sum the fire damage enchantments on an item.
+///
+/// # Arguments
+///
+/// * `item` - Item to inspect
+///
+/// # Returns
+///
+/// * `u32` - Total fire damage bonus
+fn fire_damage_bonus(item: &Item) -> u32 {
+    item.enchantments
+        .iter()
+        .map(|enchantment| match enchantment {
+            Enchantment::FireDamage(n) => *n,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Sum the attack bonus from socketed ruby gems.
+///
+/// # Arguments
+///
+/// * `item` - Item to inspect
+///
+/// # Returns
+///
+/// * `u32` - Total gem attack bonus
+fn gem_attack_bonus(item: &Item) -> u32 {
+    item.socketed_gems
+        .iter()
+        .map(|gem| match gem {
+            GemType::RubyGem(n) => *n,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Sum the defense bonus from socketed sapphire gems.
+///
+/// # Arguments
+///
+/// * `item` - Item to inspect
+///
+/// # Returns
+///
+/// * `u32` - Total gem defense bonus
+fn gem_defense_bonus(item: &Item) -> u32 {
+    item.socketed_gems
+        .iter()
+        .map(|gem| match gem {
+            GemType::SapphireGem(n) => *n,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Create the merchant with its fixed catalog.
+///
+/// # Returns
+///
+/// * `Merchant` - Merchant selling potions, a map and gear
+fn create_merchant() -> Merchant {
+    Merchant {
+        item_catalog: vec![(201, 20), (211, 50), (2, 100), (103, 150), (102, 80)],
+    }
+}
+
+/// Get the gold the merchant pays for an item.
+///
+/// The merchant buys back its own stock at half the catalog price.
+///
+/// # Arguments
+///
+/// * `item_id` - Item ID to sell
+///
+/// # Returns
+///
+/// * `Option<u32>` - Sell price, or None if the merchant doesn't stock the item
+fn merchant_sell_price(item_id: u32) -> Option<u32> {
+    create_merchant()
+        .item_catalog
+        .into_iter()
+        .find(|&(id, _)| id == item_id)
+        .map(|(_, price)| price / 2)
+}
+
+/// Count the carried items with a given ID.
+///
+/// # Arguments
+///
+/// * `inv` - Current inventory state
+/// * `item_id` - Item ID to count
+///
+/// # Returns
+///
+/// * `u32` - Number of matching items carried
+fn count_item(inv: &InventoryState, item_id: u32) -> u32 {
+    inv.item_ids.iter().filter(|&&id| id == item_id).count() as u32
+}
+
+/// Create default inventory state.
+///
+/// # Returns
+///
+/// * `InventoryState` - Fresh inventory state
+fn create_default_inventory() -> InventoryState {
+    InventoryState {
+        equipped_weapon: 0,
+        equipped_offhand: 0,
+        equipped_armor: 0,
+        equipped_ring: 0,
+        item_count: 0,
+        item_ids: Vec::new(),
+        max_capacity: DEFAULT_MAX_CAPACITY,
+        gold: 0,
+        is_poisoned: false,
+        poison_turns_remaining: 0,
+        weapon_curse: None,
+        armor_curse: None,
+        weapon_poison_hits: 0,
+    }
+}
+
+/// Calculate the attack bonus contributed by an off-hand weapon.
+///
+/// # Arguments
+///
+/// * `offhand_id` - Off-hand weapon ID (0 = none)
+///
+/// # Returns
+///
+/// * `u32` - Reduced attack bonus for the off hand
+fn offhand_attack_bonus(offhand_id: u32) -> u32 {
+    if offhand_id == 0 {
+        return 0;
+    }
+    get_item_by_id(offhand_id).attack_bonus * OFFHAND_BONUS_PERCENT / 100
+}
+
+/// Calculate health after healing.
+///
+/// # Arguments
+///
+/// * `current` - Current health
+/// * `heal` - Amount to heal
+/// * `max` - Maximum health
+///
+/// # Returns
+///
+/// * `u32` - Health after healing
+fn calculate_healed_health(current: u32, heal: u32, max: u32) -> u32 {
+    (current + heal).min(max)
+}
+
+/// Calculate health restored from healing.
+///
+/// # Arguments
+///
+/// * `current` - Current health
+/// * `heal` - Amount to heal
+/// * `max` - Maximum health
+///
+/// # Returns
+///
+/// * `u32` - Actual amount restored
+fn calculate_health_restored(current: u32, heal: u32, max: u32) -> u32 {
+    let new_health = calculate_healed_health(current, heal, max);
+    new_health - current
+}
+
+/// Create successful heal result.
+///
+/// # Arguments
+///
+/// * `restored` - Health restored
+///
+/// # Returns
+///
+/// * `UseResult` - Success result
+fn create_heal_result(restored: u32) -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: restored,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: format!("Restored {} health!", restored),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Create attack boost result.
+///
+/// # Arguments
+///
+/// * `boost` - Attack boost amount
+///
+/// # Returns
+///
+/// * `UseResult` - Success result
+fn create_attack_boost_result(boost: u32) -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: boost,
+        defense_boost: 0,
+        message: format!("Attack increased by {}!", boost),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Create defense boost result.
+///
+/// # Arguments
+///
+/// * `boost` - Defense boost amount
+///
+/// # Returns
+///
+/// * `UseResult` - Success result
+fn create_defense_boost_result(boost: u32) -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: boost,
+        message: format!("Defense increased by {}!", boost),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Create antidote result.
+///
+/// # Returns
+///
+/// * `UseResult` - Success result
+fn create_antidote_result() -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: "Cured poison!".to_string(),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Create result for using an item that isn't carried.
+///
+/// # Arguments
+///
+/// * `name` - Name of the missing item
+///
+/// # Returns
+///
+/// * `UseResult` - Failure result
+fn create_missing_item_result(name: &str) -> UseResult {
+    UseResult {
+        success: false,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: format!("You have no {}.", name),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Create poisoned blade result.
+///
+/// # Returns
+///
+/// * `UseResult` - Weapon coated result
+fn create_poisoned_blade_result() -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: "Your blade drips with poison!".to_string(),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Poison the player, stacking onto any poison already running.
+///
+/// # Arguments
+///
+/// * `inv` - Current inventory state
+/// * `duration` - Turns of poison to add
+///
+/// # Returns
+///
+/// * `InventoryState` - Poisoned inventory state
+fn apply_poison(inv: InventoryState, duration: u32) -> InventoryState {
+    let turns = inv.poison_turns_remaining.saturating_add(duration);
+    InventoryState {
+        is_poisoned: turns > 0,
+        poison_turns_remaining: turns,
+        ..inv
+    }
+}
+
+/// Tick poison for one turn.
+///
+/// # Arguments
+///
+/// * `inv` - Current inventory state
+///
+/// # Returns
+///
+/// * `(InventoryState, u32)` - Updated state and damage dealt this tick
+fn tick_poison(inv: InventoryState) -> (InventoryState, u32) {
+    if inv.poison_turns_remaining == 0 {
+        return (
+            InventoryState {
+                is_poisoned: false,
+                ..inv
+            },
+            0,
+        );
+    }
+    let turns = inv.poison_turns_remaining - 1;
+    let inv = InventoryState {
+        is_poisoned: turns > 0,
+        poison_turns_remaining: turns,
+        ..inv
+    };
+    (inv, POISON_TICK_DAMAGE)
+}
+
+/// Cure any poison on the player.
+///
+/// # Arguments
+///
+/// * `inv` - Current inventory state
+///
+/// # Returns
+///
+/// * `InventoryState` - Cured inventory state
+fn cure_poison(inv: InventoryState) -> InventoryState {
+    InventoryState {
+        is_poisoned: false,
+        poison_turns_remaining: 0,
+        ..inv
+    }
+}
+
+/// Create curse stone result.
+///
+/// # Returns
+///
+/// * `UseResult` - Curse lifted result
+fn create_curse_stone_result() -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: "The curse is lifted!".to_string(),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Use a mystery item, identifying it before applying its effect.
+///
+/// # Arguments
+///
+/// * `item_id` - Mystery item ID
+/// * `current` - Current health
+/// * `max` - Maximum health
+///
+/// # Returns
+///
+/// * `UseResult` - Effect of the real item, marked as identified
+fn use_mystery_item(item_id: u32, current: u32, max: u32) -> UseResult {
+    let real_id = mystery_true_id(item_id);
+    let name = get_item_by_id(real_id).name;
+    let result = <Component as UsageGuest>::use_item(real_id, current, max);
+    UseResult {
+        identified: true,
+        message: format!("It was a {}! {}", name, result.message),
+        ..result
+    }
+}
+
+/// Create scroll of identify result.
+///
+/// # Returns
+///
+/// * `UseResult` - Prompt to pick an item to identify
+fn create_identify_scroll_result() -> UseResult {
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: "Choose an item to identify.".to_string(),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Create magic map result.
+///
+/// # Arguments
+///
+/// * `area` - Name of the revealed area, if known
+///
+/// # Returns
+///
+/// * `UseResult` - Map reveal result
+fn create_magic_map_result(area: Option<String>) -> UseResult {
+    let message = match &area {
+        Some(name) => format!("The map reveals all of {}!", name),
+        None => "Unfold the map where you stand to reveal the area.".to_string(),
+    };
+    UseResult {
+        success: true,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message,
+        identified: false,
+        reveals_area: area,
+    }
+}
+
+/// Create unknown item result.
+///
+/// # Returns
+///
+/// * `UseResult` - Failure result
+fn create_unknown_item_result() -> UseResult {
+    UseResult {
+        success: false,
+        health_restored: 0,
+        attack_boost: 0,
+        defense_boost: 0,
+        message: "Unknown item!".to_string(),
+        identified: false,
+        reveals_area: None,
+    }
+}
+
+/// Convert Item to ItemsItem (they're the same type).
+///
+/// # Arguments
+///
+/// * `item` - Source item
+///
+/// # Returns
+///
+/// * `ItemsItem` - Same item
+fn to_items_item(item: Item) -> ItemsItem {
+    item
+}
+
+impl ItemsGuest for Component {
+    /// Create a weapon item.
+    ///
+    /// # Arguments
+    ///
+    /// * `weapon` - Weapon type to create
+    ///
+    /// # Returns
+    ///
+    /// * `Item` - Created weapon item
+    fn create_weapon(weapon: WeaponType) -> ItemsItem {
+        let item = create_weapon_item(&weapon);
+        to_items_item(item)
+    }
+
+    /// Create an armor item.
+    ///
+    /// # Arguments
+    ///
+    /// * `armor` - Armor type to create
+    ///
+    /// # Returns
+    ///
+    /// * `Item` - Created armor item
+    fn create_armor(armor: ArmorType) -> ItemsItem {
+        let item = create_armor_item(&armor);
+        to_items_item(item)
+    }
+
+    /// Create a consumable item.
+    ///
+    /// # Arguments
+    ///
+    /// * `consumable` - Consumable type to create
+    /// * `quantity` - Initial quantity
+    ///
+    /// # Returns
+    ///
+    /// * `Item` - Created consumable item
+    fn create_consumable(consumable: ConsumableType, quantity: u32) -> ItemsItem {
+        let item = create_consumable_item(&consumable, quantity);
+        to_items_item(item)
+    }
+
+    /// Get item stats by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - Item ID to look up
+    ///
+    /// # Returns
+    ///
+    /// * `Item` - Item with stats
+    fn get_item_stats(item_id: u32) -> ItemsItem {
+        let item = obfuscate_item(get_item_by_id(item_id));
+        to_items_item(item)
+    }
+
+    /// Reveal an unidentified item.
+    ///
+    /// The quantity, equipped flag, enchantments and socketed gems of the
+    /// unidentified item carry over to the revealed one.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Item to identify
+    ///
+    /// # Returns
+    ///
+    /// * `Item` - The item it turns out to be
+    fn identify_item(item: ItemsItem) -> ItemsItem {
+        if item.identified {
+            return item;
+        }
+        ItemsItem {
+            quantity: item.quantity,
+            is_equipped: item.is_equipped,
+            enchantments: item.enchantments,
+            gem_slots: item.gem_slots,
+            socketed_gems: item.socketed_gems,
+            ..get_item_by_id(mystery_true_id(item.id))
+        }
+    }
+
+    /// Format items as a table.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Items to list
+    ///
+    /// # Returns
+    ///
+    /// * `String` - One header line and one line per item
+    fn format_inventory_table(items: Vec<ItemsItem>) -> String {
+        let mut lines = vec!["Name | ATK | DEF | HEAL".to_string()];
+        for item in &items {
+            lines.push(format!(
+                "{} | {} | {} | {}",
+                item.name,
+                stat_cell(item, item.attack_bonus),
+                stat_cell(item, item.defense_bonus),
+                stat_cell(item, item.heal_amount)
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Sum the weight of all carried items.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Items to weigh
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Total weight, counting each unit of a stack
+    fn total_inventory_weight(items: Vec<ItemsItem>) -> u32 {
+        total_inventory_weight(&items)
+    }
+
+    /// Add an enchantment to an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Item to enchant
+    /// * `enchantment` - Enchantment to add
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Item, String>` - Enchanted item, or an error if slots are full
+    fn enchant_item(mut item: ItemsItem, enchantment: Enchantment) -> Result<ItemsItem, String> {
+        if item.enchantments.len() >= MAX_ENCHANTMENTS {
+            return Err("Item enchantment slots full".to_string());
+        }
+        item.enchantments.push(enchantment);
+        Ok(item)
+    }
+
+    /// Socket a gem into an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - Item to socket
+    /// * `gem` - Gem to insert
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Item, String>` - Socketed item, or an error if slots are full
+    fn socket_gem(mut item: ItemsItem, gem: GemType) -> Result<ItemsItem, String> {
+        if item.socketed_gems.len() >= item.gem_slots as usize {
+            return Err("Item gem slots full".to_string());
+        }
+        item.socketed_gems.push(gem);
+        Ok(item)
+    }
+
+    /// Break an item into crafting materials.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - Item ID to dismantle
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(u32, u32)>` - Materials as (material ID, quantity), empty if it cannot be dismantled
+    fn dismantle_item(item_id: u32) -> Vec<(u32, u32)> {
+        dismantle_item(item_id)
+    }
+
+    /// Craft an item from ingredients.
+    ///
+    /// # Arguments
+    ///
+    /// * `ingredients` - Ingredients as (item ID, quantity)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Item, String>` - Crafted item, or an error if no recipe matches
+    fn combine_items(ingredients: Vec<(u32, u32)>) -> Result<ItemsItem, String> {
+        combine_items(&ingredients).map(to_items_item)
+    }
+}
+
+/// Get item by ID (helper function).
+///
+/// # Arguments
+///
+/// * `item_id` - Item ID to look up
+///
+/// # Returns
+///
+/// * `Item` - Item with stats
+fn get_item_by_id(item_id: u32) -> Item {
     match item_id {
         1 => create_weapon_item(&WeaponType::WoodenSword),
         2 => create_weapon_item(&WeaponType::SteelSword),
@@ -511,198 +1521,858 @@ fn get_item_by_id(item_id: u32) -> Item {
         203 => create_consumable_item(&ConsumableType::AttackBoost, 1),
         204 => create_consumable_item(&ConsumableType::DefenseBoost, 1),
         205 => create_consumable_item(&ConsumableType::Antidote, 1),
+        206 => create_consumable_item(&ConsumableType::PoisonedBlade, 1),
+        CURSE_STONE_ID => create_consumable_item(&ConsumableType::CurseStone, 1),
+        210 => create_consumable_item(&ConsumableType::ScrollOfIdentify, 1),
+        211 => create_consumable_item(&ConsumableType::MagicMap, 1),
+        212 => create_four_leaf_clover(),
+        302 => create_hookshot(),
+        3001..=3010 => create_material_item(item_id),
+        401..=405 => create_cursed_item(item_id),
+        501..=505 => create_mystery_item(item_id),
         _ => create_unknown_item(),
     }
 }
 
-/// Create unknown item placeholder.
-///
-/// # Returns
-///
-/// * `Item` - Unknown item
-fn create_unknown_item() -> Item {
-    Item {
-        id: 0,
-        name: "Unknown".to_string(),
-        category: ItemCategory::Treasure,
-        attack_bonus: 0,
-        defense_bonus: 0,
-        heal_amount: 0,
-        quantity: 0,
-        is_equipped: false,
+/// Sum the weight of all carried items.
+///
+/// # Arguments
+///
+/// * `items` - Items to weigh
+///
+/// # Returns
+///
+/// * `u32` - Total weight, counting each unit of a stack
+fn total_inventory_weight(items: &[Item]) -> u32 {
+    items.iter().map(|item| item.weight * item.quantity).sum()
+}
+
+/// Look up an item's quantity in a snapshot.
+///
+/// # Arguments
+///
+/// * `snapshot` - Snapshot to search
+/// * `item_id` - Item ID to look up
+///
+/// # Returns
+///
+/// * `u32` - Quantity held, 0 if absent
+fn snapshot_count(snapshot: &InventorySnapshot, item_id: u32) -> u32 {
+    snapshot
+        .item_counts
+        .iter()
+        .find(|(id, _)| *id == item_id)
+        .map_or(0, |(_, quantity)| *quantity)
+}
+
+/// Recommend the strongest weapon and armor from a set of items.
+///
+/// Weapons are ranked by attack bonus and armor by defense bonus; ties
+/// go to the lower item ID so the choice is deterministic.
+///
+/// # Arguments
+///
+/// * `available_items` - Items the player could equip
+/// * `_player_level` - Player's level (no item has a level requirement yet)
+///
+/// # Returns
+///
+/// * `(Option<u32>, Option<u32>)` - Recommended weapon ID and armor ID
+fn calculate_optimal_gear(
+    available_items: &[Item],
+    _player_level: u32,
+) -> (Option<u32>, Option<u32>) {
+    let mut weapons: Vec<&Item> = available_items
+        .iter()
+        .filter(|item| item.category == ItemCategory::Weapon)
+        .collect();
+    weapons.sort_by_key(|item| (std::cmp::Reverse(item.attack_bonus), item.id));
+    let armor = available_items
+        .iter()
+        .filter(|item| item.category == ItemCategory::Armor)
+        .min_by_key(|item| (std::cmp::Reverse(item.defense_bonus), item.id));
+    (
+        weapons.first().map(|item| item.id),
+        armor.map(|item| item.id),
+    )
+}
+
+/// List the items held in greater quantity by one snapshot than another.
+///
+/// # Arguments
+///
+/// * `more` - Snapshot that may hold more
+/// * `less` - Snapshot to compare against
+///
+/// # Returns
+///
+/// * `Vec<u32>` - IDs of the items with a higher count in `more`
+fn increased_items(more: &InventorySnapshot, less: &InventorySnapshot) -> Vec<u32> {
+    more.item_counts
+        .iter()
+        .filter(|(id, quantity)| *quantity > snapshot_count(less, *id))
+        .map(|(id, _)| *id)
+        .collect()
+}
+
+/// Create unknown item placeholder.
+///
+/// # Returns
+///
+/// * `Item` - Unknown item
+fn create_unknown_item() -> Item {
+    Item {
+        id: 0,
+        name: "Unknown".to_string(),
+        category: ItemCategory::Treasure,
+        attack_bonus: 0,
+        crit_chance: 0,
+        defense_bonus: 0,
+        heal_amount: 0,
+        quantity: 0,
+        is_equipped: false,
+        is_cursed: false,
+        curse_effect: None,
+        enchantments: Vec::new(),
+        gem_slots: 0,
+        socketed_gems: Vec::new(),
+        identified: true,
+        weight: 0,
+    }
+}
+
+impl ManagementGuest for Component {
+    /// Create a new empty inventory.
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Fresh inventory
+    fn create_inventory() -> InventoryState {
+        create_default_inventory()
+    }
+
+    /// Add an item to the inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to add
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn add_item(inv: InventoryState, item_id: u32) -> InventoryState {
+        if Self::is_full(inv.clone()) {
+            return inv;
+        }
+        let mut item_ids = inv.item_ids;
+        item_ids.push(item_id);
+        InventoryState {
+            item_count: item_ids.len() as u32,
+            item_ids,
+            ..inv
+        }
+    }
+
+    /// Remove an item from the inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to remove
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn remove_item(inv: InventoryState, item_id: u32) -> InventoryState {
+        let Some(index) = inv.item_ids.iter().position(|&id| id == item_id) else {
+            return inv;
+        };
+        let mut item_ids = inv.item_ids;
+        item_ids.remove(index);
+        InventoryState {
+            item_count: item_ids.len() as u32,
+            item_ids,
+            ..inv
+        }
+    }
+
+    /// Check if an item is carried.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to look for
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if at least one matching item is carried
+    fn has_item(inv: InventoryState, item_id: u32) -> bool {
+        count_item(&inv, item_id) > 0
+    }
+
+    /// Count the carried items with a given ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to count
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Number of matching items carried
+    fn item_count_of(inv: InventoryState, item_id: u32) -> u32 {
+        count_item(&inv, item_id)
+    }
+
+    /// Drop an item from the inventory.
+    ///
+    /// Every slot holding the item is unequipped. A cursed weapon or armor
+    /// cannot be dropped while it is equipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to drop
+    ///
+    /// # Returns
+    ///
+    /// * `(InventoryState, bool)` - Updated inventory and whether the drop succeeded
+    fn drop_item(inv: InventoryState, item_id: u32) -> (InventoryState, bool) {
+        if count_item(&inv, item_id) == 0 || get_item_by_id(item_id).id == 0 {
+            return (inv, false);
+        }
+        if (inv.weapon_curse.is_some() && inv.equipped_weapon == item_id)
+            || (inv.armor_curse.is_some() && inv.equipped_armor == item_id)
+        {
+            return (inv, false);
+        }
+        let mut inv = inv;
+        for equipped in [
+            &mut inv.equipped_weapon,
+            &mut inv.equipped_offhand,
+            &mut inv.equipped_armor,
+            &mut inv.equipped_ring,
+        ] {
+            if *equipped == item_id {
+                *equipped = 0;
+            }
+        }
+        (Self::remove_item(inv, item_id), true)
+    }
+
+    /// Equip a weapon by item ID.
+    ///
+    /// A cursed weapon stays equipped until its curse is lifted.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Weapon ID to equip
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn equip_weapon(inv: InventoryState, item_id: u32) -> InventoryState {
+        if inv.weapon_curse.is_some() {
+            return inv;
+        }
+        let weapon_poison_hits = if item_id == inv.equipped_weapon {
+            inv.weapon_poison_hits
+        } else {
+            0
+        };
+        InventoryState {
+            equipped_weapon: item_id,
+            weapon_poison_hits,
+            ..inv
+        }
+    }
+
+    /// Equip a weapon and apply its curse to player stats.
+    ///
+    /// The debuff actually applied is remembered so a Curse Stone can
+    /// restore exactly that much. Non-weapons are not equipped, and a
+    /// cursed weapon stays equipped until its curse is lifted.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `stats` - Current player stats
+    /// * `item_id` - Weapon ID to equip
+    ///
+    /// # Returns
+    ///
+    /// * `(InventoryState, PlayerStats)` - Updated inventory and stats
+    fn equip_weapon_with_stats(
+        inv: InventoryState,
+        mut stats: PlayerStats,
+        item_id: u32,
+    ) -> (InventoryState, PlayerStats) {
+        let item = get_item_by_id(item_id);
+        if !matches!(item.category, ItemCategory::Weapon) || inv.weapon_curse.is_some() {
+            return (inv, stats);
+        }
+        let weapon_curse = item
+            .curse_effect
+            .map(|curse| apply_curse(&mut stats, &curse));
+        let inv = InventoryState {
+            weapon_curse,
+            ..Self::equip_weapon(inv, item_id)
+        };
+        (inv, stats)
+    }
+
+    /// Equip an off-hand weapon by item ID.
+    ///
+    /// Only weapons can be held in the off hand; 0 empties the slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Weapon ID to equip in the off hand
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn equip_offhand(inv: InventoryState, item_id: u32) -> InventoryState {
+        if item_id != 0 && !matches!(get_item_by_id(item_id).category, ItemCategory::Weapon) {
+            return inv;
+        }
+        InventoryState {
+            equipped_offhand: item_id,
+            ..inv
+        }
+    }
+
+    /// Equip armor by item ID.
+    ///
+    /// Cursed armor stays equipped until its curse is lifted.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Armor ID to equip
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn equip_armor(inv: InventoryState, item_id: u32) -> InventoryState {
+        if inv.armor_curse.is_some() {
+            return inv;
+        }
+        InventoryState {
+            equipped_armor: item_id,
+            ..inv
+        }
+    }
+
+    /// Equip armor and apply its curse, if any, to the player's stats.
+    ///
+    /// The debuff actually applied is remembered so a Curse Stone can
+    /// restore exactly that much. Non-armor is not equipped, and cursed
+    /// armor stays equipped until its curse is lifted.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `stats` - Current player stats
+    /// * `item_id` - Armor ID to equip
+    ///
+    /// # Returns
+    ///
+    /// * `(InventoryState, PlayerStats)` - Updated inventory and stats
+    fn equip_armor_with_stats(
+        inv: InventoryState,
+        mut stats: PlayerStats,
+        item_id: u32,
+    ) -> (InventoryState, PlayerStats) {
+        let item = get_item_by_id(item_id);
+        if !matches!(item.category, ItemCategory::Armor) || inv.armor_curse.is_some() {
+            return (inv, stats);
+        }
+        let armor_curse = item
+            .curse_effect
+            .map(|curse| apply_curse(&mut stats, &curse));
+        let inv = InventoryState {
+            armor_curse,
+            ..Self::equip_armor(inv, item_id)
+        };
+        (inv, stats)
+    }
+
+    /// Equip a ring slot item by item ID.
+    ///
+    /// Only accessories fit the ring slot; 0 empties the slot.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Ring slot item ID to equip
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn equip_ring(inv: InventoryState, item_id: u32) -> InventoryState {
+        if item_id != 0 && !matches!(get_item_by_id(item_id).category, ItemCategory::Accessory) {
+            return inv;
+        }
+        InventoryState {
+            equipped_ring: item_id,
+            ..inv
+        }
+    }
+
+    /// Check if the HookShot is equipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if the ring slot holds the HookShot
+    fn is_hookshot_equipped(inv: InventoryState) -> bool {
+        is_hookshot_equipped(&inv)
+    }
+
+    /// Add gold to the inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `amount` - Gold to add
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn add_gold(inv: InventoryState, amount: u32) -> InventoryState {
+        InventoryState {
+            gold: inv.gold + amount,
+            ..inv
+        }
+    }
+
+    /// Spend gold from the inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `amount` - Gold to spend
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn spend_gold(inv: InventoryState, amount: u32) -> InventoryState {
+        if inv.gold < amount {
+            return inv;
+        }
+        InventoryState {
+            gold: inv.gold - amount,
+            ..inv
+        }
+    }
+
+    /// Check if inventory is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if full
+    fn is_full(inv: InventoryState) -> bool {
+        inv.item_ids.len() as u32 >= inv.max_capacity
+    }
+
+    /// Take a snapshot of the inventory.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `items` - Items currently carried
+    ///
+    /// # Returns
+    ///
+    /// * `InventorySnapshot` - Copy of the inventory and item counts
+    fn take_snapshot(inv: InventoryState, items: Vec<Item>) -> InventorySnapshot {
+        InventorySnapshot {
+            state: inv,
+            item_counts: items.iter().map(|item| (item.id, item.quantity)).collect(),
+        }
+    }
+
+    /// Compare two inventory snapshots.
+    ///
+    /// # Arguments
+    ///
+    /// * `before` - Earlier snapshot
+    /// * `after` - Later snapshot
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryDiff` - Items added and removed, and the gold change
+    fn diff_snapshots(before: InventorySnapshot, after: InventorySnapshot) -> InventoryDiff {
+        InventoryDiff {
+            added: increased_items(&after, &before),
+            removed: increased_items(&before, &after),
+            gold_delta: after.state.gold as i32 - before.state.gold as i32,
+        }
+    }
+
+    /// Recommend the best weapon and armor to equip.
+    ///
+    /// # Arguments
+    ///
+    /// * `available_items` - Items the player could equip
+    /// * `player_level` - Player's level
+    ///
+    /// # Returns
+    ///
+    /// * `(Option<u32>, Option<u32>)` - Recommended weapon ID and armor ID
+    fn calculate_optimal_gear(
+        available_items: Vec<Item>,
+        player_level: u32,
+    ) -> (Option<u32>, Option<u32>) {
+        calculate_optimal_gear(&available_items, player_level)
+    }
+}
+
+impl ShopGuest for Component {
+    /// Get the merchant's catalog.
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(u32, u32)>` - Items for sale as (item ID, price)
+    fn merchant_catalog() -> Vec<(u32, u32)> {
+        create_merchant().item_catalog
+    }
+
+    /// Check if the inventory holds enough gold for a price.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `price` - Price to pay
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - True if gold covers the price
+    fn can_afford(inv: InventoryState, price: u32) -> bool {
+        inv.gold >= price
+    }
+
+    /// Buy an item from the merchant.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to buy
+    /// * `price` - Price to pay
+    ///
+    /// # Returns
+    ///
+    /// * `Option<InventoryState>` - Updated inventory, or `None` if the
+    ///   merchant doesn't sell the item at that price, the player can't
+    ///   afford it or has no room for it
+    fn buy_item(inv: InventoryState, item_id: u32, price: u32) -> Option<InventoryState> {
+        if !create_merchant().item_catalog.contains(&(item_id, price))
+            || !Self::can_afford(inv.clone(), price)
+            || <Self as ManagementGuest>::is_full(inv.clone())
+        {
+            return None;
+        }
+        let inv = <Self as ManagementGuest>::spend_gold(inv, price);
+        Some(<Self as ManagementGuest>::add_item(inv, item_id))
+    }
+
+    /// Sell a carried item.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `item_id` - Item ID to sell
+    /// * `sell_price` - Gold received
+    ///
+    /// # Returns
+    ///
+    /// * `InventoryState` - Updated inventory
+    fn sell_item(inv: InventoryState, item_id: u32, sell_price: u32) -> InventoryState {
+        if count_item(&inv, item_id) == 0 {
+            return inv;
+        }
+        let inv = <Self as ManagementGuest>::remove_item(inv, item_id);
+        <Self as ManagementGuest>::add_gold(inv, sell_price)
+    }
+
+    /// Get the gold the merchant pays for an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - Item ID to sell
+    ///
+    /// # Returns
+    ///
+    /// * `Option<u32>` - Half the catalog price, or None if not stocked
+    fn sell_price(item_id: u32) -> Option<u32> {
+        merchant_sell_price(item_id)
     }
 }
 
-impl ManagementGuest for Component {
-    /// Create a new empty inventory.
+impl UsageGuest for Component {
+    /// Use a consumable item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_id` - Item ID to use
+    /// * `current_health` - Current health
+    /// * `max_health` - Maximum health
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Fresh inventory
-    fn create_inventory() -> InventoryState {
-        create_default_inventory()
+    /// * `UseResult` - Result of using item
+    fn use_item(item_id: u32, current_health: u32, max_health: u32) -> UseResult {
+        match item_id {
+            201 => use_health_potion(current_health, max_health, 50),
+            202 => use_health_potion(current_health, max_health, max_health),
+            203 => create_attack_boost_result(5),
+            204 => create_defense_boost_result(5),
+            205 => create_antidote_result(),
+            206 => create_poisoned_blade_result(),
+            CURSE_STONE_ID => create_curse_stone_result(),
+            SCROLL_OF_IDENTIFY_ID => create_identify_scroll_result(),
+            MAGIC_MAP_ID => create_magic_map_result(None),
+            501..=505 => use_mystery_item(item_id, current_health, max_health),
+            _ => create_unknown_item_result(),
+        }
     }
 
-    /// Add an item to the inventory.
+    /// Read a Magic Map to reveal the player's area.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_name` - Name of the area the player is in
+    ///
+    /// # Returns
+    ///
+    /// * `UseResult` - Result revealing the area
+    fn use_magic_map(area_name: String) -> UseResult {
+        create_magic_map_result(Some(area_name))
+    }
+
+    /// Use a Curse Stone to lift an equipped cursed item's debuff.
+    ///
+    /// Consumes one carried Curse Stone and restores the debuff that was
+    /// actually applied when the weapon or armor was equipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `inv` - Current inventory state
+    /// * `stats` - Current player stats
+    /// * `cursed_item_id` - ID of the cursed weapon or armor whose debuff is lifted
+    ///
+    /// # Returns
+    ///
+    /// * `Option<(InventoryState, PlayerStats)>` - Inventory without the
+    ///   stone and restored stats, or `None` if no stone is carried or the
+    ///   item is not the equipped, cursed weapon or armor
+    fn use_curse_stone(
+        inv: InventoryState,
+        mut stats: PlayerStats,
+        cursed_item_id: u32,
+    ) -> Option<(InventoryState, PlayerStats)> {
+        if count_item(&inv, CURSE_STONE_ID) == 0 {
+            return None;
+        }
+        let weapon_curse = inv
+            .weapon_curse
+            .filter(|_| inv.equipped_weapon == cursed_item_id);
+        let armor_curse = inv
+            .armor_curse
+            .filter(|_| inv.equipped_armor == cursed_item_id);
+        let curse = weapon_curse.or(armor_curse)?;
+        remove_curse(&mut stats, &curse);
+        let inv = <Self as ManagementGuest>::remove_item(inv, CURSE_STONE_ID);
+        let inv = if weapon_curse.is_some() {
+            InventoryState {
+                weapon_curse: None,
+                ..inv
+            }
+        } else {
+            InventoryState {
+                armor_curse: None,
+                ..inv
+            }
+        };
+        Some((inv, stats))
+    }
+
+    /// Read a Scroll of Identify to reveal a carried mystery item.
+    ///
+    /// Consumes one carried scroll and replaces the mystery item's ID in
+    /// the inventory, including its equipment slot, with the real one.
     ///
     /// # Arguments
     ///
     /// * `inv` - Current inventory state
-    /// * `item_id` - Item ID to add
+    /// * `item` - Unidentified item to reveal
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Updated inventory
-    fn add_item(inv: InventoryState, _item_id: u32) -> InventoryState {
-        if inv.item_count >= inv.max_capacity {
-            return inv;
+    /// * `Option<(InventoryState, Item)>` - Inventory without the scroll
+    ///   and the revealed item, or `None` if no scroll is carried or the
+    ///   item is not a carried, unidentified item
+    fn use_identify_scroll(
+        inv: InventoryState,
+        item: ItemsItem,
+    ) -> Option<(InventoryState, ItemsItem)> {
+        if item.identified
+            || count_item(&inv, SCROLL_OF_IDENTIFY_ID) == 0
+            || count_item(&inv, item.id) == 0
+        {
+            return None;
         }
-        InventoryState {
-            item_count: inv.item_count + 1,
-            ..inv
+        let mystery_id = item.id;
+        let revealed = <Self as ItemsGuest>::identify_item(item);
+        let mut inv = <Self as ManagementGuest>::remove_item(inv, SCROLL_OF_IDENTIFY_ID);
+        if let Some(slot) = inv.item_ids.iter_mut().find(|id| **id == mystery_id) {
+            *slot = revealed.id;
+        }
+        for equipped in [&mut inv.equipped_weapon, &mut inv.equipped_armor] {
+            if *equipped == mystery_id {
+                *equipped = revealed.id;
+            }
         }
+        Some((inv, revealed))
     }
 
-    /// Remove an item from the inventory.
+    /// Drink a carried Antidote to cure poison.
     ///
     /// # Arguments
     ///
     /// * `inv` - Current inventory state
-    /// * `item_id` - Item ID to remove
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Updated inventory
-    fn remove_item(inv: InventoryState, _item_id: u32) -> InventoryState {
-        if inv.item_count == 0 {
-            return inv;
-        }
-        InventoryState {
-            item_count: inv.item_count - 1,
-            ..inv
+    /// * `(InventoryState, UseResult)` - Cured state without the antidote,
+    ///   or the unchanged state and a failure if no Antidote is carried
+    fn use_antidote(inv: InventoryState) -> (InventoryState, UseResult) {
+        if count_item(&inv, ANTIDOTE_ID) == 0 {
+            return (inv, create_missing_item_result("Antidote"));
         }
+        let inv = <Self as ManagementGuest>::remove_item(inv, ANTIDOTE_ID);
+        (cure_poison(inv), create_antidote_result())
     }
 
-    /// Equip a weapon by item ID.
+    /// Coat the equipped weapon with a carried Poisoned Blade.
     ///
     /// # Arguments
     ///
     /// * `inv` - Current inventory state
-    /// * `item_id` - Weapon ID to equip
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Updated inventory
-    fn equip_weapon(inv: InventoryState, item_id: u32) -> InventoryState {
-        InventoryState {
-            equipped_weapon: item_id,
-            ..inv
+    /// * `Option<InventoryState>` - Inventory without the coating and with
+    ///   poisoned hits on the weapon, or `None` if no coating is carried or
+    ///   no weapon is equipped
+    fn use_poisoned_blade(inv: InventoryState) -> Option<InventoryState> {
+        if count_item(&inv, POISONED_BLADE_ID) == 0 || inv.equipped_weapon == 0 {
+            return None;
         }
+        let inv = <Self as ManagementGuest>::remove_item(inv, POISONED_BLADE_ID);
+        Some(InventoryState {
+            weapon_poison_hits: POISONED_BLADE_HITS,
+            ..inv
+        })
     }
 
-    /// Equip armor by item ID.
+    /// Spend one poisoned hit of the equipped weapon.
     ///
     /// # Arguments
     ///
     /// * `inv` - Current inventory state
-    /// * `item_id` - Armor ID to equip
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Updated inventory
-    fn equip_armor(inv: InventoryState, item_id: u32) -> InventoryState {
-        InventoryState {
-            equipped_armor: item_id,
-            ..inv
+    /// * `(InventoryState, bool)` - Updated state and whether the hit
+    ///   poisons its target
+    fn strike_with_weapon(inv: InventoryState) -> (InventoryState, bool) {
+        if inv.weapon_poison_hits == 0 {
+            return (inv, false);
         }
+        let inv = InventoryState {
+            weapon_poison_hits: inv.weapon_poison_hits - 1,
+            ..inv
+        };
+        (inv, true)
     }
 
-    /// Add gold to the inventory.
+    /// Poison the player for extra turns.
     ///
     /// # Arguments
     ///
     /// * `inv` - Current inventory state
-    /// * `amount` - Gold to add
+    /// * `duration` - Turns of poison to add
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Updated inventory
-    fn add_gold(inv: InventoryState, amount: u32) -> InventoryState {
-        InventoryState {
-            gold: inv.gold + amount,
-            ..inv
-        }
+    /// * `InventoryState` - Poisoned inventory state
+    fn apply_poison(inv: InventoryState, duration: u32) -> InventoryState {
+        apply_poison(inv, duration)
     }
 
-    /// Spend gold from the inventory.
+    /// Tick poison for one turn.
     ///
     /// # Arguments
     ///
     /// * `inv` - Current inventory state
-    /// * `amount` - Gold to spend
     ///
     /// # Returns
     ///
-    /// * `InventoryState` - Updated inventory
-    fn spend_gold(inv: InventoryState, amount: u32) -> InventoryState {
-        if inv.gold < amount {
-            return inv;
-        }
-        InventoryState {
-            gold: inv.gold - amount,
-            ..inv
-        }
+    /// * `(InventoryState, u32)` - Updated state and damage dealt this tick
+    fn tick_poison(inv: InventoryState) -> (InventoryState, u32) {
+        tick_poison(inv)
     }
 
-    /// Check if inventory is full.
+    /// Get total attack bonus from main-hand and off-hand weapons.
     ///
     /// # Arguments
     ///
-    /// * `inv` - Current inventory state
+    /// * `weapon` - Equipped weapon, with its enchantments and gems
+    /// * `offhand_id` - Equipped off-hand weapon ID (0 = none)
     ///
     /// # Returns
     ///
-    /// * `bool` - True if full
-    fn is_full(inv: InventoryState) -> bool {
-        inv.item_count >= inv.max_capacity
+    /// * `u32` - Attack bonus including fire damage enchantments
+    fn get_total_attack_bonus(weapon: Item, offhand_id: u32) -> u32 {
+        Self::get_item_attack_bonus(weapon) + offhand_attack_bonus(offhand_id)
     }
-}
 
-impl UsageGuest for Component {
-    /// Use a consumable item.
+    /// Get an item's attack bonus including enchantments.
     ///
     /// # Arguments
     ///
-    /// * `item_id` - Item ID to use
-    /// * `current_health` - Current health
-    /// * `max_health` - Maximum health
+    /// * `item` - Item to inspect
     ///
     /// # Returns
     ///
-    /// * `UseResult` - Result of using item
-    fn use_item(item_id: u32, current_health: u32, max_health: u32) -> UseResult {
-        match item_id {
-            201 => use_health_potion(current_health, max_health, 50),
-            202 => use_health_potion(current_health, max_health, max_health),
-            203 => create_attack_boost_result(5),
-            204 => create_defense_boost_result(5),
-            205 => create_antidote_result(),
-            _ => create_unknown_item_result(),
-        }
+    /// * `u32` - Attack bonus plus fire damage and gems
+    fn get_item_attack_bonus(item: Item) -> u32 {
+        item.attack_bonus + fire_damage_bonus(&item) + gem_attack_bonus(&item)
     }
 
-    /// Get total attack bonus from weapon.
+    /// Get an item's defense bonus including socketed gems.
     ///
     /// # Arguments
     ///
-    /// * `weapon_id` - Equipped weapon ID
+    /// * `item` - Item to inspect
     ///
     /// # Returns
     ///
-    /// * `u32` - Attack bonus
-    fn get_total_attack_bonus(weapon_id: u32) -> u32 {
-        let item = get_item_by_id(weapon_id);
-        item.attack_bonus
+    /// * `u32` - Defense bonus plus gems
+    fn get_item_defense_bonus(item: Item) -> u32 {
+        item.defense_bonus + gem_defense_bonus(&item)
     }
 
     /// Get total defense bonus from armor.
@@ -716,7 +2386,7 @@ impl UsageGuest for Component {
     /// * `u32` - Defense bonus
     fn get_total_defense_bonus(armor_id: u32) -> u32 {
         let item = get_item_by_id(armor_id);
-        item.defense_bonus
+        Self::get_item_defense_bonus(item)
     }
 }
 
@@ -886,15 +2556,61 @@ mod tests {
         let inv = create_default_inventory();
         let updated = <Component as ManagementGuest>::add_item(inv, 1);
         assert_eq!(updated.item_count, 1);
+        assert_eq!(updated.item_ids, vec![1]);
     }
 
     #[test]
     /// Test remove item from inventory.
     fn test_remove_item() {
         let mut inv = create_default_inventory();
+        inv.item_ids = vec![1, 2, 1, 3, 4];
         inv.item_count = 5;
         let updated = <Component as ManagementGuest>::remove_item(inv, 1);
         assert_eq!(updated.item_count, 4);
+        assert_eq!(updated.item_ids, vec![2, 1, 3, 4]);
+    }
+
+    #[test]
+    /// Test removing an item that is not carried leaves the inventory unchanged.
+    fn test_remove_missing_item() {
+        let mut inv = create_default_inventory();
+        inv.item_ids = vec![2];
+        inv.item_count = 1;
+        let updated = <Component as ManagementGuest>::remove_item(inv, 1);
+        assert_eq!(updated.item_ids, vec![2]);
+        assert_eq!(updated.item_count, 1);
+    }
+
+    #[test]
+    /// Test removing duplicates takes the earliest copy each time.
+    fn test_remove_duplicate_items_in_order() {
+        let inv = [3, 201, 5, 201]
+            .into_iter()
+            .fold(create_default_inventory(), |inv, id| {
+                <Component as ManagementGuest>::add_item(inv, id)
+            });
+        let inv = <Component as ManagementGuest>::remove_item(inv, 201);
+        assert_eq!(inv.item_ids, vec![3, 5, 201]);
+        let inv = <Component as ManagementGuest>::remove_item(inv, 201);
+        assert_eq!(inv.item_ids, vec![3, 5]);
+        assert!(!<Component as ManagementGuest>::has_item(inv, 201));
+    }
+
+    #[test]
+    /// Test has_item and item_count_of.
+    fn test_has_item_and_count() {
+        let inv = [201, 2, 201]
+            .into_iter()
+            .fold(create_default_inventory(), |inv, id| {
+                <Component as ManagementGuest>::add_item(inv, id)
+            });
+        assert!(<Component as ManagementGuest>::has_item(inv.clone(), 2));
+        assert!(!<Component as ManagementGuest>::has_item(inv.clone(), 3));
+        assert_eq!(
+            <Component as ManagementGuest>::item_count_of(inv.clone(), 201),
+            2
+        );
+        assert_eq!(<Component as ManagementGuest>::item_count_of(inv, 3), 0);
     }
 
     #[test]
@@ -942,6 +2658,7 @@ mod tests {
     /// Test is full true.
     fn test_is_full_true() {
         let mut inv = create_default_inventory();
+        inv.item_ids = vec![1; 20];
         inv.item_count = 20;
         assert!(<Component as ManagementGuest>::is_full(inv));
     }
@@ -987,14 +2704,699 @@ mod tests {
     #[test]
     /// Test get attack bonus.
     fn test_get_total_attack_bonus() {
-        let bonus = <Component as UsageGuest>::get_total_attack_bonus(3);
+        let bonus = <Component as UsageGuest>::get_total_attack_bonus(get_item_by_id(3), 0);
         assert_eq!(bonus, 25);
     }
 
+    #[test]
+    /// Test equip offhand.
+    fn test_equip_offhand() {
+        let inv = create_default_inventory();
+        let updated = <Component as ManagementGuest>::equip_offhand(inv, 1);
+        assert_eq!(updated.equipped_offhand, 1);
+        assert_eq!(updated.equipped_weapon, 0);
+    }
+
+    #[test]
+    /// Test dual wooden swords fall short of a steel sword.
+    fn test_dual_wield_wooden_swords() {
+        let dual = <Component as UsageGuest>::get_total_attack_bonus(get_item_by_id(1), 1);
+        let single = <Component as UsageGuest>::get_total_attack_bonus(get_item_by_id(2), 0);
+        assert_eq!(dual, 8);
+        assert!(dual < single);
+    }
+
+    #[test]
+    /// Test upgraded dual wield beats a single steel sword.
+    fn test_dual_wield_steel_swords() {
+        let dual = <Component as UsageGuest>::get_total_attack_bonus(get_item_by_id(2), 2);
+        let single = <Component as UsageGuest>::get_total_attack_bonus(get_item_by_id(2), 0);
+        assert_eq!(dual, 16);
+        assert!(dual > single);
+    }
+
     #[test]
     /// Test get defense bonus.
     fn test_get_total_defense_bonus() {
         let bonus = <Component as UsageGuest>::get_total_defense_bonus(103);
         assert_eq!(bonus, 10);
     }
+
+    #[test]
+    /// Test cursed items are flagged with a curse effect.
+    fn test_cursed_item_lookup() {
+        let blade = get_item_by_id(401);
+        assert_eq!(blade.name, "Cursed Blade");
+        assert_eq!(blade.attack_bonus, 30);
+        assert!(blade.is_cursed);
+        assert!(matches!(
+            blade.curse_effect,
+            Some(CurseEffect::DefenseReduction(10))
+        ));
+        assert!(!get_item_by_id(1).is_cursed);
+    }
+
+    #[test]
+    /// Test equipping a cursed blade and lifting the curse with a Curse Stone.
+    fn test_curse_stone_reverses_curse() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 15,
+            max_health: 100,
+        };
+        let (inv, cursed) = <Component as ManagementGuest>::equip_weapon_with_stats(
+            create_default_inventory(),
+            stats,
+            401,
+        );
+        assert_eq!(inv.equipped_weapon, 401);
+        assert_eq!(cursed.defense, 5);
+        let inv = <Component as ManagementGuest>::add_item(inv, CURSE_STONE_ID);
+        let (inv, restored) = <Component as UsageGuest>::use_curse_stone(inv, cursed, 401).unwrap();
+        assert_eq!(restored.defense, 15);
+        assert!(!<Component as ManagementGuest>::has_item(
+            inv,
+            CURSE_STONE_ID
+        ));
+        assert!(<Component as UsageGuest>::use_item(209, 100, 100).success);
+    }
+
+    #[test]
+    /// Test cursed armor applies its curse until a Curse Stone lifts it.
+    fn test_cursed_armor_curse_and_stone() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 15,
+            max_health: 100,
+        };
+        let (inv, cursed) = <Component as ManagementGuest>::equip_armor_with_stats(
+            create_default_inventory(),
+            stats,
+            404,
+        );
+        assert_eq!(inv.equipped_armor, 404);
+        assert_eq!(cursed.attack, 2);
+        let stuck = <Component as ManagementGuest>::equip_armor(inv.clone(), 103);
+        assert_eq!(stuck.equipped_armor, 404);
+        let inv = <Component as ManagementGuest>::add_item(inv, CURSE_STONE_ID);
+        let (inv, restored) = <Component as UsageGuest>::use_curse_stone(inv, cursed, 404).unwrap();
+        assert_eq!(restored.attack, 10);
+        assert!(inv.armor_curse.is_none());
+        let swapped = <Component as ManagementGuest>::equip_armor(inv, 103);
+        assert_eq!(swapped.equipped_armor, 103);
+    }
+
+    #[test]
+    /// Test only armor is equipped as armor with stats.
+    fn test_equip_armor_with_stats_rejects_weapons() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 15,
+            max_health: 100,
+        };
+        let (inv, unchanged) = <Component as ManagementGuest>::equip_armor_with_stats(
+            create_default_inventory(),
+            stats,
+            401,
+        );
+        assert_eq!(inv.equipped_armor, 0);
+        assert!(inv.armor_curse.is_none());
+        assert_eq!(unchanged.defense, 15);
+    }
+
+    #[test]
+    /// Test fire damage enchantment adds to attack bonus.
+    fn test_enchant_fire_damage() {
+        let sword = get_item_by_id(2);
+        let enchanted =
+            <Component as ItemsGuest>::enchant_item(sword, Enchantment::FireDamage(5)).unwrap();
+        let bonus = <Component as UsageGuest>::get_item_attack_bonus(enchanted.clone());
+        assert_eq!(bonus, 15);
+        let total = <Component as UsageGuest>::get_total_attack_bonus(enchanted, 0);
+        assert_eq!(total, 15);
+        let dual = <Component as UsageGuest>::get_total_attack_bonus(
+            <Component as ItemsGuest>::enchant_item(get_item_by_id(2), Enchantment::FireDamage(5))
+                .unwrap(),
+            2,
+        );
+        assert_eq!(dual, 21);
+    }
+
+    #[test]
+    /// Test a third enchantment is rejected.
+    fn test_enchant_slots_full() {
+        let mut sword = get_item_by_id(1);
+        sword = <Component as ItemsGuest>::enchant_item(sword, Enchantment::FrostSlow).unwrap();
+        sword = <Component as ItemsGuest>::enchant_item(sword, Enchantment::ExtraReach).unwrap();
+        let result = <Component as ItemsGuest>::enchant_item(sword, Enchantment::LifeSteal(3));
+        assert_eq!(result.unwrap_err(), "Item enchantment slots full");
+    }
+
+    #[test]
+    /// Test socketing a ruby gem into a steel sword.
+    fn test_socket_ruby_gem() {
+        let sword = get_item_by_id(2);
+        assert_eq!(
+            <Component as UsageGuest>::get_item_attack_bonus(sword.clone()),
+            10
+        );
+        let socketed = <Component as ItemsGuest>::socket_gem(sword, GemType::RubyGem(10)).unwrap();
+        assert_eq!(
+            <Component as UsageGuest>::get_item_attack_bonus(socketed),
+            20
+        );
+    }
+
+    #[test]
+    /// Test gem slot counts and full sockets.
+    fn test_socket_gem_slots() {
+        assert_eq!(get_item_by_id(1).gem_slots, 0);
+        assert_eq!(get_item_by_id(3).gem_slots, 1);
+        let wooden = get_item_by_id(1);
+        let result = <Component as ItemsGuest>::socket_gem(wooden, GemType::RubyGem(5));
+        assert_eq!(result.unwrap_err(), "Item gem slots full");
+        let mut mail = get_item_by_id(103);
+        mail = <Component as ItemsGuest>::socket_gem(mail, GemType::SapphireGem(4)).unwrap();
+        mail = <Component as ItemsGuest>::socket_gem(mail, GemType::EmeraldGem(20)).unwrap();
+        let base = get_item_by_id(103).defense_bonus;
+        assert_eq!(
+            <Component as UsageGuest>::get_item_defense_bonus(mail),
+            base + 4
+        );
+    }
+
+    #[test]
+    /// Test dropping the equipped weapon clears the weapon slot.
+    fn test_drop_equipped_weapon() {
+        let inv = create_default_inventory();
+        let inv = <Component as ManagementGuest>::add_item(inv, 2);
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 2);
+        let (dropped, success) = <Component as ManagementGuest>::drop_item(inv, 2);
+        assert!(success);
+        assert_eq!(dropped.equipped_weapon, 0);
+        assert_eq!(dropped.item_count, 0);
+    }
+
+    #[test]
+    /// Test dropping equipped gear clears the offhand, armor and ring slots.
+    fn test_drop_clears_every_slot() {
+        let mut inv = create_default_inventory();
+        for item_id in [1, 103, FOUR_LEAF_CLOVER_ID] {
+            inv = <Component as ManagementGuest>::add_item(inv, item_id);
+        }
+        let inv = <Component as ManagementGuest>::equip_offhand(inv, 1);
+        let inv = <Component as ManagementGuest>::equip_armor(inv, 103);
+        let inv = <Component as ManagementGuest>::equip_ring(inv, FOUR_LEAF_CLOVER_ID);
+        let (inv, _) = <Component as ManagementGuest>::drop_item(inv, 1);
+        assert_eq!(inv.equipped_offhand, 0);
+        let (inv, _) = <Component as ManagementGuest>::drop_item(inv, 103);
+        assert_eq!(inv.equipped_armor, 0);
+        let (inv, success) = <Component as ManagementGuest>::drop_item(inv, FOUR_LEAF_CLOVER_ID);
+        assert!(success);
+        assert_eq!(inv.equipped_ring, 0);
+        assert_eq!(inv.item_count, 0);
+    }
+
+    #[test]
+    /// Test a cursed equipped weapon cannot be dropped.
+    fn test_drop_cursed_weapon_refused() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 4,
+            max_health: 100,
+        };
+        let inv = <Component as ManagementGuest>::add_item(create_default_inventory(), 401);
+        let (inv, _) = <Component as ManagementGuest>::equip_weapon_with_stats(inv, stats, 401);
+        assert!(inv.weapon_curse.is_some());
+        let (inv, success) = <Component as ManagementGuest>::drop_item(inv, 401);
+        assert!(!success);
+        assert_eq!(inv.equipped_weapon, 401);
+        assert_eq!(count_item(&inv, 401), 1);
+    }
+
+    #[test]
+    /// Test dropping from an empty inventory fails.
+    fn test_drop_item_empty() {
+        let inv = create_default_inventory();
+        let (_, success) = <Component as ManagementGuest>::drop_item(inv, 1);
+        assert!(!success);
+    }
+
+    #[test]
+    /// Test using an unidentified health potion identifies it and heals.
+    fn test_use_unidentified_potion() {
+        let item = <Component as ItemsGuest>::get_item_stats(502);
+        assert_eq!(item.name, "Unidentified Potion");
+        assert!(!item.identified);
+        assert_eq!(item.heal_amount, 0);
+        let result = <Component as UsageGuest>::use_item(502, 40, 100);
+        assert!(result.success);
+        assert!(result.identified);
+        assert_eq!(result.health_restored, 50);
+    }
+
+    #[test]
+    /// Test a scroll of identify reveals hidden stats and the table hides them.
+    fn test_identify_item() {
+        let mystery = get_item_by_id(501);
+        let table = <Component as ItemsGuest>::format_inventory_table(vec![mystery.clone()]);
+        assert!(table.contains("Unidentified Weapon | ??? | ??? | ???"));
+        let revealed = <Component as ItemsGuest>::identify_item(mystery);
+        assert!(revealed.identified);
+        assert_eq!(revealed.name, "Fire Rod");
+        assert_eq!(revealed.attack_bonus, 15);
+    }
+
+    #[test]
+    /// Test identifying keeps enchantments and gems and leaves other IDs alone.
+    fn test_identify_item_keeps_modifiers() {
+        let mut mystery = get_item_by_id(501);
+        mystery =
+            <Component as ItemsGuest>::enchant_item(mystery, Enchantment::FireDamage(5)).unwrap();
+        mystery.gem_slots = 1;
+        mystery = <Component as ItemsGuest>::socket_gem(mystery, GemType::RubyGem(3)).unwrap();
+        let revealed = <Component as ItemsGuest>::identify_item(mystery);
+        assert_eq!(revealed.id, 5);
+        assert_eq!(revealed.enchantments.len(), 1);
+        assert_eq!(revealed.socketed_gems.len(), 1);
+        assert_eq!(mystery_true_id(505), 401);
+        assert_eq!(mystery_true_id(1), 1);
+        assert_eq!(mystery_true_id(999), 999);
+    }
+
+    #[test]
+    /// Test a Scroll of Identify is consumed to reveal a carried mystery item.
+    fn test_use_identify_scroll() {
+        let inv = <Component as ManagementGuest>::create_inventory();
+        let inv = <Component as ManagementGuest>::add_item(inv, 501);
+        let mystery = get_item_by_id(501);
+        assert!(
+            <Component as UsageGuest>::use_identify_scroll(inv.clone(), mystery.clone()).is_none()
+        );
+        let inv = <Component as ManagementGuest>::add_item(inv, SCROLL_OF_IDENTIFY_ID);
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 501);
+        let (inv, revealed) = <Component as UsageGuest>::use_identify_scroll(inv, mystery).unwrap();
+        assert_eq!(revealed.name, "Fire Rod");
+        assert_eq!(count_item(&inv, SCROLL_OF_IDENTIFY_ID), 0);
+        assert_eq!(count_item(&inv, 501), 0);
+        assert_eq!(count_item(&inv, 5), 1);
+        assert_eq!(inv.equipped_weapon, 5);
+        assert!(<Component as UsageGuest>::use_identify_scroll(inv, revealed).is_none());
+    }
+
+    #[test]
+    /// Test item weights by category.
+    fn test_item_weights() {
+        assert_eq!(get_item_by_id(1).weight, 8);
+        assert_eq!(get_item_by_id(103).weight, 20);
+        assert_eq!(get_item_by_id(201).weight, 1);
+        assert_eq!(get_item_by_id(401).weight, 14);
+    }
+
+    #[test]
+    /// Test carrying every weapon weighs more than the capacity of 50.
+    fn test_total_inventory_weight_all_weapons() {
+        let weapons: Vec<Item> = (1..=5).map(get_item_by_id).collect();
+        assert_eq!(total_inventory_weight(&weapons), 60);
+        let potions = create_consumable_item(&ConsumableType::HealthPotion, 3);
+        assert_eq!(total_inventory_weight(&[potions]), 3);
+    }
+
+    #[test]
+    /// Test the Magic Map reveals the named area.
+    fn test_use_magic_map() {
+        let item = get_item_by_id(MAGIC_MAP_ID);
+        assert_eq!(item.name, "Magic Map");
+        assert_eq!(item.category, ItemCategory::Consumable);
+        let result = <Component as UsageGuest>::use_item(MAGIC_MAP_ID, 50, 100);
+        assert!(result.success);
+        assert_eq!(result.reveals_area, None);
+        let result = <Component as UsageGuest>::use_magic_map("Hyrule Castle".to_string());
+        assert_eq!(result.reveals_area, Some("Hyrule Castle".to_string()));
+    }
+
+    #[test]
+    /// Test the Four-Leaf Clover is a light accessory.
+    fn test_four_leaf_clover_item() {
+        let clover = get_item_by_id(212);
+        assert_eq!(clover.name, "Four-Leaf Clover");
+        assert_eq!(clover.category, ItemCategory::Accessory);
+        assert_eq!(clover.weight, 1);
+    }
+
+    #[test]
+    /// Test diffing snapshots around drinking a potion and finding gold.
+    fn test_diff_snapshots() {
+        let inv = create_default_inventory();
+        let potions = create_consumable_item(&ConsumableType::HealthPotion, 2);
+        let before = <Component as ManagementGuest>::take_snapshot(inv, vec![potions]);
+        let inv = <Component as ManagementGuest>::add_gold(create_default_inventory(), 25);
+        let items = vec![
+            create_consumable_item(&ConsumableType::HealthPotion, 1),
+            get_item_by_id(2),
+        ];
+        let after = <Component as ManagementGuest>::take_snapshot(inv, items);
+        let diff = <Component as ManagementGuest>::diff_snapshots(before, after);
+        assert_eq!(diff.added, vec![2]);
+        assert_eq!(diff.removed, vec![201]);
+        assert_eq!(diff.gold_delta, 25);
+    }
+
+    #[test]
+    /// Test dismantling the Master Sword and crafting it back.
+    fn test_dismantle_master_sword() {
+        let materials = <Component as ItemsGuest>::dismantle_item(3);
+        assert_eq!(materials, vec![(IRON_INGOT_ID, 2), (MAGIC_GEM_ID, 1)]);
+        assert_eq!(get_item_by_id(IRON_INGOT_ID).name, "Iron Ingot");
+        assert_eq!(get_item_by_id(MAGIC_GEM_ID).attack_bonus, 0);
+        let crafted = <Component as ItemsGuest>::combine_items(materials).unwrap();
+        assert_eq!(crafted.id, 3);
+        let split = vec![(MAGIC_GEM_ID, 1), (IRON_INGOT_ID, 1), (IRON_INGOT_ID, 1)];
+        assert_eq!(combine_items(&split).unwrap().id, 3);
+    }
+
+    #[test]
+    /// Test items without a recipe cannot be dismantled or crafted.
+    fn test_dismantle_item_without_recipe() {
+        assert_eq!(dismantle_item(1), vec![(IRON_INGOT_ID, 1)]);
+        assert!(dismantle_item(201).is_empty());
+        assert!(dismantle_item(IRON_INGOT_ID).is_empty());
+        assert_eq!(
+            combine_items(&[(MAGIC_GEM_ID, 3)]).unwrap_err(),
+            "No recipe uses these ingredients"
+        );
+    }
+
+    #[test]
+    /// Test optimal gear picks the strongest weapon and armor.
+    fn test_calculate_optimal_gear() {
+        let items: Vec<Item> = [1, 2, 3, 102, 103, 201].map(get_item_by_id).into();
+        assert_eq!(calculate_optimal_gear(&items, 1), (Some(3), Some(103)));
+        let swords: Vec<Item> = [1, 2].map(get_item_by_id).into();
+        assert_eq!(calculate_optimal_gear(&swords, 1), (Some(2), None));
+    }
+
+    #[test]
+    /// Test optimal gear breaks ties by lower item ID.
+    fn test_calculate_optimal_gear_tie() {
+        let mut copy = get_item_by_id(1);
+        copy.id = 7;
+        let items = vec![copy, get_item_by_id(1)];
+        assert_eq!(calculate_optimal_gear(&items, 1), (Some(1), None));
+    }
+
+    #[test]
+    /// Test the HookShot is detected in the ring slot.
+    fn test_is_hookshot_equipped() {
+        let inv = create_default_inventory();
+        assert!(!is_hookshot_equipped(&inv));
+        let inv = <Component as ManagementGuest>::equip_ring(inv, HOOKSHOT_ID);
+        assert!(is_hookshot_equipped(&inv));
+        assert_eq!(get_item_by_id(HOOKSHOT_ID).name, "HookShot");
+    }
+
+    #[test]
+    /// Test only accessories can be equipped in the ring slot.
+    fn test_equip_ring_rejects_non_accessories() {
+        let inv = <Component as ManagementGuest>::equip_ring(create_default_inventory(), 212);
+        let sword = <Component as ManagementGuest>::equip_ring(inv.clone(), 1);
+        assert_eq!(sword.equipped_ring, FOUR_LEAF_CLOVER_ID);
+        let missing = <Component as ManagementGuest>::equip_ring(inv, 999);
+        assert_eq!(missing.equipped_ring, FOUR_LEAF_CLOVER_ID);
+        let empty = <Component as ManagementGuest>::equip_ring(missing, 0);
+        assert_eq!(empty.equipped_ring, 0);
+    }
+
+    #[test]
+    /// Test weapons carry a crit chance.
+    fn test_weapon_crit_chance() {
+        assert_eq!(create_weapon_item(&WeaponType::MasterSword).crit_chance, 15);
+        assert_eq!(create_weapon_item(&WeaponType::WoodenSword).crit_chance, 0);
+    }
+
+    #[test]
+    /// Test buying an item spends gold and stores the item.
+    fn test_buy_item_success() {
+        let mut inv = create_default_inventory();
+        inv.gold = 30;
+        let inv = <Component as ShopGuest>::buy_item(inv, 201, 20).unwrap();
+        assert_eq!(inv.gold, 10);
+        assert_eq!(inv.item_ids, vec![201]);
+    }
+
+    #[test]
+    /// Test buying fails without enough gold.
+    fn test_buy_item_insufficient_gold() {
+        let mut inv = create_default_inventory();
+        inv.gold = 19;
+        assert!(!<Component as ShopGuest>::can_afford(inv.clone(), 20));
+        assert!(<Component as ShopGuest>::buy_item(inv, 201, 20).is_none());
+    }
+
+    #[test]
+    /// Test buying fails with a full inventory.
+    fn test_buy_item_inventory_full() {
+        let mut inv = create_default_inventory();
+        inv.gold = 500;
+        inv.item_ids = vec![1; DEFAULT_MAX_CAPACITY as usize];
+        inv.item_count = DEFAULT_MAX_CAPACITY;
+        assert!(<Component as ShopGuest>::buy_item(inv, 201, 20).is_none());
+    }
+
+    #[test]
+    /// Test selling a carried item and an item that isn't carried.
+    fn test_sell_item() {
+        let mut inv = create_default_inventory();
+        inv.item_ids = vec![2];
+        inv.item_count = 1;
+        let sold = <Component as ShopGuest>::sell_item(inv, 2, 50);
+        assert_eq!((sold.gold, sold.item_count), (50, 0));
+        let unchanged = <Component as ShopGuest>::sell_item(sold, 2, 50);
+        assert_eq!(unchanged.gold, 50);
+    }
+
+    #[test]
+    /// Test buying fails for items or prices not in the catalog.
+    fn test_buy_item_not_in_catalog() {
+        let mut inv = create_default_inventory();
+        inv.gold = 500;
+        assert!(<Component as ShopGuest>::buy_item(inv.clone(), 201, 0).is_none());
+        assert!(<Component as ShopGuest>::buy_item(inv, 5, 20).is_none());
+    }
+
+    #[test]
+    /// Test buying each catalog item and selling it back returns half its price.
+    fn test_buy_sell_round_trip() {
+        let catalog = <Component as ShopGuest>::merchant_catalog();
+        let mut inv = create_default_inventory();
+        inv.gold = catalog.iter().map(|&(_, price)| price).max().unwrap();
+        for (item_id, price) in catalog {
+            let bought = <Component as ShopGuest>::buy_item(inv.clone(), item_id, price)
+                .expect("every catalog item should be affordable");
+            let sell_price = <Component as ShopGuest>::sell_price(item_id).unwrap();
+            assert_eq!(sell_price, price / 2);
+            let sold = <Component as ShopGuest>::sell_item(bought, item_id, sell_price);
+            assert_eq!(sold.gold, inv.gold - price + price / 2);
+            assert_eq!(count_item(&sold, item_id), 0);
+        }
+        assert_eq!(<Component as ShopGuest>::sell_price(1), None);
+    }
+
+    #[test]
+    /// Test the merchant catalog is fixed and priced.
+    fn test_merchant_catalog() {
+        let catalog = <Component as ShopGuest>::merchant_catalog();
+        assert_eq!(catalog.len(), 5);
+        assert!(catalog
+            .iter()
+            .all(|&(id, price)| get_item_by_id(id).id == id && price > 0));
+    }
+
+    #[test]
+    /// Test an antidote with no poison running leaves the player cured.
+    fn test_use_antidote_zero_turns() {
+        let inv = <Component as ManagementGuest>::add_item(create_default_inventory(), ANTIDOTE_ID);
+        let (inv, result) = <Component as UsageGuest>::use_antidote(inv);
+        assert!(result.success);
+        assert!(!inv.is_poisoned);
+        assert_eq!(inv.poison_turns_remaining, 0);
+        let poisoned = apply_poison(create_default_inventory(), 3);
+        let poisoned = <Component as ManagementGuest>::add_item(poisoned, ANTIDOTE_ID);
+        let (cured, _) = <Component as UsageGuest>::use_antidote(poisoned);
+        assert!(!cured.is_poisoned);
+        assert_eq!(cured.poison_turns_remaining, 0);
+    }
+
+    #[test]
+    /// Test an antidote is required and used up.
+    fn test_use_antidote_consumes_antidote() {
+        let poisoned = apply_poison(create_default_inventory(), 3);
+        let (inv, result) = <Component as UsageGuest>::use_antidote(poisoned);
+        assert!(!result.success);
+        assert_eq!(inv.poison_turns_remaining, 3);
+        let inv = <Component as ManagementGuest>::add_item(inv, ANTIDOTE_ID);
+        let (inv, result) = <Component as UsageGuest>::use_antidote(inv);
+        assert!(result.success);
+        assert_eq!(count_item(&inv, ANTIDOTE_ID), 0);
+        assert!(!inv.is_poisoned);
+    }
+
+    #[test]
+    /// Test poison duration saturates instead of overflowing.
+    fn test_apply_poison_saturates() {
+        let inv = apply_poison(create_default_inventory(), u32::MAX);
+        let inv = apply_poison(inv, 5);
+        assert_eq!(inv.poison_turns_remaining, u32::MAX);
+    }
+
+    #[test]
+    /// Test a Poisoned Blade coats the equipped weapon for a few hits.
+    fn test_use_poisoned_blade() {
+        let inv =
+            <Component as ManagementGuest>::add_item(create_default_inventory(), POISONED_BLADE_ID);
+        assert!(<Component as UsageGuest>::use_poisoned_blade(inv.clone()).is_none());
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 1);
+        let mut inv = <Component as UsageGuest>::use_poisoned_blade(inv).unwrap();
+        assert_eq!(count_item(&inv, POISONED_BLADE_ID), 0);
+        for _ in 0..POISONED_BLADE_HITS {
+            let (next, poisons) = <Component as UsageGuest>::strike_with_weapon(inv);
+            assert!(poisons);
+            inv = next;
+        }
+        let (inv, poisons) = <Component as UsageGuest>::strike_with_weapon(inv);
+        assert!(!poisons);
+        assert!(<Component as UsageGuest>::use_poisoned_blade(inv).is_none());
+    }
+
+    #[test]
+    /// Test swapping weapons wipes the coating off.
+    fn test_poison_coating_cleared_on_swap() {
+        let inv =
+            <Component as ManagementGuest>::add_item(create_default_inventory(), POISONED_BLADE_ID);
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 1);
+        let inv = <Component as UsageGuest>::use_poisoned_blade(inv).unwrap();
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 1);
+        assert_eq!(inv.weapon_poison_hits, POISONED_BLADE_HITS);
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 2);
+        assert_eq!(inv.weapon_poison_hits, 0);
+    }
+
+    #[test]
+    /// Test poison deals damage each tick and expires at zero turns.
+    fn test_tick_poison_expires() {
+        let inv = apply_poison(create_default_inventory(), 2);
+        let (inv, damage) = tick_poison(inv);
+        assert_eq!(
+            (damage, inv.poison_turns_remaining),
+            (POISON_TICK_DAMAGE, 1)
+        );
+        assert!(inv.is_poisoned);
+        let (inv, damage) = tick_poison(inv);
+        assert_eq!(
+            (damage, inv.poison_turns_remaining),
+            (POISON_TICK_DAMAGE, 0)
+        );
+        assert!(!inv.is_poisoned);
+        let (_, damage) = tick_poison(inv);
+        assert_eq!(damage, 0);
+    }
+
+    #[test]
+    /// Test poisoning an already poisoned player extends the duration.
+    fn test_apply_poison_stacks() {
+        let inv = apply_poison(create_default_inventory(), 3);
+        let inv = apply_poison(inv, 2);
+        assert!(inv.is_poisoned);
+        assert_eq!(inv.poison_turns_remaining, 5);
+    }
+
+    #[test]
+    /// Test the poisoned blade is a usable consumable.
+    fn test_poisoned_blade_item() {
+        let item = get_item_by_id(206);
+        assert_eq!(item.name, "Poisoned Blade");
+        assert!(<Component as UsageGuest>::use_item(206, 50, 100).success);
+    }
+
+    #[test]
+    /// Test a Curse Stone is required, consumed and only lifts the equipped curse.
+    fn test_curse_stone_requirements() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 15,
+            max_health: 100,
+        };
+        let (inv, cursed) = <Component as ManagementGuest>::equip_weapon_with_stats(
+            create_default_inventory(),
+            stats,
+            401,
+        );
+        assert!(<Component as UsageGuest>::use_curse_stone(inv.clone(), cursed, 401).is_none());
+        let inv = <Component as ManagementGuest>::add_item(inv, CURSE_STONE_ID);
+        assert!(<Component as UsageGuest>::use_curse_stone(inv.clone(), cursed, 402).is_none());
+        let (inv, restored) = <Component as UsageGuest>::use_curse_stone(inv, cursed, 401).unwrap();
+        assert_eq!(restored.defense, 15);
+        let inv = <Component as ManagementGuest>::add_item(inv, CURSE_STONE_ID);
+        assert!(<Component as UsageGuest>::use_curse_stone(inv, restored, 401).is_none());
+    }
+
+    #[test]
+    /// Test lifting a curse restores only the debuff actually applied.
+    fn test_curse_stone_restores_saturated_curse() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 4,
+            max_health: 100,
+        };
+        let (inv, cursed) = <Component as ManagementGuest>::equip_weapon_with_stats(
+            create_default_inventory(),
+            stats,
+            401,
+        );
+        assert_eq!(cursed.defense, 0);
+        assert!(matches!(
+            inv.weapon_curse,
+            Some(CurseEffect::DefenseReduction(4))
+        ));
+        let inv = <Component as ManagementGuest>::add_item(inv, CURSE_STONE_ID);
+        let (_, restored) = <Component as UsageGuest>::use_curse_stone(inv, cursed, 401).unwrap();
+        assert_eq!(restored.defense, 4);
+    }
+
+    #[test]
+    /// Test curses do not stack and armor is not equipped as a weapon.
+    fn test_equip_weapon_with_stats_rejects_stacking_and_armor() {
+        let stats = PlayerStats {
+            attack: 10,
+            defense: 15,
+            max_health: 100,
+        };
+        let (inv, same) = <Component as ManagementGuest>::equip_weapon_with_stats(
+            create_default_inventory(),
+            stats,
+            404,
+        );
+        assert_eq!(inv.equipped_weapon, 0);
+        assert_eq!(same.attack, 10);
+        let (inv, cursed) =
+            <Component as ManagementGuest>::equip_weapon_with_stats(inv, stats, 401);
+        let (inv, after) =
+            <Component as ManagementGuest>::equip_weapon_with_stats(inv, cursed, 402);
+        assert_eq!(inv.equipped_weapon, 401);
+        assert_eq!(after.max_health, 100);
+        let inv = <Component as ManagementGuest>::equip_weapon(inv, 1);
+        assert_eq!(inv.equipped_weapon, 401);
+    }
+
+    #[test]
+    /// Test non-weapons cannot be equipped in the off hand.
+    fn test_equip_offhand_rejects_non_weapons() {
+        let inv = <Component as ManagementGuest>::equip_offhand(create_default_inventory(), 1);
+        let armored = <Component as ManagementGuest>::equip_offhand(inv.clone(), 101);
+        assert_eq!(armored.equipped_offhand, 1);
+        let potion = <Component as ManagementGuest>::equip_offhand(inv, 201);
+        assert_eq!(potion.equipped_offhand, 1);
+        let empty = <Component as ManagementGuest>::equip_offhand(potion, 0);
+        assert_eq!(empty.equipped_offhand, 0);
+    }
 }
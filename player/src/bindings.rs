@@ -8,14 +8,15 @@ pub mod exports {
         pub mod player {
             /// Direction enum for player movement.
             ///
-            /// Represents the four cardinal directions the player can move
-            /// in a top-down 2D game world.
+            /// Represents the four cardinal and four diagonal directions the player
+            /// can move in a top-down 2D game world.
             #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
             pub mod types {
                 #[used]
                 #[doc(hidden)]
                 static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
-                /// Cardinal direction for movement.
+                use super::super::super::super::_rt;
+                /// Cardinal or diagonal direction for movement.
                 #[repr(u8)]
                 #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
                 pub enum Direction {
@@ -27,6 +28,14 @@ pub mod exports {
                     West,
                     /// Move rightward (east).
                     East,
+                    /// Move up and right (northeast).
+                    NorthEast,
+                    /// Move up and left (northwest).
+                    NorthWest,
+                    /// Move down and right (southeast).
+                    SouthEast,
+                    /// Move down and left (southwest).
+                    SouthWest,
                 }
                 impl ::core::fmt::Debug for Direction {
                     fn fmt(
@@ -42,6 +51,18 @@ pub mod exports {
                             }
                             Direction::West => f.debug_tuple("Direction::West").finish(),
                             Direction::East => f.debug_tuple("Direction::East").finish(),
+                            Direction::NorthEast => {
+                                f.debug_tuple("Direction::NorthEast").finish()
+                            }
+                            Direction::NorthWest => {
+                                f.debug_tuple("Direction::NorthWest").finish()
+                            }
+                            Direction::SouthEast => {
+                                f.debug_tuple("Direction::SouthEast").finish()
+                            }
+                            Direction::SouthWest => {
+                                f.debug_tuple("Direction::SouthWest").finish()
+                            }
                         }
                     }
                 }
@@ -56,6 +77,10 @@ pub mod exports {
                             1 => Direction::South,
                             2 => Direction::West,
                             3 => Direction::East,
+                            4 => Direction::NorthEast,
+                            5 => Direction::NorthWest,
+                            6 => Direction::SouthEast,
+                            7 => Direction::SouthWest,
                             _ => panic!("invalid enum discriminant"),
                         }
                     }
@@ -81,8 +106,7 @@ pub mod exports {
                     }
                 }
                 /// Player combat and health statistics.
-                #[repr(C)]
-                #[derive(Clone, Copy)]
+                #[derive(Clone)]
                 pub struct PlayerStats {
                     /// Current health points (0 = defeated).
                     pub health: u32,
@@ -96,6 +120,20 @@ pub mod exports {
                     pub experience: u32,
                     /// Current player level.
                     pub level: u32,
+                    /// Current shield points absorbed before health.
+                    pub shield_hp: u32,
+                    /// Maximum shield points (0 without a shield).
+                    pub max_shield_hp: u32,
+                    /// Luck, raising critical hit chance and loot quality.
+                    pub luck: u32,
+                    /// Speed deciding battle initiative and flee success.
+                    pub speed: u32,
+                    /// Current mana for casting spells.
+                    pub mana: u32,
+                    /// Maximum mana.
+                    pub max_mana: u32,
+                    /// Spells recharging as (spell ID, turns remaining).
+                    pub spells_on_cooldown: _rt::Vec<(u32, u32)>,
                 }
                 impl ::core::fmt::Debug for PlayerStats {
                     fn fmt(
@@ -109,6 +147,70 @@ pub mod exports {
                             .field("defense", &self.defense)
                             .field("experience", &self.experience)
                             .field("level", &self.level)
+                            .field("shield-hp", &self.shield_hp)
+                            .field("max-shield-hp", &self.max_shield_hp)
+                            .field("luck", &self.luck)
+                            .field("speed", &self.speed)
+                            .field("mana", &self.mana)
+                            .field("max-mana", &self.max_mana)
+                            .field("spells-on-cooldown", &self.spells_on_cooldown)
+                            .finish()
+                    }
+                }
+                /// Announcement of a level up that just happened.
+                #[derive(Clone)]
+                pub struct LevelUpMessage {
+                    /// Level reached.
+                    pub new_level: u32,
+                    /// Max health gained.
+                    pub health_gained: u32,
+                    /// Attack gained.
+                    pub attack_gained: u32,
+                    /// Defense gained.
+                    pub defense_gained: u32,
+                    /// Text announcing the level up.
+                    pub message: _rt::String,
+                }
+                impl ::core::fmt::Debug for LevelUpMessage {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("LevelUpMessage")
+                            .field("new-level", &self.new_level)
+                            .field("health-gained", &self.health_gained)
+                            .field("attack-gained", &self.attack_gained)
+                            .field("defense-gained", &self.defense_gained)
+                            .field("message", &self.message)
+                            .finish()
+                    }
+                }
+                /// Bonuses granted by the next level up.
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct LevelUpPreview {
+                    /// Level reached after leveling up.
+                    pub next_level: u32,
+                    /// Max health gained.
+                    pub health_gain: u32,
+                    /// Attack gained.
+                    pub attack_gain: u32,
+                    /// Defense gained.
+                    pub defense_gain: u32,
+                    /// Experience needed to reach the next level.
+                    pub exp_required: u32,
+                }
+                impl ::core::fmt::Debug for LevelUpPreview {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        f.debug_struct("LevelUpPreview")
+                            .field("next-level", &self.next_level)
+                            .field("health-gain", &self.health_gain)
+                            .field("attack-gain", &self.attack_gain)
+                            .field("defense-gain", &self.defense_gain)
+                            .field("exp-required", &self.exp_required)
                             .finish()
                     }
                 }
@@ -185,7 +287,8 @@ pub mod exports {
                     ///
                     /// Returns the new position after movement.
                     fn move_player(current_pos: Position, dir: Direction) -> Position;
-                    /// Calculate distance between two positions (Manhattan distance).
+                    /// Calculate distance between two positions (Chebyshev distance, since
+                    /// the player can move diagonally).
                     fn calculate_distance(start: Position, end: Position) -> u32;
                 }
                 #[doc(hidden)]
@@ -221,6 +324,8 @@ pub mod exports {
                 static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
                 use super::super::super::super::_rt;
                 pub type PlayerStats = super::super::super::super::exports::docs::player::types::PlayerStats;
+                pub type LevelUpPreview = super::super::super::super::exports::docs::player::types::LevelUpPreview;
+                pub type LevelUpMessage = super::super::super::super::exports::docs::player::types::LevelUpMessage;
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
                 pub unsafe fn _export_create_player_cabi<T: Guest>() -> *mut u8 {
@@ -234,6 +339,13 @@ pub mod exports {
                         defense: defense2,
                         experience: experience2,
                         level: level2,
+                        shield_hp: shield_hp2,
+                        max_shield_hp: max_shield_hp2,
+                        luck: luck2,
+                        speed: speed2,
+                        mana: mana2,
+                        max_mana: max_mana2,
+                        spells_on_cooldown: spells_on_cooldown2,
                     } = result0;
                     *ptr1.add(0).cast::<i32>() = _rt::as_i32(health2);
                     *ptr1.add(4).cast::<i32>() = _rt::as_i32(max_health2);
@@ -241,10 +353,54 @@ pub mod exports {
                     *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense2);
                     *ptr1.add(16).cast::<i32>() = _rt::as_i32(experience2);
                     *ptr1.add(20).cast::<i32>() = _rt::as_i32(level2);
+                    *ptr1.add(24).cast::<i32>() = _rt::as_i32(shield_hp2);
+                    *ptr1.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp2);
+                    *ptr1.add(32).cast::<i32>() = _rt::as_i32(luck2);
+                    *ptr1.add(36).cast::<i32>() = _rt::as_i32(speed2);
+                    *ptr1.add(40).cast::<i32>() = _rt::as_i32(mana2);
+                    *ptr1.add(44).cast::<i32>() = _rt::as_i32(max_mana2);
+                    let vec4 = spells_on_cooldown2;
+                    let len4 = vec4.len();
+                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec4.len() * 8,
+                        4,
+                    );
+                    let result4 = if layout4.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout4);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec4.into_iter().enumerate() {
+                        let base = result4.add(i * 8);
+                        {
+                            let (t3_0, t3_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t3_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t3_1);
+                        }
+                    }
+                    *ptr1
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len4;
+                    *ptr1.add(48).cast::<*mut u8>() = result4;
                     ptr1
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn __post_return_create_player<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_take_damage_cabi<T: Guest>(
                     arg0: i32,
                     arg1: i32,
@@ -253,9 +409,30 @@ pub mod exports {
                     arg4: i32,
                     arg5: i32,
                     arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::take_damage(
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::take_damage(
                         super::super::super::super::exports::docs::player::types::PlayerStats {
                             health: arg0 as u32,
                             max_health: arg1 as u32,
@@ -263,25 +440,83 @@ pub mod exports {
                             defense: arg3 as u32,
                             experience: arg4 as u32,
                             level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
                         },
-                        arg6 as u32,
+                        arg14 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::player::types::PlayerStats {
-                        health: health2,
-                        max_health: max_health2,
-                        attack: attack2,
-                        defense: defense2,
-                        experience: experience2,
-                        level: level2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(health2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(max_health2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(attack2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(experience2);
-                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(level2);
-                    ptr1
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_take_damage<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -293,9 +528,30 @@ pub mod exports {
                     arg4: i32,
                     arg5: i32,
                     arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::heal(
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::heal(
                         super::super::super::super::exports::docs::player::types::PlayerStats {
                             health: arg0 as u32,
                             max_health: arg1 as u32,
@@ -303,25 +559,83 @@ pub mod exports {
                             defense: arg3 as u32,
                             experience: arg4 as u32,
                             level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
                         },
-                        arg6 as u32,
+                        arg14 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
                     let super::super::super::super::exports::docs::player::types::PlayerStats {
-                        health: health2,
-                        max_health: max_health2,
-                        attack: attack2,
-                        defense: defense2,
-                        experience: experience2,
-                        level: level2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(health2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(max_health2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(attack2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(experience2);
-                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(level2);
-                    ptr1
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_heal<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -333,9 +647,31 @@ pub mod exports {
                     arg4: i32,
                     arg5: i32,
                     arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
+                    arg15: i32,
                 ) -> *mut u8 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::gain_experience(
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::gain_experience(
                         super::super::super::super::exports::docs::player::types::PlayerStats {
                             health: arg0 as u32,
                             max_health: arg1 as u32,
@@ -343,25 +679,143 @@ pub mod exports {
                             defense: arg3 as u32,
                             experience: arg4 as u32,
                             level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
                         },
-                        arg6 as u32,
+                        arg14 as u32,
+                        arg15 as u32,
                     );
-                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let (t5_0, t5_1) = result3;
                     let super::super::super::super::exports::docs::player::types::PlayerStats {
-                        health: health2,
-                        max_health: max_health2,
-                        attack: attack2,
-                        defense: defense2,
-                        experience: experience2,
-                        level: level2,
-                    } = result0;
-                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(health2);
-                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(max_health2);
-                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(attack2);
-                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense2);
-                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(experience2);
-                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(level2);
-                    ptr1
+                        health: health6,
+                        max_health: max_health6,
+                        attack: attack6,
+                        defense: defense6,
+                        experience: experience6,
+                        level: level6,
+                        shield_hp: shield_hp6,
+                        max_shield_hp: max_shield_hp6,
+                        luck: luck6,
+                        speed: speed6,
+                        mana: mana6,
+                        max_mana: max_mana6,
+                        spells_on_cooldown: spells_on_cooldown6,
+                    } = t5_0;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health6);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health6);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack6);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense6);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience6);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level6);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp6);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp6);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck6);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed6);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana6);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana6);
+                    let vec8 = spells_on_cooldown6;
+                    let len8 = vec8.len();
+                    let layout8 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec8.len() * 8,
+                        4,
+                    );
+                    let result8 = if layout8.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout8).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout8);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec8.into_iter().enumerate() {
+                        let base = result8.add(i * 8);
+                        {
+                            let (t7_0, t7_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t7_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t7_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len8;
+                    *ptr4.add(48).cast::<*mut u8>() = result8;
+                    match t5_1 {
+                        Some(e) => {
+                            *ptr4
+                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::exports::docs::player::types::LevelUpMessage {
+                                new_level: new_level9,
+                                health_gained: health_gained9,
+                                attack_gained: attack_gained9,
+                                defense_gained: defense_gained9,
+                                message: message9,
+                            } = e;
+                            *ptr4
+                                .add(48 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(new_level9);
+                            *ptr4
+                                .add(52 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(health_gained9);
+                            *ptr4
+                                .add(56 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack_gained9);
+                            *ptr4
+                                .add(60 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense_gained9);
+                            let vec10 = (message9.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr4
+                                .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len10;
+                            *ptr4
+                                .add(64 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr10.cast_mut();
+                        }
+                        None => {
+                            *ptr4
+                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_gain_experience<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let l3 = i32::from(
+                        *arg0
+                            .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    match l3 {
+                        0 => {}
+                        _ => {
+                            let l4 = *arg0
+                                .add(64 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l5 = *arg0
+                                .add(64 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l4, l5, 1);
+                        }
+                    }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
@@ -372,23 +826,61 @@ pub mod exports {
                     arg3: i32,
                     arg4: i32,
                     arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
                 ) -> i32 {
                     #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-                    let result0 = T::is_defeated(super::super::super::super::exports::docs::player::types::PlayerStats {
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::is_defeated(super::super::super::super::exports::docs::player::types::PlayerStats {
                         health: arg0 as u32,
                         max_health: arg1 as u32,
                         attack: arg2 as u32,
                         defense: arg3 as u32,
                         experience: arg4 as u32,
                         level: arg5 as u32,
+                        shield_hp: arg6 as u32,
+                        max_shield_hp: arg7 as u32,
+                        luck: arg8 as u32,
+                        speed: arg9 as u32,
+                        mana: arg10 as u32,
+                        max_mana: arg11 as u32,
+                        spells_on_cooldown: result2,
                     });
-                    match result0 {
+                    match result3 {
                         true => 1,
                         false => 0,
                     }
                 }
                 #[doc(hidden)]
                 #[allow(non_snake_case)]
+                pub unsafe fn _export_health_percentage_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::health_percentage(arg0 as u32, arg1 as u32);
+                    _rt::as_i32(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
                 pub unsafe fn _export_exp_to_next_level_cabi<T: Guest>(
                     arg0: i32,
                 ) -> i32 {
@@ -396,6 +888,858 @@ pub mod exports {
                     let result0 = T::exp_to_next_level(arg0 as u32);
                     _rt::as_i32(result0)
                 }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_armor_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::equip_armor(
+                        super::super::super::super::exports::docs::player::types::PlayerStats {
+                            health: arg0 as u32,
+                            max_health: arg1 as u32,
+                            attack: arg2 as u32,
+                            defense: arg3 as u32,
+                            experience: arg4 as u32,
+                            level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
+                        },
+                        arg14 as u32,
+                    );
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_armor<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_regen_shield_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::regen_shield(super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: arg0 as u32,
+                        max_health: arg1 as u32,
+                        attack: arg2 as u32,
+                        defense: arg3 as u32,
+                        experience: arg4 as u32,
+                        level: arg5 as u32,
+                        shield_hp: arg6 as u32,
+                        max_shield_hp: arg7 as u32,
+                        luck: arg8 as u32,
+                        speed: arg9 as u32,
+                        mana: arg10 as u32,
+                        max_mana: arg11 as u32,
+                        spells_on_cooldown: result2,
+                    });
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_regen_shield<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_equip_ring_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::equip_ring(
+                        super::super::super::super::exports::docs::player::types::PlayerStats {
+                            health: arg0 as u32,
+                            max_health: arg1 as u32,
+                            attack: arg2 as u32,
+                            defense: arg3 as u32,
+                            experience: arg4 as u32,
+                            level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
+                        },
+                        arg14 as u32,
+                    );
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_equip_ring<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_calculate_combat_rating_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
+                    arg15: i32,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::calculate_combat_rating(
+                        super::super::super::super::exports::docs::player::types::PlayerStats {
+                            health: arg0 as u32,
+                            max_health: arg1 as u32,
+                            attack: arg2 as u32,
+                            defense: arg3 as u32,
+                            experience: arg4 as u32,
+                            level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
+                        },
+                        arg14 as u32,
+                        arg15 as u32,
+                    );
+                    _rt::as_i32(result3)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_level_summary_cabi<T: Guest>(
+                    arg0: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_level_summary(arg0 as u32);
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::player::types::LevelUpPreview {
+                        next_level: next_level2,
+                        health_gain: health_gain2,
+                        attack_gain: attack_gain2,
+                        defense_gain: defense_gain2,
+                        exp_required: exp_required2,
+                    } = result0;
+                    *ptr1.add(0).cast::<i32>() = _rt::as_i32(next_level2);
+                    *ptr1.add(4).cast::<i32>() = _rt::as_i32(health_gain2);
+                    *ptr1.add(8).cast::<i32>() = _rt::as_i32(attack_gain2);
+                    *ptr1.add(12).cast::<i32>() = _rt::as_i32(defense_gain2);
+                    *ptr1.add(16).cast::<i32>() = _rt::as_i32(exp_required2);
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_spell_mana_cost_cabi<T: Guest>(arg0: i32) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::spell_mana_cost(arg0 as u32);
+                    _rt::as_i32(result0)
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_cast_spell_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
+                    arg15: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::cast_spell(
+                        super::super::super::super::exports::docs::player::types::PlayerStats {
+                            health: arg0 as u32,
+                            max_health: arg1 as u32,
+                            attack: arg2 as u32,
+                            defense: arg3 as u32,
+                            experience: arg4 as u32,
+                            level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
+                        },
+                        arg14 as u32,
+                        arg15 as u32,
+                    );
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::exports::docs::player::types::PlayerStats {
+                                health: health5,
+                                max_health: max_health5,
+                                attack: attack5,
+                                defense: defense5,
+                                experience: experience5,
+                                level: level5,
+                                shield_hp: shield_hp5,
+                                max_shield_hp: max_shield_hp5,
+                                luck: luck5,
+                                speed: speed5,
+                                mana: mana5,
+                                max_mana: max_mana5,
+                                spells_on_cooldown: spells_on_cooldown5,
+                            } = e;
+                            *ptr4
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(health5);
+                            *ptr4
+                                .add(4 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_health5);
+                            *ptr4
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(attack5);
+                            *ptr4
+                                .add(12 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(defense5);
+                            *ptr4
+                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(experience5);
+                            *ptr4
+                                .add(20 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(level5);
+                            *ptr4
+                                .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(shield_hp5);
+                            *ptr4
+                                .add(28 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                            *ptr4
+                                .add(32 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(luck5);
+                            *ptr4
+                                .add(36 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(speed5);
+                            *ptr4
+                                .add(40 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(mana5);
+                            *ptr4
+                                .add(44 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(max_mana5);
+                            let vec7 = spells_on_cooldown5;
+                            let len7 = vec7.len();
+                            let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec7.len() * 8,
+                                4,
+                            );
+                            let result7 = if layout7.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout7);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec7.into_iter().enumerate() {
+                                let base = result7.add(i * 8);
+                                {
+                                    let (t6_0, t6_1) = e;
+                                    *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                                    *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                                }
+                            }
+                            *ptr4
+                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr4
+                                .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result7;
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec8 = (e.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr4
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len8;
+                            *ptr4
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr8.cast_mut();
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_cast_spell<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0
+                                .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let base3 = l1;
+                            let len3 = l2;
+                            _rt::cabi_dealloc(base3, len3 * 8, 4);
+                        }
+                        _ => {
+                            let l4 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l5 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l4, l5, 1);
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_regen_mana_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                    arg14: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::regen_mana(
+                        super::super::super::super::exports::docs::player::types::PlayerStats {
+                            health: arg0 as u32,
+                            max_health: arg1 as u32,
+                            attack: arg2 as u32,
+                            defense: arg3 as u32,
+                            experience: arg4 as u32,
+                            level: arg5 as u32,
+                            shield_hp: arg6 as u32,
+                            max_shield_hp: arg7 as u32,
+                            luck: arg8 as u32,
+                            speed: arg9 as u32,
+                            mana: arg10 as u32,
+                            max_mana: arg11 as u32,
+                            spells_on_cooldown: result2,
+                        },
+                        arg14 as u32,
+                    );
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_regen_mana<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_tick_cooldowns_cabi<T: Guest>(
+                    arg0: i32,
+                    arg1: i32,
+                    arg2: i32,
+                    arg3: i32,
+                    arg4: i32,
+                    arg5: i32,
+                    arg6: i32,
+                    arg7: i32,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: *mut u8,
+                    arg13: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let base2 = arg12;
+                    let len2 = arg13;
+                    let mut result2 = _rt::Vec::with_capacity(len2);
+                    for i in 0..len2 {
+                        let base = base2.add(i * 8);
+                        let e2 = {
+                            let l0 = *base.add(0).cast::<i32>();
+                            let l1 = *base.add(4).cast::<i32>();
+                            (l0 as u32, l1 as u32)
+                        };
+                        result2.push(e2);
+                    }
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                    let result3 = T::tick_cooldowns(super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: arg0 as u32,
+                        max_health: arg1 as u32,
+                        attack: arg2 as u32,
+                        defense: arg3 as u32,
+                        experience: arg4 as u32,
+                        level: arg5 as u32,
+                        shield_hp: arg6 as u32,
+                        max_shield_hp: arg7 as u32,
+                        luck: arg8 as u32,
+                        speed: arg9 as u32,
+                        mana: arg10 as u32,
+                        max_mana: arg11 as u32,
+                        spells_on_cooldown: result2,
+                    });
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let super::super::super::super::exports::docs::player::types::PlayerStats {
+                        health: health5,
+                        max_health: max_health5,
+                        attack: attack5,
+                        defense: defense5,
+                        experience: experience5,
+                        level: level5,
+                        shield_hp: shield_hp5,
+                        max_shield_hp: max_shield_hp5,
+                        luck: luck5,
+                        speed: speed5,
+                        mana: mana5,
+                        max_mana: max_mana5,
+                        spells_on_cooldown: spells_on_cooldown5,
+                    } = result3;
+                    *ptr4.add(0).cast::<i32>() = _rt::as_i32(health5);
+                    *ptr4.add(4).cast::<i32>() = _rt::as_i32(max_health5);
+                    *ptr4.add(8).cast::<i32>() = _rt::as_i32(attack5);
+                    *ptr4.add(12).cast::<i32>() = _rt::as_i32(defense5);
+                    *ptr4.add(16).cast::<i32>() = _rt::as_i32(experience5);
+                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(level5);
+                    *ptr4.add(24).cast::<i32>() = _rt::as_i32(shield_hp5);
+                    *ptr4.add(28).cast::<i32>() = _rt::as_i32(max_shield_hp5);
+                    *ptr4.add(32).cast::<i32>() = _rt::as_i32(luck5);
+                    *ptr4.add(36).cast::<i32>() = _rt::as_i32(speed5);
+                    *ptr4.add(40).cast::<i32>() = _rt::as_i32(mana5);
+                    *ptr4.add(44).cast::<i32>() = _rt::as_i32(max_mana5);
+                    let vec7 = spells_on_cooldown5;
+                    let len7 = vec7.len();
+                    let layout7 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec7.len() * 8,
+                        4,
+                    );
+                    let result7 = if layout7.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout7).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout7);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec7.into_iter().enumerate() {
+                        let base = result7.add(i * 8);
+                        {
+                            let (t6_0, t6_1) = e;
+                            *base.add(0).cast::<i32>() = _rt::as_i32(t6_0);
+                            *base.add(4).cast::<i32>() = _rt::as_i32(t6_1);
+                        }
+                    }
+                    *ptr4
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>() = len7;
+                    *ptr4.add(48).cast::<*mut u8>() = result7;
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_tick_cooldowns<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(48).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(48 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base2 = l0;
+                    let len2 = l1;
+                    _rt::cabi_dealloc(base2, len2 * 8, 4);
+                }
                 pub trait Guest {
                     /// Create a new player with default starting stats.
                     fn create_player() -> PlayerStats;
@@ -409,12 +1753,58 @@ pub mod exports {
                     fn heal(stats: PlayerStats, amount: u32) -> PlayerStats;
                     /// Add experience points and handle level-up.
                     ///
-                    /// Returns updated stats with new experience and potentially new level.
-                    fn gain_experience(stats: PlayerStats, exp: u32) -> PlayerStats;
+                    /// Returns updated stats with new experience and potentially new level,
+                    /// plus an announcement when a level up happened. Leveling up adds the
+                    /// health bonus to current health without healing any damage taken.
+                    /// Players at `max-level` never level up.
+                    fn gain_experience(
+                        stats: PlayerStats,
+                        exp: u32,
+                        max_level: u32,
+                    ) -> (PlayerStats, Option<LevelUpMessage>);
                     /// Check if the player is defeated (health = 0).
                     fn is_defeated(stats: PlayerStats) -> bool;
+                    /// Get current health as a percentage of max health (0-100).
+                    ///
+                    /// Returns 0 when max is 0.
+                    fn health_percentage(current: u32, max: u32) -> u32;
                     /// Get the experience needed for the next level.
                     fn exp_to_next_level(current_level: u32) -> u32;
+                    /// Apply equipped armor, granting a shield pool for the shield (id 104).
+                    fn equip_armor(stats: PlayerStats, armor_id: u32) -> PlayerStats;
+                    /// Regenerate shield points at the end of a turn.
+                    ///
+                    /// Cannot exceed max-shield-hp.
+                    fn regen_shield(stats: PlayerStats) -> PlayerStats;
+                    /// Apply the equipped ring, granting luck for the Four-Leaf Clover (id 212).
+                    fn equip_ring(stats: PlayerStats, ring_id: u32) -> PlayerStats;
+                    /// Score the player's overall power from stats, gold and weapon attack bonus.
+                    ///
+                    /// The weapon bonus is the inventory's get-total-attack-bonus.
+                    fn calculate_combat_rating(
+                        stats: PlayerStats,
+                        gold: u32,
+                        weapon_bonus: u32,
+                    ) -> u32;
+                    /// Preview the bonuses the next level up will provide.
+                    fn get_level_summary(current_level: u32) -> LevelUpPreview;
+                    /// Get the mana cost of a spell (1 = Fireball, 2 = Ice Arrow, 3 = Heal Self).
+                    ///
+                    /// Returns 0 for unknown spells.
+                    fn spell_mana_cost(spell_id: u32) -> u32;
+                    /// Cast a spell, spending mana and starting its cooldown.
+                    ///
+                    /// Fails when the spell is unknown, on cooldown, or unaffordable, or
+                    /// when `mana-cost` differs from the spell's cost.
+                    fn cast_spell(
+                        stats: PlayerStats,
+                        spell_id: u32,
+                        mana_cost: u32,
+                    ) -> Result<PlayerStats, _rt::String>;
+                    /// Restore mana, capped at max-mana.
+                    fn regen_mana(stats: PlayerStats, amount: u32) -> PlayerStats;
+                    /// Count every spell cooldown down by one turn, dropping finished ones.
+                    fn tick_cooldowns(stats: PlayerStats) -> PlayerStats;
                 }
                 #[doc(hidden)]
                 macro_rules! __export_docs_player_stats_0_1_0_cabi {
@@ -423,39 +1813,161 @@ pub mod exports {
                         "docs:player/stats@0.1.0#create-player")] unsafe extern "C" fn
                         export_create_player() -> * mut u8 { unsafe {
                         $($path_to_types)*:: _export_create_player_cabi::<$ty > () } }
-                        #[unsafe (export_name = "docs:player/stats@0.1.0#take-damage")]
+                        #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#create-player")] unsafe extern
+                        "C" fn _post_return_create_player(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_create_player::<$ty > (arg0) }
+                        } #[unsafe (export_name = "docs:player/stats@0.1.0#take-damage")]
                         unsafe extern "C" fn export_take_damage(arg0 : i32, arg1 : i32,
-                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32,) -> *
-                        mut u8 { unsafe { $($path_to_types)*::
-                        _export_take_damage_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
-                        arg5, arg6) } } #[unsafe (export_name =
-                        "docs:player/stats@0.1.0#heal")] unsafe extern "C" fn
-                        export_heal(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32, arg4
-                        : i32, arg5 : i32, arg6 : i32,) -> * mut u8 { unsafe {
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 :
+                        * mut u8, arg13 : usize, arg14 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_take_damage_cabi::<$ty > (arg0,
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13, arg14) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#take-damage")] unsafe extern
+                        "C" fn _post_return_take_damage(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_take_damage::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:player/stats@0.1.0#heal")] unsafe
+                        extern "C" fn export_heal(arg0 : i32, arg1 : i32, arg2 : i32,
+                        arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8
+                        : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : * mut u8,
+                        arg13 : usize, arg14 : i32,) -> * mut u8 { unsafe {
                         $($path_to_types)*:: _export_heal_cabi::<$ty > (arg0, arg1, arg2,
-                        arg3, arg4, arg5, arg6) } } #[unsafe (export_name =
+                        arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12,
+                        arg13, arg14) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#heal")] unsafe extern "C" fn
+                        _post_return_heal(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_heal::<$ty > (arg0) } }
+                        #[unsafe (export_name =
                         "docs:player/stats@0.1.0#gain-experience")] unsafe extern "C" fn
                         export_gain_experience(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32, arg6 : i32,) -> * mut u8 { unsafe {
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : * mut u8, arg13 :
+                        usize, arg14 : i32, arg15 : i32,) -> * mut u8 { unsafe {
                         $($path_to_types)*:: _export_gain_experience_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5, arg6) } } #[unsafe (export_name =
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13, arg14, arg15) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#gain-experience")] unsafe
+                        extern "C" fn _post_return_gain_experience(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_gain_experience::<$ty
+                        > (arg0) } } #[unsafe (export_name =
                         "docs:player/stats@0.1.0#is-defeated")] unsafe extern "C" fn
                         export_is_defeated(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
-                        i32, arg4 : i32, arg5 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_is_defeated_cabi::<$ty > (arg0,
-                        arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-                        "docs:player/stats@0.1.0#exp-to-next-level")] unsafe extern "C"
-                        fn export_exp_to_next_level(arg0 : i32,) -> i32 { unsafe {
-                        $($path_to_types)*:: _export_exp_to_next_level_cabi::<$ty >
-                        (arg0) } } };
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : * mut u8, arg13 :
+                        usize,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_is_defeated_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4,
+                        arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13) } }
+                        #[unsafe (export_name =
+                        "docs:player/stats@0.1.0#health-percentage")] unsafe extern "C"
+                        fn export_health_percentage(arg0 : i32, arg1 : i32,) -> i32 {
+                        unsafe { $($path_to_types)*::
+                        _export_health_percentage_cabi::<$ty > (arg0, arg1) } } #[unsafe
+                        (export_name = "docs:player/stats@0.1.0#exp-to-next-level")]
+                        unsafe extern "C" fn export_exp_to_next_level(arg0 : i32,) -> i32
+                        { unsafe { $($path_to_types)*::
+                        _export_exp_to_next_level_cabi::<$ty > (arg0) } } #[unsafe
+                        (export_name = "docs:player/stats@0.1.0#equip-armor")] unsafe
+                        extern "C" fn export_equip_armor(arg0 : i32, arg1 : i32, arg2 :
+                        i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32,
+                        arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 : * mut
+                        u8, arg13 : usize, arg14 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_equip_armor_cabi::<$ty > (arg0,
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13, arg14) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#equip-armor")] unsafe extern
+                        "C" fn _post_return_equip_armor(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_equip_armor::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:player/stats@0.1.0#regen-shield")]
+                        unsafe extern "C" fn export_regen_shield(arg0 : i32, arg1 : i32,
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 :
+                        * mut u8, arg13 : usize,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_regen_shield_cabi::<$ty > (arg0,
+                        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10,
+                        arg11, arg12, arg13) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#regen-shield")] unsafe extern
+                        "C" fn _post_return_regen_shield(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_regen_shield::<$ty > (arg0) }
+                        } #[unsafe (export_name = "docs:player/stats@0.1.0#equip-ring")]
+                        unsafe extern "C" fn export_equip_ring(arg0 : i32, arg1 : i32,
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 :
+                        * mut u8, arg13 : usize, arg14 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_equip_ring_cabi::<$ty > (arg0, arg1,
+                        arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11,
+                        arg12, arg13, arg14) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#equip-ring")] unsafe extern
+                        "C" fn _post_return_equip_ring(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_equip_ring::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:player/stats@0.1.0#calculate-combat-rating")] unsafe extern
+                        "C" fn export_calculate_combat_rating(arg0 : i32, arg1 : i32,
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 :
+                        * mut u8, arg13 : usize, arg14 : i32, arg15 : i32,) -> i32 {
+                        unsafe { $($path_to_types)*::
+                        _export_calculate_combat_rating_cabi::<$ty > (arg0, arg1, arg2,
+                        arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12,
+                        arg13, arg14, arg15) } } #[unsafe (export_name =
+                        "docs:player/stats@0.1.0#get-level-summary")] unsafe extern "C"
+                        fn export_get_level_summary(arg0 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_get_level_summary_cabi::<$ty >
+                        (arg0) } } #[unsafe (export_name =
+                        "docs:player/stats@0.1.0#spell-mana-cost")] unsafe extern "C" fn
+                        export_spell_mana_cost(arg0 : i32,) -> i32 { unsafe {
+                        $($path_to_types)*:: _export_spell_mana_cost_cabi::<$ty > (arg0)
+                        } } #[unsafe (export_name =
+                        "docs:player/stats@0.1.0#cast-spell")] unsafe extern "C" fn
+                        export_cast_spell(arg0 : i32, arg1 : i32, arg2 : i32, arg3 : i32,
+                        arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32, arg9
+                        : i32, arg10 : i32, arg11 : i32, arg12 : * mut u8, arg13 : usize,
+                        arg14 : i32, arg15 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_cast_spell_cabi::<$ty > (arg0, arg1,
+                        arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11,
+                        arg12, arg13, arg14, arg15) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#cast-spell")] unsafe extern
+                        "C" fn _post_return_cast_spell(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_cast_spell::<$ty > (arg0) } }
+                        #[unsafe (export_name = "docs:player/stats@0.1.0#regen-mana")]
+                        unsafe extern "C" fn export_regen_mana(arg0 : i32, arg1 : i32,
+                        arg2 : i32, arg3 : i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7
+                        : i32, arg8 : i32, arg9 : i32, arg10 : i32, arg11 : i32, arg12 :
+                        * mut u8, arg13 : usize, arg14 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_regen_mana_cabi::<$ty > (arg0, arg1,
+                        arg2, arg3, arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11,
+                        arg12, arg13, arg14) } } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#regen-mana")] unsafe extern
+                        "C" fn _post_return_regen_mana(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_regen_mana::<$ty > (arg0) } }
+                        #[unsafe (export_name =
+                        "docs:player/stats@0.1.0#tick-cooldowns")] unsafe extern "C" fn
+                        export_tick_cooldowns(arg0 : i32, arg1 : i32, arg2 : i32, arg3 :
+                        i32, arg4 : i32, arg5 : i32, arg6 : i32, arg7 : i32, arg8 : i32,
+                        arg9 : i32, arg10 : i32, arg11 : i32, arg12 : * mut u8, arg13 :
+                        usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_tick_cooldowns_cabi::<$ty > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9, arg10, arg11, arg12, arg13) }
+                        } #[unsafe (export_name =
+                        "cabi_post_docs:player/stats@0.1.0#tick-cooldowns")] unsafe
+                        extern "C" fn _post_return_tick_cooldowns(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*:: __post_return_tick_cooldowns::<$ty
+                        > (arg0) } } };
                     };
                 }
                 #[doc(hidden)]
                 pub(crate) use __export_docs_player_stats_0_1_0_cabi;
-                #[repr(align(4))]
-                struct _RetArea([::core::mem::MaybeUninit<u8>; 24]);
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 64 + 5 * ::core::mem::size_of::<*const u8>()],
+                );
                 static mut _RET_AREA: _RetArea = _RetArea(
-                    [::core::mem::MaybeUninit::uninit(); 24],
+                    [::core::mem::MaybeUninit::uninit(); 64
+                        + 5 * ::core::mem::size_of::<*const u8>()],
                 );
             }
         }
@@ -464,6 +1976,8 @@ pub mod exports {
 #[rustfmt::skip]
 mod _rt {
     #![allow(dead_code, clippy::all)]
+    pub use alloc_crate::vec::Vec;
+    pub use alloc_crate::string::String;
     #[cfg(target_arch = "wasm32")]
     pub fn run_ctors_once() {
         wit_bindgen_rt::run_ctors_once();
@@ -527,6 +2041,15 @@ mod _rt {
             self as i32
         }
     }
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    extern crate alloc as alloc_crate;
+    pub use alloc_crate::alloc;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -570,23 +2093,39 @@ pub(crate) use __export_player_impl as export;
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 780] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x8f\x05\x01A\x02\x01\
-A\x09\x01B\x06\x01m\x04\x05north\x05south\x04west\x04east\x04\0\x09direction\x03\
-\0\0\x01r\x02\x01xz\x01yz\x04\0\x08position\x03\0\x02\x01r\x06\x06healthy\x0amax\
--healthy\x06attacky\x07defensey\x0aexperiencey\x05levely\x04\0\x0cplayer-stats\x03\
-\0\x04\x04\0\x17docs:player/types@0.1.0\x05\0\x02\x03\0\0\x09direction\x02\x03\0\
-\0\x08position\x01B\x08\x02\x03\x02\x01\x01\x04\0\x09direction\x03\0\0\x02\x03\x02\
-\x01\x02\x04\0\x08position\x03\0\x02\x01@\x02\x0bcurrent-pos\x03\x03dir\x01\0\x03\
-\x04\0\x0bmove-player\x01\x04\x01@\x02\x05start\x03\x03end\x03\0y\x04\0\x12calcu\
-late-distance\x01\x05\x04\0\x1adocs:player/movement@0.1.0\x05\x03\x02\x03\0\0\x0c\
-player-stats\x01B\x0e\x02\x03\x02\x01\x04\x04\0\x0cplayer-stats\x03\0\0\x01@\0\0\
-\x01\x04\0\x0dcreate-player\x01\x02\x01@\x02\x05stats\x01\x0araw-damagey\0\x01\x04\
-\0\x0btake-damage\x01\x03\x01@\x02\x05stats\x01\x06amounty\0\x01\x04\0\x04heal\x01\
-\x04\x01@\x02\x05stats\x01\x03expy\0\x01\x04\0\x0fgain-experience\x01\x05\x01@\x01\
-\x05stats\x01\0\x7f\x04\0\x0bis-defeated\x01\x06\x01@\x01\x0dcurrent-levely\0y\x04\
-\0\x11exp-to-next-level\x01\x07\x04\0\x17docs:player/stats@0.1.0\x05\x05\x04\0\x18\
-docs:player/player@0.1.0\x04\0\x0b\x0c\x01\0\x06player\x03\0\0\0G\x09producers\x01\
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1573] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xa8\x0b\x01A\x02\x01\
+A\x0b\x01B\x0c\x01m\x08\x05north\x05south\x04west\x04east\x0anorth-east\x0anorth\
+-west\x0asouth-east\x0asouth-west\x04\0\x09direction\x03\0\0\x01r\x02\x01xz\x01y\
+z\x04\0\x08position\x03\0\x02\x01o\x02yy\x01p\x04\x01r\x0d\x06healthy\x0amax-hea\
+lthy\x06attacky\x07defensey\x0aexperiencey\x05levely\x09shield-hpy\x0dmax-shield\
+-hpy\x04lucky\x05speedy\x04manay\x08max-manay\x12spells-on-cooldown\x05\x04\0\x0c\
+player-stats\x03\0\x06\x01r\x05\x09new-levely\x0dhealth-gainedy\x0dattack-gained\
+y\x0edefense-gainedy\x07messages\x04\0\x10level-up-message\x03\0\x08\x01r\x05\x0a\
+next-levely\x0bhealth-gainy\x0battack-gainy\x0cdefense-gainy\x0cexp-requiredy\x04\
+\0\x10level-up-preview\x03\0\x0a\x04\0\x17docs:player/types@0.1.0\x05\0\x02\x03\0\
+\0\x09direction\x02\x03\0\0\x08position\x01B\x08\x02\x03\x02\x01\x01\x04\0\x09di\
+rection\x03\0\0\x02\x03\x02\x01\x02\x04\0\x08position\x03\0\x02\x01@\x02\x0bcurr\
+ent-pos\x03\x03dir\x01\0\x03\x04\0\x0bmove-player\x01\x04\x01@\x02\x05start\x03\x03\
+end\x03\0y\x04\0\x12calculate-distance\x01\x05\x04\0\x1adocs:player/movement@0.1\
+.0\x05\x03\x02\x03\0\0\x0cplayer-stats\x02\x03\0\0\x10level-up-preview\x02\x03\0\
+\0\x10level-up-message\x01B'\x02\x03\x02\x01\x04\x04\0\x0cplayer-stats\x03\0\0\x02\
+\x03\x02\x01\x05\x04\0\x10level-up-preview\x03\0\x02\x02\x03\x02\x01\x06\x04\0\x10\
+level-up-message\x03\0\x04\x01@\0\0\x01\x04\0\x0dcreate-player\x01\x06\x01@\x02\x05\
+stats\x01\x0araw-damagey\0\x01\x04\0\x0btake-damage\x01\x07\x01@\x02\x05stats\x01\
+\x06amounty\0\x01\x04\0\x04heal\x01\x08\x01k\x05\x01o\x02\x01\x09\x01@\x03\x05st\
+ats\x01\x03expy\x09max-levely\0\x0a\x04\0\x0fgain-experience\x01\x0b\x01@\x01\x05\
+stats\x01\0\x7f\x04\0\x0bis-defeated\x01\x0c\x01@\x02\x07currenty\x03maxy\0y\x04\
+\0\x11health-percentage\x01\x0d\x01@\x01\x0dcurrent-levely\0y\x04\0\x11exp-to-ne\
+xt-level\x01\x0e\x01@\x02\x05stats\x01\x08armor-idy\0\x01\x04\0\x0bequip-armor\x01\
+\x0f\x01@\x01\x05stats\x01\0\x01\x04\0\x0cregen-shield\x01\x10\x01@\x02\x05stats\
+\x01\x07ring-idy\0\x01\x04\0\x0aequip-ring\x01\x11\x01@\x03\x05stats\x01\x04gold\
+y\x0cweapon-bonusy\0y\x04\0\x17calculate-combat-rating\x01\x12\x01@\x01\x0dcurre\
+nt-levely\0\x03\x04\0\x11get-level-summary\x01\x13\x01@\x01\x08spell-idy\0y\x04\0\
+\x0fspell-mana-cost\x01\x14\x01j\x01\x01\x01s\x01@\x03\x05stats\x01\x08spell-idy\
+\x09mana-costy\0\x15\x04\0\x0acast-spell\x01\x16\x04\0\x0aregen-mana\x01\x08\x04\
+\0\x0etick-cooldowns\x01\x10\x04\0\x17docs:player/stats@0.1.0\x05\x07\x04\0\x18d\
+ocs:player/player@0.1.0\x04\0\x0b\x0c\x01\0\x06player\x03\0\0\0G\x09producers\x01\
 \x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
@@ -30,7 +30,9 @@
 mod bindings;
 
 use bindings::exports::docs::player::movement::{Direction, Guest as MovementGuest, Position};
-use bindings::exports::docs::player::stats::{Guest as StatsGuest, PlayerStats};
+use bindings::exports::docs::player::stats::{
+    Guest as StatsGuest, LevelUpMessage, LevelUpPreview, PlayerStats,
+};
 
 /// Default starting health for new players.
 const STARTING_HEALTH: u32 = 100;
@@ -41,6 +43,22 @@ const STARTING_ATTACK: u32 = 10;
 /// Default starting defense for new players.
 const STARTING_DEFENSE: u32 = 5;
 
+/// Default starting luck for new players.
+const STARTING_LUCK: u32 = 5;
+
+/// Default starting speed for new players.
+const STARTING_SPEED: u32 = 10;
+
+/// Default starting mana for new players.
+const STARTING_MANA: u32 = 50;
+
+/// Known spells as (spell ID, name, mana cost, cooldown turns).
+const SPELLS: [(u32, &str, u32, u32); 3] = [
+    (1, "Fireball", 15, 2),
+    (2, "Ice Arrow", 10, 1),
+    (3, "Heal Self", 20, 3),
+];
+
 /// Base experience required for level 2.
 const BASE_EXP_REQUIREMENT: u32 = 100;
 
@@ -56,6 +74,21 @@ const ATTACK_PER_LEVEL: u32 = 3;
 /// Defense bonus per level up.
 const DEFENSE_PER_LEVEL: u32 = 2;
 
+/// Inventory item ID of the shield armor.
+const SHIELD_ARMOR_ID: u32 = 104;
+
+/// Shield points granted by equipping the shield.
+const SHIELD_HP: u32 = 30;
+
+/// Shield points regenerated per turn.
+const SHIELD_REGEN_PER_TURN: u32 = 5;
+
+/// Inventory item ID of the Four-Leaf Clover ring.
+const FOUR_LEAF_CLOVER_ID: u32 = 212;
+
+/// Luck granted by wearing the Four-Leaf Clover.
+const CLOVER_LUCK: u32 = 15;
+
 /// Component structure for player functionality.
 struct Component;
 
@@ -72,8 +105,8 @@ bindings::export!(Component with_types_in bindings);
 /// * `i32` - The X coordinate change (-1, 0, or 1)
 fn delta_x(dir: &Direction) -> i32 {
     match dir {
-        Direction::West => -1,
-        Direction::East => 1,
+        Direction::West | Direction::NorthWest | Direction::SouthWest => -1,
+        Direction::East | Direction::NorthEast | Direction::SouthEast => 1,
         _ => 0,
     }
 }
@@ -89,12 +122,25 @@ fn delta_x(dir: &Direction) -> i32 {
 /// * `i32` - The Y coordinate change (-1, 0, or 1)
 fn delta_y(dir: &Direction) -> i32 {
     match dir {
-        Direction::North => -1,
-        Direction::South => 1,
+        Direction::North | Direction::NorthEast | Direction::NorthWest => -1,
+        Direction::South | Direction::SouthEast | Direction::SouthWest => 1,
         _ => 0,
     }
 }
 
+/// Check if a direction moves along both axes.
+///
+/// # Arguments
+///
+/// * `dir` - The direction to check
+///
+/// # Returns
+///
+/// * `bool` - True for the four diagonal directions
+fn is_diagonal(dir: &Direction) -> bool {
+    delta_x(dir) != 0 && delta_y(dir) != 0
+}
+
 /// Calculate new X position after movement.
 ///
 /// # Arguments
@@ -164,6 +210,13 @@ fn create_default_stats() -> PlayerStats {
         defense: STARTING_DEFENSE,
         experience: 0,
         level: 1,
+        shield_hp: 0,
+        max_shield_hp: 0,
+        luck: STARTING_LUCK,
+        speed: STARTING_SPEED,
+        mana: STARTING_MANA,
+        max_mana: STARTING_MANA,
+        spells_on_cooldown: Vec::new(),
     }
 }
 
@@ -196,6 +249,89 @@ fn apply_damage_to_health(current_health: u32, damage: u32) -> u32 {
     current_health.saturating_sub(damage)
 }
 
+/// Split incoming damage between the shield and health.
+///
+/// # Arguments
+///
+/// * `shield_hp` - Current shield points
+/// * `raw_damage` - Incoming damage
+///
+/// # Returns
+///
+/// * `(u32, u32)` - Remaining shield points and damage left for health
+fn absorb_with_shield(shield_hp: u32, raw_damage: u32) -> (u32, u32) {
+    let absorbed = shield_hp.min(raw_damage);
+    (shield_hp - absorbed, raw_damage - absorbed)
+}
+
+/// Calculate shield points for an armor piece.
+///
+/// # Arguments
+///
+/// * `armor_id` - Equipped armor item ID
+///
+/// # Returns
+///
+/// * `u32` - Maximum shield points granted
+fn armor_shield_hp(armor_id: u32) -> u32 {
+    if armor_id == SHIELD_ARMOR_ID {
+        SHIELD_HP
+    } else {
+        0
+    }
+}
+
+/// Calculate a single number scoring the player's power.
+///
+/// # Arguments
+///
+/// * `stats` - Current player stats
+/// * `gold` - Gold carried
+/// * `weapon_bonus` - Weapon attack bonus reported by the inventory
+///
+/// # Returns
+///
+/// * `u32` - Combat rating
+fn calculate_combat_rating(stats: &PlayerStats, gold: u32, weapon_bonus: u32) -> u32 {
+    stats.level * 50
+        + stats.attack * 3
+        + stats.defense * 2
+        + stats.max_health / 5
+        + gold / 10
+        + weapon_bonus * 4
+}
+
+/// Calculate the luck granted by a ring.
+///
+/// # Arguments
+///
+/// * `ring_id` - Equipped ring item ID
+///
+/// # Returns
+///
+/// * `u32` - Luck bonus granted
+fn ring_luck_bonus(ring_id: u32) -> u32 {
+    if ring_id == FOUR_LEAF_CLOVER_ID {
+        CLOVER_LUCK
+    } else {
+        0
+    }
+}
+
+/// Calculate regenerated shield value.
+///
+/// # Arguments
+///
+/// * `current` - Current shield points
+/// * `max` - Maximum shield points
+///
+/// # Returns
+///
+/// * `u32` - Shield points after regeneration
+fn calculate_regen_shield(current: u32, max: u32) -> u32 {
+    (current + SHIELD_REGEN_PER_TURN).min(max)
+}
+
 /// Calculate healed health value.
 ///
 /// # Arguments
@@ -211,19 +347,56 @@ fn calculate_healed_health(current: u32, amount: u32, max: u32) -> u32 {
     (current + amount).min(max)
 }
 
+/// Calculate health as a percentage of the maximum.
+///
+/// Returns 0 for a zero maximum and never exceeds 100.
+///
+/// # Arguments
+///
+/// * `current` - Current health
+/// * `max` - Maximum health
+///
+/// # Returns
+///
+/// * `u32` - Health percentage from 0 to 100
+fn health_percentage(current: u32, max: u32) -> u32 {
+    if max == 0 {
+        return 0;
+    }
+    (u64::from(current) * 100 / u64::from(max)).min(100) as u32
+}
+
 /// Check if player should level up.
 ///
 /// # Arguments
 ///
 /// * `experience` - Current experience points
 /// * `level` - Current level
+/// * `max_level` - Level cap
 ///
 /// # Returns
 ///
 /// * `bool` - True if should level up
-fn should_level_up(experience: u32, level: u32) -> bool {
+fn should_level_up(experience: u32, level: u32, max_level: u32) -> bool {
     let required = calculate_exp_requirement(level);
-    experience >= required
+    experience >= required && !at_max_level(experience, level, max_level)
+}
+
+/// Check if player has reached the level cap.
+///
+/// Experience keeps accumulating at the cap but no longer counts.
+///
+/// # Arguments
+///
+/// * `_experience` - Current experience points
+/// * `level` - Current level
+/// * `max_level` - Level cap
+///
+/// # Returns
+///
+/// * `bool` - True if no further level ups are allowed
+fn at_max_level(_experience: u32, level: u32, max_level: u32) -> bool {
+    level >= max_level
 }
 
 /// Calculate experience requirement for next level.
@@ -279,24 +452,159 @@ fn level_up_defense(current: u32) -> u32 {
     current + DEFENSE_PER_LEVEL
 }
 
-/// Apply level up bonuses to stats.
+/// Build a preview of the bonuses from the next level up.
+///
+/// # Arguments
+///
+/// * `current_level` - Current level
+///
+/// # Returns
+///
+/// * `LevelUpPreview` - Gains and experience needed for the next level
+fn get_level_summary(current_level: u32) -> LevelUpPreview {
+    LevelUpPreview {
+        next_level: current_level + 1,
+        health_gain: HEALTH_PER_LEVEL,
+        attack_gain: ATTACK_PER_LEVEL,
+        defense_gain: DEFENSE_PER_LEVEL,
+        exp_required: calculate_exp_requirement(current_level),
+    }
+}
+
+/// Look up a spell's mana cost and cooldown.
+///
+/// # Arguments
+///
+/// * `spell_id` - Spell ID
+///
+/// # Returns
+///
+/// * `Option<(u32, u32)>` - Mana cost and cooldown turns, or None if unknown
+fn spell_info(spell_id: u32) -> Option<(u32, u32)> {
+    SPELLS
+        .iter()
+        .find(|&&(id, _, _, _)| id == spell_id)
+        .map(|&(_, _, cost, cooldown)| (cost, cooldown))
+}
+
+/// Cast a spell, spending mana and starting its cooldown.
+///
+/// The caller's mana cost must match the spell's cost in `SPELLS`.
 ///
 /// # Arguments
 ///
 /// * `stats` - Current player stats
+/// * `spell_id` - Spell ID
+/// * `mana_cost` - Mana spent on the cast
 ///
 /// # Returns
 ///
-/// * `PlayerStats` - Stats after level up
-fn apply_level_up(stats: PlayerStats) -> PlayerStats {
+/// * `Result<PlayerStats, &'static str>` - Stats after casting, or why the cast failed
+fn cast_spell(
+    mut stats: PlayerStats,
+    spell_id: u32,
+    mana_cost: u32,
+) -> Result<PlayerStats, &'static str> {
+    let Some((cost, cooldown)) = spell_info(spell_id) else {
+        return Err("Unknown spell");
+    };
+    if mana_cost != cost {
+        return Err("Wrong mana cost");
+    }
+    if stats
+        .spells_on_cooldown
+        .iter()
+        .any(|&(id, _)| id == spell_id)
+    {
+        return Err("Spell on cooldown");
+    }
+    if stats.mana < mana_cost {
+        return Err("Not enough mana");
+    }
+    stats.mana -= mana_cost;
+    stats.spells_on_cooldown.push((spell_id, cooldown));
+    Ok(stats)
+}
+
+/// Restore mana without exceeding the maximum.
+///
+/// # Arguments
+///
+/// * `stats` - Current player stats
+/// * `amount` - Mana to restore
+///
+/// # Returns
+///
+/// * `PlayerStats` - Stats with restored mana
+fn regen_mana(stats: PlayerStats, amount: u32) -> PlayerStats {
     PlayerStats {
-        health: level_up_max_health(stats.max_health),
-        max_health: level_up_max_health(stats.max_health),
+        mana: stats.mana.saturating_add(amount).min(stats.max_mana),
+        ..stats
+    }
+}
+
+/// Count spell cooldowns down by one turn, dropping finished ones.
+///
+/// # Arguments
+///
+/// * `stats` - Current player stats
+///
+/// # Returns
+///
+/// * `PlayerStats` - Stats with updated cooldowns
+fn tick_cooldowns(mut stats: PlayerStats) -> PlayerStats {
+    stats.spells_on_cooldown = stats
+        .spells_on_cooldown
+        .into_iter()
+        .filter(|&(_, turns)| turns > 1)
+        .map(|(id, turns)| (id, turns - 1))
+        .collect();
+    stats
+}
+
+/// Apply level up bonuses to stats, raising health by the same amount as max health.
+///
+/// # Arguments
+///
+/// * `stats` - Current player stats
+/// * `max_level` - Level cap
+///
+/// # Returns
+///
+/// * `(PlayerStats, Option<LevelUpMessage>)` - Stats after level up and its
+///   announcement, or the unchanged stats at the cap
+fn apply_level_up(stats: PlayerStats, max_level: u32) -> (PlayerStats, Option<LevelUpMessage>) {
+    if stats.level + 1 > max_level {
+        return (stats, None);
+    }
+    let new_level = stats.level + 1;
+    let message = LevelUpMessage {
+        new_level,
+        health_gained: HEALTH_PER_LEVEL,
+        attack_gained: ATTACK_PER_LEVEL,
+        defense_gained: DEFENSE_PER_LEVEL,
+        message: format!(
+            "LEVEL UP! You are now level {}! HP +{}, ATK +{}, DEF +{}!",
+            new_level, HEALTH_PER_LEVEL, ATTACK_PER_LEVEL, DEFENSE_PER_LEVEL
+        ),
+    };
+    let max_health = level_up_max_health(stats.max_health);
+    let leveled = PlayerStats {
+        health: (stats.health + HEALTH_PER_LEVEL).min(max_health),
+        max_health,
         attack: level_up_attack(stats.attack),
         defense: level_up_defense(stats.defense),
         experience: stats.experience,
-        level: stats.level + 1,
-    }
+        level: new_level,
+        shield_hp: stats.shield_hp,
+        max_shield_hp: stats.max_shield_hp,
+        luck: stats.luck,
+        speed: stats.speed,
+        mana: stats.mana,
+        max_mana: stats.max_mana,
+        spells_on_cooldown: stats.spells_on_cooldown,
+    };
+    (leveled, Some(message))
 }
 
 /// Process experience gain with level up check.
@@ -305,14 +613,19 @@ fn apply_level_up(stats: PlayerStats) -> PlayerStats {
 ///
 /// * `stats` - Current player stats
 /// * `exp` - Experience gained
+/// * `max_level` - Level cap
 ///
 /// # Returns
 ///
-/// * `PlayerStats` - Updated stats
-fn process_experience_gain(stats: PlayerStats, exp: u32) -> PlayerStats {
+/// * `(PlayerStats, Option<LevelUpMessage>)` - Updated stats and any level up announcement
+fn process_experience_gain(
+    stats: PlayerStats,
+    exp: u32,
+    max_level: u32,
+) -> (PlayerStats, Option<LevelUpMessage>) {
     let new_exp = stats.experience + exp;
     let updated = create_stats_with_exp(stats, new_exp);
-    check_and_apply_level_up(updated)
+    check_and_apply_level_up(updated, max_level)
 }
 
 /// Create stats with updated experience.
@@ -333,6 +646,13 @@ fn create_stats_with_exp(stats: PlayerStats, new_exp: u32) -> PlayerStats {
         defense: stats.defense,
         experience: new_exp,
         level: stats.level,
+        shield_hp: stats.shield_hp,
+        max_shield_hp: stats.max_shield_hp,
+        luck: stats.luck,
+        speed: stats.speed,
+        mana: stats.mana,
+        max_mana: stats.max_mana,
+        spells_on_cooldown: stats.spells_on_cooldown,
     }
 }
 
@@ -341,15 +661,19 @@ fn create_stats_with_exp(stats: PlayerStats, new_exp: u32) -> PlayerStats {
 /// # Arguments
 ///
 /// * `stats` - Stats to check
+/// * `max_level` - Level cap
 ///
 /// # Returns
 ///
-/// * `PlayerStats` - Potentially leveled up stats
-fn check_and_apply_level_up(stats: PlayerStats) -> PlayerStats {
-    if should_level_up(stats.experience, stats.level) {
-        apply_level_up(stats)
+/// * `(PlayerStats, Option<LevelUpMessage>)` - Potentially leveled up stats and announcement
+fn check_and_apply_level_up(
+    stats: PlayerStats,
+    max_level: u32,
+) -> (PlayerStats, Option<LevelUpMessage>) {
+    if should_level_up(stats.experience, stats.level, max_level) {
+        apply_level_up(stats, max_level)
     } else {
-        stats
+        (stats, None)
     }
 }
 
@@ -373,7 +697,10 @@ impl MovementGuest for Component {
         }
     }
 
-    /// Calculate Manhattan distance between two positions.
+    /// Calculate distance between two positions.
+    ///
+    /// The player can always move diagonally, so a diagonal step counts
+    /// as one.
     ///
     /// # Arguments
     ///
@@ -382,11 +709,11 @@ impl MovementGuest for Component {
     ///
     /// # Returns
     ///
-    /// * `u32` - Manhattan distance
+    /// * `u32` - Chebyshev distance
     fn calculate_distance(start: Position, end: Position) -> u32 {
         let h_dist = horizontal_distance(start.x, end.x);
         let v_dist = vertical_distance(start.y, end.y);
-        h_dist + v_dist
+        h_dist.max(v_dist)
     }
 }
 
@@ -411,10 +738,15 @@ impl StatsGuest for Component {
     ///
     /// * `PlayerStats` - Updated stats after damage
     fn take_damage(stats: PlayerStats, raw_damage: u32) -> PlayerStats {
-        let effective = calculate_effective_damage(raw_damage, stats.defense);
+        let (shield_hp, remaining) = absorb_with_shield(stats.shield_hp, raw_damage);
+        if remaining == 0 {
+            return PlayerStats { shield_hp, ..stats };
+        }
+        let effective = calculate_effective_damage(remaining, stats.defense);
         let new_health = apply_damage_to_health(stats.health, effective);
         PlayerStats {
             health: new_health,
+            shield_hp,
             ..stats
         }
     }
@@ -443,12 +775,17 @@ impl StatsGuest for Component {
     ///
     /// * `stats` - Current player stats
     /// * `exp` - Experience to add
+    /// * `max_level` - Level cap, the game state's `max_player_level`
     ///
     /// # Returns
     ///
-    /// * `PlayerStats` - Updated stats with experience
-    fn gain_experience(stats: PlayerStats, exp: u32) -> PlayerStats {
-        process_experience_gain(stats, exp)
+    /// * `(PlayerStats, Option<LevelUpMessage>)` - Updated stats and any level up announcement
+    fn gain_experience(
+        stats: PlayerStats,
+        exp: u32,
+        max_level: u32,
+    ) -> (PlayerStats, Option<LevelUpMessage>) {
+        process_experience_gain(stats, exp, max_level)
     }
 
     /// Check if the player is defeated.
@@ -464,6 +801,20 @@ impl StatsGuest for Component {
         stats.health == 0
     }
 
+    /// Get health as a percentage of max health.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Current health
+    /// * `max` - Maximum health
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Health percentage from 0 to 100
+    fn health_percentage(current: u32, max: u32) -> u32 {
+        health_percentage(current, max)
+    }
+
     /// Get experience required for the next level.
     ///
     /// # Arguments
@@ -476,6 +827,151 @@ impl StatsGuest for Component {
     fn exp_to_next_level(current_level: u32) -> u32 {
         calculate_exp_requirement(current_level)
     }
+
+    /// Apply equipped armor to the player's shield pool.
+    ///
+    /// New shield armor starts with a full shield; re-equipping the same
+    /// shield keeps the shield points it has left.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    /// * `armor_id` - Equipped armor item ID
+    ///
+    /// # Returns
+    ///
+    /// * `PlayerStats` - Updated stats with shield points
+    fn equip_armor(stats: PlayerStats, armor_id: u32) -> PlayerStats {
+        let max_shield_hp = armor_shield_hp(armor_id);
+        let shield_hp = if max_shield_hp == stats.max_shield_hp {
+            stats.shield_hp.min(max_shield_hp)
+        } else {
+            max_shield_hp
+        };
+        PlayerStats {
+            shield_hp,
+            max_shield_hp,
+            ..stats
+        }
+    }
+
+    /// Regenerate shield points at the end of a turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    ///
+    /// # Returns
+    ///
+    /// * `PlayerStats` - Updated stats after regeneration
+    fn regen_shield(stats: PlayerStats) -> PlayerStats {
+        let shield_hp = calculate_regen_shield(stats.shield_hp, stats.max_shield_hp);
+        PlayerStats { shield_hp, ..stats }
+    }
+
+    /// Apply the equipped ring to the player's luck.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    /// * `ring_id` - Equipped ring item ID (0 = none)
+    ///
+    /// # Returns
+    ///
+    /// * `PlayerStats` - Updated stats with the ring's luck
+    fn equip_ring(stats: PlayerStats, ring_id: u32) -> PlayerStats {
+        PlayerStats {
+            luck: STARTING_LUCK + ring_luck_bonus(ring_id),
+            ..stats
+        }
+    }
+
+    /// Score the player's overall power.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    /// * `gold` - Gold carried
+    /// * `weapon_bonus` - Weapon attack bonus reported by the inventory
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Combat rating
+    fn calculate_combat_rating(stats: PlayerStats, gold: u32, weapon_bonus: u32) -> u32 {
+        calculate_combat_rating(&stats, gold, weapon_bonus)
+    }
+
+    /// Preview the bonuses the next level up will provide.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_level` - The player's current level
+    ///
+    /// # Returns
+    ///
+    /// * `LevelUpPreview` - Gains and experience needed for the next level
+    fn get_level_summary(current_level: u32) -> LevelUpPreview {
+        get_level_summary(current_level)
+    }
+
+    /// Get the mana cost of a spell.
+    ///
+    /// # Arguments
+    ///
+    /// * `spell_id` - Spell ID
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - Mana cost, or 0 for unknown spells
+    fn spell_mana_cost(spell_id: u32) -> u32 {
+        spell_info(spell_id).map_or(0, |(cost, _)| cost)
+    }
+
+    /// Cast a spell.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    /// * `spell_id` - Spell ID
+    /// * `mana_cost` - Mana spent on the cast
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PlayerStats, String>` - Stats after casting, or why the cast failed
+    fn cast_spell(
+        stats: PlayerStats,
+        spell_id: u32,
+        mana_cost: u32,
+    ) -> Result<PlayerStats, String> {
+        cast_spell(stats, spell_id, mana_cost).map_err(str::to_string)
+    }
+
+    /// Restore mana.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    /// * `amount` - Mana to restore
+    ///
+    /// # Returns
+    ///
+    /// * `PlayerStats` - Stats with restored mana
+    fn regen_mana(stats: PlayerStats, amount: u32) -> PlayerStats {
+        regen_mana(stats, amount)
+    }
+
+    /// Count spell cooldowns down by one turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - Current player stats
+    ///
+    /// # Returns
+    ///
+    /// * `PlayerStats` - Stats with updated cooldowns
+    fn tick_cooldowns(stats: PlayerStats) -> PlayerStats {
+        tick_cooldowns(stats)
+    }
 }
 
 #[cfg(test)]
@@ -605,14 +1101,14 @@ mod tests {
     #[test]
     /// Test level up check returns true.
     fn test_should_level_up_true() {
-        let result = should_level_up(150, 1);
+        let result = should_level_up(150, 1, 20);
         assert!(result);
     }
 
     #[test]
     /// Test level up check returns false.
     fn test_should_level_up_false() {
-        let result = should_level_up(50, 1);
+        let result = should_level_up(50, 1, 20);
         assert!(!result);
     }
 
@@ -654,12 +1150,38 @@ mod tests {
     }
 
     #[test]
-    /// Test Manhattan distance calculation.
+    /// Test Chebyshev distance calculation.
     fn test_calculate_distance() {
         let start = Position { x: 0, y: 0 };
         let end = Position { x: 3, y: 4 };
         let result = <Component as MovementGuest>::calculate_distance(start, end);
-        assert_eq!(result, 7);
+        assert_eq!(result, 4);
+        let end = Position { x: 3, y: 3 };
+        let result = <Component as MovementGuest>::calculate_distance(start, end);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    /// Test diagonal deltas move along both axes.
+    fn test_diagonal_deltas() {
+        assert_eq!(delta_x(&Direction::NorthEast), 1);
+        assert_eq!(delta_y(&Direction::NorthEast), -1);
+        assert_eq!(delta_x(&Direction::SouthWest), -1);
+        assert_eq!(delta_y(&Direction::SouthWest), 1);
+        assert!(is_diagonal(&Direction::NorthWest));
+        assert!(is_diagonal(&Direction::SouthEast));
+        assert!(!is_diagonal(&Direction::North));
+        assert!(!is_diagonal(&Direction::West));
+    }
+
+    #[test]
+    /// Test repeated northeast moves are not clamped.
+    fn test_move_player_northeast_sequence() {
+        let mut pos = Position { x: 0, y: 0 };
+        for _ in 0..5 {
+            pos = <Component as MovementGuest>::move_player(pos, Direction::NorthEast);
+        }
+        assert_eq!((pos.x, pos.y), (5, -5));
     }
 
     #[test]
@@ -708,4 +1230,241 @@ mod tests {
         let result = <Component as StatsGuest>::exp_to_next_level(1);
         assert_eq!(result, BASE_EXP_REQUIREMENT);
     }
+
+    #[test]
+    /// Test equipping the shield grants shield points.
+    fn test_equip_armor_shield() {
+        let stats = create_default_stats();
+        let result = <Component as StatsGuest>::equip_armor(stats, SHIELD_ARMOR_ID);
+        assert_eq!(result.shield_hp, SHIELD_HP);
+        assert_eq!(result.max_shield_hp, SHIELD_HP);
+    }
+
+    #[test]
+    /// Test re-equipping the shield keeps its remaining shield points.
+    fn test_equip_armor_shield_again() {
+        let stats = <Component as StatsGuest>::equip_armor(create_default_stats(), SHIELD_ARMOR_ID);
+        let damaged = <Component as StatsGuest>::take_damage(stats, 20);
+        let result = <Component as StatsGuest>::equip_armor(damaged, SHIELD_ARMOR_ID);
+        assert_eq!(result.shield_hp, 10);
+        assert_eq!(result.max_shield_hp, SHIELD_HP);
+    }
+
+    #[test]
+    /// Test equipping other armor grants no shield.
+    fn test_equip_armor_no_shield() {
+        let stats = create_default_stats();
+        let result = <Component as StatsGuest>::equip_armor(stats, 103);
+        assert_eq!(result.shield_hp, 0);
+    }
+
+    #[test]
+    /// Test shield fully absorbs damage.
+    fn test_take_damage_shield_absorbs() {
+        let stats = <Component as StatsGuest>::equip_armor(create_default_stats(), 104);
+        let result = <Component as StatsGuest>::take_damage(stats, 20);
+        assert_eq!(result.shield_hp, 10);
+        assert_eq!(result.health, 100);
+    }
+
+    #[test]
+    /// Test damage overflows from shield into health.
+    fn test_take_damage_shield_overflow() {
+        let mut stats = <Component as StatsGuest>::equip_armor(create_default_stats(), 104);
+        stats.shield_hp = 5;
+        let result = <Component as StatsGuest>::take_damage(stats, 20);
+        assert_eq!(result.shield_hp, 0);
+        assert!(result.health < 100);
+    }
+
+    #[test]
+    /// Test shield regeneration is capped at max.
+    fn test_regen_shield() {
+        let mut stats = <Component as StatsGuest>::equip_armor(create_default_stats(), 104);
+        stats.shield_hp = 10;
+        let result = <Component as StatsGuest>::regen_shield(stats);
+        assert_eq!(result.shield_hp, 15);
+        let mut stats = result;
+        stats.shield_hp = 28;
+        let result = <Component as StatsGuest>::regen_shield(stats);
+        assert_eq!(result.shield_hp, SHIELD_HP);
+    }
+
+    #[test]
+    /// Test that new players start with default luck.
+    fn test_create_default_stats_luck() {
+        assert_eq!(create_default_stats().luck, STARTING_LUCK);
+    }
+
+    #[test]
+    /// Test that the Four-Leaf Clover adds luck and removing it takes it away.
+    fn test_equip_ring_four_leaf_clover() {
+        let stats = <Component as StatsGuest>::equip_ring(create_default_stats(), 212);
+        assert_eq!(stats.luck, 20);
+        let stats = <Component as StatsGuest>::equip_ring(stats, 0);
+        assert_eq!(stats.luck, STARTING_LUCK);
+    }
+
+    #[test]
+    /// Test that new players start with default speed.
+    fn test_create_default_stats_speed() {
+        assert_eq!(create_default_stats().speed, STARTING_SPEED);
+    }
+
+    #[test]
+    /// Test combat rating of a level 1 player with a Wooden Sword.
+    fn test_calculate_combat_rating_wooden_sword() {
+        let stats = create_default_stats();
+        let rating = <Component as StatsGuest>::calculate_combat_rating(stats, 100, 5);
+        assert_eq!(rating, 50 + 10 * 3 + 5 * 2 + 100 / 5 + 100 / 10 + 5 * 4);
+    }
+
+    #[test]
+    /// Test a fresh player's combat rating stays in the expected range.
+    fn test_calculate_combat_rating_fresh_player() {
+        let rating = calculate_combat_rating(&create_default_stats(), 0, 0);
+        assert!((80..=120).contains(&rating));
+    }
+
+    #[test]
+    /// Test the level 1 preview matches the base experience requirement.
+    fn test_get_level_summary_level_one() {
+        let preview = <Component as StatsGuest>::get_level_summary(1);
+        assert_eq!(preview.next_level, 2);
+        assert_eq!(preview.exp_required, BASE_EXP_REQUIREMENT);
+        assert_eq!(preview.health_gain, HEALTH_PER_LEVEL);
+        assert_eq!(preview.attack_gain, ATTACK_PER_LEVEL);
+        assert_eq!(preview.defense_gain, DEFENSE_PER_LEVEL);
+    }
+
+    #[test]
+    /// Test the level 3 preview uses the scaled experience requirement.
+    fn test_get_level_summary_level_three() {
+        let preview = get_level_summary(3);
+        assert_eq!((preview.next_level, preview.exp_required), (4, 225));
+    }
+
+    #[test]
+    /// Test leveling up raises health without healing damage taken.
+    fn test_gain_experience_level_up_keeps_damage() {
+        let stats = PlayerStats {
+            health: 10,
+            ..create_default_stats()
+        };
+        let (stats, message) =
+            <Component as StatsGuest>::gain_experience(stats, BASE_EXP_REQUIREMENT, 20);
+        assert_eq!(stats.level, 2);
+        assert_eq!((stats.health, stats.max_health), (30, 120));
+        let message = message.unwrap();
+        assert_eq!(message.new_level, 2);
+        assert_eq!(message.health_gained, HEALTH_PER_LEVEL);
+        assert_eq!(
+            message.message,
+            "LEVEL UP! You are now level 2! HP +20, ATK +3, DEF +2!"
+        );
+    }
+
+    #[test]
+    /// Test a player at full health stays at full health after leveling up.
+    fn test_gain_experience_level_up_full_health() {
+        let (stats, _) = process_experience_gain(create_default_stats(), BASE_EXP_REQUIREMENT, 20);
+        assert_eq!(stats.health, stats.max_health);
+        assert_eq!(stats.max_health, 120);
+    }
+
+    #[test]
+    /// Test gaining too little experience gives no level up message.
+    fn test_gain_experience_no_level_up() {
+        let (stats, message) = process_experience_gain(create_default_stats(), 10, 20);
+        assert_eq!(stats.level, 1);
+        assert!(message.is_none());
+    }
+
+    #[test]
+    /// Test a player at the level cap keeps their level and stats.
+    fn test_gain_experience_at_max_level() {
+        let capped = PlayerStats {
+            level: 20,
+            ..create_default_stats()
+        };
+        let (stats, message) = process_experience_gain(capped.clone(), 9999, 20);
+        assert_eq!(stats.level, 20);
+        assert_eq!(
+            (stats.max_health, stats.attack, stats.defense),
+            (capped.max_health, capped.attack, capped.defense)
+        );
+        assert!(message.is_none());
+        assert!(at_max_level(9999, 20, 20));
+        assert!(!should_level_up(9999, 20, 20));
+    }
+
+    #[test]
+    /// Test health percentage edge cases.
+    fn test_health_percentage() {
+        assert_eq!(health_percentage(0, 100), 0);
+        assert_eq!(health_percentage(100, 100), 100);
+        assert_eq!(health_percentage(25, 100), 25);
+        assert_eq!(health_percentage(40, 0), 0);
+        assert_eq!(health_percentage(150, 100), 100);
+    }
+
+    #[test]
+    /// Test casting without enough mana fails and changes nothing.
+    fn test_cast_spell_not_enough_mana() {
+        let broke = PlayerStats {
+            mana: 5,
+            ..create_default_stats()
+        };
+        assert_eq!(
+            cast_spell(broke.clone(), 1, 15).unwrap_err(),
+            "Not enough mana"
+        );
+        let stats = cast_spell(create_default_stats(), 2, 10).unwrap();
+        assert_eq!(stats.mana, STARTING_MANA - 10);
+        assert_eq!(stats.spells_on_cooldown, vec![(2, 1)]);
+    }
+
+    #[test]
+    /// Test a cast with a cost other than the spell's own is rejected.
+    fn test_cast_spell_wrong_mana_cost() {
+        let stats = create_default_stats();
+        assert_eq!(
+            cast_spell(stats.clone(), 1, 0).unwrap_err(),
+            "Wrong mana cost"
+        );
+        assert_eq!(
+            cast_spell(stats.clone(), 1, 30).unwrap_err(),
+            "Wrong mana cost"
+        );
+        assert_eq!(cast_spell(stats, 1, 15).unwrap().mana, STARTING_MANA - 15);
+    }
+
+    #[test]
+    /// Test a spell comes off cooldown after its cooldown turns.
+    fn test_spell_cooldown_expires() {
+        let stats = cast_spell(create_default_stats(), 1, 15).unwrap();
+        assert_eq!(
+            cast_spell(stats.clone(), 1, 15).unwrap_err(),
+            "Spell on cooldown"
+        );
+        let stats = tick_cooldowns(stats);
+        assert_eq!(stats.spells_on_cooldown, vec![(1, 1)]);
+        assert!(cast_spell(stats.clone(), 1, 15).is_err());
+        let stats = tick_cooldowns(stats);
+        assert!(stats.spells_on_cooldown.is_empty());
+        assert!(cast_spell(stats, 1, 15).is_ok());
+    }
+
+    #[test]
+    /// Test mana regeneration never exceeds max mana.
+    fn test_regen_mana_caps() {
+        let stats = PlayerStats {
+            mana: 45,
+            ..create_default_stats()
+        };
+        assert_eq!(regen_mana(stats.clone(), 3).mana, 48);
+        assert_eq!(regen_mana(stats, u32::MAX).mana, STARTING_MANA);
+        assert_eq!(<Component as StatsGuest>::spell_mana_cost(3), 20);
+        assert_eq!(<Component as StatsGuest>::spell_mana_cost(9), 0);
+    }
 }